@@ -3,7 +3,7 @@
 //! # Overview
 //!
 //! This target measures key `DirectedGraph` operations such as creation,
-//! insertion, neighbor retrieval, and existence checks.
+//! insertion, neighbor retrieval, degree queries, and existence checks.
 //!
 //! # Run
 //!
@@ -123,3 +123,71 @@ fn get_node_by_id_from_directed_graph(bencher: Bencher) {
             let _node = dg.get_node_by_id("A");
         });
 }
+
+/// Builds a star graph where node `HUB` has an edge to each of `leaf_count` leaves.
+fn build_star_graph(leaf_count: usize) -> DirectedGraph {
+    let hub = DefaultNode::new("HUB".to_string());
+    let mut graph = DirectedGraph::default();
+    graph.insert_node(hub.clone());
+    for index in 0..leaf_count {
+        let leaf = DefaultNode::new(format!("L{}", index));
+        graph.insert_node(leaf.clone());
+        graph.insert_edge(&hub, &leaf, Some(1));
+    }
+    graph
+}
+
+#[bench(args = [10, 1_000])]
+fn out_degree_in_directed_graph(bencher: Bencher, leaf_count: usize) {
+    let hub = DefaultNode::new("HUB".to_string());
+    bencher
+        .with_inputs(|| build_star_graph(leaf_count))
+        .bench_refs(|dg| dg.out_degree(&hub));
+}
+
+#[bench(args = [10, 1_000])]
+fn neighbors_count_in_directed_graph(bencher: Bencher, leaf_count: usize) {
+    let hub = DefaultNode::new("HUB".to_string());
+    bencher
+        .with_inputs(|| build_star_graph(leaf_count))
+        .bench_refs(|dg| dg.neighbors(&hub).count());
+}
+
+/// Builds a sparse graph of `node_count` nodes where every node has edges to
+/// the next three nodes (wrapping around), like a road network.
+fn build_sparse_graph(node_count: usize) -> DirectedGraph {
+    let nodes: Vec<DefaultNode> = (0..node_count)
+        .map(|index| DefaultNode::new(format!("N{}", index)))
+        .collect();
+    let mut graph = DirectedGraph::new(nodes.clone());
+    for index in 0..node_count {
+        for step in 1..=3 {
+            graph.insert_edge(&nodes[index], &nodes[(index + step) % node_count], Some(1));
+        }
+    }
+    graph
+}
+
+/// Neighbor lookup through the indexed adjacency lists, `O(degree)`.
+#[bench(args = [1_000])]
+fn neighbors_in_sparse_directed_graph(bencher: Bencher, node_count: usize) {
+    let node = DefaultNode::new(format!("N{}", node_count / 2));
+    bencher
+        .with_inputs(|| build_sparse_graph(node_count))
+        .bench_refs(|dg| dg.neighbors(&node).count());
+}
+
+/// Neighbor lookup by scanning every edge, `O(E)`, as a flat edge list would
+/// need; the baseline for `neighbors_in_sparse_directed_graph`.
+#[bench(args = [1_000])]
+fn edge_scan_neighbors_in_sparse_directed_graph(bencher: Bencher, node_count: usize) {
+    let node = DefaultNode::new(format!("N{}", node_count / 2));
+    bencher
+        .with_inputs(|| build_sparse_graph(node_count))
+        .bench_refs(|dg| {
+            dg.all_edges()
+                .into_iter()
+                .filter(|(from, _, _)| *from == &node)
+                .count()
+        });
+}
@@ -0,0 +1,49 @@
+//! Prints the `tracing` spans emitted by a Dijkstra search.
+//!
+//! Run with:
+//!
+//! ```text
+//! cargo run --example tracing_spans --features tracing
+//! ```
+//!
+//! Each closed span is printed with its fields (node/edge counts, start and
+//! end IDs) and its busy/idle durations.
+
+use shortest_path_finder::algorithms::algorithm::{Algorithm, SearchResult};
+use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+use shortest_path_finder::graphs::directed::DirectedGraph;
+use shortest_path_finder::graphs::graph::Graph;
+use shortest_path_finder::nodes::default_node::DefaultNode;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+fn main() {
+    tracing_subscriber::fmt()
+        .with_span_events(FmtSpan::CLOSE)
+        .with_target(false)
+        .init();
+
+    let mut graph = DirectedGraph::default();
+    let nodes: Vec<DefaultNode> = ["A", "B", "C", "D"]
+        .iter()
+        .map(|id| DefaultNode::new(id.to_string()))
+        .collect();
+    for node in &nodes {
+        graph.insert_node(node.clone());
+    }
+    for (from, to, weight) in [(0, 1, 4), (1, 2, 3), (0, 2, 9), (2, 3, 1)] {
+        assert!(
+            graph
+                .insert_edge(&nodes[from], &nodes[to], Some(weight))
+                .is_none()
+        );
+    }
+
+    let result = DijkstraAlgorithm::new(graph)
+        .shortest_path("A", "D")
+        .expect("D should be reachable from A");
+    println!(
+        "A -> D has distance {} over {} nodes",
+        result.get_total_distance(),
+        result.get_path().len()
+    );
+}
@@ -53,6 +53,7 @@ use std::{
     collections::{BinaryHeap, HashMap},
     error::Error,
     fmt::Display,
+    time::Instant,
 };
 
 use log::warn;
@@ -60,12 +61,13 @@ use log::warn;
 use crate::{
     algorithms::{
         a_star_algorithm::utils::{determine_path_cost, prepare_g_cost_map},
-        algorithm::{Algorithm, SearchResult},
+        algorithm::{Algorithm, SearchResult, SearchStats},
     },
     graphs::graph::Graph,
     nodes::trait_decl::{
         coordinate_datatype::CoordinateDatatype, coordinates_node::CoordinatesNode,
     },
+    output::distance_format::DistanceFormat,
     weight_types::numeric_datatype::NumericDatatype,
 };
 
@@ -157,21 +159,103 @@ impl<WD: NumericDatatype, N: CoordinatesNode, G: Graph<Node = N, Weight = WD> +
         start_node_id: &str,
         end_node_id: &str,
     ) -> Result<Self::AlgorithmSearchResult, Self::ExecutionError> {
+        self.search(start_node_id, end_node_id, &mut SearchStats::default())
+    }
+}
+
+impl<WD: NumericDatatype, N: CoordinatesNode, G: Graph<Node = N, Weight = WD> + Display>
+    AStar<WD, N, G>
+{
+    /// Creates a new [`AStar`] instance bound to `graph`.
+    ///
+    /// # Parameters
+    ///
+    /// - `graph`: concrete graph used during subsequent path search calls.
+    ///
+    /// # Returns
+    ///
+    /// A ready-to-use algorithm instance.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shortest_path_finder::algorithms::a_star_algorithm::a_star::AStar;
+    /// use shortest_path_finder::graphs::two_dimensional_coordinate_graph::TwoDimensionalCoordinateGraph;
+    ///
+    /// let graph = TwoDimensionalCoordinateGraph::<i32>::new(vec![]);
+    /// let _a_star = AStar::new(graph);
+    /// ```
+    pub fn new(graph: G) -> Self {
+        Self { graph }
+    }
+
+    /// Finds the shortest path like [`Algorithm::shortest_path`] and reports
+    /// how much work the search did.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: identifier of the start node.
+    /// - `end_node_id`: identifier of the destination node.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((AStarSearchResult<...>, SearchStats))` with the same result as
+    ///   [`Algorithm::shortest_path`]. `nodes_expanded` counts nodes popped
+    ///   from the open queue and `edges_relaxed` counts neighbours inserted
+    ///   into it with a new best g-cost.
+    /// - `Err(AStarExecutionError)` under the same conditions as
+    ///   [`Algorithm::shortest_path`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use shortest_path_finder::algorithms::a_star_algorithm::a_star::AStar;
+    /// use shortest_path_finder::graphs::two_dimensional_coordinate_graph::TwoDimensionalCoordinateGraph;
+    /// use shortest_path_finder::nodes::two_dimensional_node::TwoDimensionalNode;
+    ///
+    /// let node = TwoDimensionalNode::new(0, 0, "A".to_string()).unwrap();
+    /// let graph = TwoDimensionalCoordinateGraph::new(vec![node]);
+    ///
+    /// let (_result, stats) = AStar::new(graph).shortest_path_with_stats("A", "A").unwrap();
+    /// assert_eq!(stats.nodes_expanded, 1);
+    /// ```
+    pub fn shortest_path_with_stats(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+    ) -> Result<(AStarSearchResult<WD, N>, SearchStats), AStarExecutionError> {
+        let started = Instant::now();
+        let mut stats = SearchStats::default();
+        let result = self.search(start_node_id, end_node_id, &mut stats)?;
+        stats.elapsed = started.elapsed();
+        Ok((result, stats))
+    }
+
+    /// Runs the open/closed queue loop and records its work in `stats`.
+    ///
+    /// Shared core of [`Algorithm::shortest_path`] and
+    /// [`AStar::shortest_path_with_stats`].
+    fn search(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+        stats: &mut SearchStats,
+    ) -> Result<AStarSearchResult<WD, N>, AStarExecutionError> {
         if !self.graph.is_weighted() {
-            return Err(Self::ExecutionError::new(
+            return Err(AStarExecutionError::new(
                 "The graph needs to be weighted for the A* algorithm to work!".to_string(),
             ));
         }
 
         let start_node = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
-            Self::ExecutionError::new(format!(
+            AStarExecutionError::new(format!(
                 "Start node with id '{}' not found in the graph!",
                 start_node_id
             ))
         })?;
 
         let end_node = self.graph.get_node_by_id(end_node_id).ok_or_else(|| {
-            Self::ExecutionError::new(format!(
+            AStarExecutionError::new(format!(
                 "End node with id '{}' not found in the graph!",
                 end_node_id
             ))
@@ -202,6 +286,7 @@ impl<WD: NumericDatatype, N: CoordinatesNode, G: Graph<Node = N, Weight = WD> +
             predecessor,
         }) = open_queue.pop()
         {
+            stats.nodes_expanded += 1;
             // if the node is the destination node -> break
             if node == end_node {
                 // move the node to the "closed_queue", don't change any data of the node, because
@@ -263,6 +348,8 @@ impl<WD: NumericDatatype, N: CoordinatesNode, G: Graph<Node = N, Weight = WD> +
                     // Persist best-known g-cost for future comparisons.
                     g_costs.insert(neighbour.get_id().to_string(), tentative_g_cost);
 
+                    stats.edges_relaxed += 1;
+
                     // Insert queue element with updated predecessor and heuristic score.
                     open_queue.push(AStarQueueElement::new(
                         neighbour,
@@ -277,36 +364,9 @@ impl<WD: NumericDatatype, N: CoordinatesNode, G: Graph<Node = N, Weight = WD> +
         // the last element in the "closed_queue" is the destination node, so we can reconstruct
         // the path from the destination node to the start node by following the predecessors
         let (path, distance) =
-            determine_path_cost(closed_queue).map_err(|e| Self::ExecutionError::new(e.message))?;
-
-        AStarSearchResult::new(distance, path).map_err(Self::ExecutionError::new)
-    }
-}
+            determine_path_cost(closed_queue).map_err(|e| AStarExecutionError::new(e.message))?;
 
-impl<WD: NumericDatatype, N: CoordinatesNode, G: Graph<Node = N, Weight = WD> + Display>
-    AStar<WD, N, G>
-{
-    /// Creates a new [`AStar`] instance bound to `graph`.
-    ///
-    /// # Parameters
-    ///
-    /// - `graph`: concrete graph used during subsequent path search calls.
-    ///
-    /// # Returns
-    ///
-    /// A ready-to-use algorithm instance.
-    ///
-    /// # Example
-    ///
-    /// ```no_run
-    /// use shortest_path_finder::algorithms::a_star_algorithm::a_star::AStar;
-    /// use shortest_path_finder::graphs::two_dimensional_coordinate_graph::TwoDimensionalCoordinateGraph;
-    ///
-    /// let graph = TwoDimensionalCoordinateGraph::<i32>::new(vec![]);
-    /// let _a_star = AStar::new(graph);
-    /// ```
-    pub fn new(graph: G) -> Self {
-        Self { graph }
+        AStarSearchResult::new(distance, path).map_err(AStarExecutionError::new)
     }
 
     /// Internal heuristic function used for A* queue prioritization.
@@ -434,15 +494,46 @@ impl<WD: NumericDatatype, N: CoordinatesNode> AStarSearchResult<WD, N> {
 
         Ok(Self { path, distance })
     }
+
+    /// Formats the result like its `Display` impl, but renders the distance
+    /// with `format` (unit label and precision).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::a_star_algorithm::a_star::AStarSearchResult;
+    /// use shortest_path_finder::nodes::two_dimensional_node::TwoDimensionalNode;
+    /// use shortest_path_finder::output::distance_format::DistanceFormat;
+    ///
+    /// let path = vec![
+    ///     TwoDimensionalNode::new(0, 0, "A".to_string()).unwrap(),
+    ///     TwoDimensionalNode::new(1, 1, "B".to_string()).unwrap(),
+    /// ];
+    /// let result = AStarSearchResult::new(1.41421f32, path).unwrap();
+    /// let format = DistanceFormat {
+    ///     unit: Some("km".to_string()),
+    ///     precision: Some(2),
+    /// };
+    /// assert_eq!(result.format_with(&format), "Path: A -> B\n Distance: 1.41 km");
+    /// ```
+    pub fn format_with(&self, format: &DistanceFormat) -> String {
+        let formatted_path = self
+            .path
+            .iter()
+            .map(|node| node.get_id())
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        format!(
+            "Path: {}\n Distance: {}",
+            formatted_path,
+            format.format(&self.distance)
+        )
+    }
 }
 
 impl<WD: NumericDatatype, N: CoordinatesNode> Display for AStarSearchResult<WD, N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut formatted_path = self.path[0].get_id().to_string();
-        for node in &self.path[1..] {
-            formatted_path = format!("{} -> {}", formatted_path, node.get_id())
-        }
-        write!(f, "Path: {}\n Distance: {}", formatted_path, self.distance)
+        f.write_str(&self.format_with(&DistanceFormat::default()))
     }
 }
 
@@ -1,9 +1,10 @@
 //! Core algorithm abstractions used by this crate.
 //!
-//! This module provides three key building blocks:
+//! This module provides these key building blocks:
 //! - [`Algorithms`]: a user-facing selector for supported shortest-path algorithms.
 //! - [`Algorithm`]: a trait that algorithm engines (for example Dijkstra or A*) implement.
 //! - [`SearchResult`]: a trait describing the result object returned by an algorithm run.
+//! - [`SearchStats`]: work counters of one search, for comparing algorithms.
 //!
 //! The traits in this module are intentionally generic so they can be reused for different
 //! graph implementations, node types, and numeric weight/distance types.
@@ -16,7 +17,7 @@
 //! use shortest_path_finder::algorithms::algorithm::Algorithms;
 //!
 //! let algorithm = Algorithms::get_from_string("Dijkstra");
-//! assert!(matches!(algorithm, Algorithms::Dijkstra));
+//! assert!(matches!(algorithm, Some(Algorithms::Dijkstra)));
 //! ```
 //!
 //! Consume a search result produced by a concrete algorithm implementation:
@@ -39,9 +40,13 @@
 use std::{
     error::Error,
     fmt::{Debug, Display},
+    time::Duration,
 };
 
-use crate::graphs::graph::GraphNode;
+use crate::{
+    algorithms::registry::is_algorithm_registered, graphs::graph::GraphNode,
+    output::json::json_string,
+};
 
 /// Enumeration over all algorithms currently exposed by the application layer.
 ///
@@ -52,7 +57,24 @@ pub enum Algorithms {
     /// Select the Dijkstra shortest-path algorithm.
     Dijkstra,
     /// Select the A* shortest-path algorithm.
+    ///
+    /// On graphs without node coordinates the heuristic is zero, so the
+    /// CLI runs Dijkstra instead, which finds the same paths.
     AStar,
+    /// Select Bellman-Ford, which accepts negative edge weights.
+    ///
+    /// Choosing it makes the file parser accept signed directed weights
+    /// (`A->B:-3`).
+    BellmanFord,
+    /// Select breadth-first search, which finds the path with the fewest
+    /// edges and is the algorithm for unweighted graphs.
+    Bfs,
+    /// Select depth-first search, which returns the first path it finds
+    /// without optimizing its distance.
+    Dfs,
+    /// Select a custom algorithm registered under this name with
+    /// [`register_algorithm`](crate::algorithms::registry::register_algorithm).
+    Custom(String),
 }
 
 impl Algorithms {
@@ -64,30 +86,45 @@ impl Algorithms {
     ///
     /// Recognized values are currently:
     /// - `"Dijkstra"`
-    /// - `"AStar"`
+    /// - `"AStar"` or `"a_star"`
+    /// - `"BellmanFord"` or `"bellman-ford"`
+    /// - `"BFS"` or `"bfs"`
+    /// - `"DFS"` or `"dfs"`
     ///
-    /// Any unknown value falls back to [`Algorithms::Dijkstra`].
+    /// Other values select a custom algorithm if one is registered under
+    /// that name (see [`crate::algorithms::registry`]).
     ///
     /// # Returns
     ///
-    /// A concrete [`Algorithms`] variant.
+    /// - `Some` with the matching [`Algorithms`] variant.
+    /// - `None` if `src` is neither a built-in nor a registered name.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use shortest_path_finder::algorithms::algorithm::Algorithms;
     ///
-    /// assert!(matches!(Algorithms::get_from_string("Dijkstra"), Algorithms::Dijkstra));
-    /// assert!(matches!(Algorithms::get_from_string("AStar"), Algorithms::AStar));
-    ///
-    /// // Unknown input currently defaults to Dijkstra.
-    /// assert!(matches!(Algorithms::get_from_string("unknown"), Algorithms::Dijkstra));
+    /// assert!(matches!(Algorithms::get_from_string("Dijkstra"), Some(Algorithms::Dijkstra)));
+    /// assert!(matches!(Algorithms::get_from_string("AStar"), Some(Algorithms::AStar)));
+    /// assert!(matches!(Algorithms::get_from_string("a_star"), Some(Algorithms::AStar)));
+    /// assert!(matches!(
+    ///     Algorithms::get_from_string("bellman-ford"),
+    ///     Some(Algorithms::BellmanFord)
+    /// ));
+    /// assert!(matches!(Algorithms::get_from_string("BFS"), Some(Algorithms::Bfs)));
+    /// assert!(matches!(Algorithms::get_from_string("dfs"), Some(Algorithms::Dfs)));
+    ///
+    /// assert!(Algorithms::get_from_string("unknown").is_none());
     /// ```
-    pub fn get_from_string(src: &str) -> Self {
+    pub fn get_from_string(src: &str) -> Option<Self> {
         match src {
-            "Dijkstra" => Self::Dijkstra,
-            "AStar" => Self::AStar,
-            _ => Self::Dijkstra,
+            "Dijkstra" => Some(Self::Dijkstra),
+            "AStar" | "a_star" => Some(Self::AStar),
+            "BellmanFord" | "bellman-ford" => Some(Self::BellmanFord),
+            "BFS" | "bfs" => Some(Self::Bfs),
+            "DFS" | "dfs" => Some(Self::Dfs),
+            custom if is_algorithm_registered(custom) => Some(Self::Custom(custom.to_string())),
+            _ => None,
         }
     }
 }
@@ -231,4 +268,89 @@ pub trait SearchResult: Display + Debug {
     /// assert_eq!(result.get_path().len(), 3);
     /// ```
     fn get_path(&self) -> &Vec<Self::Node>;
+
+    /// Encodes the result as a JSON object with the node IDs of the path and
+    /// the total distance.
+    ///
+    /// The distance is written with its [`Display`] form, without the unit
+    /// or precision of the text output.
+    ///
+    /// # Returns
+    ///
+    /// Compact JSON such as `{"path":["A","B","D"],"distance":9}`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::algorithm::SearchResult;
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraSearchResult;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let path = vec![
+    ///     DefaultNode::new("A".to_string()),
+    ///     DefaultNode::new("B \"north\"".to_string()),
+    /// ];
+    /// let result = DijkstraSearchResult::new(path, 4u16).unwrap();
+    ///
+    /// assert_eq!(
+    ///     result.to_json(),
+    ///     r#"{"path":["A","B \"north\""],"distance":4}"#
+    /// );
+    /// ```
+    fn to_json(&self) -> String {
+        let path: Vec<String> = self
+            .get_path()
+            .iter()
+            .map(|node| json_string(node.get_id()))
+            .collect();
+        format!(
+            "{{\"path\":[{}],\"distance\":{}}}",
+            path.join(","),
+            self.get_total_distance()
+        )
+    }
+}
+
+/// Work counters of one search, for comparing algorithms on the same query.
+///
+/// Returned next to the search result by the `shortest_path_with_stats`
+/// methods of the algorithms that support it, for example
+/// [`DijkstraAlgorithm::shortest_path_with_stats`](crate::algorithms::dijkstra::DijkstraAlgorithm::shortest_path_with_stats).
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use shortest_path_finder::algorithms::algorithm::SearchStats;
+///
+/// let stats = SearchStats {
+///     elapsed: Duration::from_micros(1500),
+///     nodes_expanded: 4,
+///     edges_relaxed: 6,
+/// };
+/// assert_eq!(
+///     stats.to_string(),
+///     "elapsed: 1.5ms, nodes expanded: 4, edges relaxed: 6"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    /// Wall-clock time of the search, including input validation.
+    pub elapsed: Duration,
+    /// Nodes taken from the queue and expanded; stale queue entries that are
+    /// skipped do not count.
+    pub nodes_expanded: usize,
+    /// Edges whose relaxation lowered the tentative distance of their target.
+    pub edges_relaxed: usize,
+}
+
+impl Display for SearchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "elapsed: {:?}, nodes expanded: {}, edges relaxed: {}",
+            self.elapsed, self.nodes_expanded, self.edges_relaxed
+        )
+    }
 }
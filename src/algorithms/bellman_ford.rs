@@ -0,0 +1,311 @@
+//! Bellman-Ford shortest paths for graphs with negative edge weights.
+//!
+//! # Overview
+//!
+//! [`BellmanFordAlgorithm`] finds the shortest path on graphs whose edges may
+//! be negative, such as a
+//! [`SignedDirectedGraph`](crate::graphs::directed::SignedDirectedGraph).
+//! Dijkstra rejects those graphs; Bellman-Ford instead relaxes every edge
+//! up to `|V| - 1` times and reports a negative cycle reachable from the start
+//! node as an error, since no shortest path exists then.
+//!
+//! # Main types
+//!
+//! - [`BellmanFordAlgorithm`]: algorithm engine operating on a concrete graph.
+//! - [`BellmanFordSearchResult`]: path and total weight of a successful search.
+//! - [`BellmanFordError`]: execution error payload.
+//!
+//! # Example
+//!
+//! ```rust
+//! use shortest_path_finder::algorithms::algorithm::{Algorithm, SearchResult};
+//! use shortest_path_finder::algorithms::bellman_ford::BellmanFordAlgorithm;
+//! use shortest_path_finder::graphs::directed::SignedDirectedGraph;
+//!
+//! let edges = vec![
+//!     ("A".to_string(), "B".to_string(), 4),
+//!     ("A".to_string(), "C".to_string(), 5),
+//!     ("C".to_string(), "B".to_string(), -3),
+//! ];
+//! let graph = SignedDirectedGraph::from_edges(&edges).unwrap();
+//!
+//! let result = BellmanFordAlgorithm::new(graph).shortest_path("A", "B").unwrap();
+//! assert_eq!(result.get_total_distance(), 2);
+//! assert_eq!(result.to_string(), "A -> C -> B (total: 2)");
+//! ```
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Debug, Display},
+};
+
+use crate::{
+    algorithms::algorithm::{Algorithm, SearchResult},
+    graphs::graph::{Graph, GraphNode, GraphWeight},
+    output::distance_format::DistanceFormat,
+};
+
+/// Bellman-Ford engine bound to one graph.
+///
+/// # Type Parameters
+///
+/// - `G`: Any graph type; its weights may be negative.
+#[derive(Debug, Clone)]
+pub struct BellmanFordAlgorithm<G: Graph + Display> {
+    /// Graph instance processed by this algorithm implementation.
+    graph: G,
+}
+
+impl<G: Graph + Display> BellmanFordAlgorithm<G> {
+    /// Creates a new [`BellmanFordAlgorithm`] bound to a graph instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::bellman_ford::BellmanFordAlgorithm;
+    /// use shortest_path_finder::graphs::directed::SignedDirectedGraph;
+    ///
+    /// let _algorithm = BellmanFordAlgorithm::new(SignedDirectedGraph::new_signed(vec![]));
+    /// ```
+    pub fn new(graph: G) -> Self {
+        Self { graph }
+    }
+}
+
+impl<G: Graph + Display> Algorithm for BellmanFordAlgorithm<G>
+where
+    G::Node: Debug,
+{
+    type ExecutionError = BellmanFordError;
+
+    type AlgorithmSearchResult = BellmanFordSearchResult<G::Node, G::Weight>;
+
+    type NodeOfUsedGraph = G::Node;
+
+    /// Finds the shortest path from `start_node_id` to `end_node_id`, allowing
+    /// negative edge weights.
+    ///
+    /// Every round relaxes all edges leaving nodes that have been reached so
+    /// far. A simple path has at most `|V| - 1` edges, so after `|V| - 1`
+    /// rounds every shortest distance is final; the loop stops earlier once a
+    /// round changes nothing.
+    ///
+    /// # Negative cycles
+    ///
+    /// After the `|V| - 1` rounds one extra pass checks every edge once more.
+    /// If any edge can still lower a distance, a negative cycle is reachable
+    /// from the start node and the search returns an error. The check is a
+    /// single bounded pass, so detection always terminates after at most
+    /// `|V|` rounds in total. Negative cycles that cannot be reached from the
+    /// start node have no distance and are not reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BellmanFordError`] if a node is missing, the end node
+    /// cannot be reached, or a negative cycle is reachable from the start
+    /// node.
+    fn shortest_path(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+    ) -> Result<BellmanFordSearchResult<G::Node, G::Weight>, BellmanFordError> {
+        let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+            BellmanFordError::new(format!(
+                "The start node {} is not in the graph {}!",
+                start_node_id, self.graph
+            ))
+        })?;
+        let end = self.graph.get_node_by_id(end_node_id).ok_or_else(|| {
+            BellmanFordError::new(format!(
+                "The end node {} is not in the graph {}!",
+                end_node_id, self.graph
+            ))
+        })?;
+
+        let nodes = self.graph.get_all_nodes();
+        let index_by_id: HashMap<&str, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.get_id(), index))
+            .collect();
+
+        // `None` marks nodes that have not been reached yet.
+        let mut distances: Vec<Option<G::Weight>> = vec![None; nodes.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; nodes.len()];
+        distances[index_by_id[start.get_id()]] = Some(G::Weight::zero());
+
+        for _ in 1..nodes.len() {
+            if !self.relax_all_edges(&index_by_id, &mut distances, &mut previous) {
+                break;
+            }
+        }
+
+        // Any improvement after |V| - 1 rounds can only come from a negative cycle.
+        if self.relax_all_edges(&index_by_id, &mut distances, &mut previous) {
+            return Err(BellmanFordError::new(format!(
+                "A negative cycle is reachable from {}, so no shortest path to {} exists!",
+                start_node_id, end_node_id
+            )));
+        }
+
+        let end_index = index_by_id[end.get_id()];
+        let Some(distance) = distances[end_index] else {
+            return Err(BellmanFordError::new(format!(
+                "No path from {} to {} exists!",
+                start_node_id, end_node_id
+            )));
+        };
+
+        let mut path = vec![nodes[end_index].clone()];
+        let mut current = end_index;
+        while let Some(predecessor) = previous[current] {
+            path.push(nodes[predecessor].clone());
+            current = predecessor;
+        }
+        path.reverse();
+
+        Ok(BellmanFordSearchResult::new(path, distance))
+    }
+}
+
+impl<G: Graph + Display> BellmanFordAlgorithm<G> {
+    /// Relaxes every edge leaving a reached node once.
+    ///
+    /// # Returns
+    ///
+    /// `true` if at least one distance was lowered.
+    fn relax_all_edges(
+        &self,
+        index_by_id: &HashMap<&str, usize>,
+        distances: &mut [Option<G::Weight>],
+        previous: &mut [Option<usize>],
+    ) -> bool {
+        let mut changed = false;
+        for (index, node) in self.graph.get_all_nodes().iter().enumerate() {
+            let Some(distance) = distances[index] else {
+                continue;
+            };
+            for (neighbour, weight) in self.graph.neighbors(node) {
+                let neighbour_index = index_by_id[neighbour.get_id()];
+                let candidate = distance + weight;
+                if distances[neighbour_index].is_none_or(|known| candidate < known) {
+                    distances[neighbour_index] = Some(candidate);
+                    previous[neighbour_index] = Some(index);
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+}
+
+/// Search result produced by [`BellmanFordAlgorithm`].
+#[derive(Debug, Clone)]
+pub struct BellmanFordSearchResult<N: GraphNode, W: GraphWeight> {
+    /// Ordered node sequence from start node to destination node.
+    pub path: Vec<N>,
+    /// Total weight of `path`; may be negative.
+    pub distance: W,
+}
+
+impl<N: GraphNode, W: GraphWeight> BellmanFordSearchResult<N, W> {
+    /// Creates a result from a start-to-end path and its total weight.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::bellman_ford::BellmanFordSearchResult;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let path = vec![DefaultNode::new("A".to_string()), DefaultNode::new("B".to_string())];
+    /// assert_eq!(BellmanFordSearchResult::new(path, -3).to_string(), "A -> B (total: -3)");
+    /// ```
+    pub fn new(path: Vec<N>, distance: W) -> Self {
+        Self { path, distance }
+    }
+
+    /// Formats the result like [`Display`], but with the distance rendered
+    /// through `format` (unit label and precision).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::bellman_ford::BellmanFordSearchResult;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    /// use shortest_path_finder::output::distance_format::DistanceFormat;
+    ///
+    /// let result = BellmanFordSearchResult::new(vec![DefaultNode::new("A".to_string())], 0);
+    /// let format = DistanceFormat {
+    ///     unit: Some("EUR".to_string()),
+    ///     precision: None,
+    /// };
+    /// assert_eq!(result.format_with(&format), "A (total: 0 EUR)");
+    /// ```
+    pub fn format_with(&self, format: &DistanceFormat) -> String {
+        let formatted_path = self
+            .path
+            .iter()
+            .map(|node| node.get_id())
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        format!(
+            "{} (total: {})",
+            formatted_path,
+            format.format(&self.distance)
+        )
+    }
+}
+
+/// Formats the path on one line followed by the distance, e.g.
+/// `A -> C -> B (total: 2)`.
+impl<N: GraphNode, W: GraphWeight> Display for BellmanFordSearchResult<N, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.format_with(&DistanceFormat::default()))
+    }
+}
+
+impl<N: GraphNode + Debug, W: GraphWeight> SearchResult for BellmanFordSearchResult<N, W> {
+    type Distance = W;
+
+    type Node = N;
+
+    fn get_total_distance(&self) -> W {
+        self.distance
+    }
+
+    fn get_path(&self) -> &Vec<N> {
+        &self.path
+    }
+}
+
+/// Error returned when a Bellman-Ford search fails.
+#[derive(Debug)]
+pub struct BellmanFordError {
+    /// Human-readable explanation of the failure.
+    pub message: String,
+}
+
+impl BellmanFordError {
+    /// Creates a new [`BellmanFordError`] from a message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::bellman_ford::BellmanFordError;
+    ///
+    /// let err = BellmanFordError::new("negative cycle".to_string());
+    /// assert_eq!(err.to_string(), "negative cycle");
+    /// ```
+    pub fn new(message: String) -> Self {
+        Self { message }
+    }
+}
+
+impl Display for BellmanFordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for BellmanFordError {}
@@ -0,0 +1,240 @@
+//! Breadth-first search for fewest-hop paths.
+//!
+//! # Overview
+//!
+//! [`BfsAlgorithm`] finds the path with the fewest edges between two nodes.
+//! It ignores edge weights entirely, which makes it the native algorithm for
+//! [`UnweightedGraph`](crate::graphs::unweighted::UnweightedGraph), but it
+//! accepts any [`Graph`] and respects edge direction.
+//!
+//! # Main types
+//!
+//! - [`BfsAlgorithm`]: algorithm engine operating on a concrete graph.
+//! - [`BfsSearchResult`]: path and hop count of a successful search.
+//! - [`BfsError`]: execution error payload.
+//!
+//! # Example
+//!
+//! ```rust
+//! use shortest_path_finder::algorithms::algorithm::{Algorithm, SearchResult};
+//! use shortest_path_finder::algorithms::bfs::BfsAlgorithm;
+//! use shortest_path_finder::graphs::graph::Graph;
+//! use shortest_path_finder::graphs::unweighted::UnweightedGraph;
+//! use shortest_path_finder::nodes::default_node::DefaultNode;
+//!
+//! let nodes: Vec<DefaultNode> = ["A", "B", "C"]
+//!     .iter()
+//!     .map(|id| DefaultNode::new(id.to_string()))
+//!     .collect();
+//! let mut graph = UnweightedGraph::new_directed(nodes.clone());
+//! graph.insert_edge(&nodes[0], &nodes[1], None);
+//! graph.insert_edge(&nodes[1], &nodes[2], None);
+//!
+//! let result = BfsAlgorithm::new(graph).shortest_path("A", "C").unwrap();
+//! assert_eq!(result.get_total_distance(), 2);
+//! assert_eq!(result.to_string(), "A -> B -> C (hops: 2)");
+//! ```
+
+use std::{
+    collections::{HashMap, VecDeque},
+    error::Error,
+    fmt::{Debug, Display},
+};
+
+use crate::{
+    algorithms::algorithm::{Algorithm, SearchResult},
+    graphs::graph::{Graph, GraphNode},
+};
+
+/// Breadth-first search engine bound to one graph.
+///
+/// # Type Parameters
+///
+/// - `G`: Any graph type; its weights are ignored.
+#[derive(Debug, Clone)]
+pub struct BfsAlgorithm<G: Graph + Display> {
+    /// Graph instance processed by this algorithm implementation.
+    graph: G,
+}
+
+impl<G: Graph + Display> BfsAlgorithm<G> {
+    /// Creates a new [`BfsAlgorithm`] bound to a graph instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::bfs::BfsAlgorithm;
+    /// use shortest_path_finder::graphs::unweighted::UnweightedGraph;
+    ///
+    /// let _algorithm = BfsAlgorithm::new(UnweightedGraph::new_undirected(vec![]));
+    /// ```
+    pub fn new(graph: G) -> Self {
+        Self { graph }
+    }
+}
+
+impl<G: Graph + Display> Algorithm for BfsAlgorithm<G>
+where
+    G::Node: Debug,
+{
+    type ExecutionError = BfsError;
+
+    type AlgorithmSearchResult = BfsSearchResult<G::Node>;
+
+    type NodeOfUsedGraph = G::Node;
+
+    /// Finds a path with the fewest edges from `start_node_id` to `end_node_id`.
+    ///
+    /// Neighbors are explored in the order the graph yields them, so among
+    /// several fewest-hop paths the result is deterministic for a given graph.
+    /// A search from a node to itself returns the single-node path with zero
+    /// hops.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BfsError`] if a node is missing or the end node cannot be
+    /// reached.
+    fn shortest_path(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+    ) -> Result<BfsSearchResult<G::Node>, BfsError> {
+        let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+            BfsError::new(format!(
+                "The start node {} is not in the graph {}!",
+                start_node_id, self.graph
+            ))
+        })?;
+        let end = self.graph.get_node_by_id(end_node_id).ok_or_else(|| {
+            BfsError::new(format!(
+                "The end node {} is not in the graph {}!",
+                end_node_id, self.graph
+            ))
+        })?;
+
+        // Each discovered node maps to the node it was discovered from.
+        let mut previous: HashMap<&str, Option<&G::Node>> = HashMap::new();
+        let mut queue: VecDeque<&G::Node> = VecDeque::new();
+        previous.insert(start.get_id(), None);
+        queue.push_back(start);
+
+        while let Some(position) = queue.pop_front() {
+            if position.get_id() == end.get_id() {
+                break;
+            }
+            for (neighbour, _) in self.graph.neighbors(position) {
+                if !previous.contains_key(neighbour.get_id()) {
+                    previous.insert(neighbour.get_id(), Some(position));
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        if !previous.contains_key(end.get_id()) {
+            return Err(BfsError::new(format!(
+                "No path from {} to {} exists!",
+                start_node_id, end_node_id
+            )));
+        }
+
+        let mut path = vec![end.clone()];
+        let mut current = end;
+        while let Some(Some(predecessor)) = previous.get(current.get_id()) {
+            path.push((*predecessor).clone());
+            current = predecessor;
+        }
+        path.reverse();
+
+        Ok(BfsSearchResult::new(path))
+    }
+}
+
+/// Search result produced by [`BfsAlgorithm`].
+///
+/// The distance of a BFS result is its number of hops (edges).
+#[derive(Debug, Clone)]
+pub struct BfsSearchResult<N: GraphNode> {
+    /// Ordered node sequence from start node to destination node.
+    pub path: Vec<N>,
+}
+
+impl<N: GraphNode> BfsSearchResult<N> {
+    /// Creates a result from a start-to-end path.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::bfs::BfsSearchResult;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let result = BfsSearchResult::new(vec![DefaultNode::new("A".to_string())]);
+    /// assert_eq!(result.hops(), 0);
+    /// ```
+    pub fn new(path: Vec<N>) -> Self {
+        Self { path }
+    }
+
+    /// Returns the number of edges on the path.
+    pub fn hops(&self) -> usize {
+        self.path.len().saturating_sub(1)
+    }
+}
+
+/// Formats the path on one line followed by the hop count, e.g.
+/// `A -> B -> C (hops: 2)`.
+impl<N: GraphNode> Display for BfsSearchResult<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let formatted_path = self
+            .path
+            .iter()
+            .map(|node| node.get_id())
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        write!(f, "{} (hops: {})", formatted_path, self.hops())
+    }
+}
+
+impl<N: GraphNode + Debug> SearchResult for BfsSearchResult<N> {
+    type Distance = usize;
+
+    type Node = N;
+
+    fn get_total_distance(&self) -> usize {
+        self.hops()
+    }
+
+    fn get_path(&self) -> &Vec<N> {
+        &self.path
+    }
+}
+
+/// Error returned when a BFS search fails.
+#[derive(Debug)]
+pub struct BfsError {
+    /// Human-readable explanation of the failure.
+    pub message: String,
+}
+
+impl BfsError {
+    /// Creates a new [`BfsError`] from a message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::bfs::BfsError;
+    ///
+    /// let err = BfsError::new("no route".to_string());
+    /// assert_eq!(err.to_string(), "no route");
+    /// ```
+    pub fn new(message: String) -> Self {
+        Self { message }
+    }
+}
+
+impl Display for BfsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for BfsError {}
@@ -0,0 +1,267 @@
+//! Depth-first search for the first path found between two nodes.
+//!
+//! # Overview
+//!
+//! [`DfsAlgorithm`] walks the graph depth first and returns the first path it
+//! reaches the end node on. The path is not optimized for weight or hop
+//! count; its distance is still reported as the summed edge weights so it can
+//! be compared with the results of the shortest-path algorithms.
+//!
+//! # Main types
+//!
+//! - [`DfsAlgorithm`]: algorithm engine operating on a concrete graph.
+//! - [`DfsSearchResult`]: path and summed weight of a successful search.
+//! - [`DfsError`]: execution error payload.
+//!
+//! # Example
+//!
+//! ```rust
+//! use shortest_path_finder::algorithms::algorithm::{Algorithm, SearchResult};
+//! use shortest_path_finder::algorithms::dfs::DfsAlgorithm;
+//! use shortest_path_finder::graphs::directed::DirectedGraph;
+//!
+//! let edges = vec![
+//!     ("A".to_string(), "B".to_string(), 4),
+//!     ("B".to_string(), "C".to_string(), 5),
+//! ];
+//! let graph = DirectedGraph::from_edges(&edges).unwrap();
+//!
+//! let result = DfsAlgorithm::new(graph).shortest_path("A", "C").unwrap();
+//! assert_eq!(result.get_total_distance(), 9);
+//! assert_eq!(result.to_string(), "A -> B -> C (total: 9)");
+//! ```
+
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{Debug, Display},
+};
+
+use crate::{
+    algorithms::algorithm::{Algorithm, SearchResult},
+    graphs::graph::{Graph, GraphNode, GraphWeight},
+    output::distance_format::DistanceFormat,
+};
+
+/// Depth-first search engine bound to one graph.
+///
+/// # Type Parameters
+///
+/// - `G`: Any graph type; its weights are summed along the found path.
+#[derive(Debug, Clone)]
+pub struct DfsAlgorithm<G: Graph + Display> {
+    /// Graph instance processed by this algorithm implementation.
+    graph: G,
+}
+
+impl<G: Graph + Display> DfsAlgorithm<G> {
+    /// Creates a new [`DfsAlgorithm`] bound to a graph instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dfs::DfsAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    ///
+    /// let _algorithm = DfsAlgorithm::new(DirectedGraph::new(vec![]));
+    /// ```
+    pub fn new(graph: G) -> Self {
+        Self { graph }
+    }
+}
+
+impl<G: Graph + Display> Algorithm for DfsAlgorithm<G>
+where
+    G::Node: Debug,
+{
+    type ExecutionError = DfsError;
+
+    type AlgorithmSearchResult = DfsSearchResult<G::Node, G::Weight>;
+
+    type NodeOfUsedGraph = G::Node;
+
+    /// Returns the first path from `start_node_id` to `end_node_id` that a
+    /// depth-first search discovers.
+    ///
+    /// Neighbors are explored in the order the graph yields them, so the
+    /// result is deterministic for a given graph. Every node is entered at
+    /// most once, which keeps cycles from looping forever. The search is
+    /// iterative, so deep graphs cannot overflow the call stack. A search
+    /// from a node to itself returns the single-node path with distance zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DfsError`] if a node is missing or the end node cannot be
+    /// reached.
+    fn shortest_path(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+    ) -> Result<DfsSearchResult<G::Node, G::Weight>, DfsError> {
+        let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+            DfsError::new(format!(
+                "The start node {} is not in the graph {}!",
+                start_node_id, self.graph
+            ))
+        })?;
+        let end = self.graph.get_node_by_id(end_node_id).ok_or_else(|| {
+            DfsError::new(format!(
+                "The end node {} is not in the graph {}!",
+                end_node_id, self.graph
+            ))
+        })?;
+
+        if start.get_id() == end.get_id() {
+            return Ok(DfsSearchResult::new(vec![start.clone()], G::Weight::zero()));
+        }
+
+        let mut visited: HashSet<String> = HashSet::from([start.get_id().to_string()]);
+        // The stack holds the current path; each frame keeps the neighbors
+        // that are still left to try from its node.
+        let mut stack = vec![(start, self.graph.neighbors(start))];
+        // Weight of the edge entering each stack frame after the first.
+        let mut weights: Vec<G::Weight> = Vec::new();
+
+        while let Some((_, neighbours)) = stack.last_mut() {
+            let Some((neighbour, weight)) = neighbours.next() else {
+                // Dead end: step back to the previous node.
+                stack.pop();
+                weights.pop();
+                continue;
+            };
+            if !visited.insert(neighbour.get_id().to_string()) {
+                continue;
+            }
+            weights.push(weight);
+
+            if neighbour.get_id() == end.get_id() {
+                let mut path: Vec<G::Node> =
+                    stack.iter().map(|(node, _)| (*node).clone()).collect();
+                path.push(neighbour.clone());
+                let distance = weights
+                    .into_iter()
+                    .fold(G::Weight::zero(), |total, weight| total + weight);
+                return Ok(DfsSearchResult::new(path, distance));
+            }
+            stack.push((neighbour, self.graph.neighbors(neighbour)));
+        }
+
+        Err(DfsError::new(format!(
+            "No path from {} to {} exists!",
+            start_node_id, end_node_id
+        )))
+    }
+}
+
+/// Search result produced by [`DfsAlgorithm`].
+///
+/// The distance is the sum of the edge weights along the found path, which
+/// is not necessarily the shortest one.
+#[derive(Debug, Clone)]
+pub struct DfsSearchResult<N: GraphNode, W: GraphWeight> {
+    /// Ordered node sequence from start node to destination node.
+    pub path: Vec<N>,
+    /// Summed edge weights along `path`.
+    pub distance: W,
+}
+
+impl<N: GraphNode, W: GraphWeight> DfsSearchResult<N, W> {
+    /// Creates a result from a start-to-end path and its summed weight.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dfs::DfsSearchResult;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let result = DfsSearchResult::new(vec![DefaultNode::new("A".to_string())], 0u16);
+    /// assert_eq!(result.to_string(), "A (total: 0)");
+    /// ```
+    pub fn new(path: Vec<N>, distance: W) -> Self {
+        Self { path, distance }
+    }
+
+    /// Formats the result like [`Display`], but with the distance rendered
+    /// through `format` (unit label and precision).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dfs::DfsSearchResult;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    /// use shortest_path_finder::output::distance_format::DistanceFormat;
+    ///
+    /// let path = vec![DefaultNode::new("A".to_string()), DefaultNode::new("B".to_string())];
+    /// let format = DistanceFormat {
+    ///     unit: Some("km".to_string()),
+    ///     precision: None,
+    /// };
+    /// assert_eq!(DfsSearchResult::new(path, 3u16).format_with(&format), "A -> B (total: 3 km)");
+    /// ```
+    pub fn format_with(&self, format: &DistanceFormat) -> String {
+        let formatted_path = self
+            .path
+            .iter()
+            .map(|node| node.get_id())
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        format!(
+            "{} (total: {})",
+            formatted_path,
+            format.format(&self.distance)
+        )
+    }
+}
+
+/// Formats the path on one line followed by the distance, e.g.
+/// `A -> B -> C (total: 9)`.
+impl<N: GraphNode, W: GraphWeight> Display for DfsSearchResult<N, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.format_with(&DistanceFormat::default()))
+    }
+}
+
+impl<N: GraphNode + Debug, W: GraphWeight> SearchResult for DfsSearchResult<N, W> {
+    type Distance = W;
+
+    type Node = N;
+
+    fn get_total_distance(&self) -> W {
+        self.distance
+    }
+
+    fn get_path(&self) -> &Vec<N> {
+        &self.path
+    }
+}
+
+/// Error returned when a DFS search fails.
+#[derive(Debug)]
+pub struct DfsError {
+    /// Human-readable explanation of the failure.
+    pub message: String,
+}
+
+impl DfsError {
+    /// Creates a new [`DfsError`] from a message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dfs::DfsError;
+    ///
+    /// let err = DfsError::new("no route".to_string());
+    /// assert_eq!(err.to_string(), "no route");
+    /// ```
+    pub fn new(message: String) -> Self {
+        Self { message }
+    }
+}
+
+impl Display for DfsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for DfsError {}
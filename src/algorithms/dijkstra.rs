@@ -4,15 +4,19 @@
 //! application. It supports graph types that implement [`Graph`] and uses a
 //! priority queue (`BinaryHeap`) to iteratively relax edges.
 //!
-//! Dijkstra requires all traversed edge weights to be non-negative. If a
-//! negative edge weight is encountered during processing, the algorithm returns
-//! a [`DijkstraError`].
+//! Dijkstra requires all edge weights to be non-negative. Every public query
+//! scans the graph first and returns a [`DijkstraError`] if any edge has a
+//! negative weight (for example in a
+//! [`SignedDirectedGraph`](crate::graphs::directed::SignedDirectedGraph)).
 //!
 //! # Main types
 //!
 //! - [`DijkstraAlgorithm`]: algorithm engine operating on a concrete graph.
 //! - [`DijkstraSearchResult`]: successful path computation output.
 //! - [`DijkstraError`]: execution error payload.
+//! - [`PathOutcome`]: found path or unreachable end node, without an error.
+//! - [`DijkstraEvent`]: search step reported to an observer.
+//! - [`DijkstraStepper`]: search driven one settled node at a time.
 //!
 //! # Example
 //!
@@ -42,14 +46,28 @@
 //! ```
 
 use std::{
-    collections::{BinaryHeap, HashMap},
+    cmp::{Reverse, max},
+    collections::{BinaryHeap, HashMap, HashSet},
     error::Error,
     fmt::{Debug, Display},
+    io::{BufWriter, Write},
+    time::Instant,
 };
 
 use crate::{
-    algorithms::algorithm::{Algorithm, SearchResult},
-    graphs::graph::{Graph, GraphNode, GraphWeight},
+    algorithms::{
+        algorithm::{Algorithm, SearchResult, SearchStats},
+        turn_restriction::TurnRestriction,
+    },
+    error::pathfinder_error::PathfinderError,
+    graphs::{
+        directed::DirectedGraph,
+        graph::{Graph, GraphNode, GraphWeight},
+        undirected::UndirectedGraph,
+    },
+    instrumentation::operation_span,
+    nodes::default_node::DefaultNode,
+    output::{distance_format::DistanceFormat, distance_table::escape_field},
 };
 
 /// Internal bookkeeping entry used while distances are being relaxed.
@@ -57,10 +75,17 @@ use crate::{
 /// Each node maps to one instance of this type while the algorithm is running:
 /// - `distance` stores the currently known best distance from the start node.
 /// - `previous_node` stores the predecessor used to reconstruct the final path.
-#[derive(Debug)]
+/// - `previous_edge_weight` stores the weight of the edge that was relaxed to
+///   reach the node from `previous_node`. In a multigraph this is the
+///   parallel edge the search actually used.
+///
+/// [`DijkstraStepper::execute_step`] hands out copies of these entries so a
+/// search can be watched one settled node at a time.
+#[derive(Debug, Clone)]
 pub struct ShortestDistance<N: GraphNode, W: GraphWeight + Ord> {
     distance: W,
     previous_node: Option<N>,
+    previous_edge_weight: Option<W>,
 }
 
 impl<N: GraphNode, W: GraphWeight + Ord> ShortestDistance<N, W> {
@@ -83,8 +108,33 @@ impl<N: GraphNode, W: GraphWeight + Ord> ShortestDistance<N, W> {
         Self {
             previous_node,
             distance,
+            previous_edge_weight: None,
         }
     }
+
+    /// Records that the node was reached from `previous_node` over an edge
+    /// of weight `edge_weight`, at a total of `distance`.
+    fn reach(&mut self, previous_node: N, edge_weight: W, distance: W) {
+        self.previous_node = Some(previous_node);
+        self.previous_edge_weight = Some(edge_weight);
+        self.distance = distance;
+    }
+
+    /// Returns the best-known distance from the start node.
+    pub fn get_distance(&self) -> W {
+        self.distance
+    }
+
+    /// Returns the predecessor on the best-known path, if any.
+    pub fn get_previous_node(&self) -> Option<&N> {
+        self.previous_node.as_ref()
+    }
+
+    /// Returns the weight of the edge from the predecessor, if the node was
+    /// reached over an edge.
+    pub fn get_previous_edge_weight(&self) -> Option<W> {
+        self.previous_edge_weight
+    }
 }
 
 impl<N: GraphNode, W: GraphWeight + Ord> Display for ShortestDistance<N, W> {
@@ -106,8 +156,8 @@ impl<N: GraphNode, W: GraphWeight + Ord> Display for ShortestDistance<N, W> {
 ///
 /// # Requirements
 ///
-/// - The underlying graph must be weighted.
-/// - Edge weights must be non-negative when the algorithm explores edges.
+/// - Edge weights must be non-negative. Weights filled in with a default
+///   (see [`Graph::is_weighted`]) are used like explicit ones.
 ///
 /// # Example
 ///
@@ -141,6 +191,16 @@ pub struct DijkstraAlgorithm<
 > {
     /// Graph instance processed by this algorithm implementation.
     graph: G,
+    /// Edge kinds that may be traversed; `None` allows every kind.
+    allowed_kinds: Option<HashSet<String>>,
+    /// Whether edges without a kind may be traversed while
+    /// `allowed_kinds` is set.
+    allow_edges_without_kind: bool,
+    /// Edge layers that may not be traversed.
+    disabled_layers: HashSet<String>,
+    /// Error message naming the first negative edge of `graph`, found once in
+    /// [`DijkstraAlgorithm::new`] because the graph cannot change afterwards.
+    negative_edge_error: Option<String>,
 }
 
 impl<N: GraphNode, W: GraphWeight + Ord, G: Graph<Node = N, Weight = W> + Display> Algorithm
@@ -156,6 +216,30 @@ impl<N: GraphNode, W: GraphWeight + Ord, G: Graph<Node = N, Weight = W> + Displa
         &self,
         start_node_id: &str,
         end_node_id: &str,
+    ) -> Result<DijkstraSearchResult<N, W>, DijkstraError> {
+        let _span = operation_span!(
+            "dijkstra.shortest_path",
+            start = start_node_id,
+            end = end_node_id,
+            nodes = self.graph.get_all_nodes().len(),
+        );
+
+        self.search(start_node_id, end_node_id, &mut SearchStats::default())
+    }
+}
+
+impl<N: GraphNode, W: GraphWeight + Ord, G: Graph<Node = N, Weight = W> + Display>
+    DijkstraAlgorithm<N, W, G>
+{
+    /// Runs one shortest-path search and records its work in `stats`.
+    ///
+    /// Shared core of [`Algorithm::shortest_path`] and
+    /// [`DijkstraAlgorithm::shortest_path_with_stats`].
+    fn search(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+        stats: &mut SearchStats,
     ) -> Result<DijkstraSearchResult<N, W>, DijkstraError> {
         // - loop:
         //  - get distance / weight of edge to all unvisited neighbours
@@ -163,12 +247,8 @@ impl<N: GraphNode, W: GraphWeight + Ord, G: Graph<Node = N, Weight = W> + Displa
         //  - put current node into visited nodes list and remove from unvisited nodes list
         //  - choose new current node -> unvisited node with minimal distance
 
-        // graphs need to be weighted else its not possible to calculate the distance
-        if !self.graph.is_weighted() {
-            return Err(DijkstraError::new(
-                "The graph that was created needs to be weighted!".to_string(),
-            ));
-        }
+        // graphs need to be free of negative edges else the distances are wrong
+        self.validate_graph()?;
 
         // check if the two 'Node's are in the graph <G> and get them as 'Node' objects
         let start: &N = match self.graph.get_node_by_id(start_node_id) {
@@ -191,47 +271,316 @@ impl<N: GraphNode, W: GraphWeight + Ord, G: Graph<Node = N, Weight = W> + Displa
             }
         };
 
+        // The trivial path needs no search: the start node alone, at distance zero.
+        if start.get_id() == end.get_id() {
+            return self.build_search_result(vec![start.clone()], W::zero());
+        }
+
+        let distances = self.calculate_distances_with_exclusions(
+            start,
+            &HashSet::new(),
+            &HashSet::new(),
+            stats,
+        )?;
+
+        self.search_result_from_distances(&distances, start, end)
+    }
+
+    /// Finds the shortest path like [`Algorithm::shortest_path`] and reports
+    /// how much work the search did.
+    ///
+    /// Useful for comparing algorithms or graph layouts on the same query;
+    /// the `--stats` CLI flag prints these numbers to stderr.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the start node.
+    /// - `end_node_id`: Identifier of the destination node.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((DijkstraSearchResult, SearchStats))` with the same result as
+    ///   [`Algorithm::shortest_path`]. `nodes_expanded` counts settled queue
+    ///   pops and `edges_relaxed` counts distance improvements.
+    /// - `Err(DijkstraError)` under the same conditions as
+    ///   [`Algorithm::shortest_path`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::algorithm::SearchResult;
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(7)).is_none());
+    ///
+    /// let (result, stats) = DijkstraAlgorithm::new(graph)
+    ///     .shortest_path_with_stats("A", "B")
+    ///     .unwrap();
+    /// assert_eq!(result.get_total_distance(), 7);
+    /// assert_eq!(stats.nodes_expanded, 2);
+    /// assert_eq!(stats.edges_relaxed, 1);
+    /// ```
+    pub fn shortest_path_with_stats(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+    ) -> Result<(DijkstraSearchResult<N, W>, SearchStats), DijkstraError> {
+        let started = Instant::now();
+        let mut stats = SearchStats::default();
+        let result = self.search(start_node_id, end_node_id, &mut stats)?;
+        stats.elapsed = started.elapsed();
+        Ok((result, stats))
+    }
+
+    /// Finds the shortest path like [`Algorithm::shortest_path`], but reports
+    /// an unreachable end node as a regular outcome instead of an error.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the start node.
+    /// - `end_node_id`: Identifier of the destination node.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(PathOutcome::Found(result))` with the same result as
+    ///   [`Algorithm::shortest_path`].
+    /// - `Ok(PathOutcome::Unreachable)` when no path leads from the start
+    ///   node to the end node.
+    /// - `Err(DijkstraError)` if a node is missing or the graph has a
+    ///   negative edge weight.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::{DijkstraAlgorithm, PathOutcome};
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(7)).is_none());
+    /// let dijkstra = DijkstraAlgorithm::new(graph);
+    ///
+    /// assert!(matches!(dijkstra.try_shortest_path("A", "B"), Ok(PathOutcome::Found(_))));
+    /// assert!(matches!(dijkstra.try_shortest_path("B", "A"), Ok(PathOutcome::Unreachable)));
+    /// assert!(dijkstra.try_shortest_path("A", "Z").is_err());
+    /// ```
+    pub fn try_shortest_path(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+    ) -> Result<PathOutcome<N, W>, DijkstraError> {
+        self.validate_graph()?;
+
+        let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The start node {} is not in the graph {}!",
+                start_node_id, self.graph
+            ))
+        })?;
+        let end = self.graph.get_node_by_id(end_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The end node {} is not in the graph {}!",
+                end_node_id, self.graph
+            ))
+        })?;
+
         let distances = self.calculate_distances(start)?;
+        match Self::reconstruct_path(&distances, start, end) {
+            Some((path, distance)) => Ok(PathOutcome::Found(
+                self.build_search_result(path, distance)?,
+            )),
+            None => Ok(PathOutcome::Unreachable),
+        }
+    }
 
-        // Reconstruct the shortest route by walking predecessors from end to start.
-        let mut path: Vec<N> = vec![];
-        let mut current_node = end.clone();
-        let mut output_distance = W::zero();
+    /// Finds the shortest path by searching from both ends at once.
+    ///
+    /// A forward search from the start node and a backward search over
+    /// incoming edges from the end node take turns, always expanding the side
+    /// with the smaller queue minimum. Every edge that links the two searches
+    /// proposes a route; once the two queue minimums add up to at least the
+    /// best route, no shorter one can exist and the search stops. On large
+    /// graphs the two searches meet in the middle and settle far fewer nodes
+    /// than [`Algorithm::shortest_path`].
+    ///
+    /// The backward search needs the incoming edges of every node, which the
+    /// graph only stores by source, so a reverse index is built first
+    /// (`O(V + E)`). Edge-kind and layer filters apply to both directions.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the start node.
+    /// - `end_node_id`: Identifier of the destination node.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(DijkstraSearchResult)` with the same distance as
+    ///   [`Algorithm::shortest_path`]. When several shortest paths tie, the
+    ///   two searches may pick different ones.
+    /// - `Err(DijkstraError)` under the same conditions as
+    ///   [`Algorithm::shortest_path`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::algorithm::{Algorithm, SearchResult};
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    ///
+    /// let edges: Vec<(String, String, u16)> = [("A", "B", 1), ("B", "C", 1), ("A", "C", 5)]
+    ///     .iter()
+    ///     .map(|(from, to, weight)| (from.to_string(), to.to_string(), *weight))
+    ///     .collect();
+    /// let dijkstra = DijkstraAlgorithm::new(DirectedGraph::from_edges(&edges).unwrap());
+    ///
+    /// let result = dijkstra.shortest_path_bidirectional("A", "C").unwrap();
+    /// assert_eq!(result.get_total_distance(), 2);
+    /// assert_eq!(result.get_path(), dijkstra.shortest_path("A", "C").unwrap().get_path());
+    /// ```
+    pub fn shortest_path_bidirectional(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+    ) -> Result<DijkstraSearchResult<N, W>, DijkstraError> {
+        if start_node_id == end_node_id {
+            return self.search(start_node_id, end_node_id, &mut SearchStats::default());
+        }
+        self.validate_graph()?;
 
-        while let Some(distance) = distances.get(current_node.get_id()) {
-            if current_node.get_id() == end.get_id() {
-                output_distance = distance.distance;
+        let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The start node {} is not in the graph {}!",
+                start_node_id, self.graph
+            ))
+        })?;
+        let end = self.graph.get_node_by_id(end_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The end node {} is not in the graph {}!",
+                end_node_id, self.graph
+            ))
+        })?;
+
+        let _span = operation_span!(
+            "dijkstra.shortest_path_bidirectional",
+            start = start.get_id(),
+            end = end.get_id(),
+            nodes = self.graph.get_all_nodes().len(),
+        );
+
+        let incoming = self.incoming_edges();
+
+        // Per reached node: best known distance and the neighbour it was reached
+        // from (forward: predecessor, backward: successor).
+        let mut forward: HashMap<String, (W, Option<N>)> = HashMap::new();
+        let mut backward: HashMap<String, (W, Option<N>)> = HashMap::new();
+        forward.insert(start.get_id().to_string(), (W::zero(), None));
+        backward.insert(end.get_id().to_string(), (W::zero(), None));
+        let mut forward_settled: HashSet<String> = HashSet::new();
+        let mut backward_settled: HashSet<String> = HashSet::new();
+
+        // `Reverse` turns the max-heap into a min-heap, which the stopping rule needs.
+        let mut forward_queue: BinaryHeap<Reverse<QueueItem<N, W>>> = BinaryHeap::new();
+        let mut backward_queue: BinaryHeap<Reverse<QueueItem<N, W>>> = BinaryHeap::new();
+        forward_queue.push(Reverse(QueueItem::new(W::zero(), start.clone())));
+        backward_queue.push(Reverse(QueueItem::new(W::zero(), end.clone())));
+
+        // Best complete route so far: its distance and the node where both halves meet.
+        let mut best: Option<(W, N)> = None;
+
+        while let (Some(Reverse(forward_top)), Some(Reverse(backward_top))) =
+            (forward_queue.peek(), backward_queue.peek())
+        {
+            if best.as_ref().is_some_and(|(distance, _)| {
+                forward_top.distance + backward_top.distance >= *distance
+            }) {
+                break;
             }
-            path.push(current_node);
-            let prev: &N = match &distance.previous_node {
-                Some(node) => node,
-                None => {
-                    return Err(DijkstraError::new(format!(
-                        "Unable to determine a valid path from {} to {}!",
-                        start_node_id, end_node_id
-                    )));
-                }
+            let expand_forward = forward_top.distance <= backward_top.distance;
+
+            let (queue, settled, own, other) = if expand_forward {
+                (
+                    &mut forward_queue,
+                    &mut forward_settled,
+                    &mut forward,
+                    &backward,
+                )
+            } else {
+                (
+                    &mut backward_queue,
+                    &mut backward_settled,
+                    &mut backward,
+                    &forward,
+                )
             };
-            if start.get_id() == prev.get_id() {
-                // The start node references itself as predecessor sentinel.
-                path.push(start.clone());
+            let Some(Reverse(QueueItem { distance, position })) = queue.pop() else {
                 break;
+            };
+            if !settled.insert(position.get_id().to_string()) {
+                continue;
+            }
+
+            let edges: Vec<(&N, W)> = if expand_forward {
+                self.graph
+                    .labeled_neighbors(&position)
+                    .filter(|&(_, _, kind, layer)| self.is_edge_allowed(kind, layer))
+                    .map(|(neighbour, weight, _, _)| (neighbour, weight))
+                    .collect()
+            } else {
+                incoming.get(position.get_id()).cloned().unwrap_or_default()
+            };
+
+            for (neighbour, weight) in edges {
+                let updated_distance = distance + weight;
+                if own
+                    .get(neighbour.get_id())
+                    .is_none_or(|(known, _)| updated_distance < *known)
+                {
+                    own.insert(
+                        neighbour.get_id().to_string(),
+                        (updated_distance, Some(position.clone())),
+                    );
+                    queue.push(Reverse(QueueItem::new(updated_distance, neighbour.clone())));
+                }
+
+                // The edge links both searches: a complete route through `neighbour`.
+                if let Some((other_distance, _)) = other.get(neighbour.get_id()) {
+                    let route_distance = updated_distance + *other_distance;
+                    if best
+                        .as_ref()
+                        .is_none_or(|(best_distance, _)| route_distance < *best_distance)
+                    {
+                        best = Some((route_distance, neighbour.clone()));
+                    }
+                }
             }
-            current_node = prev.clone();
         }
 
-        // check if a path really has been found
-        if path.last() != Some(start) {
+        let Some((distance, meeting)) = best else {
             return Err(DijkstraError::new("A path could not be found!".to_string()));
+        };
+        let mut path: Vec<N> = vec![meeting.clone()];
+        let mut current = &meeting;
+        while let Some((_, Some(previous))) = forward.get(current.get_id()) {
+            path.push(previous.clone());
+            current = previous;
         }
-
-        // Path is collected from end to start; reverse to return start -> end.
         path.reverse();
+        let mut current = &meeting;
+        while let Some((_, Some(next))) = backward.get(current.get_id()) {
+            path.push(next.clone());
+            current = next;
+        }
 
-        Ok(match DijkstraSearchResult::new(path, output_distance) {
-            Ok(result) => result,
-            Err(err) => return Err(DijkstraError::new(err)),
-        })
+        self.build_search_result(path, distance)
     }
 }
 
@@ -258,7 +607,96 @@ impl<N: GraphNode, W: GraphWeight + Ord, G: Graph<Node = N, Weight = W> + Displa
     /// let _algorithm = DijkstraAlgorithm::new(graph);
     /// ```
     pub fn new(graph: G) -> Self {
-        Self { graph }
+        let negative_edge_error = Self::find_negative_edge(&graph);
+        Self {
+            graph,
+            allowed_kinds: None,
+            allow_edges_without_kind: true,
+            disabled_layers: HashSet::new(),
+            negative_edge_error,
+        }
+    }
+
+    /// Restricts every search to edges whose kind is in `allowed_kinds`.
+    ///
+    /// Kinds come from [`Graph::edge_kind`], e.g. edges parsed from
+    /// `A->B:7@ferry`. Edges without a kind stay usable unless
+    /// [`DijkstraAlgorithm::with_edges_without_kind_allowed`] turns that off.
+    ///
+    /// # Parameters
+    ///
+    /// - `allowed_kinds`: Kinds that may be traversed, or `None` to lift the
+    ///   restriction again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashSet;
+    ///
+    /// use shortest_path_finder::algorithms::algorithm::{Algorithm, SearchResult};
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone(), c.clone()]);
+    /// graph.insert_edge_with_kind(&a, &c, Some(2), Some("ferry".to_string()));
+    /// graph.insert_edge_with_kind(&a, &b, Some(3), Some("highway".to_string()));
+    /// graph.insert_edge_with_kind(&b, &c, Some(3), Some("highway".to_string()));
+    ///
+    /// let no_ferries = HashSet::from(["highway".to_string()]);
+    /// let dijkstra = DijkstraAlgorithm::new(graph).with_allowed_kinds(Some(no_ferries));
+    /// assert_eq!(dijkstra.shortest_path("A", "C").unwrap().get_total_distance(), 6);
+    /// ```
+    pub fn with_allowed_kinds(mut self, allowed_kinds: Option<HashSet<String>>) -> Self {
+        self.allowed_kinds = allowed_kinds;
+        self
+    }
+
+    /// Sets whether edges without a kind may be traversed while
+    /// [`DijkstraAlgorithm::with_allowed_kinds`] is active (default `true`).
+    ///
+    /// Has no effect when no kind restriction is set.
+    pub fn with_edges_without_kind_allowed(mut self, allowed: bool) -> Self {
+        self.allow_edges_without_kind = allowed;
+        self
+    }
+
+    /// Excludes every edge whose layer is in `disabled_layers` from all
+    /// searches.
+    ///
+    /// Layers come from [`Graph::edge_layer`], e.g. edges parsed from
+    /// `A->B:7#train`. This is the blocklist counterpart of
+    /// [`DijkstraAlgorithm::with_allowed_kinds`]: edges without a layer are
+    /// always usable, and both filters apply together. An empty set (the
+    /// default) disables nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashSet;
+    ///
+    /// use shortest_path_finder::algorithms::algorithm::{Algorithm, SearchResult};
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(2)).is_none());
+    /// graph.set_edge_layer(&a, &b, Some("train".to_string()));
+    ///
+    /// let dijkstra = DijkstraAlgorithm::new(graph)
+    ///     .with_disabled_layers(HashSet::from(["train".to_string()]));
+    /// assert!(dijkstra.shortest_path("A", "B").is_err());
+    /// ```
+    pub fn with_disabled_layers(mut self, disabled_layers: HashSet<String>) -> Self {
+        self.disabled_layers = disabled_layers;
+        self
     }
 
     /// Initializes the distance map for Dijkstra processing.
@@ -275,15 +713,12 @@ impl<N: GraphNode, W: GraphWeight + Ord, G: Graph<Node = N, Weight = W> + Displa
     /// A map from node ID to internal [`ShortestDistance`] state.
     fn setup_shortest_distance(&self, start: &N) -> HashMap<String, ShortestDistance<N, W>> {
         let mut output: HashMap<String, ShortestDistance<N, W>> = HashMap::new();
-        for n in self.graph.get_all_nodes() {
+        for n in self.graph.iter_nodes() {
             if n.get_id() == start.get_id() {
                 // Start node begins with distance 0 and itself as predecessor sentinel.
                 output.insert(
                     n.get_id().to_string().clone(),
-                    ShortestDistance {
-                        distance: W::zero(),
-                        previous_node: Some(n.clone()),
-                    },
+                    ShortestDistance::new(Some(n.clone()), W::zero()),
                 );
             } else {
                 // Unknown paths are initialized with "infinite" distance.
@@ -296,6 +731,48 @@ impl<N: GraphNode, W: GraphWeight + Ord, G: Graph<Node = N, Weight = W> + Displa
         output
     }
 
+    /// Checks that the graph can be searched with Dijkstra at all.
+    ///
+    /// The graph is scanned only once, in [`DijkstraAlgorithm::new`], so this
+    /// check is cheap enough to run before every query.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DijkstraError`] when any edge, reachable or not, has a
+    /// negative weight. Rejecting the whole graph up front avoids returning
+    /// silently wrong distances; use Bellman-Ford for graphs with negative
+    /// edges.
+    ///
+    /// Graphs whose weights were partly defaulted ([`Graph::is_weighted`]
+    /// returns `false`) are accepted: every stored edge still has a usable
+    /// weight.
+    fn validate_graph(&self) -> Result<(), DijkstraError> {
+        match &self.negative_edge_error {
+            Some(message) => Err(DijkstraError::new(message.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Scans `graph` for an edge with a negative weight.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(String)` with the error message naming the first such edge.
+    /// - `None` if every weight is zero or positive.
+    fn find_negative_edge(graph: &G) -> Option<String> {
+        graph.iter_nodes().find_map(|node| {
+            graph
+                .neighbors(node)
+                .find(|(_, weight)| *weight < W::zero())
+                .map(|(neighbour, weight)| {
+                    format!(
+                        "Dijkstra does not support negative edge weights, but the graph contains the edge [ from: {}, to: {}, weight: {} ]! Use Bellman-Ford for graphs with negative weights.",
+                        node, neighbour, weight
+                    )
+                })
+        })
+    }
+
     /// Executes the core Dijkstra relaxation loop.
     ///
     /// # Parameters
@@ -311,6 +788,45 @@ impl<N: GraphNode, W: GraphWeight + Ord, G: Graph<Node = N, Weight = W> + Displa
         &self,
         start: &N,
     ) -> Result<HashMap<String, ShortestDistance<N, W>>, DijkstraError> {
+        self.calculate_distances_with_exclusions(
+            start,
+            &HashSet::new(),
+            &HashSet::new(),
+            &mut SearchStats::default(),
+        )
+    }
+
+    /// Executes the Dijkstra relaxation loop while ignoring parts of the graph.
+    ///
+    /// This is the shared core of [`DijkstraAlgorithm::calculate_distances`] and
+    /// the alternative-route search in [`DijkstraAlgorithm::second_shortest_path`].
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Node from which shortest distances are computed.
+    /// - `excluded_nodes`: IDs of nodes that must never be entered.
+    /// - `excluded_edges`: `(from, to)` ID pairs that must never be traversed.
+    ///   For undirected graphs an excluded pair blocks both directions.
+    /// - `stats`: Receives the expanded-node and relaxed-edge counts of this run.
+    ///
+    /// # Returns
+    ///
+    /// Same contract as [`DijkstraAlgorithm::calculate_distances`].
+    fn calculate_distances_with_exclusions(
+        &self,
+        start: &N,
+        excluded_nodes: &HashSet<String>,
+        excluded_edges: &HashSet<(String, String)>,
+        stats: &mut SearchStats,
+    ) -> Result<HashMap<String, ShortestDistance<N, W>>, DijkstraError> {
+        let _span = operation_span!(
+            "dijkstra.calculate_distances",
+            start = start.get_id(),
+            nodes = self.graph.get_all_nodes().len(),
+            excluded_nodes = excluded_nodes.len(),
+            excluded_edges = excluded_edges.len(),
+        );
+
         // - new list keeping track of the shortest distance from the start node to all others
         let mut distances: HashMap<String, ShortestDistance<N, W>> =
             self.setup_shortest_distance(start);
@@ -338,8 +854,9 @@ impl<N: GraphNode, W: GraphWeight + Ord, G: Graph<Node = N, Weight = W> + Displa
             {
                 continue;
             }
+            stats.nodes_expanded += 1;
 
-            for (neighbour, weight) in self.graph.neighbors(&position) {
+            for (neighbour, weight, kind, layer) in self.graph.labeled_neighbors(&position) {
                 // for Dijkstra an edges weight can't be smaller then 0
                 if weight < W::zero() {
                     return Err(DijkstraError::new(format!(
@@ -348,6 +865,14 @@ impl<N: GraphNode, W: GraphWeight + Ord, G: Graph<Node = N, Weight = W> + Displa
                     )));
                 }
 
+                // Skip nodes and edges that were removed for this run or have a disallowed kind.
+                if excluded_nodes.contains(neighbour.get_id())
+                    || self.is_edge_excluded(&position, neighbour, excluded_edges)
+                    || !self.is_edge_allowed(kind, layer)
+                {
+                    continue;
+                }
+
                 // Standard relaxation: candidate distance via the current node.
                 let updated_distance = distance + weight;
 
@@ -366,10 +891,11 @@ impl<N: GraphNode, W: GraphWeight + Ord, G: Graph<Node = N, Weight = W> + Displa
                     distances
                         .entry(neighbour.get_id().to_string().clone())
                         .and_modify(|entry| {
-                            entry.distance = updated_distance;
-                            entry.previous_node = Some(position.clone())
+                            entry.reach(position.clone(), weight, updated_distance)
                         });
 
+                    stats.edges_relaxed += 1;
+
                     // Re-enqueue neighbor with its improved tentative distance.
                     queue.push(QueueItem::new(updated_distance, neighbour.clone()));
                 }
@@ -377,53 +903,2388 @@ impl<N: GraphNode, W: GraphWeight + Ord, G: Graph<Node = N, Weight = W> + Displa
         }
         Ok(distances)
     }
-}
-
-/// Internal priority-queue element used by the Dijkstra processing loop.
-///
-/// The queue stores candidate nodes ordered by distance.
-#[derive(Eq, PartialEq)]
-struct QueueItem<N: GraphNode, W: GraphWeight> {
-    /// Candidate distance for this queue step.
-    distance: W,
-    /// Candidate node position associated with `distance`.
-    position: N,
-}
 
-impl<N: GraphNode, W: GraphWeight + Ord + Eq> QueueItem<N, W> {
-    /// Creates a new queue item.
+    /// Computes the shortest distance from one start node to every reachable node.
+    ///
+    /// This runs the relaxation loop exactly once and exposes its result
+    /// without the internal predecessor bookkeeping. Nodes that cannot be
+    /// reached from the start node are omitted from the map.
     ///
     /// # Parameters
     ///
-    /// - `distance`: Tentative distance for `position`.
-    /// - `position`: Node to be processed next by the queue consumer.
+    /// - `start_node_id`: Identifier of the node all distances are measured from.
     ///
     /// # Returns
     ///
-    /// A new [`QueueItem`].
-    fn new(distance: W, position: N) -> Self {
-        Self { distance, position }
-    }
-}
+    /// - `Ok(HashMap<String, W>)` mapping node IDs to finite shortest distances.
+    ///   The start node itself maps to `W::zero()`.
+    /// - `Err(DijkstraError)` if the start node is missing or a negative edge
+    ///   weight is encountered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let mut graph = DirectedGraph::default();
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// graph.insert_node(a.clone());
+    /// graph.insert_node(b.clone());
+    /// graph.insert_node(c.clone());
+    /// assert!(graph.insert_edge(&a, &b, Some(3)).is_none());
+    ///
+    /// let dijkstra = DijkstraAlgorithm::new(graph);
+    /// let distances = dijkstra.distances_from("A").unwrap();
+    /// assert_eq!(distances.get("A"), Some(&0));
+    /// assert_eq!(distances.get("B"), Some(&3));
+    /// assert_eq!(distances.get("C"), None);
+    /// ```
+    pub fn distances_from(&self, start_node_id: &str) -> Result<HashMap<String, W>, DijkstraError> {
+        self.validate_graph()?;
 
-impl<N: GraphNode, W: GraphWeight + Ord + Eq> PartialOrd for QueueItem<N, W> {
-    /// Defers partial ordering to [`Ord`] for `BinaryHeap` compatibility.
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+        let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The start node {} is not in the graph {}!",
+                start_node_id, self.graph
+            ))
+        })?;
+
+        self.reached_distances(start)
     }
-}
 
-impl<N: GraphNode, W: GraphWeight + Ord + Eq> Ord for QueueItem<N, W> {
-    /// Orders queue entries by distance.
+    /// Computes the shortest distance from every node to one target node.
     ///
-    /// `BinaryHeap` is a max-heap, so this ordering currently yields largest
-    /// distance first. The implementation compensates by skipping stale entries
+    /// This answers arrival-time queries such as "how far is the destination
+    /// from each node?" with a single search that starts at the target and
+    /// walks edges backward. Undirected graphs are symmetric, so this equals
+    /// [`DijkstraAlgorithm::distances_from`]; directed graphs are searched over
+    /// a reverse-adjacency index of their incoming edges. Edge-kind filters
+    /// apply to the original edge direction.
+    ///
+    /// # Parameters
+    ///
+    /// - `target_node_id`: Identifier of the node all distances are measured to.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(HashMap<String, W>)` mapping every node that can reach the target
+    ///   to its shortest distance. The target itself maps to `W::zero()`.
+    /// - `Err(DijkstraError)` if the target node is missing or the graph
+    ///   contains a negative edge weight.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone(), c.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(2)).is_none());
+    /// assert!(graph.insert_edge(&b, &c, Some(3)).is_none());
+    ///
+    /// let dijkstra = DijkstraAlgorithm::new(graph);
+    /// let distances = dijkstra.distances_to("C").unwrap();
+    /// assert_eq!(distances.get("A"), Some(&5));
+    /// assert_eq!(distances.get("C"), Some(&0));
+    ///
+    /// // Edges only count in their own direction: C can't reach A.
+    /// assert_eq!(dijkstra.distances_to("A").unwrap().get("C"), None);
+    /// ```
+    pub fn distances_to(&self, target_node_id: &str) -> Result<HashMap<String, W>, DijkstraError> {
+        self.validate_graph()?;
+
+        let target = self.graph.get_node_by_id(target_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The target node {} is not in the graph {}!",
+                target_node_id, self.graph
+            ))
+        })?;
+
+        if !self.graph.is_directed() {
+            return self.reached_distances(target);
+        }
+
+        let _span = operation_span!(
+            "dijkstra.distances_to",
+            target = target.get_id(),
+            nodes = self.graph.get_all_nodes().len(),
+        );
+
+        let incoming = self.incoming_edges();
+        let mut distances: HashMap<String, W> = HashMap::new();
+        distances.insert(target.get_id().to_string(), W::zero());
+
+        let mut queue: BinaryHeap<QueueItem<N, W>> = BinaryHeap::new();
+        queue.push(QueueItem::new(W::zero(), target.clone()));
+
+        while let Some(QueueItem { distance, position }) = queue.pop() {
+            // Skip stale queue entries superseded by a shorter known path.
+            if distances
+                .get(position.get_id())
+                .is_some_and(|&known| distance > known)
+            {
+                continue;
+            }
+
+            let Some(predecessors) = incoming.get(position.get_id()) else {
+                continue;
+            };
+            for &(predecessor, weight) in predecessors {
+                let updated_distance = distance + weight;
+                if distances
+                    .get(predecessor.get_id())
+                    .is_none_or(|&known| updated_distance < known)
+                {
+                    distances.insert(predecessor.get_id().to_string(), updated_distance);
+                    queue.push(QueueItem::new(updated_distance, predecessor.clone()));
+                }
+            }
+        }
+
+        Ok(distances)
+    }
+
+    /// Builds a reverse-adjacency index: for every node ID, the nodes with an
+    /// edge into it and that edge's weight.
+    ///
+    /// Edges whose kind is not allowed for this run are left out.
+    fn incoming_edges(&self) -> HashMap<&str, Vec<(&N, W)>> {
+        let mut incoming: HashMap<&str, Vec<(&N, W)>> = HashMap::new();
+        for from in self.graph.iter_nodes() {
+            for (to, weight, kind, layer) in self.graph.labeled_neighbors(from) {
+                if self.is_edge_allowed(kind, layer) {
+                    incoming
+                        .entry(to.get_id())
+                        .or_default()
+                        .push((from, weight));
+                }
+            }
+        }
+        incoming
+    }
+
+    /// Runs the relaxation loop from `start` and keeps only reached nodes.
+    ///
+    /// Shared by [`DijkstraAlgorithm::distances_from`] and the all-pairs
+    /// queries; callers validate the graph beforehand.
+    fn reached_distances(&self, start: &N) -> Result<HashMap<String, W>, DijkstraError> {
+        let distances = self.calculate_distances(start)?;
+
+        // Nodes without a predecessor were never reached from the start node.
+        Ok(distances
+            .into_iter()
+            .filter(|(_, entry)| entry.previous_node.is_some())
+            .map(|(id, entry)| (id, entry.distance))
+            .collect())
+    }
+
+    /// Finds the reachable node with the largest shortest distance from a start node.
+    ///
+    /// This is one endpoint of the start node's eccentricity and is useful
+    /// for coverage analysis ("how far does the network reach from here?").
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the node all distances are measured from.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some((node, distance)))` for the farthest reachable node. Ties
+    ///   are broken by the smallest node ID.
+    /// - `Ok(None)` when no node other than the start node is reachable.
+    /// - `Err(DijkstraError)` under the same conditions as
+    ///   [`DijkstraAlgorithm::distances_from`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::graph::{Graph, GraphNode};
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone(), c.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(4)).is_none());
+    /// assert!(graph.insert_edge(&a, &c, Some(9)).is_none());
+    ///
+    /// let dijkstra = DijkstraAlgorithm::new(graph);
+    /// let (farthest, distance) = dijkstra.farthest_node("B").unwrap().unwrap();
+    /// assert_eq!((farthest.get_id(), distance), ("C", 13));
+    /// ```
+    pub fn farthest_node(&self, start_node_id: &str) -> Result<Option<(N, W)>, DijkstraError> {
+        let distances = self.distances_from(start_node_id)?;
+
+        let farthest = distances
+            .iter()
+            .filter(|(id, _)| id.as_str() != start_node_id)
+            .max_by(|(id_a, distance_a), (id_b, distance_b)| {
+                // Larger distance wins; on ties the smaller ID compares as greater.
+                distance_a.cmp(distance_b).then_with(|| id_b.cmp(id_a))
+            });
+
+        Ok(farthest.and_then(|(id, distance)| {
+            self.graph
+                .get_node_by_id(id)
+                .map(|node| (node.clone(), *distance))
+        }))
+    }
+
+    /// Returns the edges of the shortest-path tree rooted at a start node.
+    ///
+    /// Every node reachable from the start contributes the edge from its
+    /// predecessor on its shortest path, so the list holds one edge fewer
+    /// than there are reachable nodes. Unreachable nodes contribute nothing.
+    /// This is the lightweight alternative to building a whole graph when the
+    /// tree is only drawn or exported.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the tree's root.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<(predecessor, node, weight)>)` ordered by the insertion
+    ///   order of `node`. The weight is the cheapest edge from the
+    ///   predecessor to the node.
+    /// - `Err(DijkstraError)` under the same conditions as
+    ///   [`DijkstraAlgorithm::distances_from`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::GraphNode;
+    ///
+    /// let edges: Vec<(String, String, u16)> = vec![
+    ///     ("A".to_string(), "B".to_string(), 2),
+    ///     ("A".to_string(), "C".to_string(), 9),
+    ///     ("B".to_string(), "C".to_string(), 3),
+    /// ];
+    /// let dijkstra = DijkstraAlgorithm::new(DirectedGraph::from_edges(&edges).unwrap());
+    ///
+    /// let tree = dijkstra.spt_edges("A").unwrap();
+    /// let tree: Vec<(&str, &str, u16)> = tree
+    ///     .iter()
+    ///     .map(|(from, to, weight)| (from.get_id(), to.get_id(), *weight))
+    ///     .collect();
+    /// assert_eq!(tree, vec![("A", "B", 2), ("B", "C", 3)]);
+    /// ```
+    pub fn spt_edges(&self, start_node_id: &str) -> Result<Vec<(N, N, W)>, DijkstraError> {
+        self.validate_graph()?;
+
+        let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The start node {} is not in the graph {}!",
+                start_node_id, self.graph
+            ))
+        })?;
+        let distances = self.calculate_distances(start)?;
+
+        let mut edges = Vec::new();
+        for node in self.graph.iter_nodes() {
+            if node.get_id() == start.get_id() {
+                continue;
+            }
+            // Nodes without a predecessor were never reached from the start node.
+            let Some(entry) = distances.get(node.get_id()) else {
+                continue;
+            };
+            if let (Some(previous), Some(weight)) =
+                (entry.previous_node.as_ref(), entry.previous_edge_weight)
+            {
+                edges.push((previous.clone(), node.clone(), weight));
+            }
+        }
+        Ok(edges)
+    }
+
+    /// Returns the radius of the graph: the smallest eccentricity of any node.
+    ///
+    /// The eccentricity of a node is its shortest distance to the farthest
+    /// other node. It is only defined for nodes that reach every node in the
+    /// graph, so on a disconnected graph no node qualifies. In directed
+    /// graphs only the nodes that reach all others count. Together with
+    /// [`DijkstraAlgorithm::center`] this identifies the most central
+    /// locations, e.g. for facility placement.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(radius))` when at least one node reaches every node.
+    /// - `Ok(None)` for an empty graph or when no node reaches every node.
+    /// - `Err(DijkstraError)` if the graph contains a negative edge weight.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone(), c.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(4)).is_none());
+    /// assert!(graph.insert_edge(&b, &c, Some(3)).is_none());
+    ///
+    /// assert_eq!(DijkstraAlgorithm::new(graph).radius().unwrap(), Some(4));
+    /// ```
+    pub fn radius(&self) -> Result<Option<W>, DijkstraError> {
+        Ok(self
+            .eccentricities()?
+            .into_iter()
+            .map(|(_, eccentricity)| eccentricity)
+            .min())
+    }
+
+    /// Returns the center of the graph: every node whose eccentricity equals
+    /// the [radius](DijkstraAlgorithm::radius).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(nodes)` in insertion order. On a path graph with equal weights
+    ///   this is the middle node, or the two middle nodes for an even node
+    ///   count.
+    /// - `Ok(vec![])` whenever [`DijkstraAlgorithm::radius`] is `None`.
+    /// - `Err(DijkstraError)` if the graph contains a negative edge weight.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone(), c.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(4)).is_none());
+    /// assert!(graph.insert_edge(&b, &c, Some(3)).is_none());
+    ///
+    /// assert_eq!(DijkstraAlgorithm::new(graph).center().unwrap(), vec![b]);
+    /// ```
+    pub fn center(&self) -> Result<Vec<N>, DijkstraError> {
+        let eccentricities = self.eccentricities()?;
+        let Some(radius) = eccentricities
+            .iter()
+            .map(|(_, eccentricity)| *eccentricity)
+            .min()
+        else {
+            return Ok(Vec::new());
+        };
+
+        Ok(eccentricities
+            .into_iter()
+            .filter(|(_, eccentricity)| *eccentricity == radius)
+            .map(|(node, _)| node)
+            .collect())
+    }
+
+    /// Computes the eccentricity of every node that reaches all nodes.
+    ///
+    /// Nodes that cannot reach some node have no finite eccentricity and are
+    /// left out, so the result is empty for disconnected graphs.
+    ///
+    /// # Returns
+    ///
+    /// `(node, eccentricity)` pairs in node insertion order.
+    fn eccentricities(&self) -> Result<Vec<(N, W)>, DijkstraError> {
+        self.validate_graph()?;
+
+        let node_count = self.graph.get_all_nodes().len();
+        let mut eccentricities = Vec::new();
+        for node in self.graph.iter_nodes() {
+            let distances = self.reached_distances(node)?;
+            if distances.len() < node_count {
+                continue;
+            }
+            if let Some(&eccentricity) = distances.values().max() {
+                eccentricities.push((node.clone(), eccentricity));
+            }
+        }
+        Ok(eccentricities)
+    }
+
+    /// Computes a shortest path and reports every step of the search to
+    /// `observer`.
+    ///
+    /// This is the hook behind the CLI's `--explain` narration. Unlike
+    /// [`Algorithm::shortest_path`], the queue settles nodes strictly in order
+    /// of distance and breaks ties by the smaller node ID, and neighbors are
+    /// relaxed in the order [`Graph::neighbors`] yields them, so the event
+    /// sequence is deterministic for a given graph. The search stops once the
+    /// end node is settled.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the start node.
+    /// - `end_node_id`: Identifier of the destination node.
+    /// - `observer`: Called with each [`DijkstraEvent`] as it happens.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(DijkstraSearchResult)` with the same distance as
+    ///   [`Algorithm::shortest_path`]. Among several equally short paths the
+    ///   chosen one may differ.
+    /// - `Err(DijkstraError)` if a node is missing, the end node is
+    ///   unreachable, or the graph contains a negative edge weight.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::algorithm::SearchResult;
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(7)).is_none());
+    ///
+    /// let mut narration = Vec::new();
+    /// let result = DijkstraAlgorithm::new(graph)
+    ///     .shortest_path_with_observer("A", "B", |event| narration.push(event.to_string()))
+    ///     .unwrap();
+    /// assert_eq!(result.get_total_distance(), 7);
+    /// assert_eq!(narration, ["Settled A (0).", "Relaxed B to 7 via A.", "Settled B (7)."]);
+    /// ```
+    pub fn shortest_path_with_observer(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+        mut observer: impl FnMut(&DijkstraEvent<N, W>),
+    ) -> Result<DijkstraSearchResult<N, W>, DijkstraError> {
+        self.validate_graph()?;
+
+        let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The start node {} is not in the graph {}!",
+                start_node_id, self.graph
+            ))
+        })?;
+        let end = self.graph.get_node_by_id(end_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The end node {} is not in the graph {}!",
+                end_node_id, self.graph
+            ))
+        })?;
+
+        let mut distances = self.setup_shortest_distance(start);
+        let mut settled: HashSet<String> = HashSet::new();
+
+        // Min-heap on (distance, node ID): the ID is the deterministic tie-break.
+        let mut queue: BinaryHeap<Reverse<(W, String)>> = BinaryHeap::new();
+        queue.push(Reverse((W::zero(), start.get_id().to_string())));
+
+        while let Some(Reverse((distance, position_id))) = queue.pop() {
+            if settled.contains(&position_id) {
+                continue;
+            }
+            let Some(position) = self.graph.get_node_by_id(&position_id) else {
+                continue;
+            };
+            observer(&DijkstraEvent::Settled {
+                node: position.clone(),
+                distance,
+            });
+            settled.insert(position_id);
+            if position.get_id() == end.get_id() {
+                break;
+            }
+
+            for (neighbour, weight, kind, layer) in self.graph.labeled_neighbors(position) {
+                if settled.contains(neighbour.get_id()) || !self.is_edge_allowed(kind, layer) {
+                    continue;
+                }
+                let updated_distance = distance + weight;
+                let Some(entry) = distances.get_mut(neighbour.get_id()) else {
+                    continue;
+                };
+                if updated_distance < entry.distance {
+                    let improved = entry.previous_node.is_some();
+                    entry.reach(position.clone(), weight, updated_distance);
+                    observer(&DijkstraEvent::Relaxed {
+                        node: neighbour.clone(),
+                        distance: updated_distance,
+                        via: position.clone(),
+                        improved,
+                    });
+                    queue.push(Reverse((updated_distance, neighbour.get_id().to_string())));
+                }
+            }
+        }
+
+        if !settled.contains(end.get_id()) {
+            return Err(DijkstraError::new("A path could not be found!".to_string()));
+        }
+        let (path, distance) = Self::reconstruct_path(&distances, start, end)
+            .ok_or_else(|| DijkstraError::new("A path could not be found!".to_string()))?;
+        let hop_weights = Self::relaxed_hop_weights(&distances, &path);
+        self.build_search_result_with_hops(path, distance, hop_weights)
+    }
+
+    /// Starts a search that is driven one settled node at a time.
+    ///
+    /// Each call to [`DijkstraStepper::execute_step`] settles the next node
+    /// and relaxes its outgoing edges, so a caller can animate the search or
+    /// inspect the evolving distances in between. Nodes are settled in the
+    /// same order as in [`DijkstraAlgorithm::shortest_path_with_observer`].
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the node the search starts from.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(DijkstraStepper)` with only the start node queued.
+    /// - `Err(DijkstraError)` if the start node is missing or the graph
+    ///   contains a negative edge weight.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::GraphNode;
+    ///
+    /// let edges = vec![
+    ///     ("A".to_string(), "B".to_string(), 4),
+    ///     ("B".to_string(), "C".to_string(), 2),
+    /// ];
+    /// let dijkstra = DijkstraAlgorithm::new(DirectedGraph::from_edges(&edges).unwrap());
+    ///
+    /// let mut stepper = dijkstra.begin("A").unwrap();
+    /// let mut order = Vec::new();
+    /// while let Some((node, entry)) = stepper.execute_step() {
+    ///     order.push(format!("{}={}", node.get_id(), entry.get_distance()));
+    /// }
+    /// assert_eq!(order, ["A=0", "B=4", "C=6"]);
+    /// assert!(stepper.is_finished());
+    /// ```
+    pub fn begin(
+        &self,
+        start_node_id: &str,
+    ) -> Result<DijkstraStepper<'_, N, W, G>, DijkstraError> {
+        self.validate_graph()?;
+
+        let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The start node {} is not in the graph {}!",
+                start_node_id, self.graph
+            ))
+        })?;
+
+        let mut distances = self.setup_shortest_distance(start);
+        // The start node needs no predecessor sentinel while stepping.
+        if let Some(entry) = distances.get_mut(start.get_id()) {
+            entry.previous_node = None;
+        }
+        let mut queue = BinaryHeap::new();
+        queue.push(Reverse((W::zero(), start.get_id().to_string())));
+
+        Ok(DijkstraStepper {
+            algorithm: self,
+            distances,
+            settled: HashSet::new(),
+            queue,
+        })
+    }
+
+    /// Counts the distinct shortest paths between two nodes.
+    ///
+    /// Useful as a reliability metric: a pair with many equally short routes
+    /// is less sensitive to a single closed edge. The edges that lie on a
+    /// shortest path are ordered topologically, and every node adds its own
+    /// path count to each neighbor it reaches at that neighbor's shortest
+    /// distance. Zero-weight edges between nodes at the same distance are
+    /// counted like any other.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the start node.
+    /// - `end_node_id`: Identifier of the destination node.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(count)` with the number of shortest paths, saturating at
+    ///   `u64::MAX`. A node has exactly one shortest path to itself.
+    /// - `Ok(0)` when the end node is unreachable.
+    /// - `Err(DijkstraError)` if a node is missing, the graph contains a
+    ///   negative edge weight, or a cycle of zero-weight edges lies on a
+    ///   shortest path, which would allow infinitely many of them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let nodes: Vec<DefaultNode> = ["A", "B", "C", "D"]
+    ///     .iter()
+    ///     .map(|id| DefaultNode::new(id.to_string()))
+    ///     .collect();
+    /// let mut graph = UndirectedGraph::new(nodes.clone());
+    /// // Two routes of length 2 from A to D: via B and via C.
+    /// assert!(graph.insert_edge(&nodes[0], &nodes[1], Some(1)).is_none());
+    /// assert!(graph.insert_edge(&nodes[0], &nodes[2], Some(1)).is_none());
+    /// assert!(graph.insert_edge(&nodes[1], &nodes[3], Some(1)).is_none());
+    /// assert!(graph.insert_edge(&nodes[2], &nodes[3], Some(1)).is_none());
+    ///
+    /// let dijkstra = DijkstraAlgorithm::new(graph);
+    /// assert_eq!(dijkstra.count_shortest_paths("A", "D").unwrap(), 2);
+    /// assert_eq!(dijkstra.count_shortest_paths("A", "A").unwrap(), 1);
+    /// ```
+    pub fn count_shortest_paths(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+    ) -> Result<u64, DijkstraError> {
+        self.validate_graph()?;
+
+        let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The start node {} is not in the graph {}!",
+                start_node_id, self.graph
+            ))
+        })?;
+        let end = self.graph.get_node_by_id(end_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The end node {} is not in the graph {}!",
+                end_node_id, self.graph
+            ))
+        })?;
+
+        let _span = operation_span!(
+            "dijkstra.count_shortest_paths",
+            start = start.get_id(),
+            end = end.get_id(),
+        );
+
+        if start.get_id() == end.get_id() {
+            return Ok(1);
+        }
+        let Some(dag) = self.shortest_path_dag(start, end)? else {
+            return Ok(0);
+        };
+
+        // The order puts every predecessor first, so its count is final when read.
+        let mut counts: HashMap<String, u64> = HashMap::new();
+        for node in &dag.order {
+            let count = match dag.predecessors.get(node.get_id()) {
+                Some(predecessors) => predecessors.iter().fold(0u64, |sum, predecessor| {
+                    sum.saturating_add(counts[predecessor.get_id()])
+                }),
+                None => 1,
+            };
+            counts.insert(node.get_id().to_string(), count);
+        }
+
+        Ok(counts[end.get_id()])
+    }
+
+    /// Returns every shortest path between two nodes, not just one of them.
+    ///
+    /// Like in [`DijkstraAlgorithm::count_shortest_paths`], every node keeps
+    /// all predecessors that reach it at its shortest distance instead of a
+    /// single one, including over zero-weight edges. Backtracking from the end node over these
+    /// predecessors then yields each minimal path once. The single-path
+    /// [`Algorithm::shortest_path`] is unchanged.
+    ///
+    /// The number of paths can grow exponentially with the graph size, e.g.
+    /// on grids; use [`DijkstraAlgorithm::count_shortest_paths`] first if that
+    /// is a concern.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the start node.
+    /// - `end_node_id`: Identifier of the destination node.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<DijkstraSearchResult>)` with one result per shortest path,
+    ///   all with the same distance, sorted by their sequence of node IDs. A
+    ///   search from a node to itself returns the single path `[start]`
+    ///   with distance zero.
+    /// - `Err(DijkstraError)` if a node is missing, the end node is
+    ///   unreachable, the graph contains a negative edge weight, or a cycle
+    ///   of zero-weight edges lies on a shortest path.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::algorithm::SearchResult;
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::graph::GraphNode;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    ///
+    /// // Two routes of length 2 from A to D: via B and via C.
+    /// let edges = vec![
+    ///     ("A".to_string(), "B".to_string(), 1),
+    ///     ("A".to_string(), "C".to_string(), 1),
+    ///     ("B".to_string(), "D".to_string(), 1),
+    ///     ("C".to_string(), "D".to_string(), 1),
+    /// ];
+    /// let dijkstra = DijkstraAlgorithm::new(UndirectedGraph::from_edges(&edges).unwrap());
+    ///
+    /// let paths: Vec<Vec<String>> = dijkstra
+    ///     .all_shortest_paths("A", "D")
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|result| result.get_path().iter().map(|n| n.get_id().to_string()).collect())
+    ///     .collect();
+    /// assert_eq!(paths, [["A", "B", "D"], ["A", "C", "D"]]);
+    /// ```
+    pub fn all_shortest_paths(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+    ) -> Result<Vec<DijkstraSearchResult<N, W>>, DijkstraError> {
+        self.validate_graph()?;
+
+        let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The start node {} is not in the graph {}!",
+                start_node_id, self.graph
+            ))
+        })?;
+        let end = self.graph.get_node_by_id(end_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The end node {} is not in the graph {}!",
+                end_node_id, self.graph
+            ))
+        })?;
+
+        let _span = operation_span!(
+            "dijkstra.all_shortest_paths",
+            start = start.get_id(),
+            end = end.get_id(),
+        );
+
+        if start.get_id() == end.get_id() {
+            return Ok(vec![
+                self.build_search_result(vec![start.clone()], W::zero())?,
+            ]);
+        }
+
+        let Some(dag) = self.shortest_path_dag(start, end)? else {
+            return Err(DijkstraError::new(format!(
+                "No path from {} to {} exists!",
+                start_node_id, end_node_id
+            )));
+        };
+
+        // Backtrack from the end node; each stack entry is a reversed partial path.
+        let mut paths: Vec<Vec<N>> = Vec::new();
+        let mut stack: Vec<Vec<N>> = vec![vec![end.clone()]];
+        while let Some(partial) = stack.pop() {
+            let last = partial.last().expect("partial paths are never empty");
+            if last.get_id() == start.get_id() {
+                let mut path = partial;
+                path.reverse();
+                paths.push(path);
+                continue;
+            }
+            for predecessor in dag.predecessors.get(last.get_id()).into_iter().flatten() {
+                let mut extended = partial.clone();
+                extended.push(predecessor.clone());
+                stack.push(extended);
+            }
+        }
+
+        paths.sort_by(|a, b| {
+            a.iter()
+                .map(|node| node.get_id())
+                .cmp(b.iter().map(|node| node.get_id()))
+        });
+        paths
+            .into_iter()
+            .map(|path| self.build_search_result(path, dag.distance))
+            .collect()
+    }
+
+    /// Finds the path whose heaviest edge is as light as possible.
+    ///
+    /// This is the minimax (bottleneck) path: instead of the sum of the edge
+    /// weights, the largest single edge weight on the path is minimized. It
+    /// suits questions like "which route avoids the steepest climb?".
+    /// Relaxation uses `max(bottleneck, weight)` in place of
+    /// `distance + weight`; otherwise the search runs like Dijkstra.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the start node.
+    /// - `end_node_id`: Identifier of the destination node.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(DijkstraSearchResult)` whose `distance` is the minimized maximum
+    ///   edge weight, not the path length. `hop_weights` still lists every
+    ///   hop. A search from a node to itself returns the path `[start]` with
+    ///   a bottleneck of zero.
+    /// - `Err(DijkstraError)` if a node is missing, the end node is
+    ///   unreachable, or the graph contains a negative edge weight.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::graph::{Graph, GraphNode};
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let nodes: Vec<DefaultNode> = ["A", "B", "C"]
+    ///     .iter()
+    ///     .map(|id| DefaultNode::new(id.to_string()))
+    ///     .collect();
+    /// let mut graph = UndirectedGraph::new(nodes.clone());
+    /// // The direct edge is shortest, but the detour never exceeds 4.
+    /// assert!(graph.insert_edge(&nodes[0], &nodes[2], Some(6)).is_none());
+    /// assert!(graph.insert_edge(&nodes[0], &nodes[1], Some(4)).is_none());
+    /// assert!(graph.insert_edge(&nodes[1], &nodes[2], Some(4)).is_none());
+    ///
+    /// let result = DijkstraAlgorithm::new(graph).minimax_path("A", "C").unwrap();
+    /// let ids: Vec<&str> = result.path.iter().map(|node| node.get_id()).collect();
+    /// assert_eq!(ids, vec!["A", "B", "C"]);
+    /// assert_eq!(result.distance, 4);
+    /// ```
+    pub fn minimax_path(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+    ) -> Result<DijkstraSearchResult<N, W>, DijkstraError> {
+        self.validate_graph()?;
+
+        let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The start node {} is not in the graph {}!",
+                start_node_id, self.graph
+            ))
+        })?;
+        let end = self.graph.get_node_by_id(end_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The end node {} is not in the graph {}!",
+                end_node_id, self.graph
+            ))
+        })?;
+
+        let _span = operation_span!(
+            "dijkstra.minimax_path",
+            start = start.get_id(),
+            end = end.get_id(),
+        );
+
+        if start.get_id() == end.get_id() {
+            return self.build_search_result(vec![start.clone()], W::zero());
+        }
+
+        // Per reached node: smallest known bottleneck and its predecessor.
+        let mut best: HashMap<String, ShortestDistance<N, W>> = HashMap::new();
+        let mut settled: HashSet<String> = HashSet::new();
+        best.insert(
+            start.get_id().to_string(),
+            ShortestDistance::new(None, W::zero()),
+        );
+
+        let mut queue: BinaryHeap<Reverse<QueueItem<N, W>>> = BinaryHeap::new();
+        queue.push(Reverse(QueueItem::new(W::zero(), start.clone())));
+
+        while let Some(Reverse(QueueItem { distance, position })) = queue.pop() {
+            if !settled.insert(position.get_id().to_string()) {
+                continue;
+            }
+            if position.get_id() == end.get_id() {
+                break;
+            }
+
+            for (neighbour, weight, kind, layer) in self.graph.labeled_neighbors(&position) {
+                if settled.contains(neighbour.get_id()) || !self.is_edge_allowed(kind, layer) {
+                    continue;
+                }
+                let bottleneck = max(distance, weight);
+                let improves = best
+                    .get(neighbour.get_id())
+                    .is_none_or(|known| bottleneck < known.distance);
+                if improves {
+                    let mut entry = ShortestDistance::new(None, bottleneck);
+                    entry.reach(position.clone(), weight, bottleneck);
+                    best.insert(neighbour.get_id().to_string(), entry);
+                    queue.push(Reverse(QueueItem::new(bottleneck, neighbour.clone())));
+                }
+            }
+        }
+
+        let (path, bottleneck) = Self::reconstruct_path(&best, start, end).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "No path from {} to {} exists!",
+                start_node_id, end_node_id
+            ))
+        })?;
+        let hop_weights = Self::relaxed_hop_weights(&best, &path);
+        self.build_search_result_with_hops(path, bottleneck, hop_weights)
+    }
+
+    /// Computes shortest distances between every pair of nodes.
+    ///
+    /// Runs single-source Dijkstra once from every node. On sparse graphs this
+    /// is cheaper than Floyd-Warshall's `O(V³)`. With the `rayon` feature,
+    /// [`DijkstraAlgorithm::all_pairs_dijkstra_parallel`] gives the same
+    /// result using all cores.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(HashMap<String, HashMap<String, W>>)` mapping each source node ID
+    ///   to the output of [`DijkstraAlgorithm::distances_from`] for that node.
+    ///   Unreachable targets are absent from the inner map.
+    /// - `Err(DijkstraError)` if the graph contains a negative edge weight.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone(), c.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(2)).is_none());
+    /// assert!(graph.insert_edge(&b, &c, Some(3)).is_none());
+    ///
+    /// let all_pairs = DijkstraAlgorithm::new(graph).all_pairs_dijkstra().unwrap();
+    /// assert_eq!(all_pairs["A"].get("C"), Some(&5));
+    /// assert_eq!(all_pairs["C"].get("A"), None);
+    /// assert_eq!(all_pairs.len(), 3);
+    /// ```
+    pub fn all_pairs_dijkstra(&self) -> Result<HashMap<String, HashMap<String, W>>, DijkstraError> {
+        self.validate_graph()?;
+
+        self.graph
+            .iter_nodes()
+            .map(|start| Ok((start.get_id().to_string(), self.reached_distances(start)?)))
+            .collect()
+    }
+
+    /// Streams shortest distances between every pair of nodes to `writer`
+    /// as CSV.
+    ///
+    /// Unlike [`DijkstraAlgorithm::all_pairs_dijkstra`], nothing is
+    /// accumulated: single-source Dijkstra runs once per node, its rows are
+    /// written immediately, and the output is flushed after every source. At
+    /// any time only one source's distances are held in memory, so the result
+    /// may be far larger than RAM.
+    ///
+    /// # Format
+    ///
+    /// A `from,to,distance` header row, then one row per reachable pair.
+    /// Sources and, within a source, targets follow the node insertion order.
+    /// Unreachable pairs are omitted, and every node is listed as reaching
+    /// itself with distance `0`. Node IDs containing a comma, a quote, or a
+    /// line break are quoted following RFC 4180.
+    ///
+    /// ```text
+    /// from,to,distance
+    /// A,A,0
+    /// A,B,2
+    /// B,B,0
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`PathfinderError::Dijkstra`] if the graph contains a negative edge
+    ///   weight; nothing is written in that case.
+    /// - [`PathfinderError::Io`] if writing or flushing fails. Rows written
+    ///   before the failure stay in `writer`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(2)).is_none());
+    ///
+    /// let mut csv = Vec::new();
+    /// DijkstraAlgorithm::new(graph).all_pairs_to_writer(&mut csv).unwrap();
+    /// assert_eq!(String::from_utf8(csv).unwrap(), "from,to,distance\nA,A,0\nA,B,2\nB,B,0\n");
+    /// ```
+    pub fn all_pairs_to_writer<Out: Write>(&self, writer: Out) -> Result<(), PathfinderError> {
+        self.validate_graph()?;
+
+        let mut writer = BufWriter::new(writer);
+        writeln!(writer, "from,to,distance")?;
+
+        for start in self.graph.iter_nodes() {
+            let distances = self.reached_distances(start)?;
+            let from = escape_field(start.get_id(), ',');
+            for target in self.graph.iter_nodes() {
+                if let Some(distance) = distances.get(target.get_id()) {
+                    writeln!(
+                        writer,
+                        "{},{},{}",
+                        from,
+                        escape_field(target.get_id(), ','),
+                        distance
+                    )?;
+                }
+            }
+            // Hand each finished source to the underlying writer right away.
+            writer.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Computes shortest paths for many `(start, end)` pairs in one call.
+    ///
+    /// Pairs are grouped by start node, and the relaxation loop runs once per
+    /// distinct start; every end sharing that start reuses the same
+    /// distance/predecessor map. This is much cheaper than calling
+    /// [`Algorithm::shortest_path`] for each pair when starts repeat. The maps
+    /// are kept until the call returns, so memory grows with the number of
+    /// distinct starts.
+    ///
+    /// # Parameters
+    ///
+    /// - `pairs`: `(start_node_id, end_node_id)` pairs.
+    ///
+    /// # Returns
+    ///
+    /// One entry per input pair, in input order. Each entry is exactly what
+    /// [`Algorithm::shortest_path`] would return for that pair, including the
+    /// error for missing nodes or unreachable ends. A negative edge weight,
+    /// the only graph-level failure, is reported for every pair.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::algorithm::SearchResult;
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone(), c.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(2)).is_none());
+    /// assert!(graph.insert_edge(&b, &c, Some(3)).is_none());
+    ///
+    /// let dijkstra = DijkstraAlgorithm::new(graph);
+    /// let results = dijkstra.shortest_paths_batch(&[("A", "C"), ("B", "A"), ("A", "B")]);
+    /// assert_eq!(results[0].as_ref().unwrap().get_total_distance(), 5);
+    /// assert!(results[1].is_err());
+    /// assert_eq!(results[2].as_ref().unwrap().get_total_distance(), 2);
+    /// ```
+    pub fn shortest_paths_batch(
+        &self,
+        pairs: &[(&str, &str)],
+    ) -> Vec<Result<DijkstraSearchResult<N, W>, DijkstraError>> {
+        if let Err(err) = self.validate_graph() {
+            return pairs
+                .iter()
+                .map(|_| Err(DijkstraError::new(err.message.clone())))
+                .collect();
+        }
+
+        // One distance map per distinct start, computed the first time the start is seen.
+        let mut distances_by_start: HashMap<&str, DistanceMapResult<N, W>> = HashMap::new();
+
+        pairs
+            .iter()
+            .map(|&(start_node_id, end_node_id)| {
+                let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+                    DijkstraError::new(format!(
+                        "The start node {} is not in the graph {}!",
+                        start_node_id, self.graph
+                    ))
+                })?;
+                let end = self.graph.get_node_by_id(end_node_id).ok_or_else(|| {
+                    DijkstraError::new(format!(
+                        "The end node {} is not in the graph {}!",
+                        end_node_id, self.graph
+                    ))
+                })?;
+
+                match distances_by_start
+                    .entry(start_node_id)
+                    .or_insert_with(|| self.calculate_distances(start))
+                {
+                    Ok(distances) => self.search_result_from_distances(distances, start, end),
+                    Err(err) => Err(DijkstraError::new(err.message.clone())),
+                }
+            })
+            .collect()
+    }
+
+    /// Computes shortest paths from one start node to several end nodes.
+    ///
+    /// The distance/predecessor map of `start_node_id` is computed once and
+    /// every path is reconstructed from it, like
+    /// [`DijkstraAlgorithm::shortest_paths_batch`] with a shared start.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the start node.
+    /// - `end_node_ids`: Identifiers of the destination nodes.
+    ///
+    /// # Returns
+    ///
+    /// One entry per end node, in input order, each exactly what
+    /// [`Algorithm::shortest_path`] would return for that end node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::algorithm::SearchResult;
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone(), c.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(2)).is_none());
+    /// assert!(graph.insert_edge(&b, &c, Some(3)).is_none());
+    ///
+    /// let dijkstra = DijkstraAlgorithm::new(graph);
+    /// let results = dijkstra.shortest_paths_from("A", &["C", "B"]);
+    /// assert_eq!(results[0].as_ref().unwrap().get_total_distance(), 5);
+    /// assert_eq!(results[1].as_ref().unwrap().get_total_distance(), 2);
+    /// ```
+    pub fn shortest_paths_from(
+        &self,
+        start_node_id: &str,
+        end_node_ids: &[&str],
+    ) -> Vec<Result<DijkstraSearchResult<N, W>, DijkstraError>> {
+        let pairs: Vec<(&str, &str)> = end_node_ids
+            .iter()
+            .map(|&end_node_id| (start_node_id, end_node_id))
+            .collect();
+        self.shortest_paths_batch(&pairs)
+    }
+
+    /// Computes the next-best simple path after the shortest one.
+    ///
+    /// The search follows Yen's algorithm with `k = 2`: every node of the
+    /// shortest path is used once as a spur node, the edge the shortest path
+    /// takes out of that node is removed, and the remaining route to the end
+    /// node is recomputed without revisiting the root path. The cheapest of
+    /// these candidates is the second-shortest path.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the start node.
+    /// - `end_node_id`: Identifier of the destination node.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(DijkstraSearchResult))` with an alternative route that is
+    ///   never identical to the shortest path.
+    /// - `Ok(None)` when the shortest path is the only simple path.
+    /// - `Err(DijkstraError)` when the shortest path itself cannot be computed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::algorithm::SearchResult;
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let mut graph = DirectedGraph::default();
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// graph.insert_node(a.clone());
+    /// graph.insert_node(b.clone());
+    /// graph.insert_node(c.clone());
+    /// assert!(graph.insert_edge(&a, &b, Some(1)).is_none());
+    /// assert!(graph.insert_edge(&b, &c, Some(1)).is_none());
+    /// assert!(graph.insert_edge(&a, &c, Some(5)).is_none());
+    ///
+    /// let dijkstra = DijkstraAlgorithm::new(graph);
+    /// let alternative = dijkstra.second_shortest_path("A", "C").unwrap().unwrap();
+    /// assert_eq!(alternative.get_total_distance(), 5);
+    /// assert_eq!(alternative.get_path().len(), 2);
+    /// ```
+    pub fn second_shortest_path(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+    ) -> Result<Option<DijkstraSearchResult<N, W>>, DijkstraError> {
+        let shortest = self.shortest_path(start_node_id, end_node_id)?;
+        let shortest_path = &shortest.path;
+        let end = &shortest_path[shortest_path.len() - 1];
+
+        let mut best_candidate: Option<(Vec<N>, W)> = None;
+
+        for spur_index in 0..shortest_path.len() - 1 {
+            let spur_node = &shortest_path[spur_index];
+            let root_path = &shortest_path[..=spur_index];
+
+            // Force a deviation right after the spur node.
+            let mut excluded_edges: HashSet<(String, String)> = HashSet::new();
+            excluded_edges.insert((
+                spur_node.get_id().to_string(),
+                shortest_path[spur_index + 1].get_id().to_string(),
+            ));
+
+            // Root nodes before the spur node must not be revisited to keep the path simple.
+            let excluded_nodes: HashSet<String> = root_path[..spur_index]
+                .iter()
+                .map(|node| node.get_id().to_string())
+                .collect();
+
+            let distances = self.calculate_distances_with_exclusions(
+                spur_node,
+                &excluded_nodes,
+                &excluded_edges,
+                &mut SearchStats::default(),
+            )?;
+            let Some((spur_path, spur_distance)) =
+                Self::reconstruct_path(&distances, spur_node, end)
+            else {
+                continue;
+            };
+
+            let root_distance = self.path_weight(root_path).ok_or_else(|| {
+                DijkstraError::new(format!(
+                    "Unable to determine the weight of the root path ending at {}!",
+                    spur_node
+                ))
+            })?;
+
+            let mut candidate_path: Vec<N> = root_path[..spur_index].to_vec();
+            candidate_path.extend(spur_path);
+            let candidate_distance = root_distance + spur_distance;
+
+            // Yen's construction always deviates, but guard the contract explicitly.
+            if candidate_path == *shortest_path {
+                continue;
+            }
+
+            let is_better = match &best_candidate {
+                Some((_, best_distance)) => candidate_distance < *best_distance,
+                None => true,
+            };
+            if is_better {
+                best_candidate = Some((candidate_path, candidate_distance));
+            }
+        }
+
+        match best_candidate {
+            Some((path, distance)) => self.build_search_result(path, distance).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Finds up to `k` loopless paths in non-decreasing order of distance
+    /// using Yen's algorithm.
+    ///
+    /// The first path is the [`Algorithm::shortest_path`] result. Every
+    /// further path is derived from the previous one: for each of its nodes
+    /// (the spur node), the edges that earlier paths with the same prefix
+    /// take next are blocked, the prefix nodes are excluded, and a Dijkstra
+    /// search from the spur node completes the route. The cheapest candidate
+    /// not found before becomes the next path. This extends the single
+    /// deviation search of [`DijkstraAlgorithm::second_shortest_path`].
+    ///
+    /// Candidates with equal distance keep the order in which they were
+    /// found, so the result is deterministic.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the start node.
+    /// - `end_node_id`: Identifier of the destination node.
+    /// - `k`: Maximum number of paths to return.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<DijkstraSearchResult>)` with at most `k` distinct paths; fewer
+    ///   when the graph has fewer simple paths, none when `k` is `0`.
+    /// - `Err(DijkstraError)` when the shortest path itself cannot be computed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::algorithm::SearchResult;
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    ///
+    /// let edges: Vec<(String, String, u16)> = [("A", "B", 1), ("B", "C", 1), ("A", "C", 5)]
+    ///     .iter()
+    ///     .map(|(from, to, weight)| (from.to_string(), to.to_string(), *weight))
+    ///     .collect();
+    /// let dijkstra = DijkstraAlgorithm::new(DirectedGraph::from_edges(&edges).unwrap());
+    ///
+    /// let paths = dijkstra.k_shortest_paths("A", "C", 3).unwrap();
+    /// let distances: Vec<u16> = paths.iter().map(|path| path.get_total_distance()).collect();
+    /// assert_eq!(distances, vec![2, 5]);
+    /// ```
+    pub fn k_shortest_paths(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+        k: usize,
+    ) -> Result<Vec<DijkstraSearchResult<N, W>>, DijkstraError> {
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+        let shortest = self.shortest_path(start_node_id, end_node_id)?;
+        let end = shortest.path[shortest.path.len() - 1].clone();
+
+        let mut found: Vec<(Vec<N>, W)> = vec![(shortest.path, shortest.distance)];
+        let mut candidates: Vec<(Vec<N>, W)> = Vec::new();
+        let mut seen: HashSet<Vec<N>> = HashSet::from([found[0].0.clone()]);
+
+        while found.len() < k {
+            let previous_path = found[found.len() - 1].0.clone();
+
+            for spur_index in 0..previous_path.len() - 1 {
+                let spur_node = &previous_path[spur_index];
+                let root_path = &previous_path[..=spur_index];
+
+                // Block the next hop of every found path that shares this root.
+                let excluded_edges: HashSet<(String, String)> = found
+                    .iter()
+                    .filter(|(path, _)| {
+                        path.len() > spur_index + 1 && path[..=spur_index] == *root_path
+                    })
+                    .map(|(path, _)| {
+                        (
+                            spur_node.get_id().to_string(),
+                            path[spur_index + 1].get_id().to_string(),
+                        )
+                    })
+                    .collect();
+
+                // Root nodes before the spur node must not be revisited to keep the path simple.
+                let excluded_nodes: HashSet<String> = root_path[..spur_index]
+                    .iter()
+                    .map(|node| node.get_id().to_string())
+                    .collect();
+
+                let distances = self.calculate_distances_with_exclusions(
+                    spur_node,
+                    &excluded_nodes,
+                    &excluded_edges,
+                    &mut SearchStats::default(),
+                )?;
+                let Some((spur_path, spur_distance)) =
+                    Self::reconstruct_path(&distances, spur_node, &end)
+                else {
+                    continue;
+                };
+
+                let root_distance = self.path_weight(root_path).ok_or_else(|| {
+                    DijkstraError::new(format!(
+                        "Unable to determine the weight of the root path ending at {}!",
+                        spur_node
+                    ))
+                })?;
+
+                let mut candidate_path: Vec<N> = root_path[..spur_index].to_vec();
+                candidate_path.extend(spur_path);
+                if seen.insert(candidate_path.clone()) {
+                    candidates.push((candidate_path, root_distance + spur_distance));
+                }
+            }
+
+            // `min_by_key` returns the first of equal candidates, keeping discovery order.
+            let Some(best_index) = candidates
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, (_, distance))| *distance)
+                .map(|(index, _)| index)
+            else {
+                break;
+            };
+            found.push(candidates.remove(best_index));
+        }
+
+        found
+            .into_iter()
+            .map(|(path, distance)| self.build_search_result(path, distance))
+            .collect()
+    }
+
+    /// Computes the shortest path while honoring forbidden transitions.
+    ///
+    /// Each [`TurnRestriction`] forbids entering `to` directly after traversing
+    /// the edge `via.0 -> via.1`. To respect this, the search state is expanded
+    /// from a node to `(node, previous node)`, so the same node can be settled
+    /// once per arriving edge. The resulting path may therefore revisit nodes
+    /// when a detour is required.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the start node.
+    /// - `end_node_id`: Identifier of the destination node.
+    /// - `restrictions`: Forbidden transitions.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(DijkstraSearchResult)` with the cheapest path using no forbidden turn.
+    /// - `Err(DijkstraError)` if a node is missing, a negative weight is
+    ///   encountered, or no allowed path exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::algorithm::SearchResult;
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::algorithms::turn_restriction::TurnRestriction;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let mut graph = DirectedGraph::default();
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// graph.insert_node(a.clone());
+    /// graph.insert_node(b.clone());
+    /// graph.insert_node(c.clone());
+    /// assert!(graph.insert_edge(&a, &b, Some(1)).is_none());
+    /// assert!(graph.insert_edge(&b, &c, Some(1)).is_none());
+    /// assert!(graph.insert_edge(&a, &c, Some(5)).is_none());
+    ///
+    /// let dijkstra = DijkstraAlgorithm::new(graph);
+    /// let restrictions = vec![TurnRestriction::new("A", "B", "C")];
+    /// let result = dijkstra
+    ///     .shortest_path_with_turn_restrictions("A", "C", &restrictions)
+    ///     .unwrap();
+    /// assert_eq!(result.get_total_distance(), 5);
+    /// ```
+    pub fn shortest_path_with_turn_restrictions(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+        restrictions: &[TurnRestriction],
+    ) -> Result<DijkstraSearchResult<N, W>, DijkstraError> {
+        self.validate_graph()?;
+
+        let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The start node {} is not in the graph {}!",
+                start_node_id, self.graph
+            ))
+        })?;
+        let end = self.graph.get_node_by_id(end_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The end node {} is not in the graph {}!",
+                end_node_id, self.graph
+            ))
+        })?;
+
+        // Search states are `(node, node we arrived from)`.
+        let mut best_distances: HashMap<(N, Option<N>), W> = HashMap::new();
+        let mut predecessors: HashMap<(N, Option<N>), (N, Option<N>)> = HashMap::new();
+        let mut queue: BinaryHeap<TurnQueueItem<N, W>> = BinaryHeap::new();
+
+        best_distances.insert((start.clone(), None), W::zero());
+        queue.push(TurnQueueItem {
+            distance: W::zero(),
+            position: start.clone(),
+            arrived_from: None,
+        });
+
+        while let Some(TurnQueueItem {
+            distance,
+            position,
+            arrived_from,
+        }) = queue.pop()
+        {
+            let state = (position, arrived_from);
+
+            // Skip stale queue entries superseded by a shorter known path.
+            if best_distances
+                .get(&state)
+                .is_some_and(|best| distance > *best)
+            {
+                continue;
+            }
+
+            // With a min-heap the first settled end state is optimal.
+            if state.0.get_id() == end.get_id() {
+                let mut path = vec![state.0.clone()];
+                let mut current = &state;
+                while let Some(previous) = predecessors.get(current) {
+                    path.push(previous.0.clone());
+                    current = previous;
+                }
+                path.reverse();
+
+                return self.build_search_result(path, distance);
+            }
+
+            for (neighbour, weight, kind, layer) in self.graph.labeled_neighbors(&state.0) {
+                if weight < W::zero() {
+                    return Err(DijkstraError::new(format!(
+                        "In the 'Dijkstra' algorithm only positive edge weights are allowed! Edge: [ from: {}, to: {}, weight: {} ]",
+                        state.0, neighbour, weight
+                    )));
+                }
+
+                if !self.is_edge_allowed(kind, layer) {
+                    continue;
+                }
+
+                // Reject the transition `arrived_from -> position -> neighbour` if restricted.
+                if let Some(previous) = &state.1
+                    && restrictions.iter().any(|restriction| {
+                        restriction.forbids(previous.get_id(), state.0.get_id(), neighbour.get_id())
+                    })
+                {
+                    continue;
+                }
+
+                let next_state = (neighbour.clone(), Some(state.0.clone()));
+                let updated_distance = distance + weight;
+
+                if best_distances
+                    .get(&next_state)
+                    .is_none_or(|best| updated_distance < *best)
+                {
+                    best_distances.insert(next_state.clone(), updated_distance);
+                    predecessors.insert(next_state.clone(), state.clone());
+                    queue.push(TurnQueueItem {
+                        distance: updated_distance,
+                        position: next_state.0,
+                        arrived_from: next_state.1,
+                    });
+                }
+            }
+        }
+
+        Err(DijkstraError::new(format!(
+            "No path from {} to {} respects the given turn restrictions!",
+            start_node_id, end_node_id
+        )))
+    }
+
+    /// Checks whether an edge with this kind and layer may be traversed.
+    ///
+    /// Takes the labels of one adjacency entry from
+    /// [`Graph::labeled_neighbors`], so parallel edges are judged one by one.
+    fn is_edge_allowed(&self, kind: Option<&str>, layer: Option<&str>) -> bool {
+        if let Some(layer) = layer
+            && self.disabled_layers.contains(layer)
+        {
+            return false;
+        }
+        let Some(allowed_kinds) = &self.allowed_kinds else {
+            return true;
+        };
+
+        match kind {
+            Some(kind) => allowed_kinds.contains(kind),
+            None => self.allow_edges_without_kind,
+        }
+    }
+
+    /// Checks whether the edge `from -> to` was excluded for the current run.
+    ///
+    /// Undirected graphs treat an excluded pair as blocked in both directions.
+    fn is_edge_excluded(
+        &self,
+        from: &N,
+        to: &N,
+        excluded_edges: &HashSet<(String, String)>,
+    ) -> bool {
+        if excluded_edges.is_empty() {
+            return false;
+        }
+
+        let forward = (from.get_id().to_string(), to.get_id().to_string());
+        if excluded_edges.contains(&forward) {
+            return true;
+        }
+
+        !self.graph.is_directed()
+            && excluded_edges.contains(&(to.get_id().to_string(), from.get_id().to_string()))
+    }
+
+    /// Collects every shortest route from `start` to `end`.
+    ///
+    /// A Dijkstra run first settles all nodes up to the distance of `end`.
+    /// An edge `u -> v` is tight when `dist(u) + weight == dist(v)`, and the
+    /// tight edges leading to `end` hold every shortest path. They are ordered
+    /// topologically rather than by distance, so a node reached over a
+    /// zero-weight edge still comes after its predecessor at the same
+    /// distance.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(ShortestPathDag))` when `end` is reachable.
+    /// - `Ok(None)` when it is not.
+    /// - `Err(DijkstraError)` if the tight edges leading to `end` contain a
+    ///   cycle, which is only possible with zero-weight edges.
+    fn shortest_path_dag(
+        &self,
+        start: &N,
+        end: &N,
+    ) -> Result<Option<ShortestPathDag<N, W>>, DijkstraError> {
+        let mut distances: HashMap<String, W> = HashMap::new();
+        let mut settled_ids: HashSet<String> = HashSet::new();
+        let mut settled: Vec<N> = Vec::new();
+        let mut end_distance = None;
+        distances.insert(start.get_id().to_string(), W::zero());
+
+        let mut queue: BinaryHeap<Reverse<QueueItem<N, W>>> = BinaryHeap::new();
+        queue.push(Reverse(QueueItem::new(W::zero(), start.clone())));
+
+        // Settle every node that is not farther away than `end`.
+        while let Some(Reverse(QueueItem { distance, position })) = queue.pop() {
+            if end_distance.is_some_and(|end_distance| distance > end_distance) {
+                break;
+            }
+            if !settled_ids.insert(position.get_id().to_string()) {
+                continue;
+            }
+            if position.get_id() == end.get_id() {
+                end_distance = Some(distance);
+            }
+
+            for (neighbour, weight, kind, layer) in self.graph.labeled_neighbors(&position) {
+                if settled_ids.contains(neighbour.get_id()) || !self.is_edge_allowed(kind, layer) {
+                    continue;
+                }
+                let updated_distance = distance + weight;
+                if distances
+                    .get(neighbour.get_id())
+                    .is_none_or(|&known| updated_distance < known)
+                {
+                    distances.insert(neighbour.get_id().to_string(), updated_distance);
+                    queue.push(Reverse(QueueItem::new(updated_distance, neighbour.clone())));
+                }
+            }
+            settled.push(position);
+        }
+        let Some(end_distance) = end_distance else {
+            return Ok(None);
+        };
+
+        let mut predecessors: HashMap<String, Vec<N>> = HashMap::new();
+        for node in &settled {
+            let distance = distances[node.get_id()];
+            for (neighbour, weight, kind, layer) in self.graph.labeled_neighbors(node) {
+                if settled_ids.contains(neighbour.get_id())
+                    && self.is_edge_allowed(kind, layer)
+                    && distances[neighbour.get_id()] == distance + weight
+                {
+                    predecessors
+                        .entry(neighbour.get_id().to_string())
+                        .or_default()
+                        .push(node.clone());
+                }
+            }
+        }
+
+        // Keep only the nodes from which `end` is reached over tight edges.
+        let mut on_route: HashSet<String> = HashSet::from([end.get_id().to_string()]);
+        let mut stack = vec![end.clone()];
+        while let Some(node) = stack.pop() {
+            for predecessor in predecessors.get(node.get_id()).into_iter().flatten() {
+                if on_route.insert(predecessor.get_id().to_string()) {
+                    stack.push(predecessor.clone());
+                }
+            }
+        }
+        predecessors.retain(|id, _| on_route.contains(id));
+
+        // Kahn's algorithm: a node is ready once all its predecessors are placed.
+        let mut waiting_for: HashMap<String, usize> = HashMap::new();
+        let mut successors: HashMap<String, Vec<N>> = HashMap::new();
+        for (id, node_predecessors) in &predecessors {
+            waiting_for.insert(id.clone(), node_predecessors.len());
+            let node = self.graph.get_node_by_id(id).expect("settled nodes exist");
+            for predecessor in node_predecessors {
+                successors
+                    .entry(predecessor.get_id().to_string())
+                    .or_default()
+                    .push(node.clone());
+            }
+        }
+        let mut ready: Vec<N> = settled
+            .iter()
+            .filter(|node| {
+                on_route.contains(node.get_id()) && !waiting_for.contains_key(node.get_id())
+            })
+            .cloned()
+            .collect();
+        let mut order = Vec::with_capacity(on_route.len());
+        while let Some(node) = ready.pop() {
+            for successor in successors.get(node.get_id()).into_iter().flatten() {
+                let waiting = waiting_for
+                    .get_mut(successor.get_id())
+                    .expect("successors have predecessors");
+                *waiting -= 1;
+                if *waiting == 0 {
+                    ready.push(successor.clone());
+                }
+            }
+            order.push(node);
+        }
+
+        if order.len() < on_route.len() {
+            return Err(DijkstraError::new(format!(
+                "The shortest paths from {} to {} run through a cycle of zero-weight edges!",
+                start.get_id(),
+                end.get_id()
+            )));
+        }
+
+        Ok(Some(ShortestPathDag {
+            distance: end_distance,
+            predecessors,
+            order,
+        }))
+    }
+
+    /// Sums the cheapest usable edge weights between consecutive nodes of
+    /// `path`; see [`DijkstraAlgorithm::hop_weights`].
+    ///
+    /// # Returns
+    ///
+    /// - `Some(W)` with the total path weight.
+    /// - `None` if two consecutive nodes are not connected.
+    fn path_weight(&self, path: &[N]) -> Option<W> {
+        Some(
+            self.hop_weights(path)?
+                .into_iter()
+                .fold(W::zero(), |total, weight| total + weight),
+        )
+    }
+
+    /// Looks up the cheapest usable edge weight between each pair of
+    /// consecutive nodes.
+    ///
+    /// Only edges that pass [`DijkstraAlgorithm::is_edge_allowed`] count, so
+    /// a hop with a filtered kind or disabled layer has no weight, just like
+    /// during relaxation. Prefer
+    /// [`DijkstraAlgorithm::relaxed_hop_weights`] when a distance map of the
+    /// search is at hand.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(Vec<W>)` with `path.len() - 1` weights.
+    /// - `None` if two consecutive nodes are not connected by a usable edge.
+    fn hop_weights(&self, path: &[N]) -> Option<Vec<W>> {
+        path.windows(2)
+            .map(|pair| {
+                self.graph
+                    .labeled_neighbors(&pair[0])
+                    .filter(|&(neighbour, _, kind, layer)| {
+                        neighbour.get_id() == pair[1].get_id() && self.is_edge_allowed(kind, layer)
+                    })
+                    .map(|(_, weight, _, _)| weight)
+                    .min()
+            })
+            .collect()
+    }
+
+    /// Reads the weights of the edges that were relaxed to reach every node
+    /// of `path` after the first one.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(Vec<W>)` with `path.len() - 1` weights.
+    /// - `None` if a node of `path` has no recorded edge weight.
+    fn relaxed_hop_weights(
+        distances: &HashMap<String, ShortestDistance<N, W>>,
+        path: &[N],
+    ) -> Option<Vec<W>> {
+        path.iter()
+            .skip(1)
+            .map(|node| distances.get(node.get_id())?.previous_edge_weight)
+            .collect()
+    }
+
+    /// Builds a [`DijkstraSearchResult`] and attaches its per-hop weights.
+    ///
+    /// A single-node `path` (start and end node are identical) becomes a
+    /// result without hops. If the hop weights cannot be resolved, the result
+    /// is returned without them.
+    fn build_search_result(
+        &self,
+        path: Vec<N>,
+        distance: W,
+    ) -> Result<DijkstraSearchResult<N, W>, DijkstraError> {
+        let hop_weights = self.hop_weights(&path);
+        self.build_search_result_with_hops(path, distance, hop_weights)
+    }
+
+    /// Builds a [`DijkstraSearchResult`] with the given per-hop weights.
+    ///
+    /// Like [`DijkstraAlgorithm::build_search_result`], but takes the weights
+    /// the search recorded instead of looking them up again. `None` (or a
+    /// single-node `path`) yields a result without hops.
+    fn build_search_result_with_hops(
+        &self,
+        path: Vec<N>,
+        distance: W,
+        hop_weights: Option<Vec<W>>,
+    ) -> Result<DijkstraSearchResult<N, W>, DijkstraError> {
+        if path.len() == 1 {
+            // `DijkstraSearchResult::new` insists on two nodes for user-built results.
+            return Ok(DijkstraSearchResult {
+                path,
+                distance,
+                hop_weights: Vec::new(),
+            });
+        }
+        let mut result = DijkstraSearchResult::new(path, distance).map_err(DijkstraError::new)?;
+        result.hop_weights = hop_weights.unwrap_or_default();
+        Ok(result)
+    }
+
+    /// Builds the [`Algorithm::shortest_path`] result for `end` from a
+    /// finished distance map of `start`.
+    ///
+    /// Shared by [`Algorithm::shortest_path`] and
+    /// [`DijkstraAlgorithm::shortest_paths_batch`] so both report identical
+    /// paths and errors.
+    fn search_result_from_distances(
+        &self,
+        distances: &HashMap<String, ShortestDistance<N, W>>,
+        start: &N,
+        end: &N,
+    ) -> Result<DijkstraSearchResult<N, W>, DijkstraError> {
+        if start.get_id() == end.get_id() {
+            return self.build_search_result(vec![start.clone()], W::zero());
+        }
+
+        // Reconstruct the shortest route by walking predecessors from end to start.
+        let mut path: Vec<N> = vec![];
+        let mut current_node = end.clone();
+        let mut output_distance = W::zero();
+
+        while let Some(distance) = distances.get(current_node.get_id()) {
+            if current_node.get_id() == end.get_id() {
+                output_distance = distance.distance;
+            }
+            path.push(current_node);
+            let prev: &N = match &distance.previous_node {
+                Some(node) => node,
+                None => {
+                    return Err(DijkstraError::new(format!(
+                        "Unable to determine a valid path from {} to {}!",
+                        start.get_id(),
+                        end.get_id()
+                    )));
+                }
+            };
+            if start.get_id() == prev.get_id() {
+                // The start node references itself as predecessor sentinel.
+                path.push(start.clone());
+                break;
+            }
+            current_node = prev.clone();
+        }
+
+        // check if a path really has been found
+        if path.last() != Some(start) {
+            return Err(DijkstraError::new("A path could not be found!".to_string()));
+        }
+
+        // Path is collected from end to start; reverse to return start -> end.
+        path.reverse();
+
+        let hop_weights = Self::relaxed_hop_weights(distances, &path);
+        self.build_search_result_with_hops(path, output_distance, hop_weights)
+    }
+
+    /// Walks predecessor links from `end` back to `start`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((path, distance))` with the path ordered from `start` to `end`.
+    /// - `None` when `end` is unreachable in `distances`.
+    fn reconstruct_path(
+        distances: &HashMap<String, ShortestDistance<N, W>>,
+        start: &N,
+        end: &N,
+    ) -> Option<(Vec<N>, W)> {
+        let total_distance = distances.get(end.get_id())?.distance;
+        let mut path: Vec<N> = vec![end.clone()];
+        let mut current_node = end;
+
+        while current_node.get_id() != start.get_id() {
+            let previous = distances
+                .get(current_node.get_id())?
+                .previous_node
+                .as_ref()?;
+            path.push(previous.clone());
+            current_node = previous;
+        }
+
+        path.reverse();
+        Some((path, total_distance))
+    }
+}
+
+impl<N: GraphNode, G: Graph<Node = N, Weight = u16> + Display> DijkstraAlgorithm<N, u16, G> {
+    /// Computes a shortest path that prefers routing through favored nodes.
+    ///
+    /// During relaxation, every edge incident to a node in `node_bonus` is
+    /// discounted by that node's bonus (by both bonuses if both endpoints are
+    /// favored). The discounted weight never drops below zero. The discount
+    /// only biases which path is chosen: the returned distance and hop weights
+    /// are the true edge weights of that path.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_node_id`: Identifier of the start node.
+    /// - `end_node_id`: Identifier of the destination node.
+    /// - `node_bonus`: Discount per favored node ID. Unknown IDs are ignored.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(DijkstraSearchResult)` with the path that is cheapest under the
+    ///   discounted weights.
+    /// - `Err(DijkstraError)` if a node is missing, no path exists, or the
+    ///   true distance of the chosen path does not fit into `u16`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    ///
+    /// use shortest_path_finder::algorithms::algorithm::SearchResult;
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::{Graph, GraphNode};
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let hub = DefaultNode::new("Hub".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone(), hub.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(5)).is_none());
+    /// assert!(graph.insert_edge(&a, &hub, Some(3)).is_none());
+    /// assert!(graph.insert_edge(&hub, &b, Some(3)).is_none());
+    ///
+    /// let dijkstra = DijkstraAlgorithm::new(graph);
+    /// let node_bonus = HashMap::from([("Hub".to_string(), 2)]);
+    /// let result = dijkstra
+    ///     .shortest_path_with_node_bonus("A", "B", &node_bonus)
+    ///     .unwrap();
+    /// assert_eq!(result.get_path()[1].get_id(), "Hub");
+    /// assert_eq!(result.get_total_distance(), 6);
+    /// ```
+    pub fn shortest_path_with_node_bonus(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+        node_bonus: &HashMap<String, u16>,
+    ) -> Result<DijkstraSearchResult<N, u16>, DijkstraError> {
+        let start = self.graph.get_node_by_id(start_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The start node {} is not in the graph {}!",
+                start_node_id, self.graph
+            ))
+        })?;
+        let end = self.graph.get_node_by_id(end_node_id).ok_or_else(|| {
+            DijkstraError::new(format!(
+                "The end node {} is not in the graph {}!",
+                end_node_id, self.graph
+            ))
+        })?;
+        if start.get_id() == end.get_id() {
+            // Discounts can't change the trivial route; keep its usual shape.
+            return self.shortest_path(start_node_id, end_node_id);
+        }
+
+        let bonus_of = |node: &N| node_bonus.get(node.get_id()).copied().unwrap_or(0);
+
+        // Discounted distances and the predecessor used to reach each node.
+        let mut biased_distances: HashMap<String, u16> = HashMap::new();
+        let mut predecessors: HashMap<String, N> = HashMap::new();
+        let mut settled: HashSet<String> = HashSet::new();
+
+        // `Reverse` turns the max-heap into a min-heap, so every node is
+        // expanded once, at its final discounted distance.
+        let mut queue: BinaryHeap<Reverse<QueueItem<N, u16>>> = BinaryHeap::new();
+
+        biased_distances.insert(start.get_id().to_string(), 0);
+        queue.push(Reverse(QueueItem::new(0, start.clone())));
+
+        while let Some(Reverse(QueueItem { distance, position })) = queue.pop() {
+            if !settled.insert(position.get_id().to_string()) {
+                continue;
+            }
+            if position.get_id() == end.get_id() {
+                break;
+            }
+
+            for (neighbour, weight, kind, layer) in self.graph.labeled_neighbors(&position) {
+                if settled.contains(neighbour.get_id()) || !self.is_edge_allowed(kind, layer) {
+                    continue;
+                }
+
+                let discount = bonus_of(&position).saturating_add(bonus_of(neighbour));
+                // Saturating keeps an overlong detour from overflowing; it
+                // then simply never beats a real distance.
+                let updated_distance = distance.saturating_add(weight.saturating_sub(discount));
+
+                if biased_distances
+                    .get(neighbour.get_id())
+                    .is_none_or(|&best| updated_distance < best)
+                {
+                    biased_distances.insert(neighbour.get_id().to_string(), updated_distance);
+                    predecessors.insert(neighbour.get_id().to_string(), position.clone());
+                    queue.push(Reverse(QueueItem::new(updated_distance, neighbour.clone())));
+                }
+            }
+        }
+
+        if !predecessors.contains_key(end.get_id()) {
+            return Err(DijkstraError::new(format!(
+                "No path from {} to {} exists!",
+                start_node_id, end_node_id
+            )));
+        }
+
+        // The start node never gets a predecessor, so the walk stops there.
+        let mut path = vec![end.clone()];
+        while let Some(previous) = predecessors.get(path[path.len() - 1].get_id()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        // Report the true weights of the chosen path, not the discounted ones.
+        let hop_weights = self.hop_weights(&path).ok_or_else(|| {
+            DijkstraError::new("Unable to resolve the weights of the chosen path!".to_string())
+        })?;
+        let distance = hop_weights
+            .iter()
+            .try_fold(0u16, |total, &weight| total.checked_add(weight))
+            .ok_or_else(|| {
+                DijkstraError::new(format!(
+                    "The distance from {} to {} exceeds {}!",
+                    start_node_id,
+                    end_node_id,
+                    u16::MAX
+                ))
+            })?;
+        self.build_search_result_with_hops(path, distance, Some(hop_weights))
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<N, W, G> DijkstraAlgorithm<N, W, G>
+where
+    N: GraphNode + Sync,
+    W: GraphWeight + Ord + Send,
+    G: Graph<Node = N, Weight = W> + Display + Sync,
+{
+    /// Parallel variant of [`DijkstraAlgorithm::all_pairs_dijkstra`].
+    ///
+    /// Every source node runs on the `rayon` thread pool. The searches are
+    /// independent and read-only, so the result is identical to the
+    /// sequential call. Requires the `rayon` feature.
+    ///
+    /// # Returns
+    ///
+    /// Same contract as [`DijkstraAlgorithm::all_pairs_dijkstra`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(4)).is_none());
+    ///
+    /// let dijkstra = DijkstraAlgorithm::new(graph);
+    /// assert_eq!(
+    ///     dijkstra.all_pairs_dijkstra_parallel().unwrap(),
+    ///     dijkstra.all_pairs_dijkstra().unwrap()
+    /// );
+    /// ```
+    pub fn all_pairs_dijkstra_parallel(
+        &self,
+    ) -> Result<HashMap<String, HashMap<String, W>>, DijkstraError> {
+        use rayon::prelude::*;
+
+        self.validate_graph()?;
+
+        self.graph
+            .get_all_nodes()
+            .par_iter()
+            .map(|start| Ok((start.get_id().to_string(), self.reached_distances(start)?)))
+            .collect()
+    }
+}
+
+/// Outcome of one relaxation run: the distance map of a start node or the error.
+type DistanceMapResult<N, W> = Result<HashMap<String, ShortestDistance<N, W>>, DijkstraError>;
+
+/// Every shortest route from a start node to one end node, as collected by
+/// [`DijkstraAlgorithm::shortest_path_dag`].
+struct ShortestPathDag<N: GraphNode, W: GraphWeight> {
+    /// Shortest distance from the start node to the end node.
+    distance: W,
+    /// Per node ID: every predecessor reaching the node at its shortest
+    /// distance, once per usable parallel edge.
+    predecessors: HashMap<String, Vec<N>>,
+    /// Nodes on a shortest route, start node first and each node after all
+    /// of its predecessors.
+    order: Vec<N>,
+}
+
+/// Internal priority-queue element used by the Dijkstra processing loop.
+///
+/// The queue stores candidate nodes ordered by distance.
+#[derive(Eq, PartialEq)]
+struct QueueItem<N: GraphNode, W: GraphWeight> {
+    /// Candidate distance for this queue step.
+    distance: W,
+    /// Candidate node position associated with `distance`.
+    position: N,
+}
+
+impl<N: GraphNode, W: GraphWeight + Ord + Eq> QueueItem<N, W> {
+    /// Creates a new queue item.
+    ///
+    /// # Parameters
+    ///
+    /// - `distance`: Tentative distance for `position`.
+    /// - `position`: Node to be processed next by the queue consumer.
+    ///
+    /// # Returns
+    ///
+    /// A new [`QueueItem`].
+    fn new(distance: W, position: N) -> Self {
+        Self { distance, position }
+    }
+}
+
+impl<N: GraphNode, W: GraphWeight + Ord + Eq> PartialOrd for QueueItem<N, W> {
+    /// Defers partial ordering to [`Ord`] for `BinaryHeap` compatibility.
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: GraphNode, W: GraphWeight + Ord + Eq> Ord for QueueItem<N, W> {
+    /// Orders queue entries by distance.
+    ///
+    /// `BinaryHeap` is a max-heap, so this ordering currently yields largest
+    /// distance first. The implementation compensates by skipping stale entries
     /// when popped, which preserves correctness for this algorithm.
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.distance.cmp(&other.distance)
     }
 }
 
+/// Internal priority-queue element used by turn-restricted searches.
+///
+/// Unlike [`QueueItem`], the ordering is reversed so `BinaryHeap` pops the
+/// smallest distance first, which lets the search stop at the first settled
+/// destination state.
+#[derive(Eq, PartialEq)]
+struct TurnQueueItem<N: GraphNode, W: GraphWeight> {
+    /// Candidate distance for this queue step.
+    distance: W,
+    /// Node reached by this queue step.
+    position: N,
+    /// Node from which `position` was entered, if any.
+    arrived_from: Option<N>,
+}
+
+impl<N: GraphNode, W: GraphWeight + Ord + Eq> PartialOrd for TurnQueueItem<N, W> {
+    /// Defers partial ordering to [`Ord`] for `BinaryHeap` compatibility.
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: GraphNode, W: GraphWeight + Ord + Eq> Ord for TurnQueueItem<N, W> {
+    /// Orders queue entries by reversed distance to emulate a min-heap.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.distance.cmp(&self.distance)
+    }
+}
+
+/// One step of a search run by [`DijkstraAlgorithm::shortest_path_with_observer`].
+///
+/// The [`Display`] form is a one-sentence narration such as
+/// `Settled A (0).` or `Relaxed C to 10 via B (improved).`
+#[derive(Debug, Clone, PartialEq)]
+pub enum DijkstraEvent<N: GraphNode, W: GraphWeight> {
+    /// `node` was taken from the queue; `distance` is now final.
+    Settled {
+        /// Node whose shortest distance is known.
+        node: N,
+        /// Shortest distance from the start node.
+        distance: W,
+    },
+    /// A shorter tentative distance to `node` was found through `via`.
+    Relaxed {
+        /// Node whose tentative distance dropped.
+        node: N,
+        /// New tentative distance.
+        distance: W,
+        /// Settled node the new route passes through last.
+        via: N,
+        /// Whether `node` already had a (longer) tentative distance.
+        improved: bool,
+    },
+}
+
+impl<N: GraphNode, W: GraphWeight> Display for DijkstraEvent<N, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DijkstraEvent::Settled { node, distance } => {
+                write!(f, "Settled {} ({}).", node.get_id(), distance)
+            }
+            DijkstraEvent::Relaxed {
+                node,
+                distance,
+                via,
+                improved,
+            } => {
+                write!(
+                    f,
+                    "Relaxed {} to {} via {}",
+                    node.get_id(),
+                    distance,
+                    via.get_id()
+                )?;
+                if *improved {
+                    write!(f, " (improved)")?;
+                }
+                write!(f, ".")
+            }
+        }
+    }
+}
+
+/// Dijkstra search driven one settled node at a time.
+///
+/// Created by [`DijkstraAlgorithm::begin`]. The stepper borrows the algorithm,
+/// so the graph cannot change while a search is in progress.
+#[derive(Debug)]
+pub struct DijkstraStepper<
+    'a,
+    N: GraphNode,
+    W: GraphWeight + Ord,
+    G: Graph<Node = N, Weight = W> + Display,
+> {
+    /// Algorithm whose graph and edge filters the search uses.
+    algorithm: &'a DijkstraAlgorithm<N, W, G>,
+    /// Best-known distance and predecessor of every node.
+    distances: HashMap<String, ShortestDistance<N, W>>,
+    /// IDs of nodes whose distance is final.
+    settled: HashSet<String>,
+    /// Min-heap on (distance, node ID); the ID is the deterministic tie-break.
+    queue: BinaryHeap<Reverse<(W, String)>>,
+}
+
+impl<N: GraphNode, W: GraphWeight + Ord, G: Graph<Node = N, Weight = W> + Display>
+    DijkstraStepper<'_, N, W, G>
+{
+    /// Settles the next node and relaxes its outgoing edges.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((node, entry))` with the settled node and its final distance
+    ///   entry. The start node has no predecessor.
+    /// - `None` once every node reachable from the start node is settled.
+    ///
+    /// # Example
+    ///
+    /// See [`DijkstraAlgorithm::begin`].
+    pub fn execute_step(&mut self) -> Option<(N, ShortestDistance<N, W>)> {
+        let graph = &self.algorithm.graph;
+        while let Some(Reverse((distance, position_id))) = self.queue.pop() {
+            // Skip stale entries of nodes settled through a shorter route.
+            if self.settled.contains(&position_id) {
+                continue;
+            }
+            let Some(position) = graph.get_node_by_id(&position_id) else {
+                continue;
+            };
+            self.settled.insert(position_id);
+
+            for (neighbour, weight, kind, layer) in graph.labeled_neighbors(position) {
+                if self.settled.contains(neighbour.get_id())
+                    || !self.algorithm.is_edge_allowed(kind, layer)
+                {
+                    continue;
+                }
+                let updated_distance = distance + weight;
+                let Some(entry) = self.distances.get_mut(neighbour.get_id()) else {
+                    continue;
+                };
+                if updated_distance < entry.distance {
+                    entry.reach(position.clone(), weight, updated_distance);
+                    self.queue
+                        .push(Reverse((updated_distance, neighbour.get_id().to_string())));
+                }
+            }
+
+            let entry = self.distances.get(position.get_id())?.clone();
+            return Some((position.clone(), entry));
+        }
+        None
+    }
+
+    /// Returns `true` once no node is left to settle.
+    pub fn is_finished(&self) -> bool {
+        self.queue
+            .iter()
+            .all(|Reverse((_, id))| self.settled.contains(id))
+    }
+
+    /// Returns the current distance entry of every node, keyed by node ID.
+    ///
+    /// Unsettled entries are tentative; nodes that have not been reached yet
+    /// still carry [`GraphWeight::max_value`] and no predecessor.
+    pub fn get_distances(&self) -> &HashMap<String, ShortestDistance<N, W>> {
+        &self.distances
+    }
+}
+
 /// Error returned when Dijkstra execution fails.
 ///
 /// This type wraps a user-facing diagnostic message.
@@ -457,18 +3318,40 @@ impl Display for DijkstraError {
 
 impl Error for DijkstraError {}
 
+/// Outcome of [`DijkstraAlgorithm::try_shortest_path`].
+///
+/// Separates "no path exists" from real failures, which stay a
+/// [`DijkstraError`].
+#[derive(Debug, Clone)]
+pub enum PathOutcome<N: GraphNode, W: GraphWeight> {
+    /// A shortest path was found.
+    Found(DijkstraSearchResult<N, W>),
+    /// The end node cannot be reached from the start node.
+    Unreachable,
+}
+
 /// Search result produced by [`DijkstraAlgorithm`].
 ///
-/// Contains the final path and total distance of the shortest route.
+/// Contains the final path, the total distance, and the weight of every hop
+/// of the shortest route.
 #[derive(Debug, Clone)]
 pub struct DijkstraSearchResult<N: GraphNode, W: GraphWeight> {
     /// Ordered node sequence from start node to destination node.
     ///
-    /// The path must contain at least two nodes.
+    /// The path contains at least two nodes, except for the single-node
+    /// result [`DijkstraAlgorithm`] returns when start and end node are
+    /// identical.
     pub path: Vec<N>,
 
     /// Sum of all edge weights along `path`.
     pub distance: W,
+
+    /// Weight of each hop, where `hop_weights[i]` belongs to the edge
+    /// `path[i] -> path[i + 1]`.
+    ///
+    /// Results produced by [`DijkstraAlgorithm`] always carry these weights.
+    /// Results built with [`DijkstraSearchResult::new`] leave it empty.
+    pub hop_weights: Vec<W>,
 }
 
 impl<N: GraphNode, W: GraphWeight> DijkstraSearchResult<N, W> {
@@ -510,24 +3393,278 @@ impl<N: GraphNode, W: GraphWeight> DijkstraSearchResult<N, W> {
             return Err("There need to be at least 2 nodes in the path from one node A to another node B! Couldn't create a 'SearchResult'!".to_string());
         }
 
-        Ok(Self { path, distance })
+        Ok(Self {
+            path,
+            distance,
+            hop_weights: Vec::new(),
+        })
+    }
+
+    /// Creates a validated [`DijkstraSearchResult`] from per-hop weights.
+    ///
+    /// The total distance is the sum of `hop_weights`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` contains fewer than two nodes or if
+    /// `hop_weights` does not contain exactly `path.len() - 1` entries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraSearchResult;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let path = vec![
+    ///     DefaultNode::new("A".to_string()),
+    ///     DefaultNode::new("B".to_string()),
+    ///     DefaultNode::new("C".to_string()),
+    /// ];
+    /// let result = DijkstraSearchResult::with_hop_weights(path, vec![4u16, 3u16]).unwrap();
+    /// assert_eq!(result.distance, 7);
+    /// ```
+    pub fn with_hop_weights(path: Vec<N>, hop_weights: Vec<W>) -> Result<Self, String> {
+        if hop_weights.len() + 1 != path.len() {
+            return Err(format!(
+                "A path with {} nodes needs exactly {} hop weights, but {} were given!",
+                path.len(),
+                path.len().saturating_sub(1),
+                hop_weights.len()
+            ));
+        }
+
+        let distance = hop_weights
+            .iter()
+            .fold(W::zero(), |total, weight| total + *weight);
+        let mut result = Self::new(path, distance)?;
+        result.hop_weights = hop_weights;
+        Ok(result)
+    }
+
+    /// Formats the result like its `Display` impl, but renders the total
+    /// distance with `format` (unit label and precision).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraSearchResult;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    /// use shortest_path_finder::output::distance_format::DistanceFormat;
+    ///
+    /// let path = vec![
+    ///     DefaultNode::new("A".to_string()),
+    ///     DefaultNode::new("B".to_string()),
+    /// ];
+    /// let result = DijkstraSearchResult::with_hop_weights(path, vec![42u16]).unwrap();
+    /// let format = DistanceFormat {
+    ///     unit: Some("km".to_string()),
+    ///     precision: None,
+    /// };
+    /// assert_eq!(result.format_with(&format), "A -(42)-> B (total: 42 km)");
+    /// ```
+    pub fn format_with(&self, format: &DistanceFormat) -> String {
+        self.render(true, format)
+    }
+
+    /// Returns every hop of the path as a `(from, to, weight)` edge, from the
+    /// start node to the destination.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result carries no per-hop weights (see
+    /// [`DijkstraSearchResult::hop_weights`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraSearchResult;
+    /// use shortest_path_finder::graphs::graph::GraphNode;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let path = vec![
+    ///     DefaultNode::new("A".to_string()),
+    ///     DefaultNode::new("B".to_string()),
+    ///     DefaultNode::new("C".to_string()),
+    /// ];
+    /// let result = DijkstraSearchResult::with_hop_weights(path, vec![4u16, 3u16]).unwrap();
+    ///
+    /// let edges = result.edges().unwrap();
+    /// assert_eq!((edges[0].0.get_id(), edges[0].1.get_id(), edges[0].2), ("A", "B", 4));
+    /// assert_eq!((edges[1].0.get_id(), edges[1].1.get_id(), edges[1].2), ("B", "C", 3));
+    /// ```
+    pub fn edges(&self) -> Result<Vec<(N, N, W)>, String> {
+        Ok(self
+            .path_hops()?
+            .map(|(pair, weight)| (pair[0].clone(), pair[1].clone(), *weight))
+            .collect())
+    }
+
+    /// Returns the same edges as [`DijkstraSearchResult::edges`], but ordered
+    /// from the destination back to the start node.
+    ///
+    /// Only the order is reversed; every edge keeps its travel direction, so
+    /// the first entry is the last hop `(second-to-last node, destination, weight)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result carries no per-hop weights.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraSearchResult;
+    /// use shortest_path_finder::graphs::graph::GraphNode;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let path = vec![
+    ///     DefaultNode::new("A".to_string()),
+    ///     DefaultNode::new("B".to_string()),
+    ///     DefaultNode::new("C".to_string()),
+    /// ];
+    /// let result = DijkstraSearchResult::with_hop_weights(path, vec![4u16, 3u16]).unwrap();
+    ///
+    /// let edges = result.edges_reversed().unwrap();
+    /// assert_eq!((edges[0].0.get_id(), edges[0].1.get_id(), edges[0].2), ("B", "C", 3));
+    /// assert_eq!((edges[1].0.get_id(), edges[1].1.get_id(), edges[1].2), ("A", "B", 4));
+    /// ```
+    pub fn edges_reversed(&self) -> Result<Vec<(N, N, W)>, String> {
+        Ok(self
+            .path_hops()?
+            .rev()
+            .map(|(pair, weight)| (pair[0].clone(), pair[1].clone(), *weight))
+            .collect())
+    }
+
+    /// Pairs every hop of the path with its weight.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the per-hop weights are missing.
+    fn path_hops(&self) -> Result<impl DoubleEndedIterator<Item = (&[N], &W)>, String> {
+        if self.hop_weights.len() + 1 != self.path.len() {
+            return Err(
+                "The search result carries no per-hop weights, so its edges can't be rebuilt!"
+                    .to_string(),
+            );
+        }
+        Ok(self.path.windows(2).zip(self.hop_weights.iter()))
+    }
+
+    /// Renders the path on one line, optionally with per-hop weights, followed
+    /// by the formatted total distance.
+    fn render(&self, with_hop_weights: bool, format: &DistanceFormat) -> String {
+        let show_weights = with_hop_weights && self.hop_weights.len() + 1 == self.path.len();
+        let mut rendered = String::new();
+        for (index, node) in self.path.iter().enumerate() {
+            if index > 0 {
+                if show_weights {
+                    rendered.push_str(&format!(" -({})-> ", self.hop_weights[index - 1]));
+                } else {
+                    rendered.push_str(" -> ");
+                }
+            }
+            rendered.push_str(node.get_id());
+        }
+        rendered.push_str(&format!(" (total: {})", format.format(&self.distance)));
+        rendered
+    }
+}
+
+impl DijkstraSearchResult<DefaultNode, u16> {
+    /// Builds a standalone [`DirectedGraph`] containing only this path.
+    ///
+    /// Every path node becomes a graph node and every hop becomes an edge
+    /// weighted with the matching entry of
+    /// [`DijkstraSearchResult::hop_weights`]. Hops that repeat an already
+    /// inserted edge are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result carries no per-hop weights.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::algorithm::Algorithm;
+    /// use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let mut graph = DirectedGraph::default();
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// graph.insert_node(a.clone());
+    /// graph.insert_node(b.clone());
+    /// graph.insert_node(c.clone());
+    /// assert!(graph.insert_edge(&a, &b, Some(2)).is_none());
+    /// assert!(graph.insert_edge(&a, &c, Some(9)).is_none());
+    ///
+    /// let result = DijkstraAlgorithm::new(graph).shortest_path("A", "B").unwrap();
+    /// let path_graph = result.to_directed_graph().unwrap();
+    /// assert_eq!(path_graph.get_all_nodes().len(), 2);
+    /// assert_eq!(path_graph.neighbors(&a).count(), 1);
+    /// ```
+    pub fn to_directed_graph(&self) -> Result<DirectedGraph, String> {
+        let mut graph = DirectedGraph::new(self.path.clone());
+        for (pair, weight) in self.path_hops()? {
+            if graph.does_edge_already_exist(&pair[0], &pair[1]) {
+                continue;
+            }
+            if let Some(err) = graph.insert_edge(&pair[0], &pair[1], Some(*weight)) {
+                return Err(err.message);
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Builds a standalone [`UndirectedGraph`] containing only this path.
+    ///
+    /// Behaves like [`DijkstraSearchResult::to_directed_graph`], but inserts
+    /// undirected edges.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result carries no per-hop weights.
+    pub fn to_undirected_graph(&self) -> Result<UndirectedGraph, String> {
+        let mut graph = UndirectedGraph::new(self.path.clone());
+        for (pair, weight) in self.path_hops()? {
+            if graph.does_edge_already_exist(&pair[0], &pair[1]) {
+                continue;
+            }
+            if let Some(err) = graph.insert_edge(&pair[0], &pair[1], Some(*weight)) {
+                return Err(err.message);
+            }
+        }
+        Ok(graph)
     }
 }
 
+/// Formats the path on one line followed by the total distance.
+///
+/// - `{}` shows the weight of every hop when
+///   [`DijkstraSearchResult::hop_weights`] is filled: `A -(7)-> B -(3)-> C (total: 10)`.
+/// - `{:#}` (or a result without hop weights) omits them: `A -> B -> C (total: 10)`.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::algorithms::dijkstra::DijkstraSearchResult;
+/// use shortest_path_finder::nodes::default_node::DefaultNode;
+///
+/// let path: Vec<DefaultNode> = ["A", "B", "C"]
+///     .iter()
+///     .map(|id| DefaultNode::new(id.to_string()))
+///     .collect();
+/// let result = DijkstraSearchResult::with_hop_weights(path, vec![7u16, 3u16]).unwrap();
+///
+/// assert_eq!(result.to_string(), "A -(7)-> B -(3)-> C (total: 10)");
+/// assert_eq!(format!("{:#}", result), "A -> B -> C (total: 10)");
+/// ```
 impl<N: GraphNode, W: GraphWeight> Display for DijkstraSearchResult<N, W> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut path_string = String::new();
-        for n in &self.path {
-            path_string = format!("{} -> {}", path_string, n.get_id());
-        }
-        write!(
-            f,
-            "
-            Path: {},
-            Distance: {}
-            ",
-            path_string, self.distance
-        )
+        f.write_str(&self.render(!f.alternate(), &DistanceFormat::default()))
     }
 }
 
@@ -5,8 +5,13 @@
 //! This module groups all shortest-path algorithm related code used by this
 //! crate:
 //! - [`algorithm`]: shared traits and algorithm-selection enum.
+//! - [`bellman_ford`]: shortest paths on graphs with negative edge weights.
+//! - [`bfs`]: breadth-first search for fewest-hop paths.
+//! - [`dfs`]: depth-first search for the first path found.
 //! - [`dijkstra`]: concrete Dijkstra implementation.
 //! - [`a_star_algorithm`]: coordinate-based A* implementation.
+//! - [`registry`]: custom algorithms selectable by name from the CLI.
+//! - [`turn_restriction`]: forbidden transitions used by restricted routing.
 //!
 //! # Usage
 //!
@@ -31,4 +36,9 @@
 
 pub mod a_star_algorithm;
 pub mod algorithm;
+pub mod bellman_ford;
+pub mod bfs;
+pub mod dfs;
 pub mod dijkstra;
+pub mod registry;
+pub mod turn_restriction;
@@ -0,0 +1,389 @@
+//! Registry for custom algorithms selectable with `--algo <name>`.
+//!
+//! # Overview
+//!
+//! Third-party routing algorithms plug into the CLI without changes to this
+//! crate: a binary built on the library registers a factory under a name and
+//! then hands its arguments to
+//! [`run_from_args`](crate::cmd_line::runner::run_from_args).
+//! [`Algorithms::get_from_string`](crate::algorithms::algorithm::Algorithms::get_from_string)
+//! resolves the registered name to
+//! [`Algorithms::Custom`](crate::algorithms::algorithm::Algorithms::Custom),
+//! and the dispatcher runs it on directed (`D`) and undirected (`UN`) graph
+//! files. Names that are neither built in nor registered are rejected.
+//!
+//! # Main types
+//!
+//! - [`DynGraph`]: the graph handed to a factory, as a trait object.
+//! - [`DynAlgorithm`]: the algorithm a factory returns, as a trait object.
+//! - [`CustomSearchResult`] and [`CustomAlgorithmError`]: the result and
+//!   error types every custom algorithm uses.
+//! - [`register_algorithm`], [`is_algorithm_registered`],
+//!   [`create_algorithm`]: the registry itself.
+//!
+//! # Rules
+//!
+//! - Built-in names (`Dijkstra`, `AStar`, ...) always win; registering one of
+//!   them has no effect on `Algorithms::get_from_string`.
+//! - Registering a name again replaces the previous factory.
+//! - The registry is process-wide and thread-safe.
+//!
+//! # Example
+//!
+//! ```rust
+//! use shortest_path_finder::algorithms::algorithm::{Algorithm, Algorithms, SearchResult};
+//! use shortest_path_finder::algorithms::registry::{
+//!     CustomAlgorithmError, CustomSearchResult, DynGraph, create_algorithm, into_dyn_graph,
+//!     register_algorithm,
+//! };
+//! use shortest_path_finder::graphs::directed::DirectedGraph;
+//! use shortest_path_finder::graphs::graph::Graph;
+//! use shortest_path_finder::nodes::default_node::DefaultNode;
+//!
+//! /// Only follows a direct edge.
+//! struct DirectOnly(Box<DynGraph>);
+//!
+//! impl Algorithm for DirectOnly {
+//!     type ExecutionError = CustomAlgorithmError;
+//!     type AlgorithmSearchResult = CustomSearchResult;
+//!     type NodeOfUsedGraph = DefaultNode;
+//!
+//!     fn shortest_path(&self, start: &str, end: &str) -> Result<CustomSearchResult, CustomAlgorithmError> {
+//!         let (from, to, weight) = self.0.edges_between(start, end).into_iter().next().ok_or_else(|| {
+//!             CustomAlgorithmError::new(format!("{} and {} are not adjacent!", start, end))
+//!         })?;
+//!         Ok(CustomSearchResult::new(vec![from.clone(), to.clone()], weight))
+//!     }
+//! }
+//!
+//! register_algorithm("direct-only", Box::new(|graph| Box::new(DirectOnly(graph))));
+//! assert!(matches!(
+//!     Algorithms::get_from_string("direct-only"),
+//!     Some(Algorithms::Custom(name)) if name == "direct-only"
+//! ));
+//!
+//! let edges = vec![("A".to_string(), "B".to_string(), 4)];
+//! let graph = into_dyn_graph(DirectedGraph::from_edges(&edges).unwrap());
+//! let algorithm = create_algorithm("direct-only", graph).unwrap();
+//! assert_eq!(algorithm.shortest_path("A", "B").unwrap().get_total_distance(), 4);
+//! ```
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use crate::{
+    algorithms::algorithm::{Algorithm, SearchResult},
+    error::pathfinder_error::PathfinderError,
+    graphs::graph::{Graph, GraphNode, LabeledNeighbor},
+    nodes::default_node::DefaultNode,
+    output::distance_format::DistanceFormat,
+};
+
+/// Graph handed to a custom algorithm factory.
+///
+/// Both CLI graph types fit behind this object: insertion errors are
+/// converted into [`PathfinderError`] so directed and undirected graphs share
+/// one type. Use [`into_dyn_graph`] to build one.
+pub type DynGraph =
+    dyn Graph<Node = DefaultNode, Weight = u16, InsertionError = PathfinderError> + Send + Sync;
+
+/// Algorithm returned by a custom algorithm factory.
+pub type DynAlgorithm = dyn Algorithm<
+        ExecutionError = CustomAlgorithmError,
+        AlgorithmSearchResult = CustomSearchResult,
+        NodeOfUsedGraph = DefaultNode,
+    >;
+
+/// Builds a custom algorithm bound to the loaded graph.
+pub type AlgorithmFactory = Box<dyn Fn(Box<DynGraph>) -> Box<DynAlgorithm> + Send + Sync>;
+
+/// Process-wide registry from algorithm name to factory.
+///
+/// Factories are stored behind [`Arc`] so [`create_algorithm`] can release
+/// the lock before calling one.
+static REGISTRY: OnceLock<RwLock<HashMap<String, Arc<AlgorithmFactory>>>> = OnceLock::new();
+
+/// Returns the registry, creating it on first use.
+fn registry() -> &'static RwLock<HashMap<String, Arc<AlgorithmFactory>>> {
+    REGISTRY.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Registers a custom algorithm under `name`.
+///
+/// Afterwards `--algo <name>` selects it, as long as `name` is not a built-in
+/// algorithm name. Registering the same name again replaces the factory.
+///
+/// # Parameters
+///
+/// - `name`: Value users pass to `--algo`; matched case-sensitively.
+/// - `factory`: Binds the algorithm to the loaded graph.
+///
+/// # Example
+///
+/// See the [module docs](self).
+pub fn register_algorithm(name: &str, factory: AlgorithmFactory) {
+    registry()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(name.to_string(), Arc::new(factory));
+}
+
+/// Returns `true` if a custom algorithm is registered under `name`.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::algorithms::registry::is_algorithm_registered;
+///
+/// assert!(!is_algorithm_registered("never-registered"));
+/// ```
+pub fn is_algorithm_registered(name: &str) -> bool {
+    registry()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .contains_key(name)
+}
+
+/// Creates the custom algorithm registered under `name`, bound to `graph`.
+///
+/// # Returns
+///
+/// The algorithm, or `None` if no factory is registered under `name`.
+///
+/// # Example
+///
+/// See the [module docs](self).
+pub fn create_algorithm(name: &str, graph: Box<DynGraph>) -> Option<Box<DynAlgorithm>> {
+    let factory = registry()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(name)
+        .cloned()?;
+    Some(factory(graph))
+}
+
+/// Wraps a concrete graph into a [`DynGraph`] for custom algorithms.
+///
+/// Every query is forwarded unchanged; insertion errors are converted into
+/// [`PathfinderError`].
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::algorithms::registry::into_dyn_graph;
+/// use shortest_path_finder::graphs::graph::Graph;
+/// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+/// use shortest_path_finder::nodes::default_node::DefaultNode;
+///
+/// let a = DefaultNode::new("A".to_string());
+/// let mut graph = into_dyn_graph(UndirectedGraph::new(vec![a.clone()]));
+/// assert!(!graph.is_directed());
+/// assert!(graph.insert_edge(&a, &DefaultNode::new("B".to_string()), Some(1)).is_some());
+/// ```
+pub fn into_dyn_graph<G>(graph: G) -> Box<DynGraph>
+where
+    G: Graph<Node = DefaultNode, Weight = u16> + Send + Sync + 'static,
+    G::InsertionError: Into<PathfinderError>,
+{
+    Box::new(ErasedGraph(graph))
+}
+
+/// Adapter behind [`into_dyn_graph`] that unifies the insertion error type.
+struct ErasedGraph<G>(G);
+
+impl<G> Graph for ErasedGraph<G>
+where
+    G: Graph<Node = DefaultNode, Weight = u16>,
+    G::InsertionError: Into<PathfinderError>,
+{
+    type Node = DefaultNode;
+
+    type Weight = u16;
+
+    type InsertionError = PathfinderError;
+
+    fn neighbors<'a>(
+        &'a self,
+        u: &Self::Node,
+    ) -> Box<dyn Iterator<Item = (&'a Self::Node, Self::Weight)> + 'a> {
+        self.0.neighbors(u)
+    }
+
+    fn out_degree(&self, u: &Self::Node) -> usize {
+        self.0.out_degree(u)
+    }
+
+    fn degree(&self, id: &str) -> Option<usize> {
+        self.0.degree(id)
+    }
+
+    fn is_directed(&self) -> bool {
+        self.0.is_directed()
+    }
+
+    fn insert_node(&mut self, new_node: Self::Node) {
+        self.0.insert_node(new_node);
+    }
+
+    fn insert_edge(
+        &mut self,
+        from: &Self::Node,
+        to: &Self::Node,
+        weight: Option<Self::Weight>,
+    ) -> Option<Self::InsertionError> {
+        self.0.insert_edge(from, to, weight).map(Into::into)
+    }
+
+    fn does_edge_already_exist(&self, from: &Self::Node, to: &Self::Node) -> bool {
+        self.0.does_edge_already_exist(from, to)
+    }
+
+    fn does_node_already_exist(&self, node: &Self::Node) -> bool {
+        self.0.does_node_already_exist(node)
+    }
+
+    fn get_node_by_id(&self, id: &str) -> Option<&Self::Node> {
+        self.0.get_node_by_id(id)
+    }
+
+    fn get_all_nodes(&self) -> &Vec<Self::Node> {
+        self.0.get_all_nodes()
+    }
+
+    fn is_weighted(&self) -> bool {
+        self.0.is_weighted()
+    }
+
+    fn edge_kind(&self, from: &Self::Node, to: &Self::Node) -> Option<&str> {
+        self.0.edge_kind(from, to)
+    }
+
+    fn edge_layer(&self, from: &Self::Node, to: &Self::Node) -> Option<&str> {
+        self.0.edge_layer(from, to)
+    }
+
+    fn labeled_neighbors<'a>(
+        &'a self,
+        u: &Self::Node,
+    ) -> Box<dyn Iterator<Item = LabeledNeighbor<'a, Self::Node, Self::Weight>> + 'a> {
+        self.0.labeled_neighbors(u)
+    }
+
+    fn abbreviation() -> String {
+        G::abbreviation()
+    }
+}
+
+/// Search result every custom algorithm returns.
+#[derive(Debug, Clone)]
+pub struct CustomSearchResult {
+    /// Ordered node sequence from start node to destination node.
+    pub path: Vec<DefaultNode>,
+    /// Total distance of the path.
+    pub distance: u16,
+}
+
+impl CustomSearchResult {
+    /// Creates a result from a start-to-end path and its total distance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::registry::CustomSearchResult;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let path = vec![DefaultNode::new("A".to_string()), DefaultNode::new("B".to_string())];
+    /// assert_eq!(CustomSearchResult::new(path, 3).to_string(), "A -> B (total: 3)");
+    /// ```
+    pub fn new(path: Vec<DefaultNode>, distance: u16) -> Self {
+        Self { path, distance }
+    }
+
+    /// Formats the result like [`Display`], but with the distance rendered
+    /// through `format` (unit label and precision).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::registry::CustomSearchResult;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    /// use shortest_path_finder::output::distance_format::DistanceFormat;
+    ///
+    /// let result = CustomSearchResult::new(vec![DefaultNode::new("A".to_string())], 0);
+    /// let format = DistanceFormat {
+    ///     unit: Some("km".to_string()),
+    ///     precision: None,
+    /// };
+    /// assert_eq!(result.format_with(&format), "A (total: 0 km)");
+    /// ```
+    pub fn format_with(&self, format: &DistanceFormat) -> String {
+        let formatted_path = self
+            .path
+            .iter()
+            .map(|node| node.get_id())
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        format!(
+            "{} (total: {})",
+            formatted_path,
+            format.format(&self.distance)
+        )
+    }
+}
+
+/// Formats the path on one line followed by the distance, e.g.
+/// `A -> B (total: 3)`.
+impl Display for CustomSearchResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.format_with(&DistanceFormat::default()))
+    }
+}
+
+impl SearchResult for CustomSearchResult {
+    type Distance = u16;
+
+    type Node = DefaultNode;
+
+    fn get_total_distance(&self) -> u16 {
+        self.distance
+    }
+
+    fn get_path(&self) -> &Vec<DefaultNode> {
+        &self.path
+    }
+}
+
+/// Error returned when a custom algorithm fails.
+#[derive(Debug)]
+pub struct CustomAlgorithmError {
+    /// Human-readable explanation of the failure.
+    pub message: String,
+}
+
+impl CustomAlgorithmError {
+    /// Creates a new [`CustomAlgorithmError`] from a message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::registry::CustomAlgorithmError;
+    ///
+    /// let err = CustomAlgorithmError::new("no route".to_string());
+    /// assert_eq!(err.to_string(), "no route");
+    /// ```
+    pub fn new(message: String) -> Self {
+        Self { message }
+    }
+}
+
+impl Display for CustomAlgorithmError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for CustomAlgorithmError {}
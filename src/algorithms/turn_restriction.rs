@@ -0,0 +1,70 @@
+//! Forbidden transitions ("turn restrictions") for routing queries.
+//!
+//! # Overview
+//!
+//! A [`TurnRestriction`] forbids continuing to a specific node after arriving
+//! over a specific edge. For example, the restriction `A -> B -> C` allows the
+//! edges `A -> B` and `B -> C` individually, but a route may not use them
+//! back to back.
+//!
+//! Restrictions are consumed by
+//! [`DijkstraAlgorithm::shortest_path_with_turn_restrictions`](crate::algorithms::dijkstra::DijkstraAlgorithm::shortest_path_with_turn_restrictions),
+//! which expands the search state from a plain node to `(node, arriving edge)`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use shortest_path_finder::algorithms::turn_restriction::TurnRestriction;
+//!
+//! let no_left_turn = TurnRestriction::new("A", "B", "C");
+//! assert_eq!(no_left_turn.via, ("A".to_string(), "B".to_string()));
+//! assert_eq!(no_left_turn.to, "C");
+//! assert!(no_left_turn.forbids("A", "B", "C"));
+//! assert!(!no_left_turn.forbids("D", "B", "C"));
+//! ```
+
+use std::fmt::Display;
+
+/// A forbidden transition `via.0 -> via.1 -> to`.
+///
+/// # Fields
+///
+/// - `via`: the arriving edge as `(from, through)` node IDs.
+/// - `to`: the node that may not be entered directly after `via`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TurnRestriction {
+    /// Arriving edge as `(from, through)` node IDs.
+    pub via: (String, String),
+    /// Node that must not follow the arriving edge.
+    pub to: String,
+}
+
+impl TurnRestriction {
+    /// Creates a restriction forbidding the transition `from -> through -> to`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::algorithms::turn_restriction::TurnRestriction;
+    ///
+    /// let restriction = TurnRestriction::new("A", "B", "C");
+    /// assert_eq!(restriction.to_string(), "A -> B -/-> C");
+    /// ```
+    pub fn new(from: &str, through: &str, to: &str) -> Self {
+        Self {
+            via: (from.to_string(), through.to_string()),
+            to: to.to_string(),
+        }
+    }
+
+    /// Returns `true` if this restriction forbids `from -> through -> to`.
+    pub fn forbids(&self, from: &str, through: &str, to: &str) -> bool {
+        self.via.0 == from && self.via.1 == through && self.to == to
+    }
+}
+
+impl Display for TurnRestriction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {} -/-> {}", self.via.0, self.via.1, self.to)
+    }
+}
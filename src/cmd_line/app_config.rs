@@ -3,19 +3,64 @@
 //! This module turns raw CLI arguments into strongly typed runtime configuration.
 //! The main entry point is [`AppConfig::setup_config`], which validates arguments,
 //! applies defaults, and returns an [`AppConfig`] used by the application runtime.
+//! The flags are declared on [`CliArgs`] and parsed with `clap`; its errors are
+//! translated into [`ConfigParseError`] values.
 //!
 //! # Supported flags
 //!
-//! - `--graph-file <relative_path_to_file>`: file used to build the graph.
-//! - `--start <node_name>`: start node identifier (required).
-//! - `--end <node_name>`: destination node identifier (required).
+//! - `--graph-file <relative_path_to_file>`: file used to build the graph;
+//!   `-` reads the graph from standard input.
+//! - `--start <node_name>`: start node identifier (required unless `--pairs`
+//!   is given).
+//! - `--end <node_name>`: destination node identifier (required unless
+//!   `--pairs` or `--distances` is given). Repeat it (`--end D --end E`) to
+//!   search several destinations from one start.
 //! - `--algo <algorithm_name>`: algorithm selector (defaults to `Dijkstra`).
 //! - `--origin <file|cmd-line>`: intended input-origin selector.
+//! - `--output <text|json|csv|tsv>` (alias `--format`): output format
+//!   (defaults to `text`; other values are rejected). `json` prints path results as
+//!   `{"path":[...],"distance":...}`; `csv` and `tsv` only affect `--distances`.
+//! - `--distances`: print the distance from `--start` to every node instead of
+//!   a single path, computed with Dijkstra. This switch takes no value.
+//! - `--seed <u64>`: seed reserved for randomized components (see
+//!   [`AppConfig::seed`]).
+//! - `--unit <label>`: unit label appended to printed distances (for example `km`).
+//! - `--precision <n>`: decimal places for floating-point distances.
+//! - `--missing-weight <default|reject>`: how edge lines without a weight
+//!   (`A->C`) are handled; `default` assigns weight `1`.
+//! - `--progress-interval <lines>`: log file-loading progress every `<lines>`
+//!   edge lines at `info` level.
+//! - `--limit <n>`: stop loading the graph file once `<n>` nodes exist.
+//! - `--lenient`: skip invalid edge lines instead of failing, and report them
+//!   after the result. This switch takes no value.
+//! - `--explain`: narrate each step of the Dijkstra search on stderr. This
+//!   switch takes no value.
+//! - `--pairs <file>`: answer every `<start> <end>` pair listed in `<file>`
+//!   instead of the single `--start`/`--end` query.
+//! - `--stats`: print search statistics (elapsed time, expanded nodes,
+//!   relaxed edges, distance) on stderr after the result. This switch takes
+//!   no value.
+//!
+//! - `--help` / `-h`: makes [`AppConfig::setup_config`] return
+//!   [`ConfigParseError::HelpRequested`]; the binary then prints
+//!   [`AppConfig::usage`].
 //!
 //! # Defaults and compatibility notes
 //!
 //! - Missing `--graph-file` defaults to `graph.txt`.
-//! - Missing or unknown `--algo` defaults to `Dijkstra`.
+//! - Missing `--algo` defaults to `Dijkstra`; an `--algo` that is neither
+//!   built in nor registered is rejected.
+//! - Missing `--output` defaults to `text`; an unknown one is rejected.
+//! - Missing `--seed` leaves [`AppConfig::seed`] unset.
+//! - Missing `--unit` and `--precision` print plain values without a unit.
+//! - Missing `--missing-weight` rejects edge lines without a weight.
+//! - Missing `--progress-interval` disables progress logging.
+//! - Missing `--limit` loads the whole file.
+//! - Missing `--lenient` fails on the first invalid edge line.
+//! - Missing `--explain` prints only the result.
+//! - Missing `--stats` prints no statistics.
+//! - With `--pairs`, `--start` and `--end` may be omitted and are ignored.
+//! - With `--distances`, `--end` may be omitted.
 //! - Input-origin parsing primarily reads from `--origin`.
 //! - Compatibility fallback: if `--origin` is absent, parser also accepts legacy
 //!   origin values from `--algo` (`file` or `cmd-line`).
@@ -52,7 +97,20 @@
 //! assert!(matches!(config.data_input, InputOrigin::File));
 //! ```
 
-use crate::{algorithms::algorithm::Algorithms, error::config_error::ConfigParseError};
+use std::num::NonZeroUsize;
+
+use clap::{
+    CommandFactory, Parser,
+    builder::NonEmptyStringValueParser,
+    error::{ContextKind, ContextValue, ErrorKind},
+};
+
+use crate::{
+    algorithms::algorithm::Algorithms,
+    data_input::file_input::MissingWeightPolicy,
+    error::config_error::ConfigParseError,
+    output::{distance_format::DistanceFormat, output_format::OutputFormat},
+};
 
 /// Minimum argument count required before parsing is attempted.
 ///
@@ -63,206 +121,171 @@ const MIN_ARGUMENT_COUNT: usize = 4;
 /// Default file path used when `--graph-file` is not provided.
 const DEFAULT_GRAPH_FILE: &str = "graph.txt";
 
-/// Internal representation of supported CLI flags.
-///
-/// This enum centralizes known flags so parser logic can map raw tokens to a
-/// fixed set of configuration slots and produce structured errors for unknown
-/// switches.
-#[derive(Copy, Clone, Debug)]
-enum KnownFlag {
-    GraphFile,
-    Start,
-    End,
-    Algo,
-    Origin,
-}
-
-impl KnownFlag {
-    /// Converts a raw CLI token to a known flag discriminator.
-    fn from_token(token: &str) -> Option<Self> {
-        match token {
-            "--graph-file" => Some(Self::GraphFile),
-            "--start" => Some(Self::Start),
-            "--end" => Some(Self::End),
-            "--algo" => Some(Self::Algo),
-            "--origin" => Some(Self::Origin),
-            _ => None,
-        }
-    }
+/// Default algorithm name used when `--algo` is not provided.
+const DEFAULT_ALGORITHM: &str = "Dijkstra";
 
-    /// Returns the canonical string spelling for a known flag.
-    fn as_str(self) -> &'static str {
-        match self {
-            Self::GraphFile => "--graph-file",
-            Self::Start => "--start",
-            Self::End => "--end",
-            Self::Algo => "--algo",
-            Self::Origin => "--origin",
-        }
-    }
-}
+/// Program name prepended when the argument vector starts with a flag.
+const PROGRAM_NAME: &str = "pathfinder";
 
-/// Parsed key-value storage for all supported CLI options.
+/// Raw command-line flags as declared for `clap`.
 ///
-/// Each field stores the original flag index and its associated value, which
-/// enables precise duplicate-flag diagnostics.
-#[derive(Default, Debug)]
-struct ParsedCliValues {
-    graph_file: Option<(usize, String)>,
-    start: Option<(usize, String)>,
-    end: Option<(usize, String)>,
-    algo: Option<(usize, String)>,
-    origin: Option<(usize, String)>,
-}
-
-impl ParsedCliValues {
-    /// Stores one parsed flag value and rejects duplicate occurrences.
-    ///
-    /// # Parameters
-    ///
-    /// - `slot`: Target storage location for a flag value.
-    /// - `flag`: Logical flag identifier used for diagnostics.
-    /// - `index`: Position of the current flag token in the original args.
-    /// - `value`: Parsed value token associated with `flag`.
-    ///
-    /// # Errors
-    ///
-    /// Returns [`ConfigParseError::DuplicateFlag`] when `slot` is already set.
-    fn set_value(
-        slot: &mut Option<(usize, String)>,
-        flag: KnownFlag,
-        index: usize,
-        value: &str,
-    ) -> Result<(), ConfigParseError> {
-        if let Some((first_index, _)) = slot {
-            return Err(ConfigParseError::DuplicateFlag {
-                flag: flag.as_str().to_string(),
-                first_index: *first_index,
-                duplicate_index: index,
-            });
-        }
-
-        *slot = Some((index, value.to_string()));
-        Ok(())
-    }
-
-    /// Routes one parsed flag/value pair into the corresponding storage slot.
-    ///
-    /// # Parameters
-    ///
-    /// - `flag`: Known flag discriminator.
-    /// - `index`: Index of the flag token in `args`.
-    /// - `value`: Associated value token.
-    ///
-    /// # Errors
-    ///
-    /// Propagates duplicate-flag errors from [`ParsedCliValues::set_value`].
-    fn insert(
-        &mut self,
-        flag: KnownFlag,
-        index: usize,
-        value: &str,
-    ) -> Result<(), ConfigParseError> {
-        match flag {
-            KnownFlag::GraphFile => Self::set_value(&mut self.graph_file, flag, index, value),
-            KnownFlag::Start => Self::set_value(&mut self.start, flag, index, value),
-            KnownFlag::End => Self::set_value(&mut self.end, flag, index, value),
-            KnownFlag::Algo => Self::set_value(&mut self.algo, flag, index, value),
-            KnownFlag::Origin => Self::set_value(&mut self.origin, flag, index, value),
-        }
-    }
-
-    /// Returns the parsed `--graph-file` value, if provided.
-    fn graph_file_value(&self) -> Option<String> {
-        self.graph_file.as_ref().map(|(_, value)| value.clone())
-    }
-
-    /// Returns the parsed `--start` value, if provided.
-    fn start_value(&self) -> Option<String> {
-        self.start.as_ref().map(|(_, value)| value.clone())
-    }
-
-    /// Returns the parsed `--end` value, if provided.
-    fn end_value(&self) -> Option<String> {
-        self.end.as_ref().map(|(_, value)| value.clone())
-    }
-
-    /// Returns the parsed `--algo` value, if provided.
-    fn algorithm_value(&self) -> Option<String> {
-        self.algo.as_ref().map(|(_, value)| value.clone())
-    }
-
-    /// Returns the parsed `--origin` value, if provided.
-    fn origin_value(&self) -> Option<String> {
-        self.origin.as_ref().map(|(_, value)| value.clone())
-    }
-}
-
-/// Parses raw CLI arguments into validated key-value pairs.
+/// Values are kept as text here; [`AppConfig::setup_config`] converts them
+/// into typed settings so invalid values are reported as
+/// [`ConfigParseError::InvalidValueForFlag`]. Every value flag rejects an
+/// empty value.
 ///
-/// # Behavior
+/// # Example
 ///
-/// - Accepts argument vectors both with and without executable name prefix.
-/// - Requires every option token to start with `--`.
-/// - Requires every known flag to be followed by a non-empty, non-flag value.
-/// - Rejects unknown and duplicate flags.
+/// ```rust
+/// use clap::Parser;
+/// use shortest_path_finder::cmd_line::app_config::CliArgs;
 ///
-/// # Errors
+/// let cli = CliArgs::try_parse_from(["pathfinder", "--start", "A", "--end", "B"]).unwrap();
 ///
-/// Returns:
-/// - [`ConfigParseError::UnexpectedArgument`] for non-flag tokens,
-/// - [`ConfigParseError::UnknownFlag`] for unsupported switches,
-/// - [`ConfigParseError::MissingValueForFlag`] when a flag has no usable value,
-/// - [`ConfigParseError::DuplicateFlag`] when a known flag appears multiple times.
-fn parse_cli_values(args: &[String]) -> Result<ParsedCliValues, ConfigParseError> {
-    let mut parsed = ParsedCliValues::default();
-    // Allow both `["--start", "A", ...]` and `["pathfinder", "--start", "A", ...]` forms.
-    let mut index = if args.first().is_some_and(|value| value.starts_with("--")) {
-        0
-    } else {
-        1
-    };
-
-    // Process tokens in pairs: flag followed by value.
-    while index < args.len() {
-        let token = &args[index];
+/// assert_eq!(cli.graph_file, "graph.txt");
+/// assert_eq!(cli.algo, "Dijkstra");
+/// assert_eq!(cli.origin, None);
+/// ```
+#[derive(Parser, Debug, Clone, PartialEq, Eq)]
+#[command(
+    name = PROGRAM_NAME,
+    about = "Finds shortest paths in a graph loaded from a file, standard input, or the terminal.",
+    override_usage = "pathfinder [OPTIONS] (--start <node> (--end <node> | --distances) | --pairs <file>)",
+    allow_negative_numbers = true
+)]
+pub struct CliArgs {
+    /// Graph file to load; '-' reads standard input.
+    #[arg(long, value_name = "path", default_value = DEFAULT_GRAPH_FILE, value_parser = NonEmptyStringValueParser::new())]
+    pub graph_file: String,
+    /// Start node ID.
+    #[arg(long, value_name = "node", required_unless_present = "pairs", value_parser = NonEmptyStringValueParser::new())]
+    pub start: Option<String>,
+    /// End node ID; repeat the flag to search several end nodes at once.
+    #[arg(long, value_name = "node", required_unless_present_any = ["pairs", "distances"], value_parser = NonEmptyStringValueParser::new())]
+    pub end: Vec<String>,
+    /// Dijkstra, AStar, BFS, DFS, BellmanFord, or a registered name.
+    #[arg(long, value_name = "name", default_value = DEFAULT_ALGORITHM, value_parser = NonEmptyStringValueParser::new())]
+    pub algo: String,
+    /// Read the graph file ('file') or type the graph into the terminal
+    /// ('cmd-line') [default: file].
+    #[arg(long, value_name = "file|cmd-line", value_parser = NonEmptyStringValueParser::new())]
+    pub origin: Option<String>,
+    /// Edge lines without a weight: 'reject' or 'default' (weight 1)
+    /// [default: reject].
+    #[arg(long, value_name = "policy", value_parser = NonEmptyStringValueParser::new())]
+    pub missing_weight: Option<String>,
+    /// Stop loading once this many nodes exist [default: load everything].
+    #[arg(long, value_name = "n", value_parser = NonEmptyStringValueParser::new())]
+    pub limit: Option<String>,
+    /// Skip invalid edge lines and report them after the result.
+    #[arg(long)]
+    pub lenient: bool,
+    /// Log loading progress every this many lines at info level [default: off].
+    #[arg(long, value_name = "lines", value_parser = NonEmptyStringValueParser::new())]
+    pub progress_interval: Option<String>,
+    /// Answer every '<start> <end>' line of this file with Dijkstra.
+    #[arg(long, value_name = "file", value_parser = NonEmptyStringValueParser::new())]
+    pub pairs: Option<String>,
+    /// Seed reserved for randomized components; no current algorithm uses it.
+    #[arg(long, value_name = "u64", value_parser = NonEmptyStringValueParser::new())]
+    pub seed: Option<String>,
+    /// Print the distance from --start to every node instead of one path.
+    #[arg(long)]
+    pub distances: bool,
+    /// Output format: text, json, or csv/tsv (--distances only) [default: text].
+    #[arg(long, visible_alias = "format", value_name = "format", value_parser = NonEmptyStringValueParser::new())]
+    pub output: Option<String>,
+    /// Unit appended to printed distances [default: none].
+    #[arg(long, value_name = "label", value_parser = NonEmptyStringValueParser::new())]
+    pub unit: Option<String>,
+    /// Decimal places of floating-point distances [default: natural].
+    #[arg(long, value_name = "n", value_parser = NonEmptyStringValueParser::new())]
+    pub precision: Option<String>,
+    /// Narrate each Dijkstra step on stderr.
+    #[arg(long)]
+    pub explain: bool,
+    /// Print search statistics on stderr.
+    #[arg(long)]
+    pub stats: bool,
+}
 
-        // Validate that the current token is a flag.
-        if !token.starts_with("--") {
-            return Err(ConfigParseError::UnexpectedArgument {
-                value: token.clone(),
-                index,
-            });
-        }
+/// Translates a `clap` parse error into the matching [`ConfigParseError`].
+///
+/// `clap` reports flags together with their value name (`--start <node>`),
+/// so the bare flag is looked up in `args` to recover argument indexes.
+/// `first_index` is the index of the first token after the program name.
+/// Errors without a structured counterpart become
+/// [`ConfigParseError::InvalidArguments`] carrying `clap`'s message.
+fn convert_clap_error(err: &clap::Error, args: &[String], first_index: usize) -> ConfigParseError {
+    let invalid_arg = err
+        .get(ContextKind::InvalidArg)
+        .and_then(|value| match value {
+            ContextValue::String(arg) => Some(arg.as_str()),
+            ContextValue::Strings(args) => args.first().map(String::as_str),
+            _ => None,
+        })
+        .map(|arg| arg.split_whitespace().next().unwrap_or(arg));
+    let positions = |token: &str| -> Vec<usize> {
+        (first_index..args.len())
+            .filter(|&index| args[index] == token)
+            .collect()
+    };
 
-        let flag = match KnownFlag::from_token(token) {
-            Some(flag) => flag,
-            None => {
-                return Err(ConfigParseError::UnknownFlag {
-                    flag: token.clone(),
+    match (err.kind(), invalid_arg) {
+        (ErrorKind::DisplayHelp, _) => ConfigParseError::HelpRequested,
+        (ErrorKind::UnknownArgument, Some(token)) => {
+            let index = positions(token).first().copied().unwrap_or(first_index);
+            if token.starts_with('-') {
+                ConfigParseError::UnknownFlag {
+                    flag: token.to_string(),
                     index,
-                });
-            }
-        };
-
-        // Validate that the flag is followed by a usable value.
-        let maybe_value = args.get(index + 1);
-        let value = match maybe_value {
-            Some(value) if !value.is_empty() && !value.starts_with("--") => value,
-            _ => {
-                return Err(ConfigParseError::MissingValueForFlag {
-                    flag: flag.as_str().to_string(),
+                }
+            } else {
+                ConfigParseError::UnexpectedArgument {
+                    value: token.to_string(),
                     index,
-                });
+                }
             }
-        };
-
-        parsed.insert(flag, index, value)?;
-        // Advance by one full pair (`--flag` + `value`).
-        index += 2;
+        }
+        (ErrorKind::InvalidValue, Some(flag)) => {
+            // The occurrence that is not followed by a usable value.
+            let index = positions(flag)
+                .into_iter()
+                .find(|&index| {
+                    args.get(index + 1)
+                        .is_none_or(|value| value.is_empty() || value.starts_with("--"))
+                })
+                .unwrap_or(first_index);
+            ConfigParseError::MissingValueForFlag {
+                flag: flag.to_string(),
+                index,
+            }
+        }
+        (ErrorKind::ArgumentConflict, Some(flag)) => match positions(flag).as_slice() {
+            [first_index, duplicate_index, ..] => ConfigParseError::DuplicateFlag {
+                flag: flag.to_string(),
+                first_index: *first_index,
+                duplicate_index: *duplicate_index,
+            },
+            _ => invalid_arguments(err),
+        },
+        (ErrorKind::MissingRequiredArgument, Some("--start")) => {
+            ConfigParseError::MissingRequiredFlag { flag: "--start" }
+        }
+        (ErrorKind::MissingRequiredArgument, Some("--end")) => {
+            ConfigParseError::MissingRequiredFlag { flag: "--end" }
+        }
+        _ => invalid_arguments(err),
     }
+}
 
-    Ok(parsed)
+/// Wraps the first line of `clap`'s message, without its `error: ` prefix.
+fn invalid_arguments(err: &clap::Error) -> ConfigParseError {
+    let rendered = err.to_string();
+    let first_line = rendered.lines().next().unwrap_or_default();
+    ConfigParseError::InvalidArguments {
+        message: first_line.trim_start_matches("error: ").to_string(),
+    }
 }
 
 /// Declares where graph data should be read from.
@@ -352,9 +375,21 @@ impl InputOrigin {
 ///
 /// - `file_path`: path to graph input file.
 /// - `start_node_id`: identifier of the start node.
-/// - `end_node_id`: identifier of the destination node.
+/// - `end_node_id`: identifier of the (first) destination node.
+/// - `end_node_ids`: identifiers of all destination nodes.
 /// - `algorithm`: shortest-path algorithm selected by the user.
 /// - `data_input`: graph-data origin.
+/// - `output_format`: format used to print results.
+/// - `print_distances`: whether the full distance table is printed.
+/// - `seed`: optional seed for randomized components.
+/// - `distance_format`: unit label and precision for printed distances.
+/// - `missing_weight_policy`: handling of edge lines without a weight.
+/// - `progress_interval`: line interval for file-loading progress logs.
+/// - `node_limit`: node count after which file loading stops.
+/// - `lenient`: whether invalid edge lines are skipped instead of fatal.
+/// - `explain`: whether the search is narrated on stderr.
+/// - `pairs_file`: file listing start/end pairs for a batch query.
+/// - `print_stats`: whether search statistics are printed on stderr.
 ///
 /// # Example
 ///
@@ -391,12 +426,49 @@ pub struct AppConfig {
     pub file_path: String,
     /// Identifier of the node where path search starts.
     pub start_node_id: String,
-    /// Identifier of the node where path search ends.
+    /// Identifier of the node where path search ends; the first `--end`
+    /// when the flag is repeated, and empty when `--distances` is set
+    /// without one.
     pub end_node_id: String,
+    /// Identifiers of every `--end` node in the order given; searched from
+    /// `start_node_id` in one batched Dijkstra run when there is more than
+    /// one.
+    pub end_node_ids: Vec<String>,
     /// Selected shortest-path algorithm.
     pub algorithm: Algorithms,
     /// Origin used to read graph data.
     pub data_input: InputOrigin,
+    /// Format used to print results.
+    pub output_format: OutputFormat,
+    /// Print the distance from the start node to every node instead of a path.
+    pub print_distances: bool,
+    /// Seed from `--seed`, or `None` when the flag is absent.
+    ///
+    /// No algorithm, graph, or input component draws random numbers yet; the
+    /// shortest-path algorithms are fully deterministic. The flag is parsed
+    /// and validated so randomized components added later can pick it up
+    /// without changing the command line.
+    pub seed: Option<u64>,
+    /// Unit label and decimal precision used when printing path distances.
+    pub distance_format: DistanceFormat,
+    /// How file input handles edge lines without a weight.
+    pub missing_weight_policy: MissingWeightPolicy,
+    /// Log file-loading progress every this many edge lines; `None` disables it.
+    pub progress_interval: Option<NonZeroUsize>,
+    /// Stop loading the graph file once this many nodes exist; `None` loads
+    /// the whole file.
+    pub node_limit: Option<NonZeroUsize>,
+    /// Skip invalid edge lines while loading and report them afterwards.
+    pub lenient: bool,
+    /// Narrate the Dijkstra search step by step on stderr.
+    pub explain: bool,
+    /// File with one `<start> <end>` pair per line; when set, every pair is
+    /// answered and `start_node_id`, `end_node_id` and `end_node_ids` are
+    /// empty.
+    pub pairs_file: Option<String>,
+    /// Print elapsed time, expanded nodes, relaxed edges and the distance of
+    /// the search on stderr.
+    pub print_stats: bool,
 }
 
 impl AppConfig {
@@ -427,6 +499,9 @@ impl AppConfig {
     /// - [`ConfigParseError::UnknownFlag`]
     /// - [`ConfigParseError::DuplicateFlag`]
     /// - [`ConfigParseError::UnexpectedArgument`]
+    /// - [`ConfigParseError::InvalidValueForFlag`]
+    /// - [`ConfigParseError::HelpRequested`] when `--help` or `-h` appears
+    ///   anywhere; no other check runs then.
     ///
     /// # Examples
     ///
@@ -497,6 +572,14 @@ impl AppConfig {
     /// assert!(matches!(err, ConfigParseError::UnknownFlag { .. }));
     /// ```
     pub fn setup_config(args: Vec<String>) -> Result<Self, ConfigParseError> {
+        // Help wins over every other check, so it works without start/end nodes.
+        if args
+            .iter()
+            .any(|arg| arg.as_str() == "--help" || arg.as_str() == "-h")
+        {
+            return Err(ConfigParseError::HelpRequested);
+        }
+
         if args.len() < MIN_ARGUMENT_COUNT {
             return Err(ConfigParseError::TooFewArguments {
                 provided: args.len(),
@@ -504,38 +587,177 @@ impl AppConfig {
             });
         }
 
-        let parsed = parse_cli_values(&args)?;
-        let file_path = parsed
-            .graph_file_value()
-            .unwrap_or_else(|| DEFAULT_GRAPH_FILE.to_string());
-        let algorithm_token = parsed.algorithm_value();
-        let algorithm = AppConfig::retrieve_algorithm(algorithm_token.as_deref());
-        let data_input = AppConfig::retrieve_data_input(&parsed, algorithm_token.as_deref());
-
-        let start_node_id = parsed
-            .start_value()
-            .ok_or(ConfigParseError::MissingRequiredFlag { flag: "--start" })?;
-        let end_node_id = parsed
-            .end_value()
-            .ok_or(ConfigParseError::MissingRequiredFlag { flag: "--end" })?;
+        // Allow both `["--start", "A", ...]` and `["pathfinder", "--start", "A", ...]` forms.
+        let starts_with_flag = args.first().is_some_and(|value| value.starts_with("--"));
+        let first_index = if starts_with_flag { 0 } else { 1 };
+        let program_name = starts_with_flag.then(|| PROGRAM_NAME.to_string());
+        let cli = CliArgs::try_parse_from(program_name.into_iter().chain(args.iter().cloned()))
+            .map_err(|err| convert_clap_error(&err, &args, first_index))?;
 
-        Ok(Self {
-            file_path,
-            start_node_id,
-            end_node_id,
-            algorithm,
-            data_input,
-        })
+        AppConfig::try_from(cli)
+    }
+
+    /// Returns the `--help` text generated from [`CliArgs`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::cmd_line::app_config::AppConfig;
+    ///
+    /// let usage = AppConfig::usage();
+    /// assert!(usage.contains("--graph-file"));
+    /// assert!(usage.contains("[default: graph.txt]"));
+    /// ```
+    pub fn usage() -> String {
+        CliArgs::command().render_help().to_string()
+    }
+
+    /// Parses the optional `--seed` value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigParseError::InvalidValueForFlag`] when `--seed` is not
+    /// a valid `u64`.
+    fn retrieve_seed(cli: &CliArgs) -> Result<Option<u64>, ConfigParseError> {
+        cli.seed
+            .as_deref()
+            .map(|raw_seed| {
+                raw_seed
+                    .parse()
+                    .map_err(|_| ConfigParseError::InvalidValueForFlag {
+                        flag: "--seed".to_string(),
+                        value: raw_seed.to_string(),
+                        expected: "an unsigned 64-bit integer",
+                    })
+            })
+            .transpose()
+    }
+
+    /// Parses the optional `--precision` value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigParseError::InvalidValueForFlag`] when `--precision` is
+    /// not a non-negative integer.
+    fn retrieve_precision(cli: &CliArgs) -> Result<Option<usize>, ConfigParseError> {
+        cli.precision
+            .as_deref()
+            .map(|raw_precision| {
+                raw_precision
+                    .parse()
+                    .map_err(|_| ConfigParseError::InvalidValueForFlag {
+                        flag: "--precision".to_string(),
+                        value: raw_precision.to_string(),
+                        expected: "a non-negative integer",
+                    })
+            })
+            .transpose()
+    }
+
+    /// Parses the optional `--missing-weight` value.
+    ///
+    /// Falls back to [`MissingWeightPolicy::Reject`] when the flag is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigParseError::InvalidValueForFlag`] for values other than
+    /// `default` and `reject`.
+    fn retrieve_missing_weight_policy(
+        cli: &CliArgs,
+    ) -> Result<MissingWeightPolicy, ConfigParseError> {
+        match cli.missing_weight.as_deref() {
+            Some(raw_policy) => MissingWeightPolicy::from_flag_value(raw_policy).ok_or(
+                ConfigParseError::InvalidValueForFlag {
+                    flag: "--missing-weight".to_string(),
+                    value: raw_policy.to_string(),
+                    expected: "'default' or 'reject'",
+                },
+            ),
+            None => Ok(MissingWeightPolicy::default()),
+        }
+    }
+
+    /// Parses the optional `--progress-interval` value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigParseError::InvalidValueForFlag`] when the value is not
+    /// a positive integer.
+    fn retrieve_progress_interval(cli: &CliArgs) -> Result<Option<NonZeroUsize>, ConfigParseError> {
+        cli.progress_interval
+            .as_deref()
+            .map(|raw_interval| {
+                raw_interval
+                    .parse()
+                    .map_err(|_| ConfigParseError::InvalidValueForFlag {
+                        flag: "--progress-interval".to_string(),
+                        value: raw_interval.to_string(),
+                        expected: "a positive integer",
+                    })
+            })
+            .transpose()
+    }
+
+    /// Parses the optional `--limit` value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigParseError::InvalidValueForFlag`] when the value is not
+    /// a positive integer.
+    fn retrieve_node_limit(cli: &CliArgs) -> Result<Option<NonZeroUsize>, ConfigParseError> {
+        cli.limit
+            .as_deref()
+            .map(|raw_limit| {
+                raw_limit
+                    .parse()
+                    .map_err(|_| ConfigParseError::InvalidValueForFlag {
+                        flag: "--limit".to_string(),
+                        value: raw_limit.to_string(),
+                        expected: "a positive integer",
+                    })
+            })
+            .transpose()
     }
 
-    /// Converts optional algorithm text into a concrete [`Algorithms`] value.
+    /// Parses the optional `--output` value.
+    ///
+    /// Falls back to [`OutputFormat::Text`] when the flag is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigParseError::InvalidValueForFlag`] for values other than
+    /// `text`, `json`, `csv` and `tsv`.
+    fn retrieve_output_format(cli: &CliArgs) -> Result<OutputFormat, ConfigParseError> {
+        match cli.output.as_deref() {
+            Some(raw_format) => OutputFormat::get_from_string(raw_format).ok_or(
+                ConfigParseError::InvalidValueForFlag {
+                    flag: "--output".to_string(),
+                    value: raw_format.to_string(),
+                    expected: "'text', 'json', 'csv' or 'tsv'",
+                },
+            ),
+            None => Ok(OutputFormat::default()),
+        }
+    }
+
+    /// Resolves `--algo` to a built-in or registered algorithm.
+    ///
+    /// The legacy origin markers `file` and `cmd-line` (see
+    /// [`Self::retrieve_data_input`]) select [`Algorithms::Dijkstra`].
+    ///
+    /// # Errors
     ///
-    /// Falls back to [`Algorithms::Dijkstra`] when the algorithm flag is not
-    /// provided.
-    fn retrieve_algorithm(raw_algorithm: Option<&str>) -> Algorithms {
-        raw_algorithm
-            .map(Algorithms::get_from_string)
-            .unwrap_or(Algorithms::Dijkstra)
+    /// Returns [`ConfigParseError::InvalidValueForFlag`] for any other name.
+    fn retrieve_algorithm(cli: &CliArgs) -> Result<Algorithms, ConfigParseError> {
+        match Algorithms::get_from_string(&cli.algo) {
+            Some(algorithm) => Ok(algorithm),
+            None if matches!(cli.algo.as_str(), "file" | "cmd-line") => Ok(Algorithms::Dijkstra),
+            None => Err(ConfigParseError::InvalidValueForFlag {
+                flag: "--algo".to_string(),
+                value: cli.algo.clone(),
+                expected: "Dijkstra, AStar, BFS, DFS, BellmanFord, or a registered algorithm name",
+            }),
+        }
     }
 
     /// Resolves input origin with compatibility fallback.
@@ -543,19 +765,75 @@ impl AppConfig {
     /// Resolution order:
     /// 1. `--origin` value,
     /// 2. legacy `--algo` values `file`/`cmd-line`,
-    /// 3. [`InputOrigin::File`] default.
-    fn retrieve_data_input(parsed: &ParsedCliValues, raw_algorithm: Option<&str>) -> InputOrigin {
-        if let Some(origin) = parsed.origin_value() {
-            return InputOrigin::get_from_string(&origin);
+    /// 3. [`InputOrigin::File`] default (any other `--algo` value).
+    fn retrieve_data_input(cli: &CliArgs) -> InputOrigin {
+        match &cli.origin {
+            Some(origin) => InputOrigin::get_from_string(origin),
+            // Keep backward compatibility for existing callers that pass
+            // `--algo cmd-line` or `--algo file` as origin markers.
+            None => InputOrigin::get_from_string(&cli.algo),
         }
+    }
+}
 
-        // Keep backward compatibility for existing callers that pass
-        // `--algo cmd-line` or `--algo file` as origin markers.
-        if let Some(algo_token) = raw_algorithm {
-            return InputOrigin::get_from_string(algo_token);
-        }
+impl TryFrom<CliArgs> for AppConfig {
+    type Error = ConfigParseError;
+
+    /// Converts parsed flags into typed settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigParseError::InvalidValueForFlag`] when `--algo` names
+    /// an unknown algorithm or a numeric or policy flag carries an invalid
+    /// value, and
+    /// [`ConfigParseError::MissingRequiredFlag`] when neither `--pairs` nor
+    /// both `--start` and `--end` (or `--start` and `--distances`) are set.
+    fn try_from(cli: CliArgs) -> Result<Self, ConfigParseError> {
+        let algorithm = AppConfig::retrieve_algorithm(&cli)?;
+        let data_input = AppConfig::retrieve_data_input(&cli);
+        let output_format = AppConfig::retrieve_output_format(&cli)?;
+        let seed = AppConfig::retrieve_seed(&cli)?;
+        let distance_format = DistanceFormat {
+            unit: cli.unit.clone(),
+            precision: AppConfig::retrieve_precision(&cli)?,
+        };
+        let missing_weight_policy = AppConfig::retrieve_missing_weight_policy(&cli)?;
+        let progress_interval = AppConfig::retrieve_progress_interval(&cli)?;
+        let node_limit = AppConfig::retrieve_node_limit(&cli)?;
+
+        // A batch query takes its start and end nodes from the pairs file.
+        let (start_node_id, end_node_ids) = if cli.pairs.is_some() {
+            (String::new(), Vec::new())
+        } else {
+            let start_node_id = cli
+                .start
+                .ok_or(ConfigParseError::MissingRequiredFlag { flag: "--start" })?;
+            if cli.end.is_empty() && !cli.distances {
+                return Err(ConfigParseError::MissingRequiredFlag { flag: "--end" });
+            }
+            (start_node_id, cli.end)
+        };
+        let end_node_id = end_node_ids.first().cloned().unwrap_or_default();
 
-        InputOrigin::File
+        Ok(Self {
+            file_path: cli.graph_file,
+            start_node_id,
+            end_node_id,
+            end_node_ids,
+            algorithm,
+            data_input,
+            output_format,
+            print_distances: cli.distances,
+            seed,
+            distance_format,
+            missing_weight_policy,
+            progress_interval,
+            node_limit,
+            lenient: cli.lenient,
+            explain: cli.explain,
+            pairs_file: cli.pairs,
+            print_stats: cli.stats,
+        })
     }
 }
 
@@ -3,7 +3,8 @@
 //! # Overview
 //!
 //! This module provides application startup configuration parsing from CLI
-//! arguments via [`app_config`].
+//! arguments via [`app_config`], and the CLI run itself (loading the graph
+//! and printing the result) via [`runner`].
 //!
 //! # Usage
 //!
@@ -19,3 +20,4 @@
 //! ```
 
 pub mod app_config;
+pub mod runner;
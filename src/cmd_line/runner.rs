@@ -0,0 +1,637 @@
+//! Library entry point of the `pathfinder` CLI.
+//!
+//! # Overview
+//!
+//! [`run_from_args`] does everything the `pathfinder` binary does after
+//! setting up logging: it parses the arguments into an
+//! [`AppConfig`](crate::cmd_line::app_config::AppConfig), loads the graph and
+//! prints the requested output. [`run`] starts from an already parsed
+//! configuration.
+//!
+//! A binary built on the library can register its own algorithms with
+//! [`register_algorithm`](crate::algorithms::registry::register_algorithm)
+//! and then call [`run_from_args`], so `--algo <name>` reaches them without
+//! changes to this crate.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use shortest_path_finder::cmd_line::runner::run_from_args;
+//!
+//! fn main() {
+//!     // register custom algorithms here, before the arguments are parsed
+//!     if let Err(err) = run_from_args(std::env::args().collect()) {
+//!         eprintln!("{}", err);
+//!         std::process::exit(1);
+//!     }
+//! }
+//! ```
+
+use std::{
+    fmt::{Debug, Display},
+    io::{self, Write},
+};
+
+use crate::{
+    algorithms::{
+        a_star_algorithm::a_star::AStar,
+        algorithm::{Algorithm, Algorithms, SearchResult, SearchStats},
+        bellman_ford::BellmanFordAlgorithm,
+        bfs::BfsAlgorithm,
+        dfs::DfsAlgorithm,
+        dijkstra::{DijkstraAlgorithm, DijkstraSearchResult},
+        registry::{DynGraph, create_algorithm, into_dyn_graph},
+    },
+    cmd_line::app_config::{AppConfig, InputOrigin},
+    data_input::{
+        file_input::{
+            FileInputGraphResult, FileInputOptions, STDIN_PATH,
+            retrieve_graph_data_from_file_with_options,
+            retrieve_graph_data_from_stdin_with_options,
+        },
+        pairs_input::retrieve_pairs_from_file,
+        terminal_input::retrieve_graph_data_from_cmd_line_with_options,
+    },
+    error::{
+        config_error::ConfigParseError, parse_error::ParseError, pathfinder_error::PathfinderError,
+    },
+    graphs::graph::{Graph, GraphNode, GraphWeight},
+    output::{distance_table::write_distance_table, output_format::OutputFormat},
+};
+
+/// Parses `args`, loads the graph, and prints the requested output.
+///
+/// This is everything the `pathfinder` binary does after setting up
+/// logging, so a binary that registers custom algorithms first can offer the
+/// same CLI by calling it with [`std::env::args`].
+///
+/// # Behavior
+///
+/// - Parses `args` (program name first) into [`AppConfig`].
+/// - Prints [`AppConfig::usage`] for `--help` and returns `Ok(())`.
+/// - Runs [`run`] with the parsed configuration.
+///
+/// # Errors
+///
+/// Returns the first [`PathfinderError`] raised while parsing the arguments or
+/// by [`run`].
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::cmd_line::runner::run_from_args;
+///
+/// let args = ["pathfinder", "--start", "A", "--end", "B", "--algo", "unknown"]
+///     .iter()
+///     .map(|arg| arg.to_string())
+///     .collect();
+/// assert!(run_from_args(args).is_err());
+/// ```
+pub fn run_from_args(args: Vec<String>) -> Result<(), PathfinderError> {
+    // validate the arguments and generate config data
+    let app_config = match AppConfig::setup_config(args) {
+        Ok(app_config) => app_config,
+        Err(ConfigParseError::HelpRequested) => {
+            print!("{}", AppConfig::usage());
+            return Ok(());
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    run(&app_config)
+}
+
+/// Loads the graph described by `app_config` and prints the requested output.
+///
+/// # Behavior
+///
+/// - Loads graph data according to `InputOrigin`.
+/// - Executes the selected algorithm, including custom algorithms registered
+///   through [`register_algorithm`](crate::algorithms::registry::register_algorithm),
+///   for the start/end node IDs.
+/// - Prints the resulting path output (or distance table) to stdout, and
+///   warnings and skipped lines to stderr.
+///
+/// # Errors
+///
+/// Returns the first [`PathfinderError`] raised while loading the graph or
+/// searching it.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::cmd_line::{app_config::AppConfig, runner::run};
+///
+/// let args = [
+///     "pathfinder",
+///     "--graph-file",
+///     "test_files/directed_graph.txt",
+///     "--start",
+///     "A",
+///     "--end",
+///     "D",
+/// ]
+/// .iter()
+/// .map(|arg| arg.to_string())
+/// .collect();
+/// let config = AppConfig::setup_config(args).unwrap();
+/// assert!(run(&config).is_ok());
+/// ```
+pub fn run(app_config: &AppConfig) -> Result<(), PathfinderError> {
+    // read the pairs first so a broken pairs file fails before a long graph load
+    let pairs = app_config
+        .pairs_file
+        .as_deref()
+        .map(retrieve_pairs_from_file)
+        .transpose()?
+        // a repeated '--end' is answered like a pairs file sharing one start
+        .or_else(|| {
+            (app_config.end_node_ids.len() > 1).then(|| {
+                app_config
+                    .end_node_ids
+                    .iter()
+                    .map(|end| (app_config.start_node_id.clone(), end.clone()))
+                    .collect()
+            })
+        });
+    // negative weights are only meaningful for Bellman-Ford
+    let file_input_options = FileInputOptions {
+        allow_negative_weights: matches!(app_config.algorithm, Algorithms::BellmanFord),
+        missing_weight: app_config.missing_weight_policy,
+        progress_interval: app_config.progress_interval,
+        node_limit: app_config.node_limit,
+        lenient: app_config.lenient,
+    };
+
+    // create the graph
+    let mut graphs = match app_config.data_input {
+        // '--graph-file -' reads the graph text from standard input
+        InputOrigin::File if app_config.file_path == STDIN_PATH => {
+            retrieve_graph_data_from_stdin_with_options(&file_input_options)?
+        }
+        InputOrigin::File => {
+            retrieve_graph_data_from_file_with_options(&app_config.file_path, &file_input_options)?
+        }
+        InputOrigin::CommandLine => {
+            retrieve_graph_data_from_cmd_line_with_options(&file_input_options)?
+        }
+    };
+    let skipped_lines = std::mem::take(&mut graphs.skipped_lines);
+    let skipped_line_count = graphs.skipped_line_count;
+    let warnings = std::mem::take(&mut graphs.warnings);
+
+    // execute the algorithm on it
+    let outcome = search_loaded_graph(graphs, pairs.as_deref(), app_config);
+    // Report warnings and skipped lines last so they are not buried under the result.
+    print_warnings(&warnings);
+    print_skipped_lines_summary(&skipped_lines, skipped_line_count);
+    outcome
+}
+
+/// Runs the configured search on whichever graph the file produced.
+///
+/// `pairs` holds the queries of `--pairs` or of a repeated `--end`; when set,
+/// they replace the single `--start`/`--end` query.
+///
+/// # Errors
+///
+/// Returns a [`PathfinderError`] if the algorithm does not support the graph
+/// type or the search fails.
+fn search_loaded_graph(
+    graphs: FileInputGraphResult,
+    pairs: Option<&[(String, String)]>,
+    app_config: &AppConfig,
+) -> Result<(), PathfinderError> {
+    if pairs.is_some()
+        && (app_config.print_distances
+            || app_config.explain
+            || !matches!(app_config.algorithm, Algorithms::Dijkstra))
+    {
+        return Err(PathfinderError::Unsupported(
+            "'--pairs' and a repeated '--end' answer batched Dijkstra path searches only; they cannot be combined with '--distances', '--explain' or another algorithm!"
+                .to_string(),
+        ));
+    }
+
+    if app_config.print_distances && !matches!(app_config.algorithm, Algorithms::Dijkstra) {
+        return Err(PathfinderError::Unsupported(
+            "'--distances' prints Dijkstra distances only; it cannot be combined with another algorithm!"
+                .to_string(),
+        ));
+    }
+
+    if app_config.explain
+        && (app_config.print_distances || !matches!(app_config.algorithm, Algorithms::Dijkstra))
+    {
+        return Err(PathfinderError::Unsupported(
+            "'--explain' narrates Dijkstra path searches only; it cannot be combined with '--distances' or another algorithm!"
+                .to_string(),
+        ));
+    }
+
+    if app_config.print_stats
+        && (app_config.print_distances
+            || app_config.explain
+            || pairs.is_some()
+            || !matches!(
+                app_config.algorithm,
+                Algorithms::Dijkstra | Algorithms::AStar
+            ))
+    {
+        return Err(PathfinderError::Unsupported(
+            "'--stats' measures single Dijkstra and A* path searches only; it cannot be combined with '--distances', '--explain', '--pairs', a repeated '--end' or another algorithm!"
+                .to_string(),
+        ));
+    }
+
+    if let Some(graph) = graphs.directed_graph {
+        if app_config.print_distances {
+            return print_distance_table(graph, app_config);
+        }
+        if let Some(pairs) = pairs {
+            return print_pair_results(graph, pairs, app_config);
+        }
+        if let Algorithms::Custom(name) = &app_config.algorithm {
+            return run_custom_algorithm(name, into_dyn_graph(graph), app_config);
+        }
+        if matches!(app_config.algorithm, Algorithms::Bfs) {
+            return run_bfs(graph, app_config);
+        }
+        if matches!(app_config.algorithm, Algorithms::Dfs) {
+            return run_dfs(graph, app_config);
+        }
+        if matches!(app_config.algorithm, Algorithms::BellmanFord) {
+            return run_bellman_ford(graph, app_config);
+        }
+        let algo = match app_config.algorithm {
+            // without coordinates the A* heuristic is zero, which is Dijkstra
+            Algorithms::Dijkstra | Algorithms::AStar => DijkstraAlgorithm::new(graph),
+            _ => {
+                return Err(PathfinderError::Unsupported(format!(
+                    "Algorithm {:?} is not implemented for directed graphs yet or a directed graph is not supported by the implementation of the algorithm!",
+                    app_config.algorithm
+                )));
+            }
+        };
+        let result = run_dijkstra(&algo, app_config)?;
+        // display the result
+        print_search_result(
+            &result,
+            || result.format_with(&app_config.distance_format),
+            app_config,
+        );
+    } else if let Some(graph) = graphs.signed_directed_graph {
+        if app_config.print_distances || !matches!(app_config.algorithm, Algorithms::BellmanFord) {
+            return Err(PathfinderError::Unsupported(format!(
+                "Algorithm {:?} cannot search this graph! The graph was loaded with signed weights, which only a Bellman-Ford path search supports.",
+                app_config.algorithm
+            )));
+        }
+        return run_bellman_ford(graph, app_config);
+    } else if let Some(graph) = graphs.undirected_graph {
+        if app_config.print_distances {
+            return print_distance_table(graph, app_config);
+        }
+        if let Some(pairs) = pairs {
+            return print_pair_results(graph, pairs, app_config);
+        }
+        if let Algorithms::Custom(name) = &app_config.algorithm {
+            return run_custom_algorithm(name, into_dyn_graph(graph), app_config);
+        }
+        if matches!(app_config.algorithm, Algorithms::Bfs) {
+            return run_bfs(graph, app_config);
+        }
+        if matches!(app_config.algorithm, Algorithms::Dfs) {
+            return run_dfs(graph, app_config);
+        }
+        if matches!(app_config.algorithm, Algorithms::BellmanFord) {
+            return run_bellman_ford(graph, app_config);
+        }
+        let algo = match app_config.algorithm {
+            // without coordinates the A* heuristic is zero, which is Dijkstra
+            Algorithms::Dijkstra | Algorithms::AStar => DijkstraAlgorithm::new(graph),
+            _ => {
+                return Err(PathfinderError::Unsupported(format!(
+                    "Algorithm {:?} is not implemented for undirected graphs yet or an undirected graph is not supported by the implementation of the algorithm!",
+                    app_config.algorithm
+                )));
+            }
+        };
+        let result = run_dijkstra(&algo, app_config)?;
+        // display the result
+        print_search_result(
+            &result,
+            || result.format_with(&app_config.distance_format),
+            app_config,
+        );
+    } else if let Some(graph) = graphs.two_dimensional_graph {
+        if app_config.print_distances {
+            return Err(PathfinderError::Unsupported(
+                "Distance tables are only supported for directed and undirected graphs!"
+                    .to_string(),
+            ));
+        }
+        let algo = match app_config.algorithm {
+            Algorithms::AStar => AStar::new(graph),
+            _ => {
+                return Err(PathfinderError::Unsupported(format!(
+                    "Algorithm {:?} is not implemented for two dimensional graphs yet or a two dimensional graph is not supported by the implementation of the algorithm!",
+                    app_config.algorithm
+                )));
+            }
+        };
+        let (start, end) = (&app_config.start_node_id, &app_config.end_node_id);
+        let result = if app_config.print_stats {
+            let (result, stats) = algo.shortest_path_with_stats(start, end)?;
+            print_search_stats(&stats, &result.get_total_distance(), app_config);
+            result
+        } else {
+            algo.shortest_path(start, end)?
+        };
+        // display the result
+        print_search_result(
+            &result,
+            || result.format_with(&app_config.distance_format),
+            app_config,
+        );
+    } else if let Some(graph) = graphs.unweighted_graph {
+        if app_config.print_distances {
+            return Err(PathfinderError::Unsupported(
+                "Distance tables need edge weights; the graph file has none!".to_string(),
+            ));
+        }
+        if pairs.is_some() {
+            return Err(PathfinderError::Unsupported(
+                "'--pairs' and a repeated '--end' run Dijkstra, which needs edge weights; the graph file has none!"
+                    .to_string(),
+            ));
+        }
+        if !matches!(app_config.algorithm, Algorithms::Bfs) {
+            return Err(PathfinderError::Unsupported(format!(
+                "Algorithm {:?} needs edge weights, but no edge in the graph file has one! Use '--algo BFS' for unweighted graphs.",
+                app_config.algorithm
+            )));
+        }
+        return run_bfs(graph, app_config);
+    } else {
+        return Err(PathfinderError::Unsupported(format!(
+            "No graph was create from the file {}!",
+            app_config.file_path
+        )));
+    };
+    Ok(())
+}
+
+/// Runs the Dijkstra path search, narrating every step on stderr when
+/// `--explain` is set and printing its statistics when `--stats` is set.
+///
+/// Neither output touches stdout, so the printed result is the same with and
+/// without these flags.
+///
+/// # Errors
+///
+/// Returns a [`PathfinderError`] if the search fails.
+fn run_dijkstra<N, W, G>(
+    algo: &DijkstraAlgorithm<N, W, G>,
+    app_config: &AppConfig,
+) -> Result<DijkstraSearchResult<N, W>, PathfinderError>
+where
+    N: GraphNode,
+    W: GraphWeight + Ord,
+    G: Graph<Node = N, Weight = W> + Display,
+{
+    let (start, end) = (&app_config.start_node_id, &app_config.end_node_id);
+    let result = if app_config.explain {
+        algo.shortest_path_with_observer(start, end, |event| eprintln!("{}", event))?
+    } else if app_config.print_stats {
+        let (result, stats) = algo.shortest_path_with_stats(start, end)?;
+        print_search_stats(&stats, &result.get_total_distance(), app_config);
+        result
+    } else {
+        algo.shortest_path(start, end)?
+    };
+    Ok(result)
+}
+
+/// Prints one path result to stdout, as JSON for `--output json` and as
+/// `text()` otherwise.
+fn print_search_result<R: SearchResult>(
+    result: &R,
+    text: impl FnOnce() -> String,
+    app_config: &AppConfig,
+) {
+    if app_config.output_format == OutputFormat::Json {
+        println!("{}", result.to_json());
+    } else {
+        println!("{}", text());
+    }
+}
+
+/// Prints the `--stats` summary of one search to stderr, e.g.
+/// `elapsed: 12.3µs, nodes expanded: 4, edges relaxed: 5, distance: 7 km`.
+fn print_search_stats<D: Display>(stats: &SearchStats, distance: &D, app_config: &AppConfig) {
+    eprintln!(
+        "{}, distance: {}",
+        stats,
+        app_config.distance_format.format(distance)
+    );
+}
+
+/// Answers every `--pairs` (or repeated `--end`) query with one batched
+/// Dijkstra run.
+///
+/// Prints one line per pair in input order, e.g. `A -> D: A -(2)-> D (total: 2)`.
+/// A pair that fails (missing node, no path) prints its error on its own line
+/// instead of aborting the remaining pairs.
+///
+/// # Errors
+///
+/// Returns a [`PathfinderError`] if writing to stdout fails.
+fn print_pair_results<N, W, G>(
+    graph: G,
+    pairs: &[(String, String)],
+    app_config: &AppConfig,
+) -> Result<(), PathfinderError>
+where
+    N: GraphNode,
+    W: GraphWeight + Ord,
+    G: Graph<Node = N, Weight = W> + Display,
+{
+    let algo = DijkstraAlgorithm::new(graph);
+    let queries: Vec<(&str, &str)> = pairs
+        .iter()
+        .map(|(start, end)| (start.as_str(), end.as_str()))
+        .collect();
+
+    let mut stdout = io::stdout().lock();
+    for ((start, end), result) in queries.iter().zip(algo.shortest_paths_batch(&queries)) {
+        match result {
+            Ok(result) if app_config.output_format == OutputFormat::Json => {
+                writeln!(stdout, "{} -> {}: {}", start, end, result.to_json())?
+            }
+            Ok(result) => writeln!(
+                stdout,
+                "{} -> {}: {}",
+                start,
+                end,
+                result.format_with(&app_config.distance_format)
+            )?,
+            Err(err) => writeln!(stdout, "{} -> {}: {}", start, end, err)?,
+        }
+    }
+    Ok(())
+}
+
+/// Runs a fewest-hop BFS search and prints its result.
+///
+/// Edge weights are ignored, so weighted `D` and `UN` graphs can be searched
+/// by hop count as well.
+///
+/// # Errors
+///
+/// Returns a [`PathfinderError`] if a node is missing or the end node is
+/// unreachable.
+fn run_bfs<G>(graph: G, app_config: &AppConfig) -> Result<(), PathfinderError>
+where
+    G: Graph + Display,
+    G::Node: Debug,
+{
+    let result = BfsAlgorithm::new(graph)
+        .shortest_path(&app_config.start_node_id, &app_config.end_node_id)?;
+    // hop counts have no unit, so the distance format does not apply
+    print_search_result(&result, || result.to_string(), app_config);
+    Ok(())
+}
+
+/// Runs a depth-first search and prints the first path it finds.
+///
+/// # Errors
+///
+/// Returns a [`PathfinderError`] if a node is missing or the end node is
+/// unreachable.
+fn run_dfs<G>(graph: G, app_config: &AppConfig) -> Result<(), PathfinderError>
+where
+    G: Graph + Display,
+    G::Node: Debug,
+{
+    let result = DfsAlgorithm::new(graph)
+        .shortest_path(&app_config.start_node_id, &app_config.end_node_id)?;
+    print_search_result(
+        &result,
+        || result.format_with(&app_config.distance_format),
+        app_config,
+    );
+    Ok(())
+}
+
+/// Runs a Bellman-Ford search and prints its result.
+///
+/// # Errors
+///
+/// Returns a [`PathfinderError`] if a node is missing, the end node is
+/// unreachable, or a negative cycle is reachable from the start node.
+fn run_bellman_ford<G>(graph: G, app_config: &AppConfig) -> Result<(), PathfinderError>
+where
+    G: Graph + Display,
+    G::Node: Debug,
+{
+    let result = BellmanFordAlgorithm::new(graph)
+        .shortest_path(&app_config.start_node_id, &app_config.end_node_id)?;
+    print_search_result(
+        &result,
+        || result.format_with(&app_config.distance_format),
+        app_config,
+    );
+    Ok(())
+}
+
+/// Runs the custom algorithm registered under `name` and prints its result.
+///
+/// # Errors
+///
+/// Returns a [`PathfinderError`] if no algorithm is registered under `name`
+/// or the search fails.
+fn run_custom_algorithm(
+    name: &str,
+    graph: Box<DynGraph>,
+    app_config: &AppConfig,
+) -> Result<(), PathfinderError> {
+    let algo = create_algorithm(name, graph).ok_or_else(|| {
+        PathfinderError::Unsupported(format!("No algorithm is registered under '{}'!", name))
+    })?;
+    let result = algo.shortest_path(&app_config.start_node_id, &app_config.end_node_id)?;
+    print_search_result(
+        &result,
+        || result.format_with(&app_config.distance_format),
+        app_config,
+    );
+    Ok(())
+}
+
+/// Prints the warnings collected while loading the graph to stderr, such as
+/// ignored duplicate edges. Prints nothing when there are none.
+fn print_warnings(warnings: &[String]) {
+    for warning in warnings {
+        eprintln!("Warning: {}", warning);
+    }
+}
+
+/// Prints the lines skipped by `--lenient` loading to stderr.
+///
+/// Only the collected errors are listed; `total` also counts lines whose
+/// errors were dropped because of the collection cap. Prints nothing when no
+/// line was skipped.
+fn print_skipped_lines_summary(skipped_lines: &[ParseError], total: usize) {
+    if total == 0 {
+        return;
+    }
+
+    eprintln!("Skipped {} invalid line(s):", total);
+    for err in skipped_lines {
+        eprintln!("  {}", err);
+    }
+    if total > skipped_lines.len() {
+        eprintln!(
+            "  ... and {} more (only the first {} are listed)",
+            total - skipped_lines.len(),
+            skipped_lines.len()
+        );
+    }
+}
+
+/// Prints the distance from `--start` to every node.
+///
+/// Rows follow the node insertion order of `graph`. Unreachable nodes are
+/// printed with an empty (CSV/TSV) or `unreachable` (text) distance.
+///
+/// # Errors
+///
+/// Returns a [`PathfinderError`] if the distances could not be computed or
+/// written.
+fn print_distance_table<N, W, G>(graph: G, app_config: &AppConfig) -> Result<(), PathfinderError>
+where
+    N: GraphNode,
+    W: GraphWeight + Ord,
+    G: Graph<Node = N, Weight = W> + Display,
+{
+    // Capture the node order before the graph moves into the algorithm.
+    let node_ids: Vec<String> = graph
+        .get_all_nodes()
+        .iter()
+        .map(|node| node.get_id().to_string())
+        .collect();
+
+    let algo = DijkstraAlgorithm::new(graph);
+    let distances = algo.distances_from(&app_config.start_node_id)?;
+
+    let rows: Vec<(String, Option<W>)> = node_ids
+        .into_iter()
+        .map(|id| {
+            let distance = distances.get(&id).copied();
+            (id, distance)
+        })
+        .collect();
+
+    write_distance_table(&mut io::stdout(), &rows, &app_config.output_format)?;
+    Ok(())
+}
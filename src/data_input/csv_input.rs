@@ -0,0 +1,241 @@
+//! Graph parsing from CSV edge lists.
+//!
+//! # Overview
+//!
+//! [`graph_from_csv`] reads one `from,to,weight` row per edge and builds a
+//! [`DirectedGraph`] or an [`UndirectedGraph`], as chosen by the caller. Many
+//! public datasets ship in this shape, usually with a header row.
+//!
+//! # Input Format
+//!
+//! ```text
+//! Header (optional): <name>,<name>,<name>     (example: source,target,weight)
+//! Edge row:          <from>,<to>,<weight>     (example: A,B,7)
+//! Quoted field:      "<text>"                 (example: "Portland, OR",Seattle,174)
+//! ```
+//!
+//! # Validation and consistency rules
+//!
+//! - The first non-empty row is a header when its third column is not a
+//!   number; it is skipped. Every other row must be an edge.
+//! - Fields are trimmed. A field wrapped in double quotes may contain commas,
+//!   and `""` inside it stands for one `"`.
+//! - Every row needs exactly three fields, non-empty node names, and a weight
+//!   that fits into `u16`.
+//! - Whitespace-only lines are ignored, nodes keep the order in which they
+//!   first appear, and duplicate edges are ignored to match file input.
+//!
+//! # Example
+//!
+//! ```rust
+//! use shortest_path_finder::data_input::csv_input::graph_from_csv;
+//! use shortest_path_finder::graphs::graph::Graph;
+//!
+//! let csv = "from,to,weight\n\"Portland, OR\",Seattle,174\n";
+//! let parsed = graph_from_csv(csv, true).expect("valid CSV");
+//! let graph = parsed.directed_graph.expect("directed was requested");
+//! let portland = graph.get_node_by_id("Portland, OR").unwrap();
+//! assert_eq!(graph.neighbors(portland).next().unwrap().1, 174);
+//! ```
+
+use crate::{
+    data_input::file_input::{FileInputError, FileInputGraphResult},
+    error::parse_error::ParseError,
+    graphs::{directed::DirectedGraph, graph::Graph, undirected::UndirectedGraph},
+    nodes::default_node::DefaultNode,
+};
+
+/// One edge row after parsing.
+struct CsvEdge {
+    /// ID of the source node.
+    from: String,
+    /// ID of the target node.
+    to: String,
+    /// Edge weight.
+    weight: u16,
+}
+
+/// Parses a CSV edge list into a directed or undirected graph.
+///
+/// See the [module docs](self) for the row format and header detection.
+///
+/// # Parameters
+///
+/// - `content`: The full CSV text.
+/// - `directed`: Build a [`DirectedGraph`] when `true`, an
+///   [`UndirectedGraph`] otherwise.
+///
+/// # Returns
+///
+/// - `Ok(FileInputGraphResult)` with `directed_graph` or `undirected_graph`
+///   set, depending on `directed`.
+///
+/// # Errors
+///
+/// Returns [`FileInputError::Parse`] with [`ParseError::InvalidDataInput`]
+/// naming the line if a row does not have three fields, a quoted field is
+/// unterminated, a node name is empty, or a weight is not a `u16`.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::data_input::{csv_input::graph_from_csv, file_input::FileInputError};
+///
+/// let err = graph_from_csv("A,B,7\nB,C\n", false).unwrap_err();
+/// assert!(err.to_string().contains("CSV line 2"));
+/// assert!(matches!(err, FileInputError::Parse(_)));
+/// ```
+pub fn graph_from_csv(
+    content: &str,
+    directed: bool,
+) -> Result<FileInputGraphResult, FileInputError> {
+    let edges = parse_edge_rows(content).map_err(FileInputError::Parse)?;
+
+    let result = if directed {
+        let mut graph = DirectedGraph::default();
+        fill_graph(&mut graph, &edges)?;
+        FileInputGraphResult::new(Some(graph), None, None)
+    } else {
+        let mut graph = UndirectedGraph::default();
+        fill_graph(&mut graph, &edges)?;
+        FileInputGraphResult::new(None, Some(graph), None)
+    };
+
+    result.ok_or(FileInputError::Parse(ParseError::InvalidDataInput(
+        "Exactly one parsed graph variant must be present in FileInputGraphResult.".to_string(),
+    )))
+}
+
+/// Inserts the endpoints and edges of every row into an empty graph.
+///
+/// # Errors
+///
+/// Returns [`FileInputError::Parse`] if the graph rejects an insertion.
+fn fill_graph<G: Graph<Node = DefaultNode, Weight = u16>>(
+    graph: &mut G,
+    edges: &[CsvEdge],
+) -> Result<(), FileInputError> {
+    for edge in edges {
+        for id in [&edge.from, &edge.to] {
+            if graph.get_node_by_id(id).is_none() {
+                graph.insert_node(DefaultNode::new(id.clone()));
+            }
+        }
+        let from = DefaultNode::new(edge.from.clone());
+        let to = DefaultNode::new(edge.to.clone());
+
+        // Duplicate edges are ignored to match file input.
+        if graph.does_edge_already_exist(&from, &to) {
+            continue;
+        }
+
+        if let Some(err) = graph.insert_edge(&from, &to, Some(edge.weight)) {
+            return Err(FileInputError::Parse(ParseError::InvalidDataInput(
+                err.to_string(),
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses every non-empty row, skipping a detected header row.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidDataInput`] naming the first invalid line.
+fn parse_edge_rows(content: &str) -> Result<Vec<CsvEdge>, ParseError> {
+    let mut edges = Vec::new();
+    let mut first_row = true;
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let fields = split_fields(line, line_number)?;
+        let [from, to, weight] = fields.as_slice() else {
+            return Err(ParseError::InvalidDataInput(format!(
+                "CSV line {} ('{}') must have the form 'from,to,weight'!",
+                line_number, line
+            )));
+        };
+
+        let is_header = first_row && weight.parse::<f64>().is_err();
+        first_row = false;
+        if is_header {
+            continue;
+        }
+
+        if from.is_empty() || to.is_empty() {
+            return Err(ParseError::InvalidDataInput(format!(
+                "CSV line {} has an empty node name!",
+                line_number
+            )));
+        }
+        let weight: u16 = weight.parse().map_err(|_| {
+            ParseError::InvalidDataInput(format!(
+                "CSV line {} has the invalid weight '{}'; expected 0..=65535!",
+                line_number, weight
+            ))
+        })?;
+
+        edges.push(CsvEdge {
+            from: from.clone(),
+            to: to.clone(),
+            weight,
+        });
+    }
+
+    Ok(edges)
+}
+
+/// Splits one CSV line into trimmed fields, honoring double-quoted fields.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidDataInput`] for an unterminated quoted field
+/// or text between a closing quote and the next comma.
+fn split_fields(line: &str, line_number: usize) -> Result<Vec<String>, ParseError> {
+    let mut fields = Vec::new();
+    let mut chars = line.chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+        let mut field = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            loop {
+                match chars.next() {
+                    Some('"') if chars.next_if_eq(&'"').is_some() => field.push('"'),
+                    Some('"') => break,
+                    Some(c) => field.push(c),
+                    None => {
+                        return Err(ParseError::InvalidDataInput(format!(
+                            "CSV line {} has an unterminated quoted field!",
+                            line_number
+                        )));
+                    }
+                }
+            }
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            if chars.peek().is_some_and(|&c| c != ',') {
+                return Err(ParseError::InvalidDataInput(format!(
+                    "CSV line {} has text after a closing quote!",
+                    line_number
+                )));
+            }
+        } else {
+            while let Some(c) = chars.next_if(|&c| c != ',') {
+                field.push(c);
+            }
+            field.truncate(field.trim_end().len());
+        }
+        fields.push(field);
+
+        if chars.next().is_none() {
+            return Ok(fields);
+        }
+    }
+}
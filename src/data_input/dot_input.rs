@@ -0,0 +1,486 @@
+//! Graph parsing from Graphviz DOT text.
+//!
+//! # Overview
+//!
+//! [`graph_from_dot`] is the counterpart of [`Graph::to_dot`]: it reads a
+//! `digraph` body into a [`DirectedGraph`] and a `graph` body into an
+//! [`UndirectedGraph`]. Only the subset of DOT that the exporter emits is
+//! supported.
+//!
+//! # Input Format
+//!
+//! ```text
+//! Header:     [strict] digraph [<name>] { | [strict] graph [<name>] {
+//! Directed:   <from> -> <to> [label="<weight>"];   (example: "A" -> "B" [label="7"];)
+//! Undirected: <from> -- <to> [label="<weight>"];   (example: A -- B [label="7"];)
+//! Node:       <id> [<attributes>];                 (example: "X";)
+//! Footer:     }
+//! ```
+//!
+//! # Validation and consistency rules
+//!
+//! - IDs are either bare (`A`, `node_1`, `42`) or double-quoted; quoted IDs
+//!   may escape `"` and `\` with a backslash, as [`Graph::to_dot`] does.
+//! - The `label` attribute holds the edge weight and must fit into `u16`.
+//!   Edges without a label get the weight `1`; other attributes are ignored.
+//! - A `digraph` only accepts `->`, a `graph` only `--`. Edge chains such as
+//!   `A -> B -> C` are not supported.
+//! - Attribute statements (`node [...]`, `edge [...]`, `graph [...]`) are
+//!   ignored. Subgraphs, ports and comments are not supported.
+//! - Nodes keep the order in which they first appear, and duplicate edges
+//!   are ignored to match file input.
+//!
+//! # Example
+//!
+//! ```rust
+//! use shortest_path_finder::data_input::dot_input::graph_from_dot;
+//! use shortest_path_finder::graphs::graph::Graph;
+//!
+//! let parsed = graph_from_dot("digraph {\n    \"A\" -> \"B\" [label=\"7\"];\n    B -> C;\n}\n")
+//!     .expect("valid DOT");
+//! let graph = parsed.directed_graph.expect("digraph bodies are directed");
+//! let b = graph.get_node_by_id("B").unwrap();
+//! // A missing label means weight 1.
+//! assert_eq!(graph.neighbors(b).next().unwrap().1, 1);
+//! ```
+
+use std::{collections::HashSet, iter::Peekable, vec::IntoIter};
+
+use crate::{
+    data_input::file_input::{FileInputError, FileInputGraphResult},
+    error::parse_error::ParseError,
+    graphs::{directed::DirectedGraph, graph::Graph, undirected::UndirectedGraph},
+    nodes::default_node::DefaultNode,
+};
+
+/// Weight of edges without a `label` attribute.
+pub const DEFAULT_DOT_WEIGHT: u16 = 1;
+
+/// One lexical token of DOT text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// A bare or quoted ID; `quoted` tells keywords apart from node names.
+    Id { value: String, quoted: bool },
+    /// An edge operator, `->` or `--`.
+    Connector(&'static str),
+    /// One of `{`, `}`, `[`, `]`, `=`, `;` and `,`.
+    Symbol(char),
+}
+
+/// Tokens paired with their 1-based line number.
+type TokenStream = Peekable<IntoIter<(usize, Token)>>;
+
+/// One edge statement after parsing.
+struct DotEdge {
+    /// ID of the source node.
+    from: String,
+    /// ID of the target node.
+    to: String,
+    /// Weight from the `label` attribute, or [`DEFAULT_DOT_WEIGHT`].
+    weight: u16,
+}
+
+/// Parsed DOT document before graph construction.
+struct DotDocument {
+    /// Whether the header was `digraph`.
+    directed: bool,
+    /// Node IDs in order of first appearance.
+    node_ids: Vec<String>,
+    /// The same IDs as `node_ids`, for duplicate checks.
+    seen: HashSet<String>,
+    /// Edge statements in document order.
+    edges: Vec<DotEdge>,
+}
+
+/// Parses DOT text into a directed or undirected graph.
+///
+/// See the [module docs](self) for the supported subset.
+///
+/// # Parameters
+///
+/// - `content`: The full DOT text, for example the output of
+///   [`Graph::to_dot`].
+///
+/// # Returns
+///
+/// - `Ok(FileInputGraphResult)` with `directed_graph` set for a `digraph`,
+///   or `undirected_graph` set for a `graph`.
+///
+/// # Errors
+///
+/// Returns [`FileInputError::Parse`] with [`ParseError::InvalidDataInput`]
+/// naming the line if the header is missing, a quoted ID is unterminated, an
+/// edge uses the wrong operator for the graph kind, a label is not a `u16`
+/// weight, or a statement falls outside the supported subset.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::data_input::{dot_input::graph_from_dot, file_input::FileInputError};
+///
+/// let err = graph_from_dot("digraph { A -- B; }").unwrap_err();
+/// assert!(matches!(err, FileInputError::Parse(_)));
+/// ```
+pub fn graph_from_dot(content: &str) -> Result<FileInputGraphResult, FileInputError> {
+    let document = parse_document(content).map_err(FileInputError::Parse)?;
+
+    let result = if document.directed {
+        let mut graph = DirectedGraph::default();
+        fill_graph(&mut graph, &document)?;
+        FileInputGraphResult::new(Some(graph), None, None)
+    } else {
+        let mut graph = UndirectedGraph::default();
+        fill_graph(&mut graph, &document)?;
+        FileInputGraphResult::new(None, Some(graph), None)
+    };
+
+    result.ok_or(FileInputError::Parse(ParseError::InvalidDataInput(
+        "Exactly one parsed graph variant must be present in FileInputGraphResult.".to_string(),
+    )))
+}
+
+/// Inserts all nodes, then all edges, into an empty graph.
+///
+/// # Errors
+///
+/// Returns [`FileInputError::Parse`] if the graph rejects an insertion.
+fn fill_graph<G: Graph<Node = DefaultNode, Weight = u16>>(
+    graph: &mut G,
+    document: &DotDocument,
+) -> Result<(), FileInputError> {
+    for id in &document.node_ids {
+        graph.insert_node(DefaultNode::new(id.clone()));
+    }
+
+    for edge in &document.edges {
+        let (Some(from), Some(to)) = (
+            graph.get_node_by_id(&edge.from).cloned(),
+            graph.get_node_by_id(&edge.to).cloned(),
+        ) else {
+            // Unreachable: every edge endpoint is recorded in `node_ids`.
+            continue;
+        };
+
+        // Duplicate edges are ignored to match file input.
+        if graph.does_edge_already_exist(&from, &to) {
+            continue;
+        }
+
+        if let Some(err) = graph.insert_edge(&from, &to, Some(edge.weight)) {
+            return Err(FileInputError::Parse(ParseError::InvalidDataInput(
+                err.to_string(),
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds an [`ParseError::InvalidDataInput`] that names the DOT line.
+fn dot_error(line: usize, message: &str) -> ParseError {
+    ParseError::InvalidDataInput(format!("DOT line {}: {}!", line, message))
+}
+
+/// Splits DOT text into tokens.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidDataInput`] for an unterminated quoted ID, a
+/// lone `-`, or a character outside the supported subset.
+fn tokenize(content: &str) -> Result<Vec<(usize, Token)>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = content.chars().peekable();
+    let mut line = 1;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            c if c.is_whitespace() => {}
+            '"' => {
+                let start_line = line;
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(escaped @ ('"' | '\\')) => value.push(escaped),
+                            Some(other) => {
+                                if other == '\n' {
+                                    line += 1;
+                                }
+                                value.push('\\');
+                                value.push(other);
+                            }
+                            None => return Err(dot_error(start_line, "unterminated quoted ID")),
+                        },
+                        Some(other) => {
+                            if other == '\n' {
+                                line += 1;
+                            }
+                            value.push(other);
+                        }
+                        None => return Err(dot_error(start_line, "unterminated quoted ID")),
+                    }
+                }
+                tokens.push((
+                    start_line,
+                    Token::Id {
+                        value,
+                        quoted: true,
+                    },
+                ));
+            }
+            '-' => match chars.next() {
+                Some('>') => tokens.push((line, Token::Connector("->"))),
+                Some('-') => tokens.push((line, Token::Connector("--"))),
+                _ => return Err(dot_error(line, "expected '->' or '--' after '-'")),
+            },
+            '{' | '}' | '[' | ']' | '=' | ';' | ',' => tokens.push((line, Token::Symbol(c))),
+            c if is_bare_id_char(c) => {
+                let mut value = c.to_string();
+                while let Some(&next) = chars.peek()
+                    && is_bare_id_char(next)
+                {
+                    value.push(next);
+                    chars.next();
+                }
+                tokens.push((
+                    line,
+                    Token::Id {
+                        value,
+                        quoted: false,
+                    },
+                ));
+            }
+            other => {
+                return Err(dot_error(
+                    line,
+                    &format!("unexpected character '{}'", other),
+                ));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Returns `true` for characters allowed in an unquoted ID.
+fn is_bare_id_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.'
+}
+
+/// Parses the header, the statements and the closing brace.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidDataInput`] naming the line of the first
+/// token that does not fit the supported subset.
+fn parse_document(content: &str) -> Result<DotDocument, ParseError> {
+    let mut tokens: TokenStream = tokenize(content)?.into_iter().peekable();
+
+    let directed = parse_header(&mut tokens)?;
+    let mut document = DotDocument {
+        directed,
+        node_ids: Vec::new(),
+        seen: HashSet::new(),
+        edges: Vec::new(),
+    };
+    let expected_connector = if directed { "->" } else { "--" };
+
+    loop {
+        let Some((line, token)) = tokens.next() else {
+            return Err(ParseError::InvalidDataInput(
+                "DOT input is missing the closing '}'!".to_string(),
+            ));
+        };
+        let (value, quoted) = match token {
+            Token::Symbol('}') => break,
+            Token::Symbol(';') => continue,
+            Token::Id { value, quoted } => (value, quoted),
+            other => {
+                return Err(dot_error(
+                    line,
+                    &format!("expected a statement, found {}", describe(&other)),
+                ));
+            }
+        };
+
+        match tokens.peek() {
+            Some((_, Token::Connector(connector))) => {
+                if *connector != expected_connector {
+                    return Err(dot_error(
+                        line,
+                        &format!(
+                            "'{}' is not allowed in a {}; use '{}'",
+                            connector,
+                            if directed { "digraph" } else { "graph" },
+                            expected_connector
+                        ),
+                    ));
+                }
+                tokens.next();
+                let to = expect_id(&mut tokens, line)?;
+                if matches!(tokens.peek(), Some((_, Token::Connector(_)))) {
+                    return Err(dot_error(line, "edge chains are not supported"));
+                }
+                let label = parse_attributes(&mut tokens)?;
+                let weight = match label {
+                    Some(label) => label.parse::<u16>().map_err(|_| {
+                        dot_error(
+                            line,
+                            &format!(
+                                "the label '{}' of the edge '{}' {} '{}' is not a weight in 0..=65535",
+                                label, value, expected_connector, to
+                            ),
+                        )
+                    })?,
+                    None => DEFAULT_DOT_WEIGHT,
+                };
+                document.add_node(&value);
+                document.add_node(&to);
+                document.edges.push(DotEdge {
+                    from: value,
+                    to,
+                    weight,
+                });
+            }
+            Some((_, Token::Symbol('='))) => {
+                return Err(dot_error(
+                    line,
+                    &format!("graph attribute '{}' is not supported", value),
+                ));
+            }
+            _ => {
+                parse_attributes(&mut tokens)?;
+                let is_attribute_statement =
+                    !quoted && matches!(value.as_str(), "node" | "edge" | "graph");
+                if !is_attribute_statement {
+                    document.add_node(&value);
+                }
+            }
+        }
+    }
+
+    if let Some((line, token)) = tokens.next() {
+        return Err(dot_error(
+            line,
+            &format!("unexpected {} after the closing '}}'", describe(&token)),
+        ));
+    }
+
+    Ok(document)
+}
+
+/// Consumes `[strict] (digraph | graph) [<name>] {` and reports whether the
+/// graph is directed.
+fn parse_header(tokens: &mut TokenStream) -> Result<bool, ParseError> {
+    let missing_header = || {
+        ParseError::InvalidDataInput(
+            "DOT input must start with 'digraph {' or 'graph {'!".to_string(),
+        )
+    };
+    let mut keyword = match tokens.next() {
+        Some((
+            _,
+            Token::Id {
+                value,
+                quoted: false,
+            },
+        )) => value,
+        _ => return Err(missing_header()),
+    };
+    if keyword.eq_ignore_ascii_case("strict") {
+        keyword = match tokens.next() {
+            Some((
+                _,
+                Token::Id {
+                    value,
+                    quoted: false,
+                },
+            )) => value,
+            _ => return Err(missing_header()),
+        };
+    }
+
+    let directed = if keyword.eq_ignore_ascii_case("digraph") {
+        true
+    } else if keyword.eq_ignore_ascii_case("graph") {
+        false
+    } else {
+        return Err(missing_header());
+    };
+
+    if matches!(tokens.peek(), Some((_, Token::Id { .. }))) {
+        tokens.next();
+    }
+    match tokens.next() {
+        Some((_, Token::Symbol('{'))) => Ok(directed),
+        _ => Err(missing_header()),
+    }
+}
+
+/// Consumes the ID that ends an edge statement.
+fn expect_id(tokens: &mut TokenStream, line: usize) -> Result<String, ParseError> {
+    match tokens.next() {
+        Some((_, Token::Id { value, .. })) => Ok(value),
+        Some((line, other)) => Err(dot_error(
+            line,
+            &format!("expected a node ID, found {}", describe(&other)),
+        )),
+        None => Err(dot_error(line, "the edge has no target node")),
+    }
+}
+
+/// Consumes an optional `[key=value, ...]` list and returns the `label`
+/// value, if any.
+fn parse_attributes(tokens: &mut TokenStream) -> Result<Option<String>, ParseError> {
+    if !matches!(tokens.peek(), Some((_, Token::Symbol('[')))) {
+        return Ok(None);
+    }
+    let (open_line, _) = tokens.next().expect("peeked token exists");
+
+    let mut label = None;
+    loop {
+        match tokens.next() {
+            Some((_, Token::Symbol(']'))) => return Ok(label),
+            Some((_, Token::Symbol(',' | ';'))) => {}
+            Some((line, Token::Id { value: key, .. })) => {
+                if !matches!(tokens.next(), Some((_, Token::Symbol('=')))) {
+                    return Err(dot_error(
+                        line,
+                        &format!("the attribute '{}' has no '=' value", key),
+                    ));
+                }
+                let value = expect_id(tokens, line)?;
+                if key == "label" {
+                    label = Some(value);
+                }
+            }
+            Some((line, other)) => {
+                return Err(dot_error(
+                    line,
+                    &format!("expected an attribute, found {}", describe(&other)),
+                ));
+            }
+            None => return Err(dot_error(open_line, "the attribute list is missing ']'")),
+        }
+    }
+}
+
+/// Describes a token for error messages.
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Id { value, .. } => format!("'{}'", value),
+        Token::Connector(connector) => format!("'{}'", connector),
+        Token::Symbol(symbol) => format!("'{}'", symbol),
+    }
+}
+
+impl DotDocument {
+    /// Records `id` unless it was already seen.
+    fn add_node(&mut self, id: &str) {
+        if self.seen.insert(id.to_string()) {
+            self.node_ids.push(id.to_string());
+        }
+    }
+}
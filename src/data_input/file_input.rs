@@ -4,43 +4,82 @@
 //!
 //! This module reads text files and converts them into one concrete graph representation:
 //! - [`DirectedGraph`] for directed, weighted edges,
+//! - [`SignedDirectedGraph`] for directed edges with possibly negative weights
+//!   (only when [`FileInputOptions::allow_negative_weights`] is set),
 //! - [`UndirectedGraph`] for undirected, weighted edges,
-//! - [`TwoDimensionalCoordinateGraph`] for two-dimensional coordinate edges.
+//! - [`TwoDimensionalCoordinateGraph`] for two-dimensional coordinate edges,
+//! - [`UnweightedGraph`] for `D`/`UN` input in which no edge line has a weight.
 //!
-//! The public entrypoint is [`retrieve_graph_data_from_file`].
+//! The public entrypoints are [`retrieve_graph_data_from_file`] and
+//! [`retrieve_graph_data_from_file_with_options`]. The same text can be read
+//! from standard input with [`retrieve_graph_data_from_stdin`], or from any
+//! reader with [`retrieve_graph_data_from_reader`].
 //!
 //! # Input Format
 //!
-//! The parser infers graph type from the first line and expects all following non-empty lines to
-//! use the same graph encoding. In the current implementation and test files, the first line is a
+//! The parser infers graph type from the first non-blank line (after comments are stripped) and
+//! expects all following non-empty lines to use the same graph encoding. That header line is a
 //! graph-type marker and must be exactly one of:
 //! - `D` for directed graph input,
 //! - `UN` for undirected graph input,
 //! - `TD` for two-dimensional graph input.
 //!
 //! Important current behavior:
-//! - The first line is used only for graph-type detection.
-//! - Graph edges are built from lines after the first line.
-//! - Therefore, the first line is a header marker and is not parsed as an edge.
+//! - The header line is used only for graph-type detection.
+//! - Graph edges are built from lines after the header line.
+//! - Therefore, the header line is a marker and is not parsed as an edge.
 //!
 //! ## Supported edge patterns
 //!
 //! ```text
 //! Header line:  D | UN | TD
 //! Directed:     <from>-><to>:<weight>         (example: A->B:7)
+//! Signed:       <from>-><to>:[-]<weight>      (example: A->B:-3, negative weights opt-in)
 //! Undirected:   <from>-<to>:<weight>          (example: A-B:7)
+//! Two-way:      <from><-><to>:<w>[|<w>]       (example: A<->B:7|9, UN only)
+//! Edge kind:    <edge>@<kind>                 (example: A->B:7@ferry, D and UN only)
+//! Edge layer:   <edge>[@<kind>]#<layer>       (example: A->B:7#train, D and UN only)
+//! No weight:    <from>-><to> | <from>-<to>    (example: A->C, see below)
+//! Quoted IDs:   "<name>"->"<name>":<weight>   (example: "New York"-"St-Louis":9, D and UN only)
 //! 2D edge line: <from>:x,y=><to>:x,y          (example: A:0,0=>B:4,2)
+//! Comment:      # <text> | <line> # <text>    (example: A->B:7 # main road)
 //! ```
 //!
 //! # Validation and consistency rules
 //!
 //! - The file must contain at least one line.
-//! - The first line must identify a supported graph type using an exact header (`D`, `UN`, `TD`).
+//! - The first non-blank line must identify a supported graph type using an exact header (`D`,
+//!   `UN`, `TD`), and at least one edge line must follow it.
 //! - Every remaining parsed line must match the syntax expected by the detected graph type.
 //! - Whitespace-only lines are ignored.
-//! - A file can produce exactly one graph variant.
-//! - Duplicate edges are ignored during insertion.
-//! - The first line is consumed for type detection and is not inserted as an edge.
+//! - Unquoted node IDs are letters and digits. Quoted IDs may also contain
+//!   spaces, hyphens and other punctuation except `"`, `#`, `@` and `:`; the
+//!   quotes are stripped, and only a separator outside quotes splits a line.
+//! - `#` starts a comment at the beginning of a line or after whitespace; the
+//!   rest of the line is ignored. Comment lines may precede the header
+//!   (`D # road network` is fine too), and a file with nothing but comments
+//!   and blank lines is rejected. A `#` glued to the edge (`A->B:7#train`) is a
+//!   layer suffix, not a comment.
+//! - A two-way `UN` line `A<->B:7|9` costs 7 from `A` to `B` and 9 back; with
+//!   a single weight (`A<->B:7`) both directions cost the same.
+//! - A file can produce exactly one graph variant. A `D` file rejects lines
+//!   with the undirected `-`/`<->` separator and a `UN` file rejects `->`,
+//!   with an error naming the line.
+//! - Duplicate edges are ignored during insertion; each ignored line is
+//!   reported in [`FileInputGraphResult::warnings`] and logged at `warn` level.
+//! - A `#<layer>` suffix assigns the edge to a layer (see
+//!   [`Graph::edge_layer`]); it always comes last.
+//! - If no `D`/`UN` edge line carries a weight (and none has an `@<kind>` or
+//!   `#<layer>` suffix), the file produces an [`UnweightedGraph`]. This does not apply
+//!   with [`MissingWeightPolicy::DefaultToOne`] or
+//!   [`FileInputOptions::allow_negative_weights`].
+//! - Otherwise `D`/`UN` lines without a weight are rejected unless
+//!   [`FileInputOptions::missing_weight`] is [`MissingWeightPolicy::DefaultToOne`],
+//!   which assigns weight `1` and makes the graph report `is_weighted() == false`.
+//! - The header line is consumed for type detection and is not inserted as an edge.
+//! - By default the first invalid edge line aborts parsing. With
+//!   [`FileInputOptions::lenient`], invalid lines are skipped and reported in
+//!   [`FileInputGraphResult::skipped_lines`] instead.
 //! - Two-dimensional file input is parsed and inserted into
 //!   [`TwoDimensionalCoordinateGraph`] in [`generate_graph_from_file`].
 //! - Two-dimensional parsing currently uses
@@ -88,22 +127,29 @@
 //! The fixture example is marked as `no_run` because it depends on repository-local files.
 
 use std::{
+    borrow::Cow,
     error::Error,
-    fmt, fs, io,
+    fmt, fs,
+    io::{self, Read},
+    num::NonZeroUsize,
     path::Path,
     str::{FromStr, Lines},
 };
 
+use log::{info, warn};
 use regex::Regex;
 use strum_macros::EnumString;
 
 use crate::{
     error::parse_error::ParseError,
     graphs::{
-        directed::DirectedGraph, graph::Graph,
+        directed::{DirectedGraph, SignedDirectedGraph},
+        graph::{Graph, GraphWeight},
         two_dimensional_coordinate_graph::TwoDimensionalCoordinateGraph,
         undirected::UndirectedGraph,
+        unweighted::UnweightedGraph,
     },
+    instrumentation::{edge_count, operation_span},
     nodes::{
         default_node::DefaultNode, node_types::NodeType, two_dimensional_node::TwoDimensionalNode,
     },
@@ -156,12 +202,240 @@ enum FoundGraphType {
 struct LineSyntaxRegexes {
     /// Regex for directed lines (`A->B:7`).
     directed: Regex,
+    /// Regex for directed lines with an optionally negative weight (`A->B:-3`).
+    signed_directed: Regex,
     /// Regex for undirected lines (`A-B:7`).
     undirected: Regex,
+    /// Regex for undirected lines with a weight per direction (`A<->B:7|9`),
+    /// capturing both endpoints, both weights, and the kind.
+    two_way_undirected: Regex,
     /// Regex for two-dimensional lines (`A:0,0=>B:4,2`).
     two_dimensional: Regex,
 }
 
+/// Options controlling how graph files are parsed.
+///
+/// The [`Default`] value matches the behavior of
+/// [`retrieve_graph_data_from_file`].
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::data_input::file_input::{FileInputOptions, MissingWeightPolicy};
+///
+/// let options = FileInputOptions {
+///     allow_negative_weights: true,
+///     ..FileInputOptions::default()
+/// };
+/// assert!(!FileInputOptions::default().allow_negative_weights);
+/// assert_eq!(FileInputOptions::default().missing_weight, MissingWeightPolicy::Reject);
+/// assert!(options.allow_negative_weights);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FileInputOptions {
+    /// Accept `A->B:-3` in directed files and parse them into a
+    /// [`SignedDirectedGraph`] instead of a [`DirectedGraph`].
+    ///
+    /// Undirected and two-dimensional files are unaffected. Dijkstra rejects
+    /// graphs with negative edges, so this is meant for Bellman-Ford.
+    pub allow_negative_weights: bool,
+    /// What to do with `D`/`UN` edge lines that have no weight (`A->C`).
+    pub missing_weight: MissingWeightPolicy,
+    /// Log a progress line at `info` level every this many edge lines.
+    ///
+    /// Each message reports the processed line count and the percentage of
+    /// the file. `None` (the default) disables progress reporting.
+    pub progress_interval: Option<NonZeroUsize>,
+    /// Stop reading edge lines once the graph holds at least this many nodes.
+    ///
+    /// The check runs before each line, so the line that reaches the limit is
+    /// finished and may add one node past it. Every later line is skipped,
+    /// including edges between nodes that already exist, so the result is a
+    /// consistent prefix of the file. `None` (the default) reads everything.
+    pub node_limit: Option<NonZeroUsize>,
+    /// Skip invalid edge lines instead of failing on the first one.
+    ///
+    /// The graph is built from the valid lines. Skipped lines are reported in
+    /// [`FileInputGraphResult::skipped_lines`] (at most
+    /// [`MAX_COLLECTED_LINE_ERRORS`] of them) and counted in
+    /// [`FileInputGraphResult::skipped_line_count`]. Problems that are not tied
+    /// to one line, such as an empty file or an unknown header, still fail
+    /// the load.
+    pub lenient: bool,
+}
+
+/// Maximum number of line errors kept by lenient parsing.
+///
+/// Later invalid lines are still skipped and counted, but their errors are
+/// dropped, so a badly broken file cannot grow memory without bound.
+pub const MAX_COLLECTED_LINE_ERRORS: usize = 100;
+
+/// Decides what happens to an edge line that failed to parse.
+///
+/// In strict mode the error aborts parsing. In lenient mode the line is
+/// skipped and its error kept, up to [`MAX_COLLECTED_LINE_ERRORS`].
+/// Lines skipped because they repeat an earlier edge are recorded as
+/// warnings in both modes.
+struct SkippedLineCollector {
+    /// Whether invalid lines are skipped instead of aborting.
+    lenient: bool,
+    /// Errors of the first skipped lines.
+    errors: Vec<ParseError>,
+    /// Number of skipped lines, including those beyond the cap.
+    count: usize,
+    /// One message per ignored duplicate edge line.
+    warnings: Vec<String>,
+}
+
+impl SkippedLineCollector {
+    /// Creates an empty collector.
+    fn new(lenient: bool) -> Self {
+        Self {
+            lenient,
+            errors: Vec::new(),
+            count: 0,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Records that the edge on `line_number` repeats an earlier edge and was
+    /// ignored, and logs the same message at `warn` level.
+    fn record_duplicate(&mut self, line_number: usize, raw_line: &str) {
+        let message = format!(
+            "Duplicate edge at line {} ('{}') was ignored",
+            line_number,
+            raw_line.trim()
+        );
+        warn!("{}", message);
+        self.warnings.push(message);
+    }
+
+    /// Returns `err` in strict mode; records it and returns `Ok` in lenient mode.
+    fn skip_or_fail(&mut self, err: ParseError) -> Result<(), ParseError> {
+        if !self.lenient {
+            return Err(err);
+        }
+        self.count += 1;
+        if self.errors.len() < MAX_COLLECTED_LINE_ERRORS {
+            self.errors.push(err);
+        }
+        Ok(())
+    }
+}
+
+/// Logs parsing progress every `interval` edge lines.
+///
+/// When disabled, [`ProgressReporter::line_processed`] is a single `None`
+/// check, so the fast path stays unaffected.
+struct ProgressReporter {
+    /// Reporting interval in edge lines, if enabled.
+    interval: Option<NonZeroUsize>,
+    /// Number of edge lines after the header, counted only when enabled.
+    total_lines: usize,
+}
+
+impl ProgressReporter {
+    /// Creates a reporter for `lines`, the full file content.
+    fn new(interval: Option<NonZeroUsize>, lines: &str) -> Self {
+        // Counting costs an extra pass, so only pay for it when reporting is on.
+        let total_lines = match interval {
+            Some(_) => lines.lines().count().saturating_sub(1),
+            None => 0,
+        };
+        Self {
+            interval,
+            total_lines,
+        }
+    }
+
+    /// Records that the parser reached edge line `processed` (1-based,
+    /// blank lines included).
+    fn line_processed(&self, processed: usize) {
+        let Some(interval) = self.interval else {
+            return;
+        };
+        if !processed.is_multiple_of(interval.get()) {
+            return;
+        }
+        let percentage = if self.total_lines == 0 {
+            100.0
+        } else {
+            processed as f64 * 100.0 / self.total_lines as f64
+        };
+        info!(
+            "Parsing edge line {} of {} ({:.1}%)",
+            processed, self.total_lines, percentage
+        );
+    }
+}
+
+/// Returns `true` once `graph` holds `node_limit` nodes and parsing should stop.
+///
+/// Logs the line where loading stopped so a truncated graph is never silent.
+fn node_limit_reached<G: Graph>(
+    graph: &G,
+    node_limit: Option<NonZeroUsize>,
+    line_number: usize,
+) -> bool {
+    let Some(limit) = node_limit else {
+        return false;
+    };
+    if graph.get_all_nodes().len() < limit.get() {
+        return false;
+    }
+    info!(
+        "Node limit of {} reached; skipping edge lines from line {} on",
+        limit, line_number
+    );
+    true
+}
+
+/// Policy for edge lines without an explicit weight, such as `A->C`.
+///
+/// # Variants
+///
+/// - [`MissingWeightPolicy::Reject`]: treat the line as a syntax error (default).
+/// - [`MissingWeightPolicy::DefaultToOne`]: insert the edge with weight `1`.
+///   The resulting graph reports [`Graph::is_weighted`] as `false`.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::data_input::file_input::MissingWeightPolicy;
+///
+/// assert_eq!(
+///     MissingWeightPolicy::from_flag_value("default"),
+///     Some(MissingWeightPolicy::DefaultToOne)
+/// );
+/// assert_eq!(MissingWeightPolicy::from_flag_value("reject"), Some(MissingWeightPolicy::Reject));
+/// assert_eq!(MissingWeightPolicy::from_flag_value("ignore"), None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingWeightPolicy {
+    /// Reject files that contain an edge without a weight.
+    #[default]
+    Reject,
+    /// Give edges without a weight the weight `1`.
+    DefaultToOne,
+}
+
+impl MissingWeightPolicy {
+    /// Parses the value of the `--missing-weight` CLI flag.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(DefaultToOne)` for `default`.
+    /// - `Some(Reject)` for `reject`.
+    /// - `None` for any other value.
+    pub fn from_flag_value(value: &str) -> Option<Self> {
+        match value {
+            "default" => Some(Self::DefaultToOne),
+            "reject" => Some(Self::Reject),
+            _ => None,
+        }
+    }
+}
+
 /// Result container for graph data loaded from file input.
 ///
 /// # Invariants
@@ -173,10 +447,29 @@ struct LineSyntaxRegexes {
 pub struct FileInputGraphResult {
     /// Parsed directed graph, if directed input was detected.
     pub directed_graph: Option<DirectedGraph>,
+    /// Parsed signed directed graph, if directed input was detected while
+    /// [`FileInputOptions::allow_negative_weights`] was set.
+    pub signed_directed_graph: Option<SignedDirectedGraph>,
     /// Parsed undirected graph, if undirected input was detected.
     pub undirected_graph: Option<UndirectedGraph>,
     /// Parsed two-dimensional graph, if two-dimensional input was detected.
     pub two_dimensional_graph: Option<TwoDimensionalCoordinateGraph>,
+    /// Parsed unweighted graph, if no `D`/`UN` edge line carried a weight.
+    pub unweighted_graph: Option<UnweightedGraph>,
+    /// Errors of lines skipped by lenient parsing, in file order.
+    ///
+    /// Always empty unless [`FileInputOptions::lenient`] was set. Holds at most
+    /// [`MAX_COLLECTED_LINE_ERRORS`] entries; each message names its line.
+    pub skipped_lines: Vec<ParseError>,
+    /// Total number of lines skipped by lenient parsing, including those whose
+    /// errors were dropped because of [`MAX_COLLECTED_LINE_ERRORS`].
+    pub skipped_line_count: usize,
+    /// Edge lines ignored because they repeat an earlier edge, in file order.
+    ///
+    /// Each message names the line, e.g. `Duplicate edge at line 4 ('A->B:9')
+    /// was ignored`, and is also logged at `warn` level. Only the first
+    /// occurrence of an edge (and its weight) is kept.
+    pub warnings: Vec<String>,
 }
 
 impl FileInputGraphResult {
@@ -231,10 +524,65 @@ impl FileInputGraphResult {
 
         Some(Self {
             directed_graph,
+            signed_directed_graph: None,
             undirected_graph,
             two_dimensional_graph,
+            unweighted_graph: None,
+            skipped_lines: Vec::new(),
+            skipped_line_count: 0,
+            warnings: Vec::new(),
         })
     }
+
+    /// Wraps a parsed [`SignedDirectedGraph`] as the only graph variant.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::data_input::file_input::FileInputGraphResult;
+    /// use shortest_path_finder::graphs::directed::SignedDirectedGraph;
+    ///
+    /// let result = FileInputGraphResult::from_signed_directed(SignedDirectedGraph::new_signed(vec![]));
+    /// assert!(result.signed_directed_graph.is_some());
+    /// assert!(result.directed_graph.is_none());
+    /// ```
+    pub fn from_signed_directed(graph: SignedDirectedGraph) -> Self {
+        Self {
+            directed_graph: None,
+            signed_directed_graph: Some(graph),
+            undirected_graph: None,
+            two_dimensional_graph: None,
+            unweighted_graph: None,
+            skipped_lines: Vec::new(),
+            skipped_line_count: 0,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Wraps a parsed [`UnweightedGraph`] as the only graph variant.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::data_input::file_input::FileInputGraphResult;
+    /// use shortest_path_finder::graphs::unweighted::UnweightedGraph;
+    ///
+    /// let result = FileInputGraphResult::from_unweighted(UnweightedGraph::new_directed(vec![]));
+    /// assert!(result.unweighted_graph.is_some());
+    /// assert!(result.directed_graph.is_none());
+    /// ```
+    pub fn from_unweighted(graph: UnweightedGraph) -> Self {
+        Self {
+            directed_graph: None,
+            signed_directed_graph: None,
+            undirected_graph: None,
+            two_dimensional_graph: None,
+            unweighted_graph: Some(graph),
+            skipped_lines: Vec::new(),
+            skipped_line_count: 0,
+            warnings: Vec::new(),
+        }
+    }
 }
 
 /// Top-level error type for file-input graph loading.
@@ -388,6 +736,65 @@ impl Error for FileInputError {
 /// ```
 pub fn retrieve_graph_data_from_file(
     file_path: &str,
+) -> Result<FileInputGraphResult, FileInputError> {
+    retrieve_graph_data_from_file_with_options(file_path, &FileInputOptions::default())
+}
+
+/// Reads a graph definition file using non-default [`FileInputOptions`].
+///
+/// # Parameters
+///
+/// - `file_path`: Path to the graph file.
+/// - `options`: Parser options, see [`FileInputOptions`].
+///
+/// # Returns
+///
+/// Same contract as [`retrieve_graph_data_from_file`], except that directed
+/// files are returned in
+/// [`FileInputGraphResult::signed_directed_graph`] when
+/// `options.allow_negative_weights` is set.
+///
+/// # Errors
+///
+/// Same as [`retrieve_graph_data_from_file`]. Without
+/// `allow_negative_weights`, a line such as `A->B:-3` is a syntax error.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::data_input::file_input::{
+///     retrieve_graph_data_from_file_with_options,
+///     FileInputOptions,
+/// };
+/// use shortest_path_finder::graphs::graph::Graph;
+/// use std::{
+///     fs,
+///     time::{SystemTime, UNIX_EPOCH},
+/// };
+///
+/// let unique_id = SystemTime::now()
+///     .duration_since(UNIX_EPOCH)
+///     .expect("system clock should be after UNIX epoch")
+///     .as_nanos();
+/// let path = std::env::temp_dir().join(format!("pathfinder-doc-signed-{}.txt", unique_id));
+/// fs::write(&path, "D\nA->B:-3\nB->C:5\n").expect("temporary graph file should be writable");
+///
+/// let path_owned = path.to_string_lossy().into_owned();
+/// let options = FileInputOptions {
+///     allow_negative_weights: true,
+///     ..FileInputOptions::default()
+/// };
+/// let parsed = retrieve_graph_data_from_file_with_options(&path_owned, &options)
+///     .expect("negative weights should be accepted");
+/// let graph = parsed.signed_directed_graph.expect("signed graph should be present");
+/// let a = graph.get_node_by_id("A").unwrap();
+/// assert_eq!(graph.neighbors(a).next().unwrap().1, -3);
+///
+/// let _ = fs::remove_file(path);
+/// ```
+pub fn retrieve_graph_data_from_file_with_options(
+    file_path: &str,
+    options: &FileInputOptions,
 ) -> Result<FileInputGraphResult, FileInputError> {
     // Normalize user input into a path handle used by std::fs.
     let rel_path = Path::new(file_path);
@@ -398,24 +805,133 @@ pub fn retrieve_graph_data_from_file(
         source,
     })?;
 
-    let res = generate_graph_from_file(file_content).map_err(FileInputError::Parse)?;
+    load_graph_text(file_path, file_content, options)
+}
+
+/// Path value that selects standard input instead of a file, as in
+/// `--graph-file -`.
+pub const STDIN_PATH: &str = "-";
+
+/// Reads a graph definition from standard input until end of file.
+///
+/// The text uses the same format as graph files, so
+/// `cat graph.txt | pathfinder --graph-file - ...` behaves like
+/// `pathfinder --graph-file graph.txt ...`.
+///
+/// # Errors
+///
+/// Same as [`retrieve_graph_data_from_file`]; I/O errors report the path as
+/// [`STDIN_PATH`].
+pub fn retrieve_graph_data_from_stdin() -> Result<FileInputGraphResult, FileInputError> {
+    retrieve_graph_data_from_stdin_with_options(&FileInputOptions::default())
+}
+
+/// Reads a graph definition from standard input using non-default
+/// [`FileInputOptions`].
+///
+/// # Errors
+///
+/// Same as [`retrieve_graph_data_from_file_with_options`].
+pub fn retrieve_graph_data_from_stdin_with_options(
+    options: &FileInputOptions,
+) -> Result<FileInputGraphResult, FileInputError> {
+    retrieve_graph_data_from_reader(io::stdin().lock(), options)
+}
+
+/// Reads a graph definition from any reader, e.g. standard input or an
+/// in-memory buffer.
+///
+/// # Errors
+///
+/// Same as [`retrieve_graph_data_from_file_with_options`]; I/O errors report
+/// the path as [`STDIN_PATH`].
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::data_input::file_input::{
+///     retrieve_graph_data_from_reader,
+///     FileInputOptions,
+/// };
+///
+/// let input = "UN\nA-B:4\n".as_bytes();
+/// let parsed = retrieve_graph_data_from_reader(input, &FileInputOptions::default())
+///     .expect("graph text should parse");
+/// assert!(parsed.undirected_graph.is_some());
+/// ```
+pub fn retrieve_graph_data_from_reader(
+    mut reader: impl Read,
+    options: &FileInputOptions,
+) -> Result<FileInputGraphResult, FileInputError> {
+    let mut content = String::new();
+    reader
+        .read_to_string(&mut content)
+        .map_err(|source| FileInputError::Io {
+            path: STDIN_PATH.to_string(),
+            source,
+        })?;
+
+    load_graph_text(STDIN_PATH, content, options)
+}
+
+/// Parses already read graph text and records its size on the load span.
+///
+/// `source` is the file path (or [`STDIN_PATH`]) shown in the span.
+pub(crate) fn load_graph_text(
+    source: &str,
+    content: String,
+    options: &FileInputOptions,
+) -> Result<FileInputGraphResult, FileInputError> {
+    let mut span = operation_span!("file_input.load", path = source; nodes, edges);
+
+    let res = generate_graph_from_file(content, options).map_err(FileInputError::Parse)?;
+
+    let (nodes, edges) = if let Some(graph) = &res.directed_graph {
+        (graph.get_all_nodes().len(), edge_count(graph))
+    } else if let Some(graph) = &res.signed_directed_graph {
+        (graph.get_all_nodes().len(), edge_count(graph))
+    } else if let Some(graph) = &res.undirected_graph {
+        (graph.get_all_nodes().len(), edge_count(graph))
+    } else if let Some(graph) = &res.two_dimensional_graph {
+        (graph.get_all_nodes().len(), edge_count(graph))
+    } else if let Some(graph) = &res.unweighted_graph {
+        (graph.get_all_nodes().len(), edge_count(graph))
+    } else {
+        (0, 0)
+    };
+    span.record("nodes", nodes);
+    span.record("edges", edges);
 
     Ok(res)
 }
 
+/// Regex fragment for one `D`/`UN` node ID.
+///
+/// Plain IDs are letters and digits. A double-quoted ID (`"New York"`,
+/// `"St-Louis"`) may hold any other character except `"`, `#`, `@` and `:`,
+/// which delimit comments, layers, kinds and weights.
+const NODE_ID_PATTERN: &str = r##"(?:[A-Za-z0-9]+|"[^"#@:]+")"##;
+
 /// Compiles all regexes required for line-syntax validation.
 ///
 /// # Node ID constraint
 ///
-/// All three formats restrict node IDs to the character class `[A-Za-z0-9]+`.
-/// IDs containing other characters (e.g. `Station-42`, `node_1`) are **not**
-/// supported in file input and will fail validation. Keep node names to letters
-/// and digits only when using file-based graph input.
+/// Unquoted node IDs are restricted to the character class `[A-Za-z0-9]+`.
+/// In `D` and `UN` files, IDs with other characters (e.g. `St-Louis`,
+/// `New York`) must be double-quoted (see [`NODE_ID_PATTERN`]); the quotes
+/// are not part of the ID. Two-dimensional lines do not support quoted IDs,
+/// so keep `TD` node names to letters and digits only.
 ///
 /// # Regex patterns
 ///
-/// - Directed: `^[A-Za-z0-9]+->[A-Za-z0-9]+:[0-9]+$`
-/// - Undirected: `^[A-Za-z0-9]+-[A-Za-z0-9]+:[0-9]+$`
+/// `<id>` stands for [`NODE_ID_PATTERN`].
+///
+/// - Directed: `^<id>-><id>:[0-9]+(@[A-Za-z0-9_]+)?$`
+/// - Signed directed: `^<id>-><id>:-?[0-9]+(@[A-Za-z0-9_]+)?$`
+/// - Undirected: `^<id>-<id>:[0-9]+(@[A-Za-z0-9_]+)?$`
+/// - Two-way undirected: `^(<id>)<->(<id>):([0-9]+)(?:\|([0-9]+))?(?:@([A-Za-z0-9_]+))?$`
+///
+/// The optional `@<kind>` suffix tags the edge with a kind such as `ferry`.
 /// - Two-dimensional: `^[A-Za-z0-9]+:-?[0-9]+,-?[0-9]+=>[A-Za-z0-9]+:-?[0-9]+,-?[0-9]+$`
 ///
 /// # Errors
@@ -423,17 +939,26 @@ pub fn retrieve_graph_data_from_file(
 /// Returns [`ParseError::RegexCompilationFailed`] when any static regex pattern
 /// cannot be compiled.
 fn compile_line_syntax_regexes() -> Result<LineSyntaxRegexes, ParseError> {
-    let directed = Regex::new(r"^[A-Za-z0-9]+->[A-Za-z0-9]+:[0-9]+$")
+    let id = NODE_ID_PATTERN;
+    let directed = Regex::new(&format!(r"^{id}->{id}:[0-9]+(@[A-Za-z0-9_]+)?$"))
+        .map_err(|err| ParseError::RegexCompilationFailed(err.to_string()))?;
+    let signed_directed = Regex::new(&format!(r"^{id}->{id}:-?[0-9]+(@[A-Za-z0-9_]+)?$"))
         .map_err(|err| ParseError::RegexCompilationFailed(err.to_string()))?;
-    let undirected = Regex::new(r"^[A-Za-z0-9]+-[A-Za-z0-9]+:[0-9]+$")
+    let undirected = Regex::new(&format!(r"^{id}-{id}:[0-9]+(@[A-Za-z0-9_]+)?$"))
         .map_err(|err| ParseError::RegexCompilationFailed(err.to_string()))?;
+    let two_way_undirected = Regex::new(&format!(
+        r"^({id})<->({id}):([0-9]+)(?:\|([0-9]+))?(?:@([A-Za-z0-9_]+))?$"
+    ))
+    .map_err(|err| ParseError::RegexCompilationFailed(err.to_string()))?;
     let two_dimensional =
         Regex::new(r"^[A-Za-z0-9]+:-?[0-9]+,-?[0-9]+=>[A-Za-z0-9]+:-?[0-9]+,-?[0-9]+$")
             .map_err(|err| ParseError::RegexCompilationFailed(err.to_string()))?;
 
     Ok(LineSyntaxRegexes {
         directed,
+        signed_directed,
         undirected,
+        two_way_undirected,
         two_dimensional,
     })
 }
@@ -444,6 +969,7 @@ fn compile_line_syntax_regexes() -> Result<LineSyntaxRegexes, ParseError> {
 ///
 /// - `line`: Raw text line to validate.
 /// - `graph_type`: Detected graph kind for strict, graph-specific validation.
+/// - `signed_weights`: Accept a leading `-` on directed weights.
 /// - `regexes`: Precompiled regexes used for matching.
 ///
 /// # Returns
@@ -455,33 +981,258 @@ fn compile_line_syntax_regexes() -> Result<LineSyntaxRegexes, ParseError> {
 ///
 /// This function only checks lexical format for the currently selected graph
 /// type. Semantic validation is handled in higher-level parsing functions.
+/// Quoted node IDs are accepted as they are; the quotes are stripped during
+/// conversion.
 fn validate_line_syntax(
     line: &str,
     graph_type: &FoundGraphType,
+    signed_weights: bool,
     regexes: &LineSyntaxRegexes,
 ) -> bool {
     let line = line.trim();
     match graph_type {
+        FoundGraphType::D if signed_weights => regexes.signed_directed.is_match(line),
         FoundGraphType::D => regexes.directed.is_match(line),
         FoundGraphType::UN => regexes.undirected.is_match(line),
         FoundGraphType::TD => regexes.two_dimensional.is_match(line),
     }
 }
 
+/// Splits `line` at the first `separator` that is not inside a quoted node ID.
+///
+/// This keeps `"St-Louis"-"New York":4` from being split at the hyphen in
+/// `St-Louis`.
+///
+/// # Returns
+///
+/// `Some((before, after))` without the separator, or `None` if `line` has no
+/// top-level `separator`.
+pub(crate) fn split_once_unquoted<'a>(
+    line: &'a str,
+    separator: &str,
+) -> Option<(&'a str, &'a str)> {
+    let mut in_quotes = false;
+    for (index, character) in line.char_indices() {
+        if character == '"' {
+            in_quotes = !in_quotes;
+        } else if !in_quotes && line[index..].starts_with(separator) {
+            return Some((&line[..index], &line[index + separator.len()..]));
+        }
+    }
+    None
+}
+
+/// Trims `token` and removes the quotes around a quoted node ID.
+///
+/// Plain IDs are returned trimmed but otherwise unchanged, so `"A"` and `A`
+/// name the same node.
+pub(crate) fn unquote_node_id(token: &str) -> &str {
+    let token = token.trim();
+    token
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .unwrap_or(token)
+}
+
 /// Returns user-facing syntax guidance for each graph type.
 ///
 /// The returned text is designed to be appended to line-level parser errors so
 /// users get both "what failed" and "what shape was expected" in one message.
 fn expected_syntax_message(graph_type: &FoundGraphType) -> &'static str {
     match graph_type {
-        FoundGraphType::D => "Expected directed syntax '<from>-><to>:<weight>' (example: A->B:5).",
-        FoundGraphType::UN => "Expected undirected syntax '<from>-<to>:<weight>' (example: A-B:5).",
+        FoundGraphType::D => {
+            "Expected directed syntax '<from>-><to>:<weight>[@<kind>][#<layer>]' (example: A->B:5)."
+        }
+        FoundGraphType::UN => {
+            "Expected undirected syntax '<from>-<to>:<weight>[@<kind>][#<layer>]' (example: A-B:5) or '<from><-><to>:<weight>[|<reverse_weight>][@<kind>][#<layer>]' (example: A<->B:7|9)."
+        }
         FoundGraphType::TD => {
             "Expected two-dimensional syntax '<from>:x,y=><to>:x,y' (example: A:0,0=>B:4,2)."
         }
     }
 }
 
+/// Splits an optional `#<layer>` suffix off a trimmed `D`/`UN` edge line.
+///
+/// The layer comes last, after the weight and an optional `@<kind>` (for
+/// example `A->B:7@ferry#boat`), and is removed before any other parsing, so
+/// the remaining line follows the regular edge syntax. A `#` inside a quoted
+/// node ID is not taken as the layer separator; syntax validation rejects
+/// such an ID later.
+///
+/// # Returns
+///
+/// `Ok((edge_line, layer))` with `layer == None` when the line has no `#`.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidDataInput`] naming the line when the layer is
+/// empty or contains anything other than letters, digits and `_`.
+fn split_layer_suffix<'a>(
+    raw_line: &'a str,
+    line_number: usize,
+    graph_type: &FoundGraphType,
+) -> Result<(&'a str, Option<String>), ParseError> {
+    let line = raw_line.trim();
+    let Some((edge, layer)) = split_once_unquoted(line, "#") else {
+        return Ok((line, None));
+    };
+
+    let is_valid_layer =
+        !layer.is_empty() && layer.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !is_valid_layer {
+        return Err(ParseError::InvalidDataInput(format!(
+            "Invalid layer at line {} ('{}'). {}",
+            line_number,
+            raw_line,
+            expected_syntax_message(graph_type)
+        )));
+    }
+
+    Ok((edge, Some(layer.to_string())))
+}
+
+/// Rejects a `D`/`UN` edge line whose separator belongs to the other graph type.
+///
+/// Only the endpoint part before the first `:` is inspected, so a negative
+/// weight (`A->B:-3`) is not mistaken for an undirected `-`. `<->` counts as
+/// undirected. Lines without any separator are left to syntax validation.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidDataInput`] naming the line and both
+/// separators when, for example, `B-C:3` appears in a `D` file or `A->B:7`
+/// in a `UN` file.
+fn check_edge_separator(
+    line: &str,
+    raw_line: &str,
+    line_number: usize,
+    graph_type: &FoundGraphType,
+) -> Result<(), ParseError> {
+    let endpoints = line.split(':').next().unwrap_or_default();
+    let line_is_directed = if split_once_unquoted(endpoints, "<->").is_some() {
+        false
+    } else if split_once_unquoted(endpoints, "->").is_some() {
+        true
+    } else if split_once_unquoted(endpoints, "-").is_some() {
+        false
+    } else {
+        return Ok(());
+    };
+
+    let (file_is_directed, expected, header) = match graph_type {
+        FoundGraphType::D => (true, "'->'", "D"),
+        FoundGraphType::UN => (false, "'-' or '<->'", "UN"),
+        FoundGraphType::TD => return Ok(()),
+    };
+    if line_is_directed == file_is_directed {
+        return Ok(());
+    }
+
+    Err(ParseError::InvalidDataInput(format!(
+        "Mixed edge syntax at line {} ('{}'): the line uses {} syntax, but the '{}' header declares {} edges. Every edge line must use {}.",
+        line_number,
+        raw_line,
+        if line_is_directed {
+            "directed '->'"
+        } else {
+            "undirected '-'"
+        },
+        header,
+        if file_is_directed {
+            "directed"
+        } else {
+            "undirected"
+        },
+        expected
+    )))
+}
+
+/// Applies `policy` to a `D`/`UN` edge line that may lack a weight.
+///
+/// A line lacks a weight when the part before an optional `@<kind>` suffix
+/// has no `:` (for example `A->C` or `A->C@ferry`).
+///
+/// # Returns
+///
+/// - `Ok((line, false))` when the line already has a weight (or is not a
+///   weight-less edge line at all; syntax validation handles that later).
+/// - `Ok((line_with_weight, true))` when the weight was filled in with `1`.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidDataInput`] naming the line when the weight is
+/// missing and `policy` is [`MissingWeightPolicy::Reject`].
+fn apply_missing_weight_policy<'a>(
+    line: &'a str,
+    line_number: usize,
+    graph_type: &FoundGraphType,
+    policy: MissingWeightPolicy,
+) -> Result<(Cow<'a, str>, bool), ParseError> {
+    // A quoted ID with `@` must not be mistaken for a kind suffix.
+    let (edge, kind) = match split_once_unquoted(line, "@") {
+        Some((edge, kind)) => (edge, Some(kind)),
+        None => (line, None),
+    };
+    if edge.contains(':') {
+        return Ok((Cow::Borrowed(line), false));
+    }
+
+    match policy {
+        MissingWeightPolicy::Reject => Err(ParseError::InvalidDataInput(format!(
+            "Missing weight at line {} ('{}'). {} Use the 'default' missing-weight policy to assign weight 1.",
+            line_number,
+            line,
+            expected_syntax_message(graph_type)
+        ))),
+        MissingWeightPolicy::DefaultToOne => {
+            let filled = match kind {
+                Some(kind) => format!("{}:1@{}", edge, kind),
+                None => format!("{}:1", edge),
+            };
+            Ok((Cow::Owned(filled), true))
+        }
+    }
+}
+
+/// Splits a one-dimensional edge line into its nodes, weight token and kind.
+///
+/// The line is split at the top-level `separator` (`->` or `-`), so
+/// separators inside quoted IDs (`"St-Louis"`) are not mistaken for it. The
+/// right side is then split at `:` into destination and weight, and an
+/// optional `@<kind>` suffix is split off the weight. Quotes around node IDs
+/// are stripped.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidLineSyntax`] when a separator is missing or
+/// repeated.
+fn split_one_dimensional_line<'a>(
+    line: &'a str,
+    separator: &str,
+) -> Result<(DefaultNode, DefaultNode, &'a str, Option<String>), ParseError> {
+    let Some((from_token, rest)) = split_once_unquoted(line.trim(), separator) else {
+        return Err(ParseError::InvalidLineSyntax);
+    };
+    if split_once_unquoted(rest, separator).is_some() {
+        return Err(ParseError::InvalidLineSyntax);
+    }
+
+    // Quoted IDs cannot contain `:`, so the first colon ends the ID.
+    let second_split_results: Vec<&str> = rest.trim().split(':').collect();
+    if second_split_results.len() != 2 {
+        return Err(ParseError::InvalidLineSyntax);
+    }
+
+    let first_node = DefaultNode::new(unquote_node_id(from_token).to_string());
+    let second_node = DefaultNode::new(unquote_node_id(second_split_results[0]).to_string());
+    let (weight_token, kind) = match second_split_results[1].split_once('@') {
+        Some((weight_token, kind)) => (weight_token, Some(kind.to_string())),
+        None => (second_split_results[1], None),
+    };
+    Ok((first_node, second_node, weight_token, kind))
+}
+
 /// Converts one validated edge line into typed node/weight data.
 ///
 /// # Parameters
@@ -491,10 +1242,11 @@ fn expected_syntax_message(graph_type: &FoundGraphType) -> &'static str {
 ///
 /// # Returns
 ///
-/// `Ok((from, to, weight))` where:
+/// `Ok((from, to, weight, kind))` where:
 /// - `from` and `to` are wrapped in [`NodeType`],
 /// - `weight` is wrapped in [`WeightType`],
-/// - and `weight` is [`WeightType::NotNecessary`] for two-dimensional parsing.
+/// - `weight` is [`WeightType::NotNecessary`] for two-dimensional parsing,
+/// - and `kind` is the optional `@<kind>` suffix (always `None` for `TD`).
 ///
 /// # Errors
 ///
@@ -508,19 +1260,23 @@ fn expected_syntax_message(graph_type: &FoundGraphType) -> &'static str {
 /// ```text
 /// Directed edge line:   A->B:12
 /// Undirected edge line: A-B:12
+/// Quoted node IDs:      "St-Louis"->"New York":12
+/// Edge with a kind:     A->B:12@highway
 /// 2D edge line:         A:0,0=>B:4,2
 /// ```
 ///
 /// # Parsing strategy
 ///
-/// - For one-dimensional graph types (`D`, `UN`): split line by edge separator,
-///   then split the right side by `:` to obtain destination and integer weight.
+/// - For one-dimensional graph types (`D`, `UN`): split line by the edge
+///   separator outside quoted IDs, then split the right side by `:` to obtain
+///   destination and integer weight, and finally split an optional `@<kind>`
+///   suffix off the weight. Quotes around node IDs are stripped.
 /// - For two-dimensional graph type (`TD`): split the line into two serialized
 ///   coordinate nodes using `=>` and parse each node with [`TwoDimensionalNode::from_str`].
 fn convert_line_to_graph_data(
     line: &str,
     detected_graph_type: &FoundGraphType,
-) -> Result<(NodeType, NodeType, WeightType), ParseError> {
+) -> Result<(NodeType, NodeType, WeightType, Option<String>), ParseError> {
     match detected_graph_type {
         FoundGraphType::UN | FoundGraphType::D => {
             // One-dimensional formats differ only by separator; downstream extraction is shared.
@@ -530,31 +1286,18 @@ fn convert_line_to_graph_data(
                 _ => return Err(ParseError::InvalidGraphType),
             };
 
-            // Split into `<from>` and `<to>:<weight>`.
-            // Validation runs before this conversion, so the separator split is deterministic.
-            let first_split_results: Vec<&str> = line.trim().split(separator).collect();
-            if first_split_results.len() != 2 {
-                return Err(ParseError::InvalidLineSyntax);
-            }
-
-            // Split destination and weight (`<to>:<weight>`).
-            let second_split_results: Vec<&str> =
-                first_split_results[1].trim().split(':').collect();
-            if second_split_results.len() != 2 {
-                return Err(ParseError::InvalidLineSyntax);
-            }
-
-            // Build strongly typed node and weight values used by graph insertions.
-            let first_node = DefaultNode::new(first_split_results[0].to_string());
-            let second_node = DefaultNode::new(second_split_results[0].to_string());
-            let weight: u16 = match second_split_results[1].parse() {
-                Ok(w) => w,
-                Err(_) => return Err(ParseError::InvalidWeightInteger),
-            };
+            let (first_node, second_node, weight_token, kind) =
+                split_one_dimensional_line(line, separator)?;
+            let weight = WeightType::U16(
+                weight_token
+                    .parse()
+                    .map_err(|_| ParseError::InvalidWeightInteger)?,
+            );
             Ok((
                 NodeType::DefaultNode(first_node),
                 NodeType::DefaultNode(second_node),
-                WeightType::U16(weight),
+                weight,
+                kind,
             ))
         }
         FoundGraphType::TD => {
@@ -574,6 +1317,7 @@ fn convert_line_to_graph_data(
                 NodeType::TwoDimensionalNode(first_node),
                 NodeType::TwoDimensionalNode(second_node),
                 WeightType::NotNecessary,
+                None,
             ))
         }
     }
@@ -584,6 +1328,7 @@ fn convert_line_to_graph_data(
 /// # Parameters
 ///
 /// - `first_line`: First non-empty line from the input file.
+/// - `line_number`: 1-based file line number of `first_line`.
 ///
 /// # Returns
 ///
@@ -598,10 +1343,13 @@ fn convert_line_to_graph_data(
 /// # Errors
 ///
 /// Returns [`ParseError::InvalidDataInput`] if the line is not exactly `D`, `UN`, or `TD`.
-fn determine_graph_from_first_line(first_line: &str) -> Result<FoundGraphType, ParseError> {
+fn determine_graph_from_first_line(
+    first_line: &str,
+    line_number: usize,
+) -> Result<FoundGraphType, ParseError> {
     let header = first_line.trim();
 
-    if header.eq_ignore_ascii_case(&DirectedGraph::abbreviation()) {
+    if header.eq_ignore_ascii_case(&<DirectedGraph>::abbreviation()) {
         Ok(FoundGraphType::D)
     } else if header.eq_ignore_ascii_case(&UndirectedGraph::abbreviation()) {
         Ok(FoundGraphType::UN)
@@ -609,28 +1357,71 @@ fn determine_graph_from_first_line(first_line: &str) -> Result<FoundGraphType, P
         Ok(FoundGraphType::TD)
     } else {
         Err(ParseError::InvalidDataInput(format!(
-            "Invalid graph header '{}'. Expected exactly one of: D, UN, TD.",
-            header
+            "Invalid graph header at line {} ('{}'). Expected exactly one of: D, UN, TD.",
+            line_number, header
         )))
     }
 }
 
+/// Removes `#` comments from every line of `text`.
+///
+/// A comment starts at a `#` at the beginning of a line or after whitespace
+/// and runs to the end of the line, so `# note` and `A->B:7 # main road` lose
+/// their comment. A `#` directly after other text is kept, because it starts
+/// a `#<layer>` suffix (`A->B:7#train`). Lines are never removed, so line
+/// numbers in error messages still match the file.
+///
+/// # Returns
+///
+/// `text` itself when it contains no `#`, otherwise the stripped copy.
+fn strip_comments(text: &str) -> Cow<'_, str> {
+    if !text.contains('#') {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(
+        text.lines()
+            .map(strip_comment)
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+/// Cuts the comment off one line; see [`strip_comments`].
+fn strip_comment(line: &str) -> &str {
+    let mut after_whitespace = true;
+    for (index, character) in line.char_indices() {
+        if character == '#' && after_whitespace {
+            return &line[..index];
+        }
+        after_whitespace = character.is_whitespace();
+    }
+    line
+}
+
 /// Builds a graph result from raw file text.
 ///
 /// # Parameters
 ///
 /// - `lines`: Full file content as one string.
+/// - `options`: Parser options; `allow_negative_weights` switches directed
+///   input to [`SignedDirectedGraph`].
 ///
 /// # Behavior
 ///
-/// - Detects graph type from the first line.
-/// - Parses all remaining non-empty lines as edges of that same graph type.
+/// - Strips `#` comments (see [`strip_comments`]) and rejects files that
+///   consist only of comments and blank lines.
+/// - Detects graph type from the first non-blank line, so comments may
+///   precede the header, and rejects files without edge lines after it.
+/// - Parses all remaining non-empty lines as edges of that same graph type;
+///   a `D`/`UN` line using the other type's separator is rejected with an
+///   error naming the line (see [`check_edge_separator`]).
 /// - Trims surrounding whitespace before per-line validation/parsing.
 /// - Inserts missing nodes before edge insertion.
-/// - Skips duplicate edges.
+/// - Skips duplicate edges and reports each one in
+///   [`FileInputGraphResult::warnings`].
 /// - Returns an error for invalid syntax or incompatible parsed node/weight variants.
 ///
-/// Note: the first line is not inserted as an edge in the resulting graph.
+/// Note: the header line is not inserted as an edge in the resulting graph.
 ///
 /// # Returns
 ///
@@ -640,25 +1431,91 @@ fn determine_graph_from_first_line(first_line: &str) -> Result<FoundGraphType, P
 /// # Important
 ///
 /// Two-dimensional graph parsing is supported in this function.
-fn generate_graph_from_file(lines: String) -> Result<FileInputGraphResult, ParseError> {
+pub(crate) fn generate_graph_from_file(
+    lines: String,
+    options: &FileInputOptions,
+) -> Result<FileInputGraphResult, ParseError> {
+    let lines = strip_comments(&lines);
+    let progress = ProgressReporter::new(options.progress_interval, &lines);
+    let mut skipped_lines = SkippedLineCollector::new(options.lenient);
     let mut lines_iter = lines.lines();
 
-    // The first line is a mandatory graph-type header (`D`, `UN`, or `TD`).
-    let first_line = match lines_iter.next() {
-        Some(line) => line,
-        None => {
-            return Err(ParseError::InvalidDataInput(
-                "The specified file is empty!".to_string(),
-            ));
+    // The first non-blank line is the mandatory graph-type header (`D`, `UN`,
+    // or `TD`); comments and blank lines may come before it.
+    let mut header_line_number = 0;
+    let first_line = loop {
+        header_line_number += 1;
+        match lines_iter.next() {
+            Some(line) if line.trim().is_empty() => {}
+            Some(line) => break line,
+            None if header_line_number == 1 => {
+                return Err(ParseError::InvalidDataInput(
+                    "The specified file is empty!".to_string(),
+                ));
+            }
+            None => {
+                return Err(ParseError::InvalidDataInput(
+                    "The specified file has no header and no edges, only comments or blank lines!"
+                        .to_string(),
+                ));
+            }
         }
     };
+    if lines_iter.clone().all(|line| line.trim().is_empty()) {
+        return Err(ParseError::InvalidDataInput(format!(
+            "The specified file has no edges after the graph header at line {}!",
+            header_line_number
+        )));
+    }
 
     // Parse the remaining lines with the graph-specific builder selected by the header.
-    let detected_graph_type = determine_graph_from_first_line(first_line)?;
-
-    match detected_graph_type {
+    let detected_graph_type = determine_graph_from_first_line(first_line, header_line_number)?;
+
+    // Weightless files get a graph without weights instead of a rejection,
+    // unless the caller asked for weights (default `1` or signed parsing).
+    let build_unweighted = options.missing_weight == MissingWeightPolicy::Reject
+        && !options.allow_negative_weights
+        && detected_graph_type != FoundGraphType::TD
+        && is_unweighted_input(lines_iter.clone(), &detected_graph_type)?;
+
+    let mut result = match detected_graph_type {
+        FoundGraphType::D | FoundGraphType::UN if build_unweighted => {
+            let unweighted_graph = generate_unweighted_graph_from_file(
+                lines_iter,
+                header_line_number,
+                detected_graph_type == FoundGraphType::D,
+                options.node_limit,
+                &progress,
+                &mut skipped_lines,
+            );
+
+            Ok(FileInputGraphResult::from_unweighted(unweighted_graph))
+        }
+        FoundGraphType::D if options.allow_negative_weights => {
+            let signed_directed_graph = generate_directed_graph_from_file::<i32>(
+                lines_iter,
+                header_line_number,
+                true,
+                options.missing_weight,
+                options.node_limit,
+                &progress,
+                &mut skipped_lines,
+            )?;
+
+            Ok(FileInputGraphResult::from_signed_directed(
+                signed_directed_graph,
+            ))
+        }
         FoundGraphType::D => {
-            let directed_graph = generate_directed_graph_from_file(lines_iter)?;
+            let directed_graph = generate_directed_graph_from_file::<u16>(
+                lines_iter,
+                header_line_number,
+                false,
+                options.missing_weight,
+                options.node_limit,
+                &progress,
+                &mut skipped_lines,
+            )?;
 
             FileInputGraphResult::new(Some(directed_graph), None, None).ok_or(
                 ParseError::InvalidDataInput(
@@ -668,7 +1525,14 @@ fn generate_graph_from_file(lines: String) -> Result<FileInputGraphResult, Parse
             )
         }
         FoundGraphType::UN => {
-            let undirected_graph = generate_undirected_graph_from_file(lines_iter)?;
+            let undirected_graph = generate_undirected_graph_from_file(
+                lines_iter,
+                header_line_number,
+                options.missing_weight,
+                options.node_limit,
+                &progress,
+                &mut skipped_lines,
+            )?;
 
             FileInputGraphResult::new(None, Some(undirected_graph), None).ok_or(
                 ParseError::InvalidDataInput(
@@ -678,8 +1542,13 @@ fn generate_graph_from_file(lines: String) -> Result<FileInputGraphResult, Parse
             )
         }
         FoundGraphType::TD => {
-            let two_dimensional_coordinate_graph =
-                generate_two_dimensional_graph_from_file(lines_iter)?;
+            let two_dimensional_coordinate_graph = generate_two_dimensional_graph_from_file(
+                lines_iter,
+                header_line_number,
+                options.node_limit,
+                &progress,
+                &mut skipped_lines,
+            )?;
 
             FileInputGraphResult::new(None, None, Some(two_dimensional_coordinate_graph)).ok_or(
                 ParseError::InvalidDataInput(
@@ -688,97 +1557,241 @@ fn generate_graph_from_file(lines: String) -> Result<FileInputGraphResult, Parse
                 ),
             )
         }
+    }?;
+
+    result.skipped_lines = skipped_lines.errors;
+    result.skipped_line_count = skipped_lines.count;
+    result.warnings = skipped_lines.warnings;
+    Ok(result)
+}
+
+/// Checks whether every non-empty edge line is a plain weightless edge.
+///
+/// Plain edges are `A->B` for `D` and `A-B` for `UN` input, without an
+/// `@<kind>` or `#<layer>` suffix. Files without any edge line are not unweighted.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidDataInput`] if the regex cannot be compiled.
+fn is_unweighted_input(
+    lines_iter: Lines,
+    detected_graph_type: &FoundGraphType,
+) -> Result<bool, ParseError> {
+    let id = NODE_ID_PATTERN;
+    let pattern = match detected_graph_type {
+        FoundGraphType::D => format!(r"^{id}->{id}$"),
+        _ => format!(r"^{id}-{id}$"),
+    };
+    let plain_edge = Regex::new(&pattern).map_err(|err| {
+        ParseError::InvalidDataInput(format!("Failed to compile unweighted edge regex: {}", err))
+    })?;
+
+    let mut edge_lines = lines_iter.map(str::trim).filter(|line| !line.is_empty());
+    let Some(first) = edge_lines.next() else {
+        return Ok(false);
+    };
+    Ok(plain_edge.is_match(first) && edge_lines.all(|line| plain_edge.is_match(line)))
+}
+
+/// Builds an unweighted graph from file lines already checked by
+/// [`is_unweighted_input`].
+///
+/// # Parameters
+///
+/// - `lines_iter`: Iterator over lines after the graph-type header.
+/// - `header_line_number`: 1-based file line number of that header.
+/// - `directed`: Whether the header was `D` (`true`) or `UN` (`false`).
+/// - `node_limit`: Node count after which the remaining lines are skipped.
+/// - `progress`: Progress reporter notified after every line.
+/// - `skipped_lines`: Records duplicate edge lines as warnings.
+///
+/// Duplicate edges are skipped, like in the weighted builders.
+fn generate_unweighted_graph_from_file(
+    lines_iter: Lines,
+    header_line_number: usize,
+    directed: bool,
+    node_limit: Option<NonZeroUsize>,
+    progress: &ProgressReporter,
+    skipped_lines: &mut SkippedLineCollector,
+) -> UnweightedGraph {
+    let mut graph = if directed {
+        UnweightedGraph::new_directed(Vec::new())
+    } else {
+        UnweightedGraph::new_undirected(Vec::new())
+    };
+    let separator = if directed { "->" } else { "-" };
+
+    for (index, raw_line) in lines_iter.enumerate() {
+        // Zero-based enumerate starts on the line right after the header.
+        let line_number = header_line_number + 1 + index;
+        progress.line_processed(index + 1);
+        if node_limit_reached(&graph, node_limit, line_number) {
+            break;
+        }
+        let Some((from, to)) = split_once_unquoted(raw_line.trim(), separator) else {
+            continue;
+        };
+
+        let from = DefaultNode::new(unquote_node_id(from).to_string());
+        let to = DefaultNode::new(unquote_node_id(to).to_string());
+        graph.insert_node(from.clone());
+        graph.insert_node(to.clone());
+        if graph.does_edge_already_exist(&from, &to) {
+            skipped_lines.record_duplicate(line_number, raw_line);
+            continue;
+        }
+        // Duplicate edges are the only possible insertion error here.
+        let _ = graph.insert_edge(&from, &to, None);
     }
+
+    graph
 }
 
 /// Builds a directed graph from file lines.
 ///
-/// # Parameters    
+/// The weight type is chosen by the caller: `u16` for a [`DirectedGraph`],
+/// `i32` for a [`SignedDirectedGraph`].
+///
+/// # Parameters
 ///
-/// - `lines_iter`: An iterator over the lines of the input file, excluding the first line.
+/// - `lines_iter`: An iterator over the lines of the input file after the header.
+/// - `header_line_number`: 1-based file line number of that header.
+/// - `signed_weights`: Accept a leading `-` on weights (`A->B:-3`).
+/// - `missing_weight`: Policy for lines without a weight (`A->C`).
+/// - `node_limit`: Node count after which the remaining lines are skipped.
+/// - `progress`: Progress reporter notified after every line.
+/// - `skipped_lines`: Decides whether an invalid line aborts parsing or is skipped.
 ///
 /// # Returns
 ///
-/// - `Ok(DirectedGraph)` if all lines are valid and the graph is successfully built.
+/// - `Ok(DirectedGraph<W>)` if all lines are valid and the graph is successfully built.
 /// - `Err(ParseError)` if any line has invalid syntax or if graph construction fails due to
-///   semantic issues (e.g., incompatible node/weight types).
+///   semantic issues (e.g., a weight that does not fit `W`).
 ///
 /// # Notes
 ///
 /// This function is currently focused on directed graph parsing. Similar functions can be
 /// implemented for undirected and two-dimensional graph parsing to improve modularity and
 /// readability.
-fn generate_directed_graph_from_file(lines_iter: Lines) -> Result<DirectedGraph, ParseError> {
-    let mut graph = DirectedGraph::default();
-    let graph_type = FoundGraphType::D;
+fn generate_directed_graph_from_file<W: GraphWeight + FromStr>(
+    lines_iter: Lines,
+    header_line_number: usize,
+    signed_weights: bool,
+    missing_weight: MissingWeightPolicy,
+    node_limit: Option<NonZeroUsize>,
+    progress: &ProgressReporter,
+    skipped_lines: &mut SkippedLineCollector,
+) -> Result<DirectedGraph<W>, ParseError> {
+    let mut graph = DirectedGraph::with_nodes(vec![]);
     let syntax_regexes = compile_line_syntax_regexes()?;
 
     for (index, raw_line) in lines_iter.enumerate() {
-        // `+2`: zero-based enumerate starts after the header line (file line 1).
-        let line_number = index + 2;
+        // Zero-based enumerate starts on the line right after the header.
+        let line_number = header_line_number + 1 + index;
+        progress.line_processed(index + 1);
+        if node_limit_reached(&graph, node_limit, line_number) {
+            break;
+        }
         let line = raw_line.trim();
 
         // Empty lines are tolerated to keep hand-authored files readable.
         if line.is_empty() {
             continue;
         }
-
-        if !validate_line_syntax(line, &graph_type, &syntax_regexes) {
-            return Err(ParseError::InvalidDataInput(format!(
-                "Invalid syntax at line {} ('{}'). {}",
-                line_number,
-                raw_line,
-                expected_syntax_message(&graph_type)
-            )));
+        match insert_directed_line(
+            &mut graph,
+            raw_line,
+            line_number,
+            signed_weights,
+            missing_weight,
+            &syntax_regexes,
+        ) {
+            Ok(true) => {}
+            Ok(false) => skipped_lines.record_duplicate(line_number, raw_line),
+            Err(err) => skipped_lines.skip_or_fail(err)?,
         }
+    }
+
+    Ok(graph)
+}
+
+/// Parses one non-empty directed edge line and inserts it into `graph`.
+///
+/// # Parameters
+///
+/// - `graph`: Graph being built.
+/// - `raw_line`: Line as it appears in the file, used in error messages.
+/// - `line_number`: 1-based file line number, used in error messages.
+/// - `signed_weights`: Accept a leading `-` on the weight.
+/// - `missing_weight`: Policy for lines without a weight.
+/// - `syntax_regexes`: Precompiled line-syntax regexes.
+///
+/// # Returns
+///
+/// `Ok(true)` when the edge was inserted, or `Ok(false)` when it repeats an
+/// earlier edge and was skipped.
+///
+/// # Errors
+///
+/// Returns a [`ParseError::InvalidDataInput`] naming the line when it cannot
+/// be parsed or inserted. The graph is left without the line's edge.
+fn insert_directed_line<W: GraphWeight + FromStr>(
+    graph: &mut DirectedGraph<W>,
+    raw_line: &str,
+    line_number: usize,
+    signed_weights: bool,
+    missing_weight: MissingWeightPolicy,
+    syntax_regexes: &LineSyntaxRegexes,
+) -> Result<bool, ParseError> {
+    let graph_type = FoundGraphType::D;
+    let (line, layer) = split_layer_suffix(raw_line, line_number, &graph_type)?;
+    check_edge_separator(line, raw_line, line_number, &graph_type)?;
+
+    let (line, weight_defaulted) =
+        apply_missing_weight_policy(line, line_number, &graph_type, missing_weight)?;
+    let line = line.as_ref();
+
+    if !validate_line_syntax(line, &graph_type, signed_weights, syntax_regexes) {
+        return Err(ParseError::InvalidDataInput(format!(
+            "Invalid syntax at line {} ('{}'). {}",
+            line_number,
+            raw_line,
+            expected_syntax_message(&graph_type)
+        )));
+    }
 
-        let (from, to, weight) = convert_line_to_graph_data(line, &graph_type).map_err(|err| {
+    let (from, to, weight, kind) = split_one_dimensional_line(line, "->")
+        .and_then(|(from, to, weight_token, kind)| {
+            let weight = weight_token
+                .parse::<W>()
+                .map_err(|_| ParseError::InvalidWeightInteger)?;
+            Ok((from, to, weight, kind))
+        })
+        .map_err(|err| {
             ParseError::InvalidDataInput(format!(
                 "Failed to parse line {} ('{}'): {}",
                 line_number, raw_line, err
             ))
         })?;
 
-        // Conversion returns generic enums; narrow them to directed-compatible payloads.
-        let from = match from {
-            NodeType::DefaultNode(node) => node,
-            _ => {
-                return Err(ParseError::InvalidDataInput(
-                    "Directed graph parsing produced an unexpected node type!".to_string(),
-                ));
-            }
-        };
-        let to = match to {
-            NodeType::DefaultNode(node) => node,
-            _ => {
-                return Err(ParseError::InvalidDataInput(
-                    "Directed graph parsing produced an unexpected node type!".to_string(),
-                ));
-            }
-        };
-        let weight = match weight {
-            WeightType::U16(value) => value,
-            _ => {
-                return Err(ParseError::InvalidDataInput(
-                    "Directed graph parsing produced an unexpected weight type!".to_string(),
-                ));
-            }
-        };
+    // Only lines that make it into the graph count as defaulted.
+    if weight_defaulted {
+        graph.mark_weights_implicit();
+    }
 
-        graph.insert_node(from.clone());
-        graph.insert_node(to.clone());
+    graph.insert_node(from.clone());
+    graph.insert_node(to.clone());
 
-        // Duplicate edges are ignored to keep insertion idempotent.
-        if graph.does_edge_already_exist(&from, &to) {
-            continue;
-        }
+    // Duplicate edges are ignored to keep insertion idempotent.
+    if graph.does_edge_already_exist(&from, &to) {
+        return Ok(false);
+    }
 
-        if let Some(err) = graph.insert_edge(&from, &to, Some(weight)) {
-            return Err(ParseError::InvalidDataInput(err.message));
-        }
+    if let Some(err) = graph.insert_edge_with_labels(&from, &to, Some(weight), kind, layer) {
+        return Err(ParseError::InvalidDataInput(err.message));
     }
 
-    Ok(graph)
+    Ok(true)
 }
 
 /// Builds an undirected graph from file lines.
@@ -786,6 +1799,11 @@ fn generate_directed_graph_from_file(lines_iter: Lines) -> Result<DirectedGraph,
 /// # Parameters
 ///
 /// - `lines_iter`: Iterator over lines after the graph-type header.
+/// - `header_line_number`: 1-based file line number of that header.
+/// - `missing_weight`: Policy for lines without a weight (`A-C`).
+/// - `node_limit`: Node count after which the remaining lines are skipped.
+/// - `progress`: Progress reporter notified after every line.
+/// - `skipped_lines`: Decides whether an invalid line aborts parsing or is skipped.
 ///
 /// # Returns
 ///
@@ -797,78 +1815,220 @@ fn generate_directed_graph_from_file(lines_iter: Lines) -> Result<DirectedGraph,
 /// - Validates each non-empty line against undirected syntax (`A-B:7`).
 /// - Converts each line into two default nodes and one `u16` weight.
 /// - Inserts missing nodes before inserting the edge.
-/// - Silently skips duplicate edges.
-fn generate_undirected_graph_from_file(lines_iter: Lines) -> Result<UndirectedGraph, ParseError> {
+/// - Skips duplicate edges and records them as warnings.
+fn generate_undirected_graph_from_file(
+    lines_iter: Lines,
+    header_line_number: usize,
+    missing_weight: MissingWeightPolicy,
+    node_limit: Option<NonZeroUsize>,
+    progress: &ProgressReporter,
+    skipped_lines: &mut SkippedLineCollector,
+) -> Result<UndirectedGraph, ParseError> {
     let mut graph = UndirectedGraph::default();
-    let graph_type = FoundGraphType::UN;
     let syntax_regexes = compile_line_syntax_regexes()?;
 
     for (index, raw_line) in lines_iter.enumerate() {
-        // `+2`: one line offset for zero-based enumerate, one for header line.
-        let line_number = index + 2;
+        // Zero-based enumerate starts on the line right after the header.
+        let line_number = header_line_number + 1 + index;
+        progress.line_processed(index + 1);
+        if node_limit_reached(&graph, node_limit, line_number) {
+            break;
+        }
         let line = raw_line.trim();
 
         // Ignore blank lines so files can contain visual separators.
         if line.is_empty() {
             continue;
         }
-
-        if !validate_line_syntax(line, &graph_type, &syntax_regexes) {
-            return Err(ParseError::InvalidDataInput(format!(
-                "Invalid syntax at line {} ('{}'). {}",
-                line_number,
-                raw_line,
-                expected_syntax_message(&graph_type)
-            )));
+        match insert_undirected_line(
+            &mut graph,
+            raw_line,
+            line_number,
+            missing_weight,
+            &syntax_regexes,
+        ) {
+            Ok(true) => {}
+            Ok(false) => skipped_lines.record_duplicate(line_number, raw_line),
+            Err(err) => skipped_lines.skip_or_fail(err)?,
         }
+    }
+
+    Ok(graph)
+}
+
+/// Parses one non-empty undirected edge line and inserts it into `graph`.
+///
+/// # Parameters
+///
+/// - `graph`: Graph being built.
+/// - `raw_line`: Line as it appears in the file, used in error messages.
+/// - `line_number`: 1-based file line number, used in error messages.
+/// - `missing_weight`: Policy for lines without a weight.
+/// - `syntax_regexes`: Precompiled line-syntax regexes.
+///
+/// # Returns
+///
+/// `Ok(true)` when the edge was inserted, or `Ok(false)` when it repeats an
+/// earlier edge and was skipped.
+///
+/// # Errors
+///
+/// Returns a [`ParseError::InvalidDataInput`] naming the line when it cannot
+/// be parsed or inserted. The graph is left without the line's edge.
+fn insert_undirected_line(
+    graph: &mut UndirectedGraph,
+    raw_line: &str,
+    line_number: usize,
+    missing_weight: MissingWeightPolicy,
+    syntax_regexes: &LineSyntaxRegexes,
+) -> Result<bool, ParseError> {
+    let graph_type = FoundGraphType::UN;
+    let (line, layer) = split_layer_suffix(raw_line, line_number, &graph_type)?;
+    check_edge_separator(line, raw_line, line_number, &graph_type)?;
+
+    let (line, weight_defaulted) =
+        apply_missing_weight_policy(line, line_number, &graph_type, missing_weight)?;
+    let line = line.as_ref();
+
+    if let Some(captures) = syntax_regexes.two_way_undirected.captures(line) {
+        return insert_two_way_undirected_line(
+            graph,
+            raw_line,
+            line_number,
+            weight_defaulted,
+            &captures,
+            layer,
+        );
+    }
 
-        let (from, to, weight) = convert_line_to_graph_data(line, &graph_type).map_err(|err| {
+    if !validate_line_syntax(line, &graph_type, false, syntax_regexes) {
+        return Err(ParseError::InvalidDataInput(format!(
+            "Invalid syntax at line {} ('{}'). {}",
+            line_number,
+            raw_line,
+            expected_syntax_message(&graph_type)
+        )));
+    }
+
+    let (from, to, weight, kind) =
+        convert_line_to_graph_data(line, &graph_type).map_err(|err| {
             ParseError::InvalidDataInput(format!(
                 "Failed to parse line {} ('{}'): {}",
                 line_number, raw_line, err
             ))
         })?;
 
-        // Enforce that converter output matches undirected graph expectations.
-        let from = match from {
-            NodeType::DefaultNode(node) => node,
-            _ => {
-                return Err(ParseError::InvalidDataInput(
-                    "Undirected graph parsing produced an unexpected node type!".to_string(),
-                ));
-            }
-        };
-        let to = match to {
-            NodeType::DefaultNode(node) => node,
-            _ => {
-                return Err(ParseError::InvalidDataInput(
-                    "Undirected graph parsing produced an unexpected node type!".to_string(),
-                ));
-            }
-        };
-        let weight = match weight {
-            WeightType::U16(value) => value,
-            _ => {
-                return Err(ParseError::InvalidDataInput(
-                    "Undirected graph parsing produced an unexpected weight type!".to_string(),
-                ));
-            }
-        };
+    // Enforce that converter output matches undirected graph expectations.
+    let from = match from {
+        NodeType::DefaultNode(node) => node,
+        _ => {
+            return Err(ParseError::InvalidDataInput(
+                "Undirected graph parsing produced an unexpected node type!".to_string(),
+            ));
+        }
+    };
+    let to = match to {
+        NodeType::DefaultNode(node) => node,
+        _ => {
+            return Err(ParseError::InvalidDataInput(
+                "Undirected graph parsing produced an unexpected node type!".to_string(),
+            ));
+        }
+    };
+    let weight = match weight {
+        WeightType::U16(value) => value,
+        _ => {
+            return Err(ParseError::InvalidDataInput(
+                "Undirected graph parsing produced an unexpected weight type!".to_string(),
+            ));
+        }
+    };
 
-        graph.insert_node(from.clone());
-        graph.insert_node(to.clone());
+    // Only lines that make it into the graph count as defaulted.
+    if weight_defaulted {
+        graph.mark_weights_implicit();
+    }
 
-        // Skip duplicates to preserve deterministic graph content.
-        if graph.does_edge_already_exist(&from, &to) {
-            continue;
-        }
+    graph.insert_node(from.clone());
+    graph.insert_node(to.clone());
 
-        if let Some(err) = graph.insert_edge(&from, &to, Some(weight)) {
-            return Err(ParseError::InvalidDataInput(err.message));
-        }
+    // Skip duplicates to preserve deterministic graph content.
+    if graph.does_edge_already_exist(&from, &to) {
+        return Ok(false);
     }
 
-    Ok(graph)
+    if let Some(err) = graph.insert_edge_with_labels(&from, &to, Some(weight), kind, layer) {
+        return Err(ParseError::InvalidDataInput(err.message));
+    }
+
+    Ok(true)
+}
+
+/// Inserts one `A<->B:7|9` line matched by
+/// [`LineSyntaxRegexes::two_way_undirected`] into `graph`.
+///
+/// A missing `|<reverse_weight>` makes the edge symmetric. Duplicate edges
+/// are skipped like in [`insert_undirected_line`]. `layer` is the line's
+/// `#<layer>` suffix, already split off by [`split_layer_suffix`].
+///
+/// # Returns
+///
+/// `Ok(true)` when the edge was inserted, or `Ok(false)` when it repeats an
+/// earlier edge and was skipped.
+///
+/// # Errors
+///
+/// Returns a [`ParseError::InvalidDataInput`] naming the line when a weight
+/// does not fit into `u16` or the edge cannot be inserted.
+fn insert_two_way_undirected_line(
+    graph: &mut UndirectedGraph,
+    raw_line: &str,
+    line_number: usize,
+    weight_defaulted: bool,
+    captures: &regex::Captures,
+    layer: Option<String>,
+) -> Result<bool, ParseError> {
+    let parse_weight = |token: &str| {
+        token.parse::<u16>().map_err(|_| {
+            ParseError::InvalidDataInput(format!(
+                "Failed to parse line {} ('{}'): {}",
+                line_number,
+                raw_line,
+                ParseError::InvalidWeightInteger
+            ))
+        })
+    };
+
+    // Groups 1-3 are mandatory in the regex; 4 (reverse weight) and 5 (kind) are optional.
+    let a = DefaultNode::new(unquote_node_id(&captures[1]).to_string());
+    let b = DefaultNode::new(unquote_node_id(&captures[2]).to_string());
+    let a_to_b = parse_weight(&captures[3])?;
+    let b_to_a = match captures.get(4) {
+        Some(token) => parse_weight(token.as_str())?,
+        None => a_to_b,
+    };
+    let kind = captures.get(5).map(|kind| kind.as_str().to_string());
+
+    if weight_defaulted {
+        graph.mark_weights_implicit();
+    }
+
+    graph.insert_node(a.clone());
+    graph.insert_node(b.clone());
+
+    // Skip duplicates to preserve deterministic graph content.
+    if graph.does_edge_already_exist(&a, &b) {
+        return Ok(false);
+    }
+
+    if let Some(err) = graph.insert_labeled_two_way_edge(&a, &b, a_to_b, b_to_a, kind, layer) {
+        return Err(ParseError::InvalidDataInput(format!(
+            "Failed to insert line {} ('{}'): {}",
+            line_number, raw_line, err.message
+        )));
+    }
+
+    Ok(true)
 }
 
 /// Builds a two-dimensional coordinate graph from file lines.
@@ -876,6 +2036,10 @@ fn generate_undirected_graph_from_file(lines_iter: Lines) -> Result<UndirectedGr
 /// # Parameters
 ///
 /// - `lines_iter`: Iterator over lines after the graph-type header.
+/// - `header_line_number`: 1-based file line number of that header.
+/// - `node_limit`: Node count after which the remaining lines are skipped.
+/// - `progress`: Progress reporter notified after every line.
+/// - `skipped_lines`: Decides whether an invalid line aborts parsing or is skipped.
 ///
 /// # Returns
 ///
@@ -887,69 +2051,111 @@ fn generate_undirected_graph_from_file(lines_iter: Lines) -> Result<UndirectedGr
 /// - Validates each non-empty line against TD syntax (`A:0,0=>B:4,2`).
 /// - Parses both endpoints as `TwoDimensionalNode<i32>`.
 /// - Inserts both endpoint nodes before edge insertion.
-/// - Silently skips duplicate edges.
+/// - Skips duplicate edges and records them as warnings.
 fn generate_two_dimensional_graph_from_file(
     lines_iter: Lines,
+    header_line_number: usize,
+    node_limit: Option<NonZeroUsize>,
+    progress: &ProgressReporter,
+    skipped_lines: &mut SkippedLineCollector,
 ) -> Result<TwoDimensionalCoordinateGraph, ParseError> {
     let mut graph = TwoDimensionalCoordinateGraph::default();
-    let graph_type = FoundGraphType::TD;
     let syntax_regexes = compile_line_syntax_regexes()?;
 
     for (index, raw_line) in lines_iter.enumerate() {
-        // `+2`: parser reports original file line numbers (header occupies line 1).
-        let line_number = index + 2;
+        // Zero-based enumerate starts on the line right after the header.
+        let line_number = header_line_number + 1 + index;
+        progress.line_processed(index + 1);
+        if node_limit_reached(&graph, node_limit, line_number) {
+            break;
+        }
         let line = raw_line.trim();
 
         // Ignore blank lines to support grouped TD edge blocks.
         if line.is_empty() {
             continue;
         }
-
-        if !validate_line_syntax(line, &graph_type, &syntax_regexes) {
-            return Err(ParseError::InvalidDataInput(format!(
-                "Invalid syntax at line {} ('{}'). {}",
-                line_number,
-                raw_line,
-                expected_syntax_message(&graph_type)
-            )));
+        match insert_two_dimensional_line(&mut graph, raw_line, line_number, &syntax_regexes) {
+            Ok(true) => {}
+            Ok(false) => skipped_lines.record_duplicate(line_number, raw_line),
+            Err(err) => skipped_lines.skip_or_fail(err)?,
         }
+    }
+    Ok(graph)
+}
 
-        let (node_a, node_b, _) = convert_line_to_graph_data(line, &graph_type).map_err(|err| {
-            ParseError::InvalidDataInput(format!(
-                "Failed to parse line {} ('{}'): {}",
-                line_number, raw_line, err
-            ))
-        })?;
-
-        // TD conversion must return TD node variants only.
-        let node_a = match node_a {
-            NodeType::TwoDimensionalNode(node) => node,
-            _ => {
-                return Err(ParseError::InvalidDataInput(
-                    "Two-dimensional graph parsing produced an unexpected node type!".to_string(),
-                ));
-            }
-        };
-        let node_b = match node_b {
-            NodeType::TwoDimensionalNode(node) => node,
-            _ => {
-                return Err(ParseError::InvalidDataInput(
-                    "Two-dimensional graph parsing produced an unexpected node type!".to_string(),
-                ));
-            }
-        };
+/// Parses one non-empty two-dimensional edge line and inserts it into `graph`.
+///
+/// # Parameters
+///
+/// - `graph`: Graph being built.
+/// - `raw_line`: Line as it appears in the file, used in error messages.
+/// - `line_number`: 1-based file line number, used in error messages.
+/// - `syntax_regexes`: Precompiled line-syntax regexes.
+///
+/// # Returns
+///
+/// `Ok(true)` when the edge was inserted, or `Ok(false)` when it repeats an
+/// earlier edge and was skipped.
+///
+/// # Errors
+///
+/// Returns a [`ParseError::InvalidDataInput`] naming the line when it cannot
+/// be parsed or inserted. The graph is left without the line's edge.
+fn insert_two_dimensional_line(
+    graph: &mut TwoDimensionalCoordinateGraph,
+    raw_line: &str,
+    line_number: usize,
+    syntax_regexes: &LineSyntaxRegexes,
+) -> Result<bool, ParseError> {
+    let graph_type = FoundGraphType::TD;
+    let line = raw_line.trim();
+
+    if !validate_line_syntax(line, &graph_type, false, syntax_regexes) {
+        return Err(ParseError::InvalidDataInput(format!(
+            "Invalid syntax at line {} ('{}'). {}",
+            line_number,
+            raw_line,
+            expected_syntax_message(&graph_type)
+        )));
+    }
 
-        graph.insert_node(node_a.clone());
-        graph.insert_node(node_b.clone());
+    let (node_a, node_b, _, _) = convert_line_to_graph_data(line, &graph_type).map_err(|err| {
+        ParseError::InvalidDataInput(format!(
+            "Failed to parse line {} ('{}'): {}",
+            line_number, raw_line, err
+        ))
+    })?;
 
-        // Skip duplicate edges to keep parser idempotent for repeated lines.
-        if graph.does_edge_already_exist(&node_a, &node_b) {
-            continue;
+    // TD conversion must return TD node variants only.
+    let node_a = match node_a {
+        NodeType::TwoDimensionalNode(node) => node,
+        _ => {
+            return Err(ParseError::InvalidDataInput(
+                "Two-dimensional graph parsing produced an unexpected node type!".to_string(),
+            ));
         }
-
-        if let Some(err) = graph.insert_edge(&node_a, &node_b, None) {
-            return Err(ParseError::InvalidDataInput(err.message));
+    };
+    let node_b = match node_b {
+        NodeType::TwoDimensionalNode(node) => node,
+        _ => {
+            return Err(ParseError::InvalidDataInput(
+                "Two-dimensional graph parsing produced an unexpected node type!".to_string(),
+            ));
         }
+    };
+
+    graph.insert_node(node_a.clone());
+    graph.insert_node(node_b.clone());
+
+    // Skip duplicate edges to keep parser idempotent for repeated lines.
+    if graph.does_edge_already_exist(&node_a, &node_b) {
+        return Ok(false);
     }
-    Ok(graph)
+
+    if let Some(err) = graph.insert_edge(&node_a, &node_b, None) {
+        return Err(ParseError::InvalidDataInput(err.message));
+    }
+
+    Ok(true)
 }
@@ -3,8 +3,16 @@
 //! # Overview
 //!
 //! This module groups all graph input origins supported by the project:
+//! - [`csv_input`]: `from,to,weight` CSV edge lists with an optional header.
+//! - [`dot_input`]: Graphviz DOT text as emitted by `Graph::to_dot`.
 //! - [`file_input`]: production parser for file-based graph definitions.
-//! - [`terminal_input`]: placeholder for future interactive terminal input.
+//! - [`pairs_input`]: start/end pairs for batch queries (`--pairs`).
+//! - `sqlite_input`: loader for SQLite `nodes`/`edges` tables (requires the
+//!   `sqlite` feature).
+//! - [`terminal_input`]: interactive graph input typed into the terminal
+//!   (`--origin cmd-line`).
+//! - [`weight_table_input`]: joins a weightless topology file with a separate
+//!   `from,to,weight` table.
 //!
 //! # Usage
 //!
@@ -15,5 +23,11 @@
 //! assert!(result.is_ok());
 //! ```
 
+pub mod csv_input;
+pub mod dot_input;
 pub mod file_input;
+pub mod pairs_input;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_input;
 pub mod terminal_input;
+pub mod weight_table_input;
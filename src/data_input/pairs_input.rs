@@ -0,0 +1,99 @@
+//! Start/end pairs for batch path queries.
+//!
+//! # Overview
+//!
+//! [`retrieve_pairs_from_file`] reads the file passed to `--pairs`. Each
+//! non-empty line names one query as two whitespace-separated node IDs:
+//!
+//! ```text
+//! A D
+//! B C
+//! A C
+//! ```
+//!
+//! The pairs are returned in file order, so batch output can be printed in
+//! the order the queries were requested. Whether the nodes exist is not
+//! checked here; that is reported per pair by the search.
+//!
+//! # Example
+//!
+//! ```rust
+//! use shortest_path_finder::data_input::pairs_input::retrieve_pairs_from_file;
+//! use std::{
+//!     fs,
+//!     time::{SystemTime, UNIX_EPOCH},
+//! };
+//!
+//! let unique_id = SystemTime::now()
+//!     .duration_since(UNIX_EPOCH)
+//!     .expect("system clock should be after UNIX epoch")
+//!     .as_nanos();
+//! let path = std::env::temp_dir().join(format!("pathfinder-doc-pairs-{}.txt", unique_id));
+//! fs::write(&path, "A D\n\nB  C\n").expect("temporary pairs file should be writable");
+//!
+//! let pairs = retrieve_pairs_from_file(&path.to_string_lossy()).unwrap();
+//! assert_eq!(
+//!     pairs,
+//!     vec![
+//!         ("A".to_string(), "D".to_string()),
+//!         ("B".to_string(), "C".to_string()),
+//!     ]
+//! );
+//!
+//! let _ = fs::remove_file(path);
+//! ```
+
+use std::fs;
+
+use crate::{data_input::file_input::FileInputError, error::parse_error::ParseError};
+
+/// Reads `<start> <end>` pairs from a file, in file order.
+///
+/// Whitespace-only lines are skipped.
+///
+/// # Parameters
+///
+/// - `path`: Path to the pairs file.
+///
+/// # Errors
+///
+/// - [`FileInputError::Io`] if the file cannot be read.
+/// - [`FileInputError::Parse`] naming the line if a line does not consist of
+///   exactly two node IDs.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::data_input::{
+///     file_input::FileInputError, pairs_input::retrieve_pairs_from_file,
+/// };
+///
+/// let err = retrieve_pairs_from_file("does/not/exist.txt").unwrap_err();
+/// assert!(matches!(err, FileInputError::Io { .. }));
+/// ```
+pub fn retrieve_pairs_from_file(path: &str) -> Result<Vec<(String, String)>, FileInputError> {
+    let content = fs::read_to_string(path).map_err(|source| FileInputError::Io {
+        path: path.to_string(),
+        source,
+    })?;
+
+    let mut pairs = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        match fields.as_slice() {
+            [] => continue,
+            [start, end] => pairs.push((start.to_string(), end.to_string())),
+            _ => {
+                return Err(FileInputError::Parse(ParseError::InvalidDataInput(
+                    format!(
+                        "Pairs line {} ('{}') must have the form '<start> <end>'!",
+                        index + 1,
+                        line.trim()
+                    ),
+                )));
+            }
+        }
+    }
+
+    Ok(pairs)
+}
@@ -0,0 +1,275 @@
+//! SQLite-based graph loading (requires the `sqlite` feature).
+//!
+//! # Overview
+//!
+//! [`retrieve_graph_data_from_sqlite`] reads a database with a `nodes` and an
+//! `edges` table and builds either a [`DirectedGraph`] or an
+//! [`UndirectedGraph`]. The result reuses [`FileInputGraphResult`], so code
+//! that already consumes file input works unchanged.
+//!
+//! # Expected schema
+//!
+//! ```sql
+//! CREATE TABLE nodes (id TEXT NOT NULL PRIMARY KEY);
+//! CREATE TABLE edges (
+//!     "from"   TEXT    NOT NULL,
+//!     "to"     TEXT    NOT NULL,
+//!     weight   INTEGER NOT NULL,
+//!     directed INTEGER NOT NULL  -- 1 for directed, 0 for undirected
+//! );
+//! ```
+//!
+//! Extra columns are ignored. `from` and `to` are SQL keywords, so they have
+//! to be quoted in hand-written queries.
+//!
+//! # Validation and consistency rules
+//!
+//! - All edge rows must agree on `directed`; mixing `0` and `1` is an error.
+//! - Every `from`/`to` must appear in `nodes`.
+//! - Weights must fit into `u16` (`0..=65535`).
+//! - Duplicate edges are ignored, as in file input.
+//! - An empty `edges` table yields a [`DirectedGraph`] containing only the
+//!   rows of `nodes`; an empty database yields an empty [`DirectedGraph`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use rusqlite::Connection;
+//! use shortest_path_finder::data_input::sqlite_input::retrieve_graph_data_from_sqlite;
+//! use shortest_path_finder::graphs::graph::Graph;
+//!
+//! let file = tempfile::NamedTempFile::new().unwrap();
+//! let path = file.path().to_string_lossy().into_owned();
+//! let connection = Connection::open(&path).unwrap();
+//! connection
+//!     .execute_batch(
+//!         r#"
+//!         CREATE TABLE nodes (id TEXT NOT NULL PRIMARY KEY);
+//!         CREATE TABLE edges ("from" TEXT, "to" TEXT, weight INTEGER, directed INTEGER);
+//!         INSERT INTO nodes VALUES ('A'), ('B');
+//!         INSERT INTO edges VALUES ('A', 'B', 7, 0);
+//!         "#,
+//!     )
+//!     .unwrap();
+//!
+//! let parsed = retrieve_graph_data_from_sqlite(&path).unwrap();
+//! let graph = parsed.undirected_graph.expect("all edges are undirected");
+//! assert!(graph.does_edge_already_exist(
+//!     graph.get_node_by_id("B").unwrap(),
+//!     graph.get_node_by_id("A").unwrap()
+//! ));
+//! ```
+
+use std::{error::Error, fmt};
+
+use rusqlite::{Connection, OpenFlags};
+
+use crate::{
+    data_input::file_input::FileInputGraphResult,
+    error::parse_error::ParseError,
+    graphs::{directed::DirectedGraph, graph::Graph, undirected::UndirectedGraph},
+    nodes::default_node::DefaultNode,
+};
+
+/// One row of the `edges` table after type conversion.
+struct EdgeRow {
+    /// ID of the source node (`"from"` column).
+    from: String,
+    /// ID of the target node (`"to"` column).
+    to: String,
+    /// Edge weight (`weight` column).
+    weight: u16,
+    /// Whether the edge is directed (`directed` column).
+    directed: bool,
+}
+
+/// Error type for SQLite graph loading.
+///
+/// # Variant semantics
+///
+/// - [`SqliteInputError::Database`]: Opening or querying the database failed,
+///   for example because the file or a table is missing.
+/// - [`SqliteInputError::Parse`]: The rows were read but do not form a valid
+///   graph (mixed directions, unknown nodes).
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::data_input::sqlite_input::SqliteInputError;
+/// use shortest_path_finder::error::parse_error::ParseError;
+///
+/// let err = SqliteInputError::Parse(ParseError::InvalidDataInput("bad row".to_string()));
+/// assert!(err.to_string().contains("bad row"));
+/// ```
+#[derive(Debug)]
+pub enum SqliteInputError {
+    /// The database could not be opened or queried.
+    Database {
+        path: String,
+        source: rusqlite::Error,
+    },
+    /// The rows could not be turned into a graph.
+    Parse(ParseError),
+}
+
+impl fmt::Display for SqliteInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SqliteInputError::Database { path, source } => {
+                write!(f, "Failed to read graph database '{}': {}", path, source)
+            }
+            SqliteInputError::Parse(source) => {
+                write!(f, "Failed to build graph from database rows: {}", source)
+            }
+        }
+    }
+}
+
+impl Error for SqliteInputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SqliteInputError::Database { source, .. } => Some(source),
+            SqliteInputError::Parse(source) => Some(source),
+        }
+    }
+}
+
+/// Reads a graph from the `nodes` and `edges` tables of a SQLite database.
+///
+/// The database is opened read-only. See the [module docs](self) for the
+/// expected schema.
+///
+/// # Parameters
+///
+/// - `path`: Path to the SQLite database file.
+///
+/// # Returns
+///
+/// - `Ok(FileInputGraphResult)` with `directed_graph` set when every edge is
+///   directed (or there are no edges), or `undirected_graph` set when every
+///   edge is undirected.
+///
+/// # Errors
+///
+/// - [`SqliteInputError::Database`] if the file cannot be opened, a table or
+///   column is missing, or a value does not convert (for example a weight
+///   outside the `u16` range).
+/// - [`SqliteInputError::Parse`] if edge rows mix directed and undirected
+///   edges or reference a node missing from `nodes`.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::data_input::sqlite_input::{
+///     SqliteInputError, retrieve_graph_data_from_sqlite,
+/// };
+///
+/// let err = retrieve_graph_data_from_sqlite("does/not/exist.sqlite").unwrap_err();
+/// assert!(matches!(err, SqliteInputError::Database { .. }));
+/// ```
+pub fn retrieve_graph_data_from_sqlite(
+    path: &str,
+) -> Result<FileInputGraphResult, SqliteInputError> {
+    let database_error = |source| SqliteInputError::Database {
+        path: path.to_string(),
+        source,
+    };
+
+    let connection = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(database_error)?;
+    let node_ids = read_node_ids(&connection).map_err(database_error)?;
+    let edges = read_edge_rows(&connection).map_err(database_error)?;
+
+    // An empty edge table carries no direction; fall back to a directed graph.
+    let directed = edges.first().is_none_or(|edge| edge.directed);
+    if let Some(edge) = edges.iter().find(|edge| edge.directed != directed) {
+        return Err(SqliteInputError::Parse(ParseError::InvalidDataInput(
+            format!(
+                "The edges table mixes directed and undirected rows (first mismatch: '{}' -> '{}')!",
+                edge.from, edge.to
+            ),
+        )));
+    }
+
+    let result = if directed {
+        let mut graph = DirectedGraph::default();
+        fill_graph(&mut graph, node_ids, &edges)?;
+        FileInputGraphResult::new(Some(graph), None, None)
+    } else {
+        let mut graph = UndirectedGraph::default();
+        fill_graph(&mut graph, node_ids, &edges)?;
+        FileInputGraphResult::new(None, Some(graph), None)
+    };
+
+    result.ok_or(SqliteInputError::Parse(ParseError::InvalidDataInput(
+        "Exactly one parsed graph variant must be present in FileInputGraphResult.".to_string(),
+    )))
+}
+
+/// Reads every `id` of the `nodes` table in row order.
+fn read_node_ids(connection: &Connection) -> Result<Vec<String>, rusqlite::Error> {
+    let mut statement = connection.prepare("SELECT id FROM nodes ORDER BY rowid")?;
+    statement.query_map([], |row| row.get(0))?.collect()
+}
+
+/// Reads every row of the `edges` table in row order.
+///
+/// Weights are range-checked here, so a value that does not fit into `u16`
+/// surfaces as a [`rusqlite::Error::IntegralValueOutOfRange`].
+fn read_edge_rows(connection: &Connection) -> Result<Vec<EdgeRow>, rusqlite::Error> {
+    let mut statement =
+        connection.prepare(r#"SELECT "from", "to", weight, directed FROM edges ORDER BY rowid"#)?;
+    statement
+        .query_map([], |row| {
+            Ok(EdgeRow {
+                from: row.get(0)?,
+                to: row.get(1)?,
+                weight: row.get(2)?,
+                directed: row.get(3)?,
+            })
+        })?
+        .collect()
+}
+
+/// Inserts all nodes, then all edges, into an empty graph.
+///
+/// # Errors
+///
+/// Returns [`SqliteInputError::Parse`] if an edge references a node that is
+/// not in `node_ids` or the graph rejects an insertion.
+fn fill_graph<G: Graph<Node = DefaultNode, Weight = u16>>(
+    graph: &mut G,
+    node_ids: Vec<String>,
+    edges: &[EdgeRow],
+) -> Result<(), SqliteInputError> {
+    for id in node_ids {
+        graph.insert_node(DefaultNode::new(id));
+    }
+
+    for edge in edges {
+        let (Some(from), Some(to)) = (
+            graph.get_node_by_id(&edge.from).cloned(),
+            graph.get_node_by_id(&edge.to).cloned(),
+        ) else {
+            return Err(SqliteInputError::Parse(ParseError::InvalidDataInput(
+                format!(
+                    "The edge '{}' -> '{}' references a node missing from the nodes table!",
+                    edge.from, edge.to
+                ),
+            )));
+        };
+
+        // Duplicate edges are ignored to match file input.
+        if graph.does_edge_already_exist(&from, &to) {
+            continue;
+        }
+
+        if let Some(err) = graph.insert_edge(&from, &to, Some(edge.weight)) {
+            return Err(SqliteInputError::Parse(ParseError::InvalidDataInput(
+                err.to_string(),
+            )));
+        }
+    }
+
+    Ok(())
+}
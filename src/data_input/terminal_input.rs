@@ -1,23 +1,117 @@
-//! Terminal-based graph input (planned module).
+//! Interactive graph input from the terminal (`--origin cmd-line`).
 //!
 //! # Overview
 //!
-//! Interactive graph input is planned but not implemented yet. Runtime support
-//! for [`InputOrigin::CommandLine`](crate::cmd_line::app_config::InputOrigin::CommandLine)
-//! currently resolves to `unimplemented!()` in the binary flow.
+//! [`retrieve_graph_data_from_cmd_line`] asks for the graph line by line: a
+//! graph-type header (`D`, `UN` or `TD`) followed by one edge per line, in
+//! the same syntax as graph files (e.g. `A->B:7`). Input ends at the first
+//! empty line or at end of input (Ctrl-D), so the collected text is then
+//! parsed exactly like a graph file.
 //!
-//! # Current Status
+//! Prompts go to stderr, so stdout only carries the search result.
 //!
-//! - No public API is exposed from this module yet.
-//! - File input remains the supported production path.
-//!
-//! # Related Usage
+//! # Example
 //!
 //! ```rust
-//! use shortest_path_finder::cmd_line::app_config::InputOrigin;
+//! use shortest_path_finder::data_input::file_input::FileInputOptions;
+//! use shortest_path_finder::data_input::terminal_input::retrieve_graph_data_from_lines;
 //!
-//! let origin = InputOrigin::CommandLine;
-//! assert!(matches!(origin, InputOrigin::CommandLine));
+//! // Everything after the empty line is not part of the graph.
+//! let typed = "D\nA->B:7\nB->C:2\n\nignored\n".as_bytes();
+//! let parsed = retrieve_graph_data_from_lines(typed, &FileInputOptions::default()).unwrap();
+//! assert!(parsed.directed_graph.is_some());
 //! ```
 
-// TODO: Implement terminal-based graph input parsing and construction.
+use std::io::{self, BufRead};
+
+use crate::{
+    data_input::file_input::{
+        FileInputError, FileInputGraphResult, FileInputOptions, load_graph_text,
+    },
+    error::parse_error::ParseError,
+};
+
+/// Source name reported for terminal input in I/O errors and load spans.
+pub const TERMINAL_SOURCE: &str = "<terminal>";
+
+/// Reads a graph interactively from the terminal.
+///
+/// # Errors
+///
+/// Same as [`retrieve_graph_data_from_lines`].
+pub fn retrieve_graph_data_from_cmd_line() -> Result<FileInputGraphResult, FileInputError> {
+    retrieve_graph_data_from_cmd_line_with_options(&FileInputOptions::default())
+}
+
+/// Reads a graph interactively from the terminal using non-default
+/// [`FileInputOptions`].
+///
+/// Prints a short usage prompt to stderr before reading standard input.
+///
+/// # Errors
+///
+/// Same as [`retrieve_graph_data_from_lines`].
+pub fn retrieve_graph_data_from_cmd_line_with_options(
+    options: &FileInputOptions,
+) -> Result<FileInputGraphResult, FileInputError> {
+    eprintln!(
+        "Enter the graph type (D, UN or TD), then one edge per line (e.g. A->B:7). Finish with an empty line or Ctrl-D:"
+    );
+    retrieve_graph_data_from_lines(io::stdin().lock(), options)
+}
+
+/// Collects graph lines from `reader` until an empty line or end of input
+/// and parses them like a graph file.
+///
+/// A line holding only whitespace counts as empty. Lines after the first
+/// empty line are left unread in `reader`.
+///
+/// # Errors
+///
+/// - [`FileInputError::Io`] if reading fails, with the path
+///   [`TERMINAL_SOURCE`].
+/// - [`FileInputError::Parse`] if nothing was entered before the first empty
+///   line or end of input, or the entered text is not a valid graph.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::data_input::file_input::{FileInputError, FileInputOptions};
+/// use shortest_path_finder::data_input::terminal_input::retrieve_graph_data_from_lines;
+///
+/// // Ctrl-D right away: nothing to build a graph from.
+/// let err = retrieve_graph_data_from_lines("".as_bytes(), &FileInputOptions::default())
+///     .unwrap_err();
+/// assert!(matches!(err, FileInputError::Parse(_)));
+/// ```
+pub fn retrieve_graph_data_from_lines(
+    mut reader: impl BufRead,
+    options: &FileInputOptions,
+) -> Result<FileInputGraphResult, FileInputError> {
+    let mut content = String::new();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        let read = reader
+            .read_line(&mut line)
+            .map_err(|source| FileInputError::Io {
+                path: TERMINAL_SOURCE.to_string(),
+                source,
+            })?;
+        // `0` bytes means end of input (Ctrl-D).
+        if read == 0 || line.trim().is_empty() {
+            break;
+        }
+        content.push_str(line.trim_end());
+        content.push('\n');
+    }
+
+    if content.is_empty() {
+        return Err(FileInputError::Parse(ParseError::InvalidDataInput(
+            "No graph data was entered! Expected a graph type (D, UN or TD) followed by one edge per line."
+                .to_string(),
+        )));
+    }
+
+    load_graph_text(TERMINAL_SOURCE, content, options)
+}
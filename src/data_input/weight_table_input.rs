@@ -0,0 +1,252 @@
+//! Graph loading from a topology file plus a separate weight table.
+//!
+//! # Overview
+//!
+//! [`retrieve_graph_with_weight_table`] joins two files:
+//! - a topology file in the regular file-input format, but with weightless
+//!   edge lines (`A->B`, `A-B`, optionally with an `@<kind>` suffix),
+//! - a weight table with one `from,to,weight` entry per line.
+//!
+//! Every topology edge receives the weight of its table entry, and the joined
+//! lines are then parsed exactly like a regular graph file, so all
+//! [file input](crate::data_input::file_input) validation rules apply.
+//!
+//! # Input Format
+//!
+//! ```text
+//! Topology:      D | UN header, then <from>-><to> or <from>-<to> lines
+//! Weight table:  <from>,<to>,<weight>          (example: A,B,7)
+//! ```
+//!
+//! # Validation and consistency rules
+//!
+//! - Only `D` and `UN` topologies are supported; `TD` graphs derive their
+//!   weights from coordinates.
+//! - Topology edge lines must not carry a weight themselves.
+//! - Every topology edge needs a table entry. Directed edges only match the
+//!   entry with the same `from`/`to` order; undirected edges match either
+//!   order.
+//! - Weights must fit into `u16`, and an edge may appear only once in the
+//!   table. Entries for edges missing from the topology are ignored.
+//! - Whitespace-only lines are ignored in both files.
+//!
+//! # Example
+//!
+//! ```rust
+//! use shortest_path_finder::data_input::weight_table_input::retrieve_graph_with_weight_table;
+//! use shortest_path_finder::graphs::graph::Graph;
+//! use std::{
+//!     fs,
+//!     time::{SystemTime, UNIX_EPOCH},
+//! };
+//!
+//! let unique_id = SystemTime::now()
+//!     .duration_since(UNIX_EPOCH)
+//!     .expect("system clock should be after UNIX epoch")
+//!     .as_nanos();
+//! let topology = std::env::temp_dir().join(format!("pathfinder-doc-topology-{}.txt", unique_id));
+//! let weights = std::env::temp_dir().join(format!("pathfinder-doc-weights-{}.txt", unique_id));
+//! fs::write(&topology, "D\nA->B\n").expect("temporary topology file should be writable");
+//! fs::write(&weights, "A,B,7\n").expect("temporary weight table should be writable");
+//!
+//! let parsed = retrieve_graph_with_weight_table(
+//!     &topology.to_string_lossy(),
+//!     &weights.to_string_lossy(),
+//! )
+//! .expect("every edge has a weight");
+//! let graph = parsed.directed_graph.expect("topology is directed");
+//! let a = graph.get_node_by_id("A").unwrap();
+//! assert_eq!(graph.neighbors(a).next().unwrap().1, 7);
+//!
+//! let _ = fs::remove_file(topology);
+//! let _ = fs::remove_file(weights);
+//! ```
+
+use std::{collections::HashMap, fs};
+
+use crate::{
+    data_input::file_input::{
+        FileInputError, FileInputGraphResult, FileInputOptions, generate_graph_from_file,
+        split_once_unquoted, unquote_node_id,
+    },
+    error::parse_error::ParseError,
+    graphs::{directed::DirectedGraph, graph::Graph, undirected::UndirectedGraph},
+};
+
+/// Weight table keyed by `(from, to)` node IDs.
+type WeightTable = HashMap<(String, String), u16>;
+
+/// Reads a weightless topology file and a weight table and joins them into
+/// one graph.
+///
+/// See the [module docs](self) for both file formats.
+///
+/// # Parameters
+///
+/// - `topology`: Path to the topology file.
+/// - `weights`: Path to the `from,to,weight` table.
+///
+/// # Returns
+///
+/// - `Ok(FileInputGraphResult)` with `directed_graph` or `undirected_graph`
+///   set, depending on the topology header.
+///
+/// # Errors
+///
+/// - [`FileInputError::Io`] if either file cannot be read.
+/// - [`FileInputError::Parse`] if a table line is malformed or duplicated,
+///   the topology header is not `D`/`UN`, a topology line already carries a
+///   weight, an edge has no table entry, or the joined lines fail regular
+///   file-input validation.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::data_input::{
+///     file_input::FileInputError, weight_table_input::retrieve_graph_with_weight_table,
+/// };
+///
+/// let err = retrieve_graph_with_weight_table("does/not/exist.txt", "weights.txt").unwrap_err();
+/// assert!(matches!(err, FileInputError::Io { .. }));
+/// ```
+pub fn retrieve_graph_with_weight_table(
+    topology: &str,
+    weights: &str,
+) -> Result<FileInputGraphResult, FileInputError> {
+    let read = |path: &str| {
+        fs::read_to_string(path).map_err(|source| FileInputError::Io {
+            path: path.to_string(),
+            source,
+        })
+    };
+    let topology_content = read(topology)?;
+    let weights_content = read(weights)?;
+
+    let weight_table = parse_weight_table(&weights_content).map_err(FileInputError::Parse)?;
+    let joined = join_topology_with_weights(&topology_content, &weight_table)
+        .map_err(FileInputError::Parse)?;
+
+    generate_graph_from_file(joined, &FileInputOptions::default()).map_err(FileInputError::Parse)
+}
+
+/// Parses `from,to,weight` lines into a [`WeightTable`].
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidDataInput`] naming the line for a malformed
+/// entry, a weight outside the `u16` range, or a repeated `(from, to)` pair.
+fn parse_weight_table(content: &str) -> Result<WeightTable, ParseError> {
+    let mut table = WeightTable::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let [from, to, weight] = fields.as_slice() else {
+            return Err(ParseError::InvalidDataInput(format!(
+                "Weight table line {} ('{}') must have the form 'from,to,weight'!",
+                index + 1,
+                line
+            )));
+        };
+        let weight: u16 = weight.parse().map_err(|_| {
+            ParseError::InvalidDataInput(format!(
+                "Weight table line {} has the invalid weight '{}'; expected 0..=65535!",
+                index + 1,
+                weight
+            ))
+        })?;
+
+        if table
+            .insert((from.to_string(), to.to_string()), weight)
+            .is_some()
+        {
+            return Err(ParseError::InvalidDataInput(format!(
+                "Weight table line {} repeats the edge '{}' -> '{}'!",
+                index + 1,
+                from,
+                to
+            )));
+        }
+    }
+
+    Ok(table)
+}
+
+/// Rewrites every topology edge line into a weighted line such as `A->B:7`.
+///
+/// Lines keep their position, so line numbers in later parse errors still
+/// match the topology file. Lines that cannot be split into two endpoints
+/// are passed through unchanged and rejected by the regular syntax check.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidDataInput`] for an unsupported header, a line
+/// that already has a weight, or an edge without a table entry.
+fn join_topology_with_weights(content: &str, table: &WeightTable) -> Result<String, ParseError> {
+    let mut lines = content.lines();
+    let header = lines.next().map(str::trim).unwrap_or_default();
+
+    let separator = if header.eq_ignore_ascii_case(&<DirectedGraph>::abbreviation()) {
+        "->"
+    } else if header.eq_ignore_ascii_case(&UndirectedGraph::abbreviation()) {
+        "-"
+    } else {
+        return Err(ParseError::InvalidDataInput(format!(
+            "Invalid topology header '{}'. Expected exactly one of: D, UN.",
+            header
+        )));
+    };
+    let directed = separator == "->";
+
+    let mut joined = vec![header.to_string()];
+    for (index, line) in lines.enumerate() {
+        // Line numbers are 1-based and include the header.
+        let line_number = index + 2;
+        let line = line.trim();
+
+        let (edge, kind) = match split_once_unquoted(line, "@") {
+            Some((edge, kind)) => (edge, Some(kind)),
+            None => (line, None),
+        };
+        let Some((from, to)) = split_once_unquoted(edge, separator) else {
+            joined.push(line.to_string());
+            continue;
+        };
+        if to.contains(':') {
+            return Err(ParseError::InvalidDataInput(format!(
+                "Topology line {} ('{}') already has a weight; weights belong in the weight table!",
+                line_number, line
+            )));
+        }
+
+        // Table entries name nodes without quotes.
+        let key = (
+            unquote_node_id(from).to_string(),
+            unquote_node_id(to).to_string(),
+        );
+        let weight = table.get(&key).or_else(|| {
+            (!directed)
+                .then(|| table.get(&(key.1.clone(), key.0.clone())))
+                .flatten()
+        });
+        let Some(weight) = weight else {
+            return Err(ParseError::InvalidDataInput(format!(
+                "Topology line {}: the edge '{}' {} '{}' has no entry in the weight table!",
+                line_number, from, separator, to
+            )));
+        };
+
+        let mut weighted = format!("{}{}{}:{}", from, separator, to, weight);
+        if let Some(kind) = kind {
+            weighted.push('@');
+            weighted.push_str(kind);
+        }
+        joined.push(weighted);
+    }
+
+    Ok(joined.join("\n"))
+}
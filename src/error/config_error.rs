@@ -33,6 +33,19 @@ pub enum ConfigParseError {
     UnknownFlag { flag: String, index: usize },
     /// A non-flag token appeared where a flag was expected.
     UnexpectedArgument { value: String, index: usize },
+    /// A flag value could not be converted into the expected type.
+    InvalidValueForFlag {
+        flag: String,
+        value: String,
+        expected: &'static str,
+    },
+    /// `--help` or `-h` was passed; the caller should print
+    /// [`AppConfig::usage`](crate::cmd_line::app_config::AppConfig::usage)
+    /// and exit successfully.
+    HelpRequested,
+    /// The arguments were rejected by `clap` for a reason without a more
+    /// specific variant; `message` is `clap`'s description.
+    InvalidArguments { message: String },
 }
 
 impl fmt::Display for ConfigParseError {
@@ -68,6 +81,19 @@ impl fmt::Display for ConfigParseError {
                 "Unexpected argument '{}' at index {}. Flags must start with '--'.",
                 value, index
             ),
+            ConfigParseError::InvalidValueForFlag {
+                flag,
+                value,
+                expected,
+            } => write!(
+                f,
+                "Invalid value '{}' for flag {}. Expected {}.",
+                value, flag, expected
+            ),
+            ConfigParseError::HelpRequested => write!(f, "Help requested."),
+            ConfigParseError::InvalidArguments { message } => {
+                write!(f, "Invalid arguments: {}.", message)
+            }
         }
     }
 }
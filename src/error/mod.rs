@@ -4,6 +4,8 @@
 //!
 //! The crate currently exposes parsing-focused error types through
 //! [`parse_error`] and CLI setup parsing errors through [`config_error`].
+//! [`pathfinder_error`] unifies these and all other crate errors behind one
+//! `#[non_exhaustive]` enum for `?`-based propagation.
 //!
 //! # Usage
 //!
@@ -17,3 +19,5 @@
 pub mod config_error;
 
 pub mod parse_error;
+
+pub mod pathfinder_error;
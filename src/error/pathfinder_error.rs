@@ -0,0 +1,236 @@
+//! Crate-wide error type that unifies all specific error types.
+//!
+//! # Overview
+//!
+//! Each module keeps its own precise error type ([`DijkstraError`],
+//! [`ParseError`], [`ConfigParseError`], graph insertion errors, ...). For
+//! callers that only want to propagate failures, [`PathfinderError`] wraps
+//! every one of them and implements `From` for each, so a single
+//! `Result<_, PathfinderError>` works with `?` across the whole crate.
+//!
+//! The enum is `#[non_exhaustive]`: new variants are added as the crate grows,
+//! so matches on it need a wildcard arm.
+//!
+//! # Example
+//!
+//! ```rust
+//! use shortest_path_finder::algorithms::algorithm::{Algorithm, SearchResult};
+//! use shortest_path_finder::algorithms::dijkstra::DijkstraAlgorithm;
+//! use shortest_path_finder::error::pathfinder_error::PathfinderError;
+//! use shortest_path_finder::graphs::directed::DirectedGraph;
+//!
+//! fn route_length(edges: &[(String, String, u16)]) -> Result<u16, PathfinderError> {
+//!     let graph = DirectedGraph::from_edges(edges)?;
+//!     let result = DijkstraAlgorithm::new(graph).shortest_path("A", "C")?;
+//!     Ok(result.get_total_distance())
+//! }
+//!
+//! let edges = vec![
+//!     ("A".to_string(), "B".to_string(), 2),
+//!     ("B".to_string(), "C".to_string(), 3),
+//! ];
+//! assert_eq!(route_length(&edges).unwrap(), 5);
+//!
+//! let err = route_length(&edges[..1]).unwrap_err();
+//! assert!(matches!(err, PathfinderError::Dijkstra(_)));
+//! ```
+
+use std::{error::Error, fmt, io};
+
+#[cfg(feature = "sqlite")]
+use crate::data_input::sqlite_input::SqliteInputError;
+use crate::{
+    algorithms::{
+        a_star_algorithm::a_star::AStarExecutionError, bellman_ford::BellmanFordError,
+        bfs::BfsError, dfs::DfsError, dijkstra::DijkstraError, registry::CustomAlgorithmError,
+    },
+    data_input::file_input::FileInputError,
+    error::{config_error::ConfigParseError, parse_error::ParseError},
+    graphs::{
+        directed::DirectedGraphInsertionError,
+        two_dimensional_coordinate_graph::TwoDimensionalGraphInsertionError,
+        undirected::UndirectedGraphInsertionError, unweighted::UnweightedGraphInsertionError,
+    },
+};
+
+/// Any error produced by the crate.
+///
+/// Every variant wraps the specific error type unchanged, so callers can
+/// still match on the details after converting.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PathfinderError {
+    /// Command-line arguments could not be parsed.
+    Config(ConfigParseError),
+    /// Graph text could not be parsed.
+    Parse(ParseError),
+    /// A graph file could not be read or parsed.
+    FileInput(FileInputError),
+    /// A graph database could not be read or turned into a graph.
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteInputError),
+    /// Dijkstra could not compute a result.
+    Dijkstra(DijkstraError),
+    /// A* could not compute a result.
+    AStar(AStarExecutionError),
+    /// Breadth-first search could not compute a result.
+    Bfs(BfsError),
+    /// Bellman-Ford could not compute a result.
+    BellmanFord(BellmanFordError),
+    /// Depth-first search could not compute a result.
+    Dfs(DfsError),
+    /// A registered custom algorithm could not compute a result.
+    CustomAlgorithm(CustomAlgorithmError),
+    /// Inserting into a directed graph failed.
+    DirectedGraphInsertion(DirectedGraphInsertionError),
+    /// Inserting into an undirected graph failed.
+    UndirectedGraphInsertion(UndirectedGraphInsertionError),
+    /// Inserting into a two-dimensional graph with `i32` coordinates failed.
+    TwoDimensionalGraphInsertion(TwoDimensionalGraphInsertionError),
+    /// Inserting into an unweighted graph failed.
+    UnweightedGraphInsertion(UnweightedGraphInsertionError),
+    /// Writing output failed.
+    Io(io::Error),
+    /// The requested combination of graph, algorithm, and options is not
+    /// supported.
+    Unsupported(String),
+}
+
+impl fmt::Display for PathfinderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathfinderError::Config(err) => write!(f, "{}", err),
+            PathfinderError::Parse(err) => write!(f, "{}", err),
+            PathfinderError::FileInput(err) => write!(f, "{}", err),
+            #[cfg(feature = "sqlite")]
+            PathfinderError::Sqlite(err) => write!(f, "{}", err),
+            PathfinderError::Dijkstra(err) => write!(f, "{}", err),
+            PathfinderError::AStar(err) => write!(f, "{}", err),
+            PathfinderError::Bfs(err) => write!(f, "{}", err),
+            PathfinderError::BellmanFord(err) => write!(f, "{}", err),
+            PathfinderError::Dfs(err) => write!(f, "{}", err),
+            PathfinderError::CustomAlgorithm(err) => write!(f, "{}", err),
+            PathfinderError::DirectedGraphInsertion(err) => write!(f, "{}", err),
+            PathfinderError::UndirectedGraphInsertion(err) => write!(f, "{}", err),
+            PathfinderError::TwoDimensionalGraphInsertion(err) => write!(f, "{}", err),
+            PathfinderError::UnweightedGraphInsertion(err) => write!(f, "{}", err),
+            PathfinderError::Io(err) => write!(f, "I/O error: {}", err),
+            PathfinderError::Unsupported(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for PathfinderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PathfinderError::Config(err) => Some(err),
+            PathfinderError::Parse(err) => Some(err),
+            PathfinderError::FileInput(err) => Some(err),
+            #[cfg(feature = "sqlite")]
+            PathfinderError::Sqlite(err) => Some(err),
+            PathfinderError::Dijkstra(err) => Some(err),
+            PathfinderError::AStar(err) => Some(err),
+            PathfinderError::Bfs(err) => Some(err),
+            PathfinderError::BellmanFord(err) => Some(err),
+            PathfinderError::Dfs(err) => Some(err),
+            PathfinderError::CustomAlgorithm(err) => Some(err),
+            PathfinderError::DirectedGraphInsertion(err) => Some(err),
+            PathfinderError::UndirectedGraphInsertion(err) => Some(err),
+            PathfinderError::TwoDimensionalGraphInsertion(err) => Some(err),
+            PathfinderError::UnweightedGraphInsertion(err) => Some(err),
+            PathfinderError::Io(err) => Some(err),
+            PathfinderError::Unsupported(_) => None,
+        }
+    }
+}
+
+impl From<ConfigParseError> for PathfinderError {
+    fn from(err: ConfigParseError) -> Self {
+        PathfinderError::Config(err)
+    }
+}
+
+impl From<ParseError> for PathfinderError {
+    fn from(err: ParseError) -> Self {
+        PathfinderError::Parse(err)
+    }
+}
+
+impl From<FileInputError> for PathfinderError {
+    fn from(err: FileInputError) -> Self {
+        PathfinderError::FileInput(err)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<SqliteInputError> for PathfinderError {
+    fn from(err: SqliteInputError) -> Self {
+        PathfinderError::Sqlite(err)
+    }
+}
+
+impl From<DijkstraError> for PathfinderError {
+    fn from(err: DijkstraError) -> Self {
+        PathfinderError::Dijkstra(err)
+    }
+}
+
+impl From<AStarExecutionError> for PathfinderError {
+    fn from(err: AStarExecutionError) -> Self {
+        PathfinderError::AStar(err)
+    }
+}
+
+impl From<BfsError> for PathfinderError {
+    fn from(err: BfsError) -> Self {
+        PathfinderError::Bfs(err)
+    }
+}
+
+impl From<BellmanFordError> for PathfinderError {
+    fn from(err: BellmanFordError) -> Self {
+        PathfinderError::BellmanFord(err)
+    }
+}
+
+impl From<DfsError> for PathfinderError {
+    fn from(err: DfsError) -> Self {
+        PathfinderError::Dfs(err)
+    }
+}
+
+impl From<CustomAlgorithmError> for PathfinderError {
+    fn from(err: CustomAlgorithmError) -> Self {
+        PathfinderError::CustomAlgorithm(err)
+    }
+}
+
+impl From<DirectedGraphInsertionError> for PathfinderError {
+    fn from(err: DirectedGraphInsertionError) -> Self {
+        PathfinderError::DirectedGraphInsertion(err)
+    }
+}
+
+impl From<UndirectedGraphInsertionError> for PathfinderError {
+    fn from(err: UndirectedGraphInsertionError) -> Self {
+        PathfinderError::UndirectedGraphInsertion(err)
+    }
+}
+
+impl From<TwoDimensionalGraphInsertionError> for PathfinderError {
+    fn from(err: TwoDimensionalGraphInsertionError) -> Self {
+        PathfinderError::TwoDimensionalGraphInsertion(err)
+    }
+}
+
+impl From<UnweightedGraphInsertionError> for PathfinderError {
+    fn from(err: UnweightedGraphInsertionError) -> Self {
+        PathfinderError::UnweightedGraphInsertion(err)
+    }
+}
+
+impl From<io::Error> for PathfinderError {
+    fn from(err: io::Error) -> Self {
+        PathfinderError::Io(err)
+    }
+}
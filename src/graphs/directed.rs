@@ -4,6 +4,9 @@
 //!
 //! This module provides a concrete weighted directed graph type:
 //! - [`DirectedGraph`] stores [`DefaultNode`] values and adjacency data.
+//!   Its weight type defaults to `u16`.
+//! - [`SignedDirectedGraph`] is the `i32`-weighted variant used for graphs
+//!   that contain negative edge weights.
 //! - [`DirectedGraphInsertionError`] reports insertion failures.
 //!
 //! It implements the shared [`Graph`](crate::graphs::graph::Graph) trait and
@@ -29,12 +32,24 @@
 //! assert!(graph.is_directed());
 //! ```
 
-use std::{collections::HashMap, error::Error, fmt::Display};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    error::Error,
+    fmt::Display,
+};
 
 use log::info;
 
 use crate::{
-    graphs::graph::{Graph, GraphNode},
+    graphs::{
+        graph::{AdjacencyMatrix, Graph, GraphNode, GraphWeight, LabeledNeighbor},
+        undirected::UndirectedGraph,
+        utils::{
+            AdjacencyEntry, EdgeLabels, contract_chains, extract_component, induce_by_edges,
+            partition_isolated, remap_node_ids, strongly_connected_components,
+            weakly_connected_components,
+        },
+    },
     nodes::default_node::DefaultNode,
 };
 
@@ -68,20 +83,55 @@ use crate::{
 /// assert_eq!(graph.get_all_nodes().len(), 2);
 /// assert_eq!(graph.neighbors(&from).count(), 1);
 /// ```
+///
+/// # Weight type
+///
+/// `W` defaults to `u16`, which is what [`DirectedGraph::new`] and
+/// [`DirectedGraph::default`] produce. Use [`SignedDirectedGraph`] when edges
+/// may carry negative weights.
 #[derive(Debug, Clone)]
-pub struct DirectedGraph {
+pub struct DirectedGraph<W: GraphWeight = u16> {
     /// All nodes currently contained in the graph.
     nodes: Vec<DefaultNode>,
     /// Fast ID-to-index lookup for node access.
     node_index_by_id: HashMap<String, usize>,
-    /// Adjacency list storing `(to_index, weight)` for each source node index.
-    adjacency: Vec<Vec<(usize, u16)>>,
+    /// Adjacency list storing `(to_index, weight, labels)` for each source
+    /// node index. The labels hold the edge kind (for example `highway`) and
+    /// the layer (for example `train`) of that one edge.
+    adjacency: Vec<Vec<AdjacencyEntry<W>>>,
+    /// Set once any edge weight was filled in instead of given explicitly
+    /// (see [`Self::mark_weights_implicit`]); [`Graph::is_weighted`] then
+    /// returns `false`.
+    has_implicit_weights: bool,
+    /// Set by [`DirectedGraph::new_multigraph`]; lets [`Graph::insert_edge`]
+    /// add further edges between the same two nodes.
+    allows_parallel_edges: bool,
 }
 
-impl Graph for DirectedGraph {
+/// Directed graph with signed `i32` weights.
+///
+/// Negative weights are stored as-is. Dijkstra rejects such graphs, so they
+/// are meant for algorithms that handle negative edges (Bellman-Ford).
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::graphs::directed::SignedDirectedGraph;
+/// use shortest_path_finder::graphs::graph::Graph;
+/// use shortest_path_finder::nodes::default_node::DefaultNode;
+///
+/// let a = DefaultNode::new("A".to_string());
+/// let b = DefaultNode::new("B".to_string());
+/// let mut graph = SignedDirectedGraph::new_signed(vec![a.clone(), b.clone()]);
+/// assert!(graph.insert_edge(&a, &b, Some(-3)).is_none());
+/// assert_eq!(graph.neighbors(&a).next().unwrap().1, -3);
+/// ```
+pub type SignedDirectedGraph = DirectedGraph<i32>;
+
+impl<W: GraphWeight> Graph for DirectedGraph<W> {
     type Node = DefaultNode;
 
-    type Weight = u16;
+    type Weight = W;
 
     type InsertionError = DirectedGraphInsertionError;
 
@@ -100,10 +150,40 @@ impl Graph for DirectedGraph {
         Box::new(
             self.adjacency[source_index]
                 .iter()
-                .map(move |(neighbor_index, weight)| (&self.nodes[*neighbor_index], *weight)),
+                .map(move |(neighbor_index, weight, _)| (&self.nodes[*neighbor_index], *weight)),
         )
     }
 
+    fn labeled_neighbors<'a>(
+        &'a self,
+        u: &Self::Node,
+    ) -> Box<dyn Iterator<Item = LabeledNeighbor<'a, Self::Node, Self::Weight>> + 'a> {
+        let Some(source_index) = self.node_index_for_id(u.get_id()) else {
+            return Box::new(std::iter::empty());
+        };
+
+        Box::new(self.adjacency[source_index].iter().map(
+            move |(neighbor_index, weight, labels)| {
+                (
+                    &self.nodes[*neighbor_index],
+                    *weight,
+                    labels.kind.as_deref(),
+                    labels.layer.as_deref(),
+                )
+            },
+        ))
+    }
+
+    fn out_degree(&self, u: &Self::Node) -> usize {
+        self.node_index_for_id(u.get_id())
+            .map_or(0, |index| self.adjacency[index].len())
+    }
+
+    fn degree(&self, id: &str) -> Option<usize> {
+        let index = self.node_index_for_id(id)?;
+        Some(self.adjacency[index].len() + self.in_degree(id)?)
+    }
+
     fn insert_node(&mut self, new_node: Self::Node) {
         if self.does_node_already_exist(&new_node) {
             return;
@@ -122,7 +202,7 @@ impl Graph for DirectedGraph {
         to: &Self::Node,
         weight: Option<Self::Weight>,
     ) -> Option<Self::InsertionError> {
-        if self.does_edge_already_exist(from, to) {
+        if !self.allows_parallel_edges && self.does_edge_already_exist(from, to) {
             return Some(DirectedGraphInsertionError::new(format!(
                 "Edge from '{}' to '{}' already exists!",
                 from.get_id(),
@@ -164,7 +244,7 @@ impl Graph for DirectedGraph {
             }
         };
 
-        self.adjacency[from_index].push((to_index, weight));
+        self.adjacency[from_index].push((to_index, weight, EdgeLabels::default()));
 
         None
     }
@@ -176,7 +256,7 @@ impl Graph for DirectedGraph {
         ) {
             return self.adjacency[from_index]
                 .iter()
-                .any(|(neighbor_index, _)| *neighbor_index == to_index);
+                .any(|(neighbor_index, ..)| *neighbor_index == to_index);
         }
         false
     }
@@ -196,7 +276,15 @@ impl Graph for DirectedGraph {
     }
 
     fn is_weighted(&self) -> bool {
-        true
+        !self.has_implicit_weights
+    }
+
+    fn edge_kind(&self, from: &Self::Node, to: &Self::Node) -> Option<&str> {
+        self.edge_labels(from, to)?.kind.as_deref()
+    }
+
+    fn edge_layer(&self, from: &Self::Node, to: &Self::Node) -> Option<&str> {
+        self.edge_labels(from, to)?.layer.as_deref()
     }
 
     fn abbreviation() -> String {
@@ -204,7 +292,31 @@ impl Graph for DirectedGraph {
     }
 }
 
-impl DirectedGraph {
+impl<W: GraphWeight> DirectedGraph<W> {
+    /// Creates an empty graph and inserts `nodes`, ignoring duplicate IDs.
+    pub(crate) fn with_nodes(nodes: Vec<DefaultNode>) -> Self {
+        let mut graph = Self {
+            nodes: Vec::new(),
+            node_index_by_id: HashMap::new(),
+            adjacency: Vec::new(),
+            has_implicit_weights: false,
+            allows_parallel_edges: false,
+        };
+
+        for node in nodes {
+            graph.insert_node(node);
+        }
+
+        graph
+    }
+
+    /// Like [`Self::with_nodes`], but the graph accepts parallel edges.
+    pub(crate) fn multigraph_with_nodes(nodes: Vec<DefaultNode>) -> Self {
+        let mut graph = Self::with_nodes(nodes);
+        graph.allows_parallel_edges = true;
+        graph
+    }
+
     /// Looks up the index of a node by its string identifier.
     ///
     /// # Parameters
@@ -219,54 +331,441 @@ impl DirectedGraph {
         self.node_index_by_id.get(id).copied()
     }
 
-    /// Creates a new directed graph from a node vector.
+    /// Returns whether the graph accepts parallel edges, see
+    /// [`DirectedGraph::new_multigraph`].
+    pub fn is_multigraph(&self) -> bool {
+        self.allows_parallel_edges
+    }
+
+    /// Inserts an edge like [`Graph::insert_edge`] and tags it with a kind.
     ///
     /// # Parameters
     ///
-    /// - `nodes`: initial node list.
-    ///   Duplicate node IDs are ignored.
+    /// - `from`, `to`, `weight`: Same as [`Graph::insert_edge`].
+    /// - `kind`: Optional edge kind such as `highway` or `ferry`, later
+    ///   reported by [`Graph::edge_kind`]. `None` behaves exactly like
+    ///   [`Graph::insert_edge`].
     ///
     /// # Returns
     ///
-    /// A new [`DirectedGraph`] instance.
+    /// - `None` on success.
+    /// - `Some(DirectedGraphInsertionError)` when the edge could not be inserted; no kind is
+    ///   recorded in that case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph
+    ///     .insert_edge_with_kind(&a, &b, Some(4), Some("highway".to_string()))
+    ///     .is_none());
+    /// assert_eq!(graph.edge_kind(&a, &b), Some("highway"));
+    /// ```
+    pub fn insert_edge_with_kind(
+        &mut self,
+        from: &DefaultNode,
+        to: &DefaultNode,
+        weight: Option<W>,
+        kind: Option<String>,
+    ) -> Option<DirectedGraphInsertionError> {
+        self.insert_edge_with_labels(from, to, weight, kind, None)
+    }
+
+    /// Inserts an edge like [`Graph::insert_edge`] and tags it with a kind
+    /// and a layer.
+    ///
+    /// Unlike [`DirectedGraph::set_edge_layer`], which acts on the earliest
+    /// `from -> to` edge, this labels exactly the inserted edge, so parallel
+    /// edges of a multigraph can carry different kinds and layers.
+    ///
+    /// # Parameters
+    ///
+    /// - `from`, `to`, `weight`: Same as [`Graph::insert_edge`].
+    /// - `kind`: Optional edge kind, as in [`Self::insert_edge_with_kind`].
+    /// - `layer`: Optional layer, as in [`Self::set_edge_layer`].
+    ///
+    /// # Returns
+    ///
+    /// - `None` on success.
+    /// - `Some(DirectedGraphInsertionError)` when the edge could not be
+    ///   inserted; no label is recorded in that case.
     ///
     /// # Example
     ///
     /// ```rust
     /// use shortest_path_finder::graphs::directed::DirectedGraph;
     /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
     ///
-    /// let graph = DirectedGraph::new(vec![]);
-    /// assert_eq!(graph.get_all_nodes().len(), 0);
-    /// assert_eq!(graph.get_all_nodes().len(), 0);
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new_multigraph(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(2)).is_none());
+    /// assert!(graph
+    ///     .insert_edge_with_labels(&a, &b, Some(5), None, Some("bus".to_string()))
+    ///     .is_none());
+    ///
+    /// let layers: Vec<_> = graph.labeled_neighbors(&a).map(|(_, _, _, layer)| layer).collect();
+    /// assert_eq!(layers, vec![None, Some("bus")]);
     /// ```
-    pub fn new(nodes: Vec<DefaultNode>) -> Self {
-        let mut graph = Self {
-            nodes: Vec::new(),
-            node_index_by_id: HashMap::new(),
-            adjacency: Vec::new(),
+    pub fn insert_edge_with_labels(
+        &mut self,
+        from: &DefaultNode,
+        to: &DefaultNode,
+        weight: Option<W>,
+        kind: Option<String>,
+        layer: Option<String>,
+    ) -> Option<DirectedGraphInsertionError> {
+        if let Some(err) = self.insert_edge(from, to, weight) {
+            return Some(err);
+        }
+
+        // `insert_edge` succeeded, so both nodes exist and the new edge is last.
+        if let Some(entry) = self
+            .node_index_for_id(from.get_id())
+            .and_then(|from_index| self.adjacency[from_index].last_mut())
+        {
+            entry.2 = EdgeLabels { kind, layer };
+        }
+
+        None
+    }
+
+    /// Returns the labels of the earliest inserted edge `from -> to`.
+    fn edge_labels(&self, from: &DefaultNode, to: &DefaultNode) -> Option<&EdgeLabels> {
+        let from_index = self.node_index_for_id(from.get_id())?;
+        let to_index = self.node_index_for_id(to.get_id())?;
+        self.adjacency[from_index]
+            .iter()
+            .find(|(neighbor_index, ..)| *neighbor_index == to_index)
+            .map(|(_, _, labels)| labels)
+    }
+
+    /// Removes the edge `from -> to`, including its kind.
+    ///
+    /// The reverse edge `to -> from` is left untouched.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the edge existed and was removed.
+    /// - `false` if either node or the edge does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(4)).is_none());
+    ///
+    /// assert!(graph.remove_edge(&a, &b));
+    /// assert!(!graph.does_edge_already_exist(&a, &b));
+    /// assert!(!graph.remove_edge(&a, &b));
+    /// ```
+    pub fn remove_edge(&mut self, from: &DefaultNode, to: &DefaultNode) -> bool {
+        let (Some(from_index), Some(to_index)) = (
+            self.node_index_for_id(from.get_id()),
+            self.node_index_for_id(to.get_id()),
+        ) else {
+            return false;
         };
 
-        for node in nodes {
-            graph.insert_node(node);
+        let neighbors = &mut self.adjacency[from_index];
+        let Some(position) = neighbors
+            .iter()
+            .position(|(neighbor_index, ..)| *neighbor_index == to_index)
+        else {
+            return false;
+        };
+        neighbors.remove(position);
+        true
+    }
+
+    /// Removes the node with ID `id` together with every incoming and outgoing edge of the node.
+    ///
+    /// Kinds and layers of the removed edges are dropped as well, so no
+    /// dangling edge remains. Remaining nodes keep their relative order.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(node)` with the removed node.
+    /// - `None` if no node has this ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone(), c.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(4)).is_none());
+    /// assert!(graph.insert_edge(&b, &c, Some(2)).is_none());
+    ///
+    /// assert_eq!(graph.remove_node("B"), Some(b));
+    /// assert_eq!(graph.get_all_nodes(), &vec![a.clone(), c.clone()]);
+    /// assert_eq!(graph.neighbors(&a).count(), 0);
+    /// assert_eq!(graph.remove_node("B"), None);
+    /// ```
+    pub fn remove_node(&mut self, id: &str) -> Option<DefaultNode> {
+        let removed_index = self.node_index_for_id(id)?;
+        let removed = self.nodes[removed_index].clone();
+
+        // Extracting every other node drops all edges touching the removed one.
+        let remaining: Vec<usize> = (0..self.nodes.len())
+            .filter(|&index| index != removed_index)
+            .collect();
+        let parts = extract_component(&self.nodes, &self.adjacency, &remaining);
+        self.nodes = parts.nodes;
+        self.node_index_by_id = parts.node_index_by_id;
+        self.adjacency = parts.adjacency;
+        Some(removed)
+    }
+
+    /// Assigns the edge `from -> to` to a layer, or clears its layer.
+    ///
+    /// Layers are reported by [`Graph::edge_layer`] and let
+    /// [`DijkstraAlgorithm::with_disabled_layers`](crate::algorithms::dijkstra::DijkstraAlgorithm::with_disabled_layers)
+    /// switch whole groups of edges off. File input sets them from the
+    /// `#<layer>` suffix, e.g. `A->B:7#train`.
+    ///
+    /// # Parameters
+    ///
+    /// - `from`, `to`: Endpoints of an existing edge.
+    /// - `layer`: The new layer, or `None` to remove the current one.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the edge exists and its layer was updated.
+    /// - `false` if either node or the edge does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(!graph.set_edge_layer(&a, &b, Some("bus".to_string())));
+    /// assert!(graph.insert_edge(&a, &b, Some(4)).is_none());
+    ///
+    /// assert!(graph.set_edge_layer(&a, &b, Some("bus".to_string())));
+    /// assert_eq!(graph.edge_layer(&a, &b), Some("bus"));
+    /// assert!(graph.set_edge_layer(&a, &b, None));
+    /// assert_eq!(graph.edge_layer(&a, &b), None);
+    /// ```
+    pub fn set_edge_layer(
+        &mut self,
+        from: &DefaultNode,
+        to: &DefaultNode,
+        layer: Option<String>,
+    ) -> bool {
+        let (Some(from_index), Some(to_index)) = (
+            self.node_index_for_id(from.get_id()),
+            self.node_index_for_id(to.get_id()),
+        ) else {
+            return false;
+        };
+        let Some(entry) = self.adjacency[from_index]
+            .iter_mut()
+            .find(|(neighbor_index, ..)| *neighbor_index == to_index)
+        else {
+            return false;
+        };
+        entry.2.layer = layer;
+        true
+    }
+
+    /// Changes the weight of the existing edge `from -> to`.
+    ///
+    /// Kind and layer are kept, so dynamic routing can adjust costs without
+    /// removing and re-inserting edges. The reverse edge `to -> from` is left
+    /// untouched. [`Graph::is_weighted`] is not affected.
+    ///
+    /// # Parameters
+    ///
+    /// - `from`, `to`: Endpoints of an existing edge.
+    /// - `weight`: The new weight.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the edge exists and its weight was updated.
+    /// - `false` if either node or the edge does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(7)).is_none());
+    ///
+    /// assert!(graph.set_edge_weight(&a, &b, 2));
+    /// assert_eq!(graph.neighbors(&a).next().unwrap().1, 2);
+    /// assert!(!graph.set_edge_weight(&b, &a, 2));
+    /// ```
+    pub fn set_edge_weight(&mut self, from: &DefaultNode, to: &DefaultNode, weight: W) -> bool {
+        let (Some(from_index), Some(to_index)) = (
+            self.node_index_for_id(from.get_id()),
+            self.node_index_for_id(to.get_id()),
+        ) else {
+            return false;
+        };
+        let Some(entry) = self.adjacency[from_index]
+            .iter_mut()
+            .find(|(neighbor_index, ..)| *neighbor_index == to_index)
+        else {
+            return false;
+        };
+        entry.1 = weight;
+        true
+    }
+
+    /// Removes every node with neither an incoming nor an outgoing edge and returns the removed nodes.
+    ///
+    /// Useful after [`DirectedGraph::remove_edge`] calls leave orphaned nodes
+    /// behind. Remaining nodes keep their relative order, and edges, weights
+    /// and kinds are unchanged. A node with a self-loop is not isolated.
+    ///
+    /// # Returns
+    ///
+    /// The removed nodes in insertion order; empty if no node was isolated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone(), c.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(4)).is_none());
+    /// assert!(graph.insert_edge(&b, &c, Some(2)).is_none());
+    /// assert!(graph.remove_edge(&b, &c));
+    ///
+    /// assert_eq!(graph.remove_isolated_nodes(), vec![c]);
+    /// assert_eq!(graph.get_all_nodes(), &vec![a, b]);
+    /// ```
+    pub fn remove_isolated_nodes(&mut self) -> Vec<DefaultNode> {
+        let (connected, isolated) = partition_isolated(&self.adjacency);
+        if isolated.is_empty() {
+            return Vec::new();
         }
 
-        graph
+        let removed = isolated
+            .iter()
+            .map(|&index| self.nodes[index].clone())
+            .collect();
+        let parts = extract_component(&self.nodes, &self.adjacency, &connected);
+        self.nodes = parts.nodes;
+        self.node_index_by_id = parts.node_index_by_id;
+        self.adjacency = parts.adjacency;
+        removed
     }
-}
 
-impl Display for DirectedGraph {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Nodes: {:?}, Adjacency: {:?}",
-            self.nodes, self.adjacency
-        )
+    /// Contracts pass-through nodes of degree two into single edges and
+    /// returns how many nodes were removed.
+    ///
+    /// A node qualifies when it is the middle of a one-way chain
+    /// `u -> v -> w` or of a two-way chain (edges in both directions to both
+    /// `u` and `w`); the latter becomes `u -> w` plus `w -> u`.
+    /// The new edge weighs the sum of the two replaced edges and keeps their
+    /// kind if both share it. Contraction repeats until no node qualifies, so
+    /// a whole chain collapses into one edge between its ends, which shrinks
+    /// road-like graphs considerably before routing. Shortest distances
+    /// between the remaining nodes are unchanged.
+    ///
+    /// A node is kept if it is protected, has a self-loop, or its two
+    /// neighbors are already adjacent (the graph stores one edge per
+    /// direction). Remaining nodes keep their relative order.
+    ///
+    /// # Parameters
+    ///
+    /// - `protected`: IDs of nodes that must survive, such as route
+    ///   endpoints. Unknown IDs are ignored.
+    ///
+    /// # Returns
+    ///
+    /// The number of contracted nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashSet;
+    ///
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let nodes: Vec<DefaultNode> = ["A", "B", "C", "D"]
+    ///     .iter()
+    ///     .map(|id| DefaultNode::new(id.to_string()))
+    ///     .collect();
+    /// let mut graph = DirectedGraph::new(nodes.clone());
+    /// assert!(graph.insert_edge(&nodes[0], &nodes[1], Some(2)).is_none());
+    /// assert!(graph.insert_edge(&nodes[1], &nodes[2], Some(3)).is_none());
+    /// assert!(graph.insert_edge(&nodes[2], &nodes[3], Some(4)).is_none());
+    ///
+    /// let protected: HashSet<String> = HashSet::from(["C".to_string()]);
+    /// assert_eq!(graph.simplify_chains(&protected), 1);
+    /// assert_eq!(
+    ///     graph.to_edge_list(),
+    ///     vec![
+    ///         ("A".to_string(), "C".to_string(), 5),
+    ///         ("C".to_string(), "D".to_string(), 4),
+    ///     ]
+    /// );
+    /// ```
+    pub fn simplify_chains(&mut self, protected: &HashSet<String>) -> usize {
+        let is_protected: Vec<bool> = self
+            .nodes
+            .iter()
+            .map(|node| protected.contains(node.get_id()))
+            .collect();
+        let contracted = contract_chains(&mut self.adjacency, &is_protected);
+        if contracted.is_empty() {
+            return 0;
+        }
+
+        let remaining: Vec<usize> = (0..self.nodes.len())
+            .filter(|index| contracted.binary_search(index).is_err())
+            .collect();
+        let parts = extract_component(&self.nodes, &self.adjacency, &remaining);
+        self.nodes = parts.nodes;
+        self.node_index_by_id = parts.node_index_by_id;
+        self.adjacency = parts.adjacency;
+        contracted.len()
     }
-}
 
-impl Default for DirectedGraph {
-    /// Creates an empty directed graph.
+    /// Records that at least one edge weight was not given explicitly.
+    ///
+    /// Used by file input when a missing weight is filled in with a default.
+    /// The stored weights stay usable, but [`Graph::is_weighted`] returns
+    /// `false` from now on so callers can tell the weights apart from
+    /// measured ones.
     ///
     /// # Example
     ///
@@ -274,11 +773,880 @@ impl Default for DirectedGraph {
     /// use shortest_path_finder::graphs::directed::DirectedGraph;
     /// use shortest_path_finder::graphs::graph::Graph;
     ///
-    /// let graph = DirectedGraph::default();
-    /// assert!(graph.get_all_nodes().is_empty());
+    /// let mut graph = DirectedGraph::default();
+    /// assert!(graph.is_weighted());
+    /// graph.mark_weights_implicit();
+    /// assert!(!graph.is_weighted());
     /// ```
-    fn default() -> Self {
-        Self::new(vec![])
+    pub fn mark_weights_implicit(&mut self) {
+        self.has_implicit_weights = true;
+    }
+
+    /// Builds a graph from `(from_id, to_id, weight)` tuples.
+    ///
+    /// Nodes are created on first mention, in the order they appear. This is
+    /// the inverse of [`Graph::to_edge_list`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DirectedGraphInsertionError`] when the list contains the same edge twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    ///
+    /// let edges: Vec<(String, String, u16)> = vec![("A".to_string(), "B".to_string(), 7)];
+    /// let graph = DirectedGraph::from_edges(&edges).unwrap();
+    /// assert_eq!(graph.get_all_nodes().len(), 2);
+    /// assert_eq!(graph.to_edge_list(), edges);
+    /// ```
+    pub fn from_edges(edges: &[(String, String, W)]) -> Result<Self, DirectedGraphInsertionError> {
+        let mut graph = Self::with_nodes(vec![]);
+
+        for (from_id, to_id, weight) in edges {
+            let from = DefaultNode::new(from_id.clone());
+            let to = DefaultNode::new(to_id.clone());
+            graph.insert_node(from.clone());
+            graph.insert_node(to.clone());
+            if let Some(err) = graph.insert_edge(&from, &to, Some(*weight)) {
+                return Err(err);
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Builds a graph from nodes and `(from_id, to_id, weight)` edges, checking
+    /// every edge first.
+    ///
+    /// This is the validated counterpart to [`DirectedGraph::new`] followed by manual
+    /// [`Graph::insert_edge`] calls: instead of trusting the caller, it
+    /// reports the first inconsistent edge. Duplicate node IDs are ignored,
+    /// as in [`DirectedGraph::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the offending edge when
+    /// - an endpoint ID is not among `nodes`, or
+    /// - the same edge appears twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let nodes = vec![DefaultNode::new("A".to_string()), DefaultNode::new("B".to_string())];
+    /// let edges = vec![("A".to_string(), "B".to_string(), 3)];
+    /// let graph = DirectedGraph::try_new(nodes.clone(), &edges).unwrap();
+    /// assert_eq!(graph.to_edge_list(), edges);
+    ///
+    /// let dangling = vec![("A".to_string(), "Z".to_string(), 1)];
+    /// let err = DirectedGraph::try_new(nodes, &dangling).unwrap_err();
+    /// assert!(err.contains("'Z'"));
+    /// ```
+    pub fn try_new(nodes: Vec<DefaultNode>, edges: &[(String, String, W)]) -> Result<Self, String> {
+        let mut graph = Self::with_nodes(nodes);
+
+        for (from_id, to_id, weight) in edges {
+            let (from, to) = match (graph.get_node_by_id(from_id), graph.get_node_by_id(to_id)) {
+                (Some(from), Some(to)) => (from.clone(), to.clone()),
+                (from, _) => {
+                    let missing = if from.is_none() { from_id } else { to_id };
+                    return Err(format!(
+                        "The edge {} -> {} references the unknown node '{}'!",
+                        from_id, to_id, missing
+                    ));
+                }
+            };
+            if graph.does_edge_already_exist(&from, &to) {
+                return Err(format!(
+                    "The edge {} -> {} is listed more than once!",
+                    from_id, to_id
+                ));
+            }
+            if let Some(err) = graph.insert_edge(&from, &to, Some(*weight)) {
+                return Err(err.message);
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Builds a graph from node labels and a weighted adjacency matrix.
+    ///
+    /// Creates one node per label, in label order, and one edge from node `i`
+    /// to node `j` for every `Some(weight)` in `matrix[i][j]`. This is the
+    /// inverse of [`Graph::adjacency_matrix`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a message when
+    /// - the matrix does not have one row per label,
+    /// - a row does not have one cell per label, or
+    /// - a label appears twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    ///
+    /// let labels = vec!["A".to_string(), "B".to_string()];
+    /// let graph = DirectedGraph::from_adjacency_matrix(
+    ///     labels.clone(),
+    ///     vec![vec![None, Some(7u16)], vec![None, None]],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(graph.to_edge_list(), vec![("A".to_string(), "B".to_string(), 7)]);
+    ///
+    /// assert!(DirectedGraph::from_adjacency_matrix(labels, vec![vec![Some(1u16)]]).is_err());
+    /// ```
+    pub fn from_adjacency_matrix(
+        labels: Vec<String>,
+        matrix: AdjacencyMatrix<W>,
+    ) -> Result<Self, String> {
+        if matrix.len() != labels.len() {
+            return Err(format!(
+                "The adjacency matrix has {} rows, but {} node labels were given!",
+                matrix.len(),
+                labels.len()
+            ));
+        }
+        if let Some((row_index, row)) = matrix
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.len() != labels.len())
+        {
+            return Err(format!(
+                "Row {} of the adjacency matrix has {} cells, but it must be square with {} columns!",
+                row_index,
+                row.len(),
+                labels.len()
+            ));
+        }
+
+        let nodes: Vec<DefaultNode> = labels.into_iter().map(DefaultNode::new).collect();
+        let mut graph = Self::with_nodes(nodes.clone());
+        if graph.get_all_nodes().len() != nodes.len() {
+            return Err("The node labels of an adjacency matrix must be unique!".to_string());
+        }
+
+        for (from, row) in nodes.iter().zip(&matrix) {
+            for (to, weight) in nodes.iter().zip(row) {
+                if let Some(weight) = weight
+                    && let Some(err) = graph.insert_edge(from, to, Some(*weight))
+                {
+                    return Err(err.message);
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Returns a copy of the graph with every node ID transformed by `f`.
+    ///
+    /// Edges and weights are carried over unchanged, so every edge keeps
+    /// pointing at the renamed versions of its original endpoints.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: Maps an old node ID to the new one, e.g. to add a region prefix
+    ///   or strip a common suffix.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Self)` with the renamed graph.
+    /// - `Err(String)` when `f` maps two distinct IDs to the same ID, which
+    ///   would silently merge two nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::{Graph, GraphNode};
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(3)).is_none());
+    ///
+    /// let prefixed = graph.map_nodes(|id| format!("north-{}", id)).unwrap();
+    /// let north_a = prefixed.get_node_by_id("north-A").unwrap();
+    /// assert_eq!(prefixed.neighbors(north_a).next().unwrap().0.get_id(), "north-B");
+    ///
+    /// assert!(graph.map_nodes(|_| "X".to_string()).is_err());
+    /// ```
+    pub fn map_nodes(&self, f: impl Fn(&str) -> String) -> Result<Self, String> {
+        let (nodes, node_index_by_id) = remap_node_ids(&self.nodes, f)?;
+
+        Ok(Self {
+            nodes,
+            node_index_by_id,
+            adjacency: self.adjacency.clone(),
+            has_implicit_weights: self.has_implicit_weights,
+            allows_parallel_edges: self.allows_parallel_edges,
+        })
+    }
+
+    /// Returns a copy of the graph whose node IDs are `"0"` to `"N-1"`, plus
+    /// the mapping from each original ID to its new index.
+    ///
+    /// Indices follow node insertion order, so relabeling the same graph
+    /// twice yields the same result. Edges, weights and kinds are carried
+    /// over unchanged. The reverse lookup (index to original ID) is simply
+    /// `self.get_all_nodes()[index]`.
+    ///
+    /// # Returns
+    ///
+    /// - The relabeled graph.
+    /// - A map from every original node ID to its dense integer index.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::{Graph, GraphNode};
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("Berlin".to_string());
+    /// let b = DefaultNode::new("Hamburg".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(3)).is_none());
+    ///
+    /// let (relabeled, index_by_id) = graph.relabel_to_integers();
+    /// assert_eq!(index_by_id["Hamburg"], 1);
+    /// let zero = relabeled.get_node_by_id("0").unwrap();
+    /// assert_eq!(relabeled.neighbors(zero).next().unwrap(), (&DefaultNode::new("1".to_string()), 3));
+    /// ```
+    pub fn relabel_to_integers(&self) -> (Self, HashMap<String, usize>) {
+        let relabeled = self
+            .map_nodes(|id| self.node_index_by_id[id].to_string())
+            .expect("node indices are unique");
+
+        (relabeled, self.node_index_by_id.clone())
+    }
+
+    /// Splits the graph into its weakly connected components, each as a
+    /// standalone graph.
+    ///
+    /// Edge direction is ignored when deciding which nodes belong together,
+    /// so `A -> B` and `C -> B` end up in one graph.
+    ///
+    /// Every component keeps its nodes, the edges between them, their
+    /// weights and kinds, and whether weights are implicit. Because no edge
+    /// crosses components, algorithms can be run per component without
+    /// missing any route.
+    ///
+    /// # Returns
+    ///
+    /// One graph per component, ordered by the insertion order of each
+    /// component's first node; nodes keep their relative order. Isolated
+    /// nodes form single-node components. An empty graph yields an empty
+    /// list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    ///
+    /// let edges: Vec<(String, String, u16)> = [("A", "B", 1), ("C", "B", 2), ("X", "Y", 5)]
+    ///     .iter()
+    ///     .map(|(from, to, weight)| (from.to_string(), to.to_string(), *weight))
+    ///     .collect();
+    /// let graph = DirectedGraph::from_edges(&edges).unwrap();
+    ///
+    /// let components = graph.split_components();
+    /// assert_eq!(components.len(), 2);
+    /// assert_eq!(components[0].get_all_nodes().len(), 3);
+    /// assert_eq!(components[1].to_edge_list(), vec![("X".to_string(), "Y".to_string(), 5)]);
+    /// ```
+    pub fn split_components(&self) -> Vec<Self> {
+        weakly_connected_components(&self.adjacency)
+            .iter()
+            .map(|members| {
+                let parts = extract_component(&self.nodes, &self.adjacency, members);
+                Self {
+                    nodes: parts.nodes,
+                    node_index_by_id: parts.node_index_by_id,
+                    adjacency: parts.adjacency,
+                    has_implicit_weights: self.has_implicit_weights,
+                    allows_parallel_edges: self.allows_parallel_edges,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the subgraph made of the edges accepted by `keep` and the nodes
+    /// they touch.
+    ///
+    /// Unlike a plain edge filter, nodes left without any kept edge are
+    /// dropped, so for example keeping only `highway` edges yields the highway
+    /// network and the towns on it. Weights, kinds, and the relative node
+    /// order are preserved.
+    ///
+    /// # Parameters
+    ///
+    /// - `keep`: Predicate over `(from, to, weight, kind)`; `kind` is the
+    ///   edge kind, if any.
+    ///
+    /// # Returns
+    ///
+    /// A new graph; `self` is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let nodes: Vec<DefaultNode> = ["A", "B", "C"]
+    ///     .iter()
+    ///     .map(|id| DefaultNode::new(id.to_string()))
+    ///     .collect();
+    /// let mut graph = DirectedGraph::new(nodes.clone());
+    /// graph.insert_edge_with_kind(&nodes[0], &nodes[1], Some(3), Some("highway".to_string()));
+    /// graph.insert_edge(&nodes[1], &nodes[2], Some(1));
+    ///
+    /// let highways = graph.induced_by_edges(|_, _, _, kind| kind == Some("highway"));
+    /// assert_eq!(highways.to_edge_list(), vec![("A".to_string(), "B".to_string(), 3)]);
+    /// assert!(highways.get_node_by_id("C").is_none());
+    /// ```
+    pub fn induced_by_edges(
+        &self,
+        keep: impl Fn(&DefaultNode, &DefaultNode, W, Option<&str>) -> bool,
+    ) -> Self {
+        let parts = induce_by_edges(&self.nodes, &self.adjacency, true, keep);
+        Self {
+            nodes: parts.nodes,
+            node_index_by_id: parts.node_index_by_id,
+            adjacency: parts.adjacency,
+            has_implicit_weights: self.has_implicit_weights,
+            allows_parallel_edges: self.allows_parallel_edges,
+        }
+    }
+
+    /// Returns the strongly connected components: groups of nodes that can
+    /// all reach each other along edge direction.
+    ///
+    /// Uses Tarjan's algorithm with an explicit stack, so deep graphs cannot
+    /// overflow the call stack. A cycle `A -> B -> C -> A` forms one
+    /// component; in a graph without cycles every node is its own component.
+    ///
+    /// # Returns
+    ///
+    /// One node list per component; nodes keep their relative order.
+    /// Components are in reverse topological order: an edge between two
+    /// components always points from a later to an earlier one. An empty
+    /// graph yields an empty list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::GraphNode;
+    ///
+    /// let edges: Vec<(String, String, u16)> = [("A", "B", 1), ("B", "A", 1), ("B", "C", 1)]
+    ///     .iter()
+    ///     .map(|(from, to, weight)| (from.to_string(), to.to_string(), *weight))
+    ///     .collect();
+    /// let graph = DirectedGraph::from_edges(&edges).unwrap();
+    ///
+    /// let components = graph.strongly_connected_components();
+    /// let ids: Vec<Vec<&str>> = components
+    ///     .iter()
+    ///     .map(|component| component.iter().map(|node| node.get_id()).collect())
+    ///     .collect();
+    /// assert_eq!(ids, vec![vec!["C"], vec!["A", "B"]]);
+    /// ```
+    pub fn strongly_connected_components(&self) -> Vec<Vec<DefaultNode>> {
+        strongly_connected_components(&self.adjacency)
+            .into_iter()
+            .map(|members| {
+                members
+                    .into_iter()
+                    .map(|index| self.nodes[index].clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Orders the nodes so that every edge `from -> to` has `from` before
+    /// `to`, using Kahn's algorithm.
+    ///
+    /// Nodes without remaining incoming edges are emitted in insertion order,
+    /// so the result is deterministic. Parallel edges and edge weights do not
+    /// affect the order.
+    ///
+    /// # Returns
+    ///
+    /// All nodes in a topological order; an empty graph yields an empty list.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message listing the nodes that could not be ordered when the
+    /// graph contains a cycle (a self-loop counts). The list holds the nodes
+    /// on a cycle plus those only reachable through one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::GraphNode;
+    ///
+    /// let edges: Vec<(String, String, u16)> = [("B", "C", 1), ("A", "B", 1)]
+    ///     .iter()
+    ///     .map(|(from, to, weight)| (from.to_string(), to.to_string(), *weight))
+    ///     .collect();
+    /// let graph = DirectedGraph::from_edges(&edges).unwrap();
+    ///
+    /// let order = graph.topological_sort().unwrap();
+    /// let ids: Vec<&str> = order.iter().map(|node| node.get_id()).collect();
+    /// assert_eq!(ids, vec!["A", "B", "C"]);
+    /// ```
+    pub fn topological_sort(&self) -> Result<Vec<DefaultNode>, String> {
+        let mut in_degree = vec![0usize; self.nodes.len()];
+        for neighbors in &self.adjacency {
+            for &(to_index, ..) in neighbors {
+                in_degree[to_index] += 1;
+            }
+        }
+
+        let mut ready: VecDeque<usize> = (0..self.nodes.len())
+            .filter(|&index| in_degree[index] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(index) = ready.pop_front() {
+            order.push(self.nodes[index].clone());
+            for &(to_index, ..) in &self.adjacency[index] {
+                in_degree[to_index] -= 1;
+                if in_degree[to_index] == 0 {
+                    ready.push_back(to_index);
+                }
+            }
+        }
+
+        if order.len() < self.nodes.len() {
+            let unordered: Vec<&str> = (0..self.nodes.len())
+                .filter(|&index| in_degree[index] > 0)
+                .map(|index| self.nodes[index].get_id())
+                .collect();
+            return Err(format!(
+                "Graph contains a cycle, no topological order exists! Unordered nodes: {}",
+                unordered.join(", ")
+            ));
+        }
+        Ok(order)
+    }
+
+    /// Returns the number of edges pointing into the node with ID `id`.
+    ///
+    /// The out-degree is [`Graph::out_degree`] and [`Graph::degree`] is the
+    /// sum of both. Edges are stored by source node, so this scans every
+    /// adjacency list (`O(V + E)`).
+    ///
+    /// # Returns
+    ///
+    /// The in-degree, or `None` if no node has the ID `id`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    ///
+    /// let edges: Vec<(String, String, u16)> = vec![
+    ///     ("A".to_string(), "C".to_string(), 1),
+    ///     ("B".to_string(), "C".to_string(), 1),
+    /// ];
+    /// let graph = DirectedGraph::from_edges(&edges).unwrap();
+    ///
+    /// assert_eq!(graph.in_degree("C"), Some(2));
+    /// assert_eq!(graph.in_degree("A"), Some(0));
+    /// assert_eq!(graph.in_degree("missing"), None);
+    /// ```
+    pub fn in_degree(&self, id: &str) -> Option<usize> {
+        let index = self.node_index_for_id(id)?;
+        Some(
+            self.adjacency
+                .iter()
+                .flatten()
+                .filter(|(to_index, ..)| *to_index == index)
+                .count(),
+        )
+    }
+
+    /// Returns whether the graph contains a directed cycle.
+    ///
+    /// A self-loop (`A -> A`) counts as a cycle. Use this to validate a
+    /// dependency graph before calling [`DirectedGraph::topological_sort`].
+    /// See [`DirectedGraph::find_cycle`] for the algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    ///
+    /// let dag: Vec<(String, String, u16)> = vec![("A".to_string(), "B".to_string(), 1)];
+    /// assert!(!DirectedGraph::from_edges(&dag).unwrap().has_cycle());
+    ///
+    /// let self_loop: Vec<(String, String, u16)> = vec![("A".to_string(), "A".to_string(), 1)];
+    /// assert!(DirectedGraph::from_edges(&self_loop).unwrap().has_cycle());
+    /// ```
+    pub fn has_cycle(&self) -> bool {
+        self.find_cycle().is_some()
+    }
+
+    /// Returns one directed cycle of the graph, if there is any.
+    ///
+    /// # Algorithm
+    ///
+    /// Depth-first search that keeps the nodes of the current path in a
+    /// "gray" set: reaching a gray node again closes a cycle, while nodes
+    /// whose descendants are all finished are never searched twice. The DFS
+    /// uses an explicit stack, so long chains cannot overflow the call stack.
+    /// Runs in `O(V + E)`; roots are tried in insertion order.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(nodes)` with the cycle in edge order: each node has an edge to
+    ///   the next one and the last node has an edge back to the first. The
+    ///   first node is not repeated, so a self-loop yields one node.
+    /// - `None` if the graph is acyclic.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::GraphNode;
+    ///
+    /// let edges: Vec<(String, String, u16)> = [("A", "B", 1), ("B", "C", 1), ("C", "B", 1)]
+    ///     .iter()
+    ///     .map(|(from, to, weight)| (from.to_string(), to.to_string(), *weight))
+    ///     .collect();
+    /// let graph = DirectedGraph::from_edges(&edges).unwrap();
+    ///
+    /// let cycle = graph.find_cycle().unwrap();
+    /// let ids: Vec<&str> = cycle.iter().map(|node| node.get_id()).collect();
+    /// assert_eq!(ids, vec!["B", "C"]);
+    /// ```
+    pub fn find_cycle(&self) -> Option<Vec<DefaultNode>> {
+        /// DFS state of a node.
+        #[derive(Clone, Copy, PartialEq)]
+        enum Color {
+            /// Not visited yet.
+            White,
+            /// On the current DFS path.
+            Gray,
+            /// Finished: no cycle is reachable through it.
+            Black,
+        }
+
+        let mut color = vec![Color::White; self.nodes.len()];
+        for root in 0..self.nodes.len() {
+            if color[root] != Color::White {
+                continue;
+            }
+            color[root] = Color::Gray;
+            // The current path as `(node, next adjacency position)` frames.
+            let mut path: Vec<(usize, usize)> = vec![(root, 0)];
+
+            while let Some(frame) = path.last_mut() {
+                let node = frame.0;
+                let Some(&(next, ..)) = self.adjacency[node].get(frame.1) else {
+                    color[node] = Color::Black;
+                    path.pop();
+                    continue;
+                };
+                frame.1 += 1;
+
+                match color[next] {
+                    Color::White => {
+                        color[next] = Color::Gray;
+                        path.push((next, 0));
+                    }
+                    Color::Gray => {
+                        let start = path.iter().position(|&(index, _)| index == next)?;
+                        return Some(
+                            path[start..]
+                                .iter()
+                                .map(|&(index, _)| self.nodes[index].clone())
+                                .collect(),
+                        );
+                    }
+                    Color::Black => {}
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the transpose: the same nodes with every edge reversed.
+    ///
+    /// Each edge `from -> to` becomes `to -> from` with the same weight, kind
+    /// and layer. Reverse reachability ("who can reach `X`?") and Kosaraju's
+    /// strongly connected components run on this graph. Node order is kept;
+    /// the outgoing edges of each node are ordered by the original source
+    /// node.
+    ///
+    /// # Returns
+    ///
+    /// A new graph; `self` is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    ///
+    /// let edges: Vec<(String, String, u16)> = vec![("A".to_string(), "B".to_string(), 4)];
+    /// let graph = DirectedGraph::from_edges(&edges).unwrap();
+    ///
+    /// let transposed = graph.transpose();
+    /// assert_eq!(transposed.to_edge_list(), vec![("B".to_string(), "A".to_string(), 4)]);
+    /// assert_eq!(graph.to_edge_list(), edges);
+    /// ```
+    pub fn transpose(&self) -> Self {
+        let mut adjacency = vec![Vec::new(); self.nodes.len()];
+        for (from_index, neighbors) in self.adjacency.iter().enumerate() {
+            for (to_index, weight, labels) in neighbors {
+                adjacency[*to_index].push((from_index, *weight, labels.clone()));
+            }
+        }
+
+        Self {
+            nodes: self.nodes.clone(),
+            node_index_by_id: self.node_index_by_id.clone(),
+            adjacency,
+            has_implicit_weights: self.has_implicit_weights,
+            allows_parallel_edges: self.allows_parallel_edges,
+        }
+    }
+}
+
+impl DirectedGraph {
+    /// Creates a new directed graph from a node vector.
+    ///
+    /// # Parameters
+    ///
+    /// - `nodes`: initial node list.
+    ///   Duplicate node IDs are ignored.
+    ///
+    /// # Returns
+    ///
+    /// A new [`DirectedGraph`] instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    ///
+    /// let graph = DirectedGraph::new(vec![]);
+    /// assert_eq!(graph.get_all_nodes().len(), 0);
+    /// assert_eq!(graph.get_all_nodes().len(), 0);
+    /// ```
+    pub fn new(nodes: Vec<DefaultNode>) -> Self {
+        Self::with_nodes(nodes)
+    }
+
+    /// Creates a directed multigraph, which accepts parallel edges.
+    ///
+    /// Unlike a graph from [`DirectedGraph::new`], [`Graph::insert_edge`]
+    /// does not reject a second `from -> to` edge, so several connections
+    /// with different weights can coexist. [`Graph::neighbors`] yields every
+    /// one of them, and shortest-path searches pick the cheapest.
+    ///
+    /// Every parallel edge keeps its own kind and layer, but edges are still
+    /// identified by their endpoints: [`Graph::edge_kind`],
+    /// [`DirectedGraph::remove_edge`], [`DirectedGraph::set_edge_layer`] and
+    /// [`DirectedGraph::set_edge_weight`] act on the earliest inserted one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new_multigraph(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(7)).is_none());
+    /// assert!(graph.insert_edge(&a, &b, Some(3)).is_none());
+    ///
+    /// assert!(graph.is_multigraph());
+    /// assert_eq!(graph.neighbors(&a).map(|(_, w)| w).collect::<Vec<_>>(), vec![7, 3]);
+    /// ```
+    pub fn new_multigraph(nodes: Vec<DefaultNode>) -> Self {
+        Self::multigraph_with_nodes(nodes)
+    }
+
+    /// Converts the graph into an [`UndirectedGraph`] by dropping edge
+    /// direction.
+    ///
+    /// Every node is carried over in insertion order, including isolated
+    /// ones. Edges that connect the same two nodes (`A -> B` and `B -> A`, or
+    /// parallel edges of a multigraph) become a single undirected edge with
+    /// the smallest of their weights; that edge keeps the kind and layer of
+    /// the cheapest directed edge, the first one on ties. Implicit weights
+    /// stay implicit.
+    ///
+    /// # Returns
+    ///
+    /// A new plain (non-multigraph) undirected graph; `self` is left
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    ///
+    /// let edges: Vec<(String, String, u16)> = vec![
+    ///     ("A".to_string(), "B".to_string(), 5),
+    ///     ("B".to_string(), "A".to_string(), 3),
+    /// ];
+    /// let undirected = DirectedGraph::from_edges(&edges).unwrap().to_undirected();
+    /// assert_eq!(undirected.to_edge_list(), vec![("A".to_string(), "B".to_string(), 3)]);
+    /// ```
+    pub fn to_undirected(&self) -> UndirectedGraph {
+        // Cheapest edge per unordered node pair, in first-seen order.
+        let mut position_by_pair: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut kept: Vec<(usize, usize, u16, &EdgeLabels)> = Vec::new();
+        for (from_index, neighbors) in self.adjacency.iter().enumerate() {
+            for (to_index, weight, labels) in neighbors {
+                let (to_index, weight) = (*to_index, *weight);
+                let pair = (from_index.min(to_index), from_index.max(to_index));
+                match position_by_pair.get(&pair) {
+                    Some(&position) if kept[position].2 <= weight => {}
+                    Some(&position) => kept[position] = (from_index, to_index, weight, labels),
+                    None => {
+                        position_by_pair.insert(pair, kept.len());
+                        kept.push((from_index, to_index, weight, labels));
+                    }
+                }
+            }
+        }
+
+        let mut graph = UndirectedGraph::new(self.nodes.clone());
+        for (from_index, to_index, weight, labels) in kept {
+            let (from, to) = (&self.nodes[from_index], &self.nodes[to_index]);
+            // Both endpoints exist and every pair is inserted once, so this cannot fail.
+            let _ = graph.insert_edge_with_labels(
+                from,
+                to,
+                Some(weight),
+                labels.kind.clone(),
+                labels.layer.clone(),
+            );
+        }
+        if self.has_implicit_weights {
+            graph.mark_weights_implicit();
+        }
+        graph
+    }
+}
+
+impl SignedDirectedGraph {
+    /// Creates a new signed directed graph from a node vector.
+    ///
+    /// # Parameters
+    ///
+    /// - `nodes`: initial node list.
+    ///   Duplicate node IDs are ignored.
+    ///
+    /// # Returns
+    ///
+    /// A new [`SignedDirectedGraph`] instance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::SignedDirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    ///
+    /// let graph = SignedDirectedGraph::new_signed(vec![]);
+    /// assert!(graph.get_all_nodes().is_empty());
+    /// ```
+    pub fn new_signed(nodes: Vec<DefaultNode>) -> Self {
+        Self::with_nodes(nodes)
+    }
+}
+
+impl<W: GraphWeight> Display for DirectedGraph<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Nodes: {:?}, Adjacency: {:?}",
+            self.nodes,
+            self.adjacency
+                .iter()
+                .map(|neighbors| {
+                    neighbors
+                        .iter()
+                        .map(|&(to_index, weight, _)| (to_index, weight))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        )
+    }
+}
+
+impl Default for DirectedGraph {
+    /// Creates an empty directed graph.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    ///
+    /// let graph = DirectedGraph::default();
+    /// assert!(graph.get_all_nodes().is_empty());
+    /// ```
+    fn default() -> Self {
+        Self::new(vec![])
+    }
+}
+
+/// Collects `(from, to, weight)` edges into a graph.
+///
+/// Nodes are created on first mention, in the order they appear. Unlike
+/// [`DirectedGraph::from_edges`], a repeated edge is not an error: the first
+/// occurrence and its weight win, later ones are skipped.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::graphs::directed::DirectedGraph;
+/// use shortest_path_finder::graphs::graph::Graph;
+/// use shortest_path_finder::nodes::default_node::DefaultNode;
+///
+/// let a = DefaultNode::new("A".to_string());
+/// let b = DefaultNode::new("B".to_string());
+/// let graph: DirectedGraph = vec![(a.clone(), b.clone(), 7), (a.clone(), b.clone(), 9)]
+///     .into_iter()
+///     .collect();
+/// assert_eq!(graph.get_all_nodes().len(), 2);
+/// assert_eq!(graph.to_edge_list(), vec![("A".to_string(), "B".to_string(), 7)]);
+/// ```
+impl<W: GraphWeight> FromIterator<(DefaultNode, DefaultNode, W)> for DirectedGraph<W> {
+    fn from_iter<I: IntoIterator<Item = (DefaultNode, DefaultNode, W)>>(edges: I) -> Self {
+        let mut graph = Self::with_nodes(vec![]);
+        for (from, to, weight) in edges {
+            graph.insert_node(from.clone());
+            graph.insert_node(to.clone());
+            // A duplicate is the only possible insertion error here.
+            let _ = graph.insert_edge(&from, &to, Some(weight));
+        }
+        graph
     }
 }
 
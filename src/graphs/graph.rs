@@ -11,6 +11,25 @@
 //! `graphs::directed`, `graphs::undirected`, and
 //! `graphs::two_dimensional_coordinate_graph`.
 //!
+//! # Edge identity
+//!
+//! Edges have no generated IDs. The bundled graphs store edges in index-based
+//! adjacency lists and address them by their endpoint IDs (`from`, `to`), so
+//! lookups such as [`Graph::edges_between`] and exports such as
+//! [`Graph::to_edge_list`] are deterministic: the same insertions always
+//! produce the same output, which keeps tests and serialized edge lists
+//! reproducible.
+//!
+//! In a simple graph the endpoint pair names exactly one edge, and the same
+//! pair removes it again, e.g.
+//! [`DirectedGraph::remove_edge`](crate::graphs::directed::DirectedGraph::remove_edge);
+//! afterwards [`Graph::edges_between`] no longer finds it. A multigraph from
+//! [`DirectedGraph::new_multigraph`](crate::graphs::directed::DirectedGraph::new_multigraph)
+//! can hold several parallel edges per pair: [`Graph::edges_between`] returns
+//! all of them in insertion order, each keeps its own kind and layer (see
+//! [`Graph::labeled_neighbors`]), and removing the pair drops the earliest
+//! inserted one.
+//!
 //! # Quick Example
 //!
 //! ```rust
@@ -28,11 +47,20 @@
 //! ```
 
 use std::{
+    collections::{BTreeMap, HashMap, HashSet},
     error::Error,
     fmt::{Debug, Display},
     ops::Add,
 };
 
+/// Square weight matrix returned by [`Graph::adjacency_matrix`]; `None`
+/// marks a missing edge.
+pub type AdjacencyMatrix<W> = Vec<Vec<Option<W>>>;
+
+/// Item of [`Graph::labeled_neighbors`]: neighbor, weight, kind and layer of
+/// one outgoing edge.
+pub type LabeledNeighbor<'a, N, W> = (&'a N, W, Option<&'a str>, Option<&'a str>);
+
 /// Trait describing the behavior of a graph data structure.
 ///
 /// A graph implementation can be directed or undirected, weighted or unweighted,
@@ -118,6 +146,18 @@ pub trait Graph {
     ///
     /// Iterator over `(neighbor, weight)` pairs.
     ///
+    /// # Parallel edges
+    ///
+    /// Implementations that store several edges between the same two nodes
+    /// (multigraphs) should yield every one of them here; this is the
+    /// inspection view and is not collapsed. Algorithms such as
+    /// [`DijkstraAlgorithm`](crate::algorithms::dijkstra::DijkstraAlgorithm)
+    /// also enumerate through this method and only ever keep the cheaper
+    /// edge: relaxation discards the more expensive candidate, and reported
+    /// hop weights use the minimum. The directed and undirected graphs in this
+    /// crate reject duplicate edges unless they were created with
+    /// `new_multigraph`.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -147,6 +187,81 @@ pub trait Graph {
         u: &Self::Node,
     ) -> Box<dyn Iterator<Item = (&'a Self::Node, Self::Weight)> + 'a>;
 
+    /// Returns how many edges leave `u` without building a neighbor iterator.
+    ///
+    /// For directed graphs this is the out-degree, for undirected graphs the
+    /// degree (a self-loop counts once). It always equals
+    /// `self.neighbors(u).count()`; implementations backed by an adjacency
+    /// list override it to avoid the boxed iterator in tight loops.
+    ///
+    /// # Returns
+    ///
+    /// The number of outgoing (or adjacent) edges, or `0` if `u` is not part
+    /// of the graph.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::{directed::DirectedGraph, graph::Graph};
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// graph.insert_edge(&a, &b, Some(6));
+    ///
+    /// assert_eq!(graph.out_degree(&a), 1);
+    /// assert_eq!(graph.out_degree(&b), 0);
+    /// ```
+    fn out_degree(&self, u: &Self::Node) -> usize {
+        self.neighbors(u).count()
+    }
+
+    /// Returns the degree of the node with ID `id`.
+    ///
+    /// For undirected graphs this is the number of incident edges, matching
+    /// [`Graph::out_degree`] (a self-loop counts once). For directed graphs it
+    /// is the in-degree plus the out-degree, so a self-loop counts twice.
+    ///
+    /// The default implementation finds incoming edges by scanning every
+    /// node's neighbors, which costs `O(V + E)` for directed graphs.
+    ///
+    /// # Returns
+    ///
+    /// The degree, or `None` if no node has the ID `id`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::{directed::DirectedGraph, graph::Graph};
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// graph.insert_edge(&a, &b, Some(6));
+    ///
+    /// assert_eq!(graph.degree("A"), Some(1));
+    /// assert_eq!(graph.degree("B"), Some(1));
+    /// assert_eq!(graph.degree("missing"), None);
+    /// ```
+    fn degree(&self, id: &str) -> Option<usize> {
+        let node = self.get_node_by_id(id)?;
+        let outgoing = self.out_degree(node);
+        if !self.is_directed() {
+            return Some(outgoing);
+        }
+        let incoming: usize = self
+            .iter_nodes()
+            .map(|from| {
+                self.neighbors(from)
+                    .filter(|(to, _)| to.get_id() == id)
+                    .count()
+            })
+            .sum();
+        Some(outgoing + incoming)
+    }
+
     /// Indicates whether edge direction is respected.
     ///
     /// # Returns
@@ -268,15 +383,564 @@ pub trait Graph {
     /// ```
     fn get_all_nodes(&self) -> &Vec<Self::Node>;
 
+    /// Iterates over all nodes by reference, in insertion order.
+    ///
+    /// Prefer this over [`Graph::get_all_nodes`] when you only need to walk
+    /// the nodes: it does not expose the backing `Vec`, so callers keep
+    /// working if the node storage changes.
+    ///
+    /// # Returns
+    ///
+    /// A boxed iterator over borrowed graph nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::{Graph, GraphNode};
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let graph = DirectedGraph::new(
+    ///     vec![DefaultNode::new("A".to_string()), DefaultNode::new("B".to_string())],
+    /// );
+    /// let ids: Vec<&str> = graph.iter_nodes().map(|node| node.get_id()).collect();
+    /// assert_eq!(ids, vec!["A", "B"]);
+    /// ```
+    fn iter_nodes<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::Node> + 'a> {
+        Box::new(self.get_all_nodes().iter())
+    }
+
     /// Indicates whether this graph carries meaningful edge weights.
     ///
-    /// Some algorithms (for example Dijkstra and A*) require weighted edges.
+    /// Some algorithms (for example A*) require weighted edges. Directed and
+    /// undirected graphs report `false` once a weight was filled in with a
+    /// default instead of given explicitly.
     ///
     /// # Returns
     ///
-    /// `true` if edge weights are available.
+    /// `true` if all edge weights were given explicitly.
     fn is_weighted(&self) -> bool;
 
+    /// Returns the kind (road type) of the edge `from -> to`, if it has one.
+    ///
+    /// Kinds such as `highway` or `ferry` let algorithms filter which edges
+    /// may be traversed. Graphs that do not store kinds keep the default,
+    /// which reports every edge as having no kind.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(kind)` if the edge exists and was inserted with a kind. Between
+    ///   parallel edges, the earliest inserted one is reported.
+    /// - `None` if the edge has no kind or does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph
+    ///     .insert_edge_with_kind(&a, &b, Some(7), Some("ferry".to_string()))
+    ///     .is_none());
+    /// assert_eq!(graph.edge_kind(&a, &b), Some("ferry"));
+    /// assert_eq!(graph.edge_kind(&b, &a), None);
+    /// ```
+    fn edge_kind(&self, _from: &Self::Node, _to: &Self::Node) -> Option<&str> {
+        None
+    }
+
+    /// Returns the layer of the edge `from -> to`, if it has one.
+    ///
+    /// Layers group the edges of a multi-modal network (for example `walk`,
+    /// `bus` and `train`) so whole groups can be switched off per query,
+    /// independently of the edge kind. Graphs that do not store layers keep
+    /// the default, which reports every edge as having no layer.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(layer)` if the edge exists and was assigned a layer. Between
+    ///   parallel edges, the earliest inserted one is reported.
+    /// - `None` if the edge has no layer or does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(7)).is_none());
+    /// assert!(graph.set_edge_layer(&a, &b, Some("train".to_string())));
+    /// assert_eq!(graph.edge_layer(&a, &b), Some("train"));
+    /// assert_eq!(graph.edge_layer(&b, &a), None);
+    /// ```
+    fn edge_layer(&self, _from: &Self::Node, _to: &Self::Node) -> Option<&str> {
+        None
+    }
+
+    /// Returns the outgoing edges of `u` together with their kind and layer.
+    ///
+    /// Unlike [`Graph::edge_kind`] and [`Graph::edge_layer`], which look an
+    /// edge up by its endpoints, this reports the labels of every parallel
+    /// edge separately, so label filters can skip one parallel edge and
+    /// still use another. The default pairs [`Graph::neighbors`] with those
+    /// per-pair lookups; the bundled graphs override it.
+    ///
+    /// # Returns
+    ///
+    /// `(neighbor, weight, kind, layer)` per edge, in [`Graph::neighbors`]
+    /// order; empty if `u` is not part of the graph.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::{Graph, GraphNode};
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new_multigraph(vec![a.clone(), b.clone()]);
+    /// assert!(graph
+    ///     .insert_edge_with_kind(&a, &b, Some(2), Some("ferry".to_string()))
+    ///     .is_none());
+    /// assert!(graph
+    ///     .insert_edge_with_kind(&a, &b, Some(5), Some("highway".to_string()))
+    ///     .is_none());
+    ///
+    /// let kinds: Vec<_> = graph
+    ///     .labeled_neighbors(&a)
+    ///     .map(|(to, weight, kind, _)| (to.get_id(), weight, kind))
+    ///     .collect();
+    /// assert_eq!(kinds, vec![("B", 2, Some("ferry")), ("B", 5, Some("highway"))]);
+    /// ```
+    fn labeled_neighbors<'a>(
+        &'a self,
+        u: &Self::Node,
+    ) -> Box<dyn Iterator<Item = LabeledNeighbor<'a, Self::Node, Self::Weight>> + 'a> {
+        let Some(from) = self.get_node_by_id(u.get_id()) else {
+            return Box::new(std::iter::empty());
+        };
+        Box::new(self.neighbors(from).map(move |(to, weight)| {
+            (
+                to,
+                weight,
+                self.edge_kind(from, to),
+                self.edge_layer(from, to),
+            )
+        }))
+    }
+
+    /// Returns every edge of the graph as `(from, to, weight)`.
+    ///
+    /// # Ordering
+    ///
+    /// - Directed graphs: edges grouped by source node in node insertion
+    ///   order, each group in edge insertion order.
+    /// - Undirected graphs: every edge once, with `from` being the endpoint
+    ///   that was inserted first (canonical order); otherwise ordered like
+    ///   directed graphs.
+    ///
+    /// The weight is the one for travelling from `from` to `to`. A two-way
+    /// undirected edge with a different weight per direction therefore loses
+    /// its reverse weight here, and so do [`Graph::to_edge_list`] and
+    /// [`Graph::to_dot`]; read it with [`Graph::neighbors`] or
+    /// [`Graph::adjacency_matrix`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::{Graph, GraphNode};
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// graph.insert_edge(&b, &a, Some(3));
+    ///
+    /// let edges = graph.all_edges();
+    /// assert_eq!(edges.len(), 1);
+    /// assert_eq!((edges[0].0.get_id(), edges[0].1.get_id(), edges[0].2), ("A", "B", 3));
+    /// ```
+    fn all_edges(&self) -> Vec<(&Self::Node, &Self::Node, Self::Weight)> {
+        let position_by_id: HashMap<&str, usize> = self
+            .get_all_nodes()
+            .iter()
+            .enumerate()
+            .map(|(position, node)| (node.get_id(), position))
+            .collect();
+
+        let mut edges = Vec::new();
+        for (from_position, from) in self.get_all_nodes().iter().enumerate() {
+            for (to, weight) in self.neighbors(from) {
+                // Undirected edges are stored in both directions; keep the canonical one.
+                if !self.is_directed()
+                    && position_by_id
+                        .get(to.get_id())
+                        .is_some_and(|&to_position| to_position < from_position)
+                {
+                    continue;
+                }
+                edges.push((from, to, weight));
+            }
+        }
+        edges
+    }
+
+    /// Returns every edge from `from_id` to `to_id` as `(from, to, weight)`.
+    ///
+    /// Direction is respected for directed graphs; for undirected graphs the
+    /// endpoint order does not matter and `from` is always the node with
+    /// `from_id`. The result lists parallel edges in insertion order: simple
+    /// graphs return at most one entry, while multigraphs such as
+    /// [`DirectedGraph::new_multigraph`](crate::graphs::directed::DirectedGraph::new_multigraph)
+    /// return every parallel edge.
+    ///
+    /// # Returns
+    ///
+    /// The matching edges, or an empty vector if either node is unknown or the
+    /// nodes are not connected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// graph.insert_edge(&a, &b, Some(4));
+    ///
+    /// let edges = graph.edges_between("B", "A");
+    /// assert_eq!(edges.len(), 1);
+    /// assert_eq!(edges[0].2, 4);
+    /// assert!(graph.edges_between("A", "missing").is_empty());
+    /// ```
+    fn edges_between(
+        &self,
+        from_id: &str,
+        to_id: &str,
+    ) -> Vec<(&Self::Node, &Self::Node, Self::Weight)> {
+        let Some(from) = self.get_node_by_id(from_id) else {
+            return Vec::new();
+        };
+        self.neighbors(from)
+            .filter(|(to, _)| to.get_id() == to_id)
+            .map(|(to, weight)| (from, to, weight))
+            .collect()
+    }
+
+    /// Returns `true` if an edge leads from node `a` to node `b`.
+    ///
+    /// For directed graphs only an `a -> b` edge counts; for undirected graphs
+    /// an edge in either orientation does. This is the cheap alternative to
+    /// `!graph.edges_between(a, b).is_empty()`, as it stops at the first
+    /// match and allocates nothing.
+    ///
+    /// # Returns
+    ///
+    /// `false` if either node is unknown or the nodes are not connected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(4)).is_none());
+    ///
+    /// assert!(graph.adjacent("A", "B"));
+    /// assert!(!graph.adjacent("B", "A"));
+    /// assert!(!graph.adjacent("A", "missing"));
+    /// ```
+    fn adjacent(&self, a: &str, b: &str) -> bool {
+        let Some(from) = self.get_node_by_id(a) else {
+            return false;
+        };
+        self.neighbors(from).any(|(to, _)| to.get_id() == b)
+    }
+
+    /// Returns the cheapest edge touching `node` as `(node, neighbor, weight)`.
+    ///
+    /// For directed graphs only outgoing edges count; for undirected graphs
+    /// every incident edge does. Ties keep the first edge in
+    /// [`Graph::neighbors`] order. This is the building block of greedy
+    /// heuristics such as nearest-neighbor tours.
+    ///
+    /// # Returns
+    ///
+    /// The cheapest edge, or `None` if `node` is unknown or has no (outgoing)
+    /// edge.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::{Graph, GraphNode};
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone(), c.clone()]);
+    /// graph.insert_edge(&a, &b, Some(4));
+    /// graph.insert_edge(&c, &a, Some(2));
+    ///
+    /// let (_, nearest, weight) = graph.min_incident_edge(&a).unwrap();
+    /// assert_eq!((nearest.get_id(), weight), ("C", 2));
+    /// ```
+    fn min_incident_edge(
+        &self,
+        node: &Self::Node,
+    ) -> Option<(&Self::Node, &Self::Node, Self::Weight)> {
+        let from = self.get_node_by_id(node.get_id())?;
+        self.neighbors(from)
+            .fold(
+                None,
+                |cheapest: Option<(&Self::Node, Self::Weight)>, edge| match cheapest {
+                    Some(best) if best.1 <= edge.1 => Some(best),
+                    _ => Some(edge),
+                },
+            )
+            .map(|(to, weight)| (from, to, weight))
+    }
+
+    /// Returns every edge as plain `(from_id, to_id, weight)` tuples.
+    ///
+    /// This is the simplest export format for interop code; the ordering and
+    /// the dropped reverse weights of two-way edges are documented on
+    /// [`Graph::all_edges`]. The directed and undirected graphs can be rebuilt
+    /// from the list with their `from_edges` constructors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    ///
+    /// let edges: Vec<(String, String, u16)> = vec![
+    ///     ("A".to_string(), "B".to_string(), 4),
+    ///     ("B".to_string(), "C".to_string(), 2),
+    /// ];
+    /// let graph = DirectedGraph::from_edges(&edges).unwrap();
+    /// assert_eq!(graph.to_edge_list(), edges);
+    /// ```
+    fn to_edge_list(&self) -> Vec<(String, String, Self::Weight)> {
+        self.all_edges()
+            .into_iter()
+            .map(|(from, to, weight)| (from.get_id().to_string(), to.get_id().to_string(), weight))
+            .collect()
+    }
+
+    /// Returns the weighted adjacency matrix of the graph.
+    ///
+    /// Rows and columns follow the node insertion order of
+    /// [`Graph::get_all_nodes`]; `matrix[i][j]` is the weight of the edge from
+    /// node `i` to node `j`, or `None` without such an edge. Undirected
+    /// graphs report every edge in both directions, so their matrix is
+    /// symmetric unless a two-way edge has different weights per direction.
+    /// Between parallel edges of a multigraph the lightest one is kept.
+    ///
+    /// # Returns
+    ///
+    /// The node-ID labels and the square matrix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    ///
+    /// let edges: Vec<(String, String, u16)> = vec![("A".to_string(), "B".to_string(), 7)];
+    /// let graph = DirectedGraph::from_edges(&edges).unwrap();
+    ///
+    /// let (labels, matrix) = graph.adjacency_matrix();
+    /// assert_eq!(labels, vec!["A".to_string(), "B".to_string()]);
+    /// assert_eq!(matrix, vec![vec![None, Some(7)], vec![None, None]]);
+    /// ```
+    fn adjacency_matrix(&self) -> (Vec<String>, AdjacencyMatrix<Self::Weight>) {
+        let nodes = self.get_all_nodes();
+        let index_by_id: HashMap<&str, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.get_id(), index))
+            .collect();
+
+        let mut matrix = vec![vec![None; nodes.len()]; nodes.len()];
+        for (row, node) in nodes.iter().enumerate() {
+            for (neighbor, weight) in self.neighbors(node) {
+                let Some(&column) = index_by_id.get(neighbor.get_id()) else {
+                    continue;
+                };
+                let cell = &mut matrix[row][column];
+                if cell.is_none_or(|current| weight < current) {
+                    *cell = Some(weight);
+                }
+            }
+        }
+
+        let labels = nodes.iter().map(|node| node.get_id().to_string()).collect();
+        (labels, matrix)
+    }
+
+    /// Renders the graph in Graphviz DOT format.
+    ///
+    /// Directed graphs become a `digraph` with `->` edges, undirected graphs a
+    /// `graph` with `--` edges. Every edge of [`Graph::all_edges`] is written
+    /// once, in that order, with its weight as `label` (the forward weight
+    /// only for two-way edges). Nodes without any
+    /// edge are listed on their own so they still show up in the drawing.
+    /// Node IDs are always quoted; `"` and `\` inside them are escaped.
+    ///
+    /// The output can be piped straight into `dot -Tpng`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    ///
+    /// let edges: Vec<(String, String, u16)> = vec![("A".to_string(), "B".to_string(), 7)];
+    /// let graph = DirectedGraph::from_edges(&edges).unwrap();
+    /// assert_eq!(graph.to_dot(), "digraph {\n    \"A\" -> \"B\" [label=\"7\"];\n}\n");
+    /// ```
+    fn to_dot(&self) -> String {
+        let (keyword, connector) = if self.is_directed() {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        let quote = |id: &str| format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""));
+
+        let edges = self.all_edges();
+        let connected: HashSet<&str> = edges
+            .iter()
+            .flat_map(|(from, to, _)| [from.get_id(), to.get_id()])
+            .collect();
+        let mut dot = format!("{} {{\n", keyword);
+        for node in self.get_all_nodes() {
+            if !connected.contains(node.get_id()) {
+                dot.push_str(&format!("    {};\n", quote(node.get_id())));
+            }
+        }
+        for (from, to, weight) in &edges {
+            dot.push_str(&format!(
+                "    {} {} {} [label=\"{}\"];\n",
+                quote(from.get_id()),
+                connector,
+                quote(to.get_id()),
+                weight
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Counts edges per weight bucket of width `bucket_size`.
+    ///
+    /// Each edge of [`Graph::all_edges`] is counted once (undirected edges are
+    /// not double counted) in the bucket `[k * bucket_size, (k + 1) * bucket_size)`
+    /// that contains its weight. Only non-empty buckets appear in the map.
+    ///
+    /// # Parameters
+    ///
+    /// - `bucket_size`: Width of every bucket; must be greater than zero.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(BTreeMap)` from bucket lower bound to edge count, in ascending
+    ///   bucket order.
+    /// - `Err(String)` if `bucket_size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    ///
+    /// let edges: Vec<(String, String, u16)> = vec![
+    ///     ("A".to_string(), "B".to_string(), 3),
+    ///     ("B".to_string(), "C".to_string(), 7),
+    ///     ("C".to_string(), "A".to_string(), 12),
+    /// ];
+    /// let graph = DirectedGraph::from_edges(&edges).unwrap();
+    ///
+    /// let histogram = graph.weight_histogram(10).unwrap();
+    /// assert_eq!(histogram.into_iter().collect::<Vec<_>>(), vec![(0, 2), (10, 1)]);
+    /// assert!(graph.weight_histogram(0).is_err());
+    /// ```
+    fn weight_histogram(&self, bucket_size: u16) -> Result<BTreeMap<u16, usize>, String>
+    where
+        Self::Weight: Into<u16>,
+    {
+        if bucket_size == 0 {
+            return Err(
+                "The bucket size of a weight histogram must be greater than zero!".to_string(),
+            );
+        }
+
+        let mut histogram = BTreeMap::new();
+        for (_, _, weight) in self.all_edges() {
+            let weight: u16 = weight.into();
+            *histogram.entry(weight - weight % bucket_size).or_insert(0) += 1;
+        }
+        Ok(histogram)
+    }
+
+    /// Checks whether `path` can still be walked in this graph.
+    ///
+    /// Every node must exist and each consecutive pair must be connected by an
+    /// existing edge (respecting direction for directed graphs). This is a
+    /// cheap way to re-validate a cached route after the graph was edited.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if every hop of `path` is an existing edge. A single-node
+    ///   path is valid if the node exists.
+    /// - `false` for an empty path or as soon as one node or hop is missing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = DirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// graph.insert_edge(&a, &b, Some(1));
+    ///
+    /// assert!(graph.is_path_valid(&[a.clone(), b.clone()]));
+    /// assert!(!graph.is_path_valid(&[b.clone(), a.clone()]));
+    ///
+    /// graph.remove_edge(&a, &b);
+    /// assert!(!graph.is_path_valid(&[a, b]));
+    /// ```
+    fn is_path_valid(&self, path: &[Self::Node]) -> bool {
+        match path {
+            [] => false,
+            [node] => self.does_node_already_exist(node),
+            _ => path
+                .windows(2)
+                .all(|hop| self.does_edge_already_exist(&hop[0], &hop[1])),
+        }
+    }
+
     /// Returns a short, stable graph-type abbreviation.
     ///
     /// Commonly used by parsing/serialization code to identify graph kinds.
@@ -287,9 +951,13 @@ pub trait Graph {
     /// use shortest_path_finder::graphs::directed::DirectedGraph;
     /// use shortest_path_finder::graphs::graph::Graph;
     ///
-    /// assert_eq!(DirectedGraph::abbreviation(), "D");
+    /// assert_eq!(<DirectedGraph>::abbreviation(), "D");
     /// ```
-    fn abbreviation() -> String;
+    ///
+    /// Not available on graph trait objects, which keeps `dyn Graph` usable.
+    fn abbreviation() -> String
+    where
+        Self: Sized;
 }
 
 /// A trait representing a type suitable for use as a weight in graph algorithms.
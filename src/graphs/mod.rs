@@ -6,7 +6,10 @@
 //! - [`graph`]: shared graph traits used by algorithms.
 //! - [`directed`]: directed weighted graph implementation.
 //! - [`undirected`]: undirected weighted graph implementation.
+//! - [`unweighted`]: directed or undirected graph without edge weights.
 //! - [`two_dimensional_coordinate_graph`]: coordinate-based graph model.
+//! - `serialization`: serde support and JSON helpers for the directed and
+//!   undirected graphs (requires the `serde` feature).
 //!
 //! # Usage
 //!
@@ -21,7 +24,10 @@
 
 pub mod directed;
 pub mod graph;
+#[cfg(feature = "serde")]
+pub mod serialization;
 pub mod two_dimensional_coordinate_graph;
 pub mod undirected;
+pub mod unweighted;
 
 mod utils;
@@ -0,0 +1,302 @@
+//! Serde support for [`DirectedGraph`] and [`UndirectedGraph`].
+//!
+//! # Overview
+//!
+//! Both graphs serialize to a [`GraphDocument`]: the node list in insertion
+//! order plus one [`EdgeRecord`] per edge, as listed by
+//! [`Graph::all_edges`]. The index-based adjacency lists are not part of the
+//! format; deserializing rebuilds them through the regular insertion methods,
+//! so a document with a dangling endpoint or a duplicate edge is rejected
+//! with the same message [`Graph::insert_edge`] would return.
+//!
+//! Edges have no IDs (see [`crate::graphs::graph`]), so there is nothing to
+//! regenerate on load.
+//!
+//! Only compiled with the `serde` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use shortest_path_finder::graphs::directed::DirectedGraph;
+//! use shortest_path_finder::graphs::graph::Graph;
+//!
+//! let edges: Vec<(String, String, u16)> = vec![("A".to_string(), "B".to_string(), 7)];
+//! let graph = DirectedGraph::from_edges(&edges).unwrap();
+//!
+//! let json = graph.to_json().unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"nodes":[{"id":"A"},{"id":"B"}],"edges":[{"from":"A","to":"B","weight":7}]}"#
+//! );
+//! assert_eq!(DirectedGraph::from_json(&json).unwrap().to_edge_list(), edges);
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as _};
+
+use crate::{
+    graphs::{
+        directed::DirectedGraph,
+        graph::{Graph, GraphNode, GraphWeight},
+        undirected::UndirectedGraph,
+    },
+    nodes::default_node::DefaultNode,
+};
+
+/// Serialized form of a graph.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound(deserialize = "W: Deserialize<'de>"))]
+pub struct GraphDocument<W> {
+    /// All nodes in insertion order, including nodes without edges.
+    pub nodes: Vec<DefaultNode>,
+    /// Every edge once, in [`Graph::all_edges`] order.
+    pub edges: Vec<EdgeRecord<W>>,
+    /// Mirrors [`Graph::is_weighted`] being `false` because weights were
+    /// filled in by a loader; omitted when `false`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub implicit_weights: bool,
+    /// Whether the graph accepts parallel edges (`new_multigraph`); omitted
+    /// when `false`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub multigraph: bool,
+}
+
+/// Serialized form of one edge.
+///
+/// Optional fields are omitted from the output when they are unset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound(deserialize = "W: Deserialize<'de>"))]
+pub struct EdgeRecord<W> {
+    /// ID of the source node (undirected: the earlier-inserted endpoint).
+    pub from: String,
+    /// ID of the target node.
+    pub to: String,
+    /// Weight of travelling from `from` to `to`.
+    pub weight: W,
+    /// Undirected graphs only: weight of travelling back from `to` to
+    /// `from`, when it differs from `weight`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reverse_weight: Option<W>,
+    /// Kind of this edge, as reported by [`Graph::labeled_neighbors`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Layer of this edge, as reported by [`Graph::labeled_neighbors`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layer: Option<String>,
+}
+
+impl<W: GraphWeight> From<&DirectedGraph<W>> for GraphDocument<W> {
+    fn from(graph: &DirectedGraph<W>) -> Self {
+        // Same order as `all_edges`, but with the labels of each parallel edge.
+        let edges = graph
+            .get_all_nodes()
+            .iter()
+            .flat_map(|from| {
+                graph
+                    .labeled_neighbors(from)
+                    .map(move |(to, weight, kind, layer)| EdgeRecord {
+                        from: from.get_id().to_string(),
+                        to: to.get_id().to_string(),
+                        weight,
+                        reverse_weight: None,
+                        kind: kind.map(str::to_string),
+                        layer: layer.map(str::to_string),
+                    })
+            })
+            .collect();
+
+        Self {
+            nodes: graph.get_all_nodes().clone(),
+            edges,
+            implicit_weights: !graph.is_weighted(),
+            multigraph: graph.is_multigraph(),
+        }
+    }
+}
+
+impl<W: GraphWeight> TryFrom<GraphDocument<W>> for DirectedGraph<W> {
+    type Error = String;
+
+    /// Rebuilds the graph; fails on the first edge that cannot be inserted.
+    fn try_from(document: GraphDocument<W>) -> Result<Self, String> {
+        let mut graph = if document.multigraph {
+            Self::multigraph_with_nodes(document.nodes)
+        } else {
+            Self::with_nodes(document.nodes)
+        };
+        for edge in document.edges {
+            let from = DefaultNode::new(edge.from);
+            let to = DefaultNode::new(edge.to);
+            if let Some(err) =
+                graph.insert_edge_with_labels(&from, &to, Some(edge.weight), edge.kind, edge.layer)
+            {
+                return Err(err.message);
+            }
+        }
+        if document.implicit_weights {
+            graph.mark_weights_implicit();
+        }
+        Ok(graph)
+    }
+}
+
+impl From<&UndirectedGraph> for GraphDocument<u16> {
+    fn from(graph: &UndirectedGraph) -> Self {
+        // Same edges and order as `all_edges`, but with the labels and the
+        // reverse weight of each parallel edge.
+        let position_by_id: HashMap<&str, usize> = graph
+            .get_all_nodes()
+            .iter()
+            .enumerate()
+            .map(|(position, node)| (node.get_id(), position))
+            .collect();
+        let mut edges = Vec::new();
+        for (from_position, from) in graph.get_all_nodes().iter().enumerate() {
+            // Counts earlier entries per neighbor, to find the reverse entry of a parallel edge.
+            let mut seen_per_neighbor: HashMap<&str, usize> = HashMap::new();
+            for (to, weight, kind, layer) in graph.labeled_neighbors(from) {
+                let occurrence = seen_per_neighbor.entry(to.get_id()).or_default();
+                let nth = *occurrence;
+                *occurrence += 1;
+                if position_by_id[to.get_id()] < from_position {
+                    continue;
+                }
+
+                let reverse_weight = graph
+                    .neighbors(to)
+                    .filter(|(neighbor, _)| neighbor.get_id() == from.get_id())
+                    .nth(nth)
+                    .map(|(_, reverse)| reverse)
+                    .filter(|&reverse| reverse != weight);
+                edges.push(EdgeRecord {
+                    from: from.get_id().to_string(),
+                    to: to.get_id().to_string(),
+                    weight,
+                    reverse_weight,
+                    kind: kind.map(str::to_string),
+                    layer: layer.map(str::to_string),
+                });
+            }
+        }
+
+        Self {
+            nodes: graph.get_all_nodes().clone(),
+            edges,
+            implicit_weights: !graph.is_weighted(),
+            multigraph: graph.is_multigraph(),
+        }
+    }
+}
+
+impl TryFrom<GraphDocument<u16>> for UndirectedGraph {
+    type Error = String;
+
+    /// Rebuilds the graph; fails on the first edge that cannot be inserted.
+    fn try_from(document: GraphDocument<u16>) -> Result<Self, String> {
+        let mut graph = if document.multigraph {
+            Self::new_multigraph(document.nodes)
+        } else {
+            Self::new(document.nodes)
+        };
+        for edge in document.edges {
+            let from = DefaultNode::new(edge.from);
+            let to = DefaultNode::new(edge.to);
+            let reverse_weight = edge.reverse_weight.unwrap_or(edge.weight);
+            if let Some(err) = graph.insert_labeled_two_way_edge(
+                &from,
+                &to,
+                edge.weight,
+                reverse_weight,
+                edge.kind,
+                edge.layer,
+            ) {
+                return Err(err.message);
+            }
+        }
+        if document.implicit_weights {
+            graph.mark_weights_implicit();
+        }
+        Ok(graph)
+    }
+}
+
+impl<W: GraphWeight + Serialize> Serialize for DirectedGraph<W> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GraphDocument::from(self).serialize(serializer)
+    }
+}
+
+impl<'de, W: GraphWeight + Deserialize<'de>> Deserialize<'de> for DirectedGraph<W> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let document = GraphDocument::<W>::deserialize(deserializer)?;
+        Self::try_from(document).map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for UndirectedGraph {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GraphDocument::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for UndirectedGraph {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let document = GraphDocument::<u16>::deserialize(deserializer)?;
+        Self::try_from(document).map_err(D::Error::custom)
+    }
+}
+
+impl<W: GraphWeight + Serialize> DirectedGraph<W> {
+    /// Serializes the graph to compact JSON (see [`GraphDocument`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`serde_json::Error`] if serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
+impl<W: GraphWeight + for<'de> Deserialize<'de>> DirectedGraph<W> {
+    /// Parses a graph from JSON written by [`Self::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`serde_json::Error`] for malformed JSON, an edge whose
+    /// endpoint is not in `nodes`, or a duplicate edge.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::directed::DirectedGraph;
+    ///
+    /// let json = r#"{"nodes":[{"id":"A"}],"edges":[{"from":"A","to":"Z","weight":1}]}"#;
+    /// let err = DirectedGraph::<u16>::from_json(json).unwrap_err();
+    /// assert!(err.to_string().contains("Z"));
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+impl UndirectedGraph {
+    /// Serializes the graph to compact JSON (see [`GraphDocument`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`serde_json::Error`] if serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Parses a graph from JSON written by [`Self::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`serde_json::Error`] for malformed JSON, an edge whose
+    /// endpoint is not in `nodes`, or a duplicate edge.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
@@ -163,6 +163,11 @@ impl<C: CoordinateDatatype> Graph for TwoDimensionalCoordinateGraph<C> {
         )
     }
 
+    fn out_degree(&self, u: &Self::Node) -> usize {
+        self.node_index_for_id(u.get_id())
+            .map_or(0, |index| self.adjacency[index].len())
+    }
+
     fn is_directed(&self) -> bool {
         false
     }
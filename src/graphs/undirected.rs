@@ -4,7 +4,14 @@
 //!
 //! This module provides:
 //! - [`UndirectedGraph`] as a weighted, non-directional graph container,
+//!   whose edges may cost differently in each direction
+//!   ([`UndirectedGraph::insert_two_way_edge`]),
 //! - adjacency lists for undirected neighbor traversal,
+//! - [`UndirectedEdge`] as a plain snapshot of one edge returned by queries
+//!   such as [`UndirectedGraph::bridges`],
+//! - [`UndirectedGraph::mst_kruskal`] and [`UndirectedGraph::mst_prim`] for
+//!   minimum spanning trees,
+//! - [`UndirectedGraph::greedy_coloring`] for conflict-free color assignment,
 //! - [`UndirectedGraphInsertionError`] for insertion failures.
 //!
 //! # File Abbreviation
@@ -27,10 +34,21 @@
 //! assert!(!graph.is_directed());
 //! ```
 
-use std::{collections::HashMap, error::Error, fmt::Display};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    error::Error,
+    fmt::Display,
+};
 
 use crate::{
-    graphs::graph::{Graph, GraphNode},
+    graphs::{
+        graph::{Graph, GraphNode, LabeledNeighbor},
+        utils::{
+            AdjacencyEntry, EdgeLabels, contract_chains, extract_component, induce_by_edges,
+            partition_isolated, remap_node_ids, weakly_connected_components,
+        },
+    },
     nodes::default_node::DefaultNode,
 };
 
@@ -48,14 +66,26 @@ use crate::{
 /// - Edges can only be inserted if both endpoint nodes already exist.
 /// - Self-loop edges are stored once.
 /// - Neighbor traversal is backed by an index-based adjacency list.
+/// - Both directions of an edge share one existence and kind, but each keeps
+///   its own weight, so [`Graph::neighbors`] yields the cost of leaving the
+///   given node. Edges inserted through [`Graph::insert_edge`] are symmetric.
 #[derive(Debug, Clone)]
 pub struct UndirectedGraph {
     /// Nodes currently contained in the graph.
     nodes: Vec<DefaultNode>,
     /// Fast ID-to-index lookup for node access.
     node_index_by_id: HashMap<String, usize>,
-    /// Adjacency list storing `(neighbor_index, weight)` for each node index.
-    adjacency: Vec<Vec<(usize, u16)>>,
+    /// Adjacency list storing `(neighbor_index, weight, labels)` for each
+    /// node index. The weight is the cost of travelling from the indexed
+    /// node; the labels (kind and layer) are stored for both directions.
+    adjacency: Vec<Vec<AdjacencyEntry<u16>>>,
+    /// Set once any edge weight was filled in instead of given explicitly
+    /// (see [`Self::mark_weights_implicit`]); [`Graph::is_weighted`] then
+    /// returns `false`.
+    has_implicit_weights: bool,
+    /// Set by [`UndirectedGraph::new_multigraph`]; lets [`Graph::insert_edge`]
+    /// add further edges between the same two nodes.
+    allows_parallel_edges: bool,
 }
 
 impl Graph for UndirectedGraph {
@@ -77,10 +107,10 @@ impl Graph for UndirectedGraph {
             // Treat an edge as present if either adjacency list contains it.
             return self.adjacency[from_index]
                 .iter()
-                .any(|(neighbor_index, _)| *neighbor_index == to_index)
+                .any(|(neighbor_index, ..)| *neighbor_index == to_index)
                 || self.adjacency[to_index]
                     .iter()
-                    .any(|(neighbor_index, _)| *neighbor_index == from_index);
+                    .any(|(neighbor_index, ..)| *neighbor_index == from_index);
         }
 
         false
@@ -97,10 +127,35 @@ impl Graph for UndirectedGraph {
         Box::new(
             self.adjacency[source_index]
                 .iter()
-                .map(move |(neighbor_index, weight)| (&self.nodes[*neighbor_index], *weight)),
+                .map(move |(neighbor_index, weight, _)| (&self.nodes[*neighbor_index], *weight)),
         )
     }
 
+    fn labeled_neighbors<'a>(
+        &'a self,
+        u: &Self::Node,
+    ) -> Box<dyn Iterator<Item = LabeledNeighbor<'a, Self::Node, Self::Weight>> + 'a> {
+        let Some(source_index) = self.node_index_for_id(u.get_id()) else {
+            return Box::new(std::iter::empty());
+        };
+
+        Box::new(self.adjacency[source_index].iter().map(
+            move |(neighbor_index, weight, labels)| {
+                (
+                    &self.nodes[*neighbor_index],
+                    *weight,
+                    labels.kind.as_deref(),
+                    labels.layer.as_deref(),
+                )
+            },
+        ))
+    }
+
+    fn out_degree(&self, u: &Self::Node) -> usize {
+        self.node_index_for_id(u.get_id())
+            .map_or(0, |index| self.adjacency[index].len())
+    }
+
     fn is_directed(&self) -> bool {
         false
     }
@@ -123,7 +178,7 @@ impl Graph for UndirectedGraph {
         to: &Self::Node,
         weight: Option<Self::Weight>,
     ) -> Option<Self::InsertionError> {
-        if self.does_edge_already_exist(from, to) {
+        if !self.allows_parallel_edges && self.does_edge_already_exist(from, to) {
             return Some(UndirectedGraphInsertionError::new(format!(
                 "The edge between '{}' and '{}' already exists in the graph!",
                 from.get_id(),
@@ -167,12 +222,12 @@ impl Graph for UndirectedGraph {
         };
 
         if a_index == b_index {
-            self.adjacency[a_index].push((b_index, weight));
+            self.adjacency[a_index].push((b_index, weight, EdgeLabels::default()));
             return None;
         }
 
-        self.adjacency[a_index].push((b_index, weight));
-        self.adjacency[b_index].push((a_index, weight));
+        self.adjacency[a_index].push((b_index, weight, EdgeLabels::default()));
+        self.adjacency[b_index].push((a_index, weight, EdgeLabels::default()));
 
         None
     }
@@ -188,7 +243,15 @@ impl Graph for UndirectedGraph {
     }
 
     fn is_weighted(&self) -> bool {
-        true
+        !self.has_implicit_weights
+    }
+
+    fn edge_kind(&self, from: &Self::Node, to: &Self::Node) -> Option<&str> {
+        self.edge_labels(from, to)?.kind.as_deref()
+    }
+
+    fn edge_layer(&self, from: &Self::Node, to: &Self::Node) -> Option<&str> {
+        self.edge_labels(from, to)?.layer.as_deref()
     }
 
     fn abbreviation() -> String {
@@ -211,6 +274,12 @@ impl UndirectedGraph {
         self.node_index_by_id.get(id).copied()
     }
 
+    /// Returns whether the graph accepts parallel edges, see
+    /// [`UndirectedGraph::new_multigraph`].
+    pub fn is_multigraph(&self) -> bool {
+        self.allows_parallel_edges
+    }
+
     /// Creates a new undirected graph from a node vector.
     ///
     /// # Arguments
@@ -237,6 +306,8 @@ impl UndirectedGraph {
             nodes: Vec::new(),
             node_index_by_id: HashMap::new(),
             adjacency: Vec::new(),
+            has_implicit_weights: false,
+            allows_parallel_edges: false,
         };
 
         for node in nodes {
@@ -245,6 +316,1339 @@ impl UndirectedGraph {
 
         graph
     }
+
+    /// Creates an undirected multigraph, which accepts parallel edges.
+    ///
+    /// Unlike a graph from [`UndirectedGraph::new`], [`Graph::insert_edge`]
+    /// does not reject a second edge between the same two nodes, so several
+    /// connections with different weights can coexist. [`Graph::neighbors`]
+    /// yields every one of them, and shortest-path searches pick the
+    /// cheapest.
+    ///
+    /// Every parallel edge keeps its own kind and layer, but edges are still
+    /// identified by their endpoints: [`Graph::edge_kind`],
+    /// [`UndirectedGraph::remove_edge`], [`UndirectedGraph::set_edge_layer`]
+    /// and [`UndirectedGraph::set_edge_weight`] act on the earliest inserted
+    /// one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = UndirectedGraph::new_multigraph(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(7)).is_none());
+    /// assert!(graph.insert_edge(&b, &a, Some(3)).is_none());
+    ///
+    /// assert!(graph.is_multigraph());
+    /// assert_eq!(graph.neighbors(&b).map(|(_, w)| w).collect::<Vec<_>>(), vec![7, 3]);
+    /// ```
+    pub fn new_multigraph(nodes: Vec<DefaultNode>) -> Self {
+        let mut graph = Self::new(nodes);
+        graph.allows_parallel_edges = true;
+        graph
+    }
+
+    /// Inserts an edge like [`Graph::insert_edge`] and tags it with a kind.
+    ///
+    /// The kind applies to both directions of the undirected edge.
+    /// # Parameters
+    ///
+    /// - `from`, `to`, `weight`: Same as [`Graph::insert_edge`].
+    /// - `kind`: Optional edge kind such as `highway` or `ferry`, later
+    ///   reported by [`Graph::edge_kind`]. `None` behaves exactly like
+    ///   [`Graph::insert_edge`].
+    ///
+    /// # Returns
+    ///
+    /// - `None` on success.
+    /// - `Some(UndirectedGraphInsertionError)` when the edge could not be inserted; no kind is
+    ///   recorded in that case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph
+    ///     .insert_edge_with_kind(&a, &b, Some(4), Some("highway".to_string()))
+    ///     .is_none());
+    /// assert_eq!(graph.edge_kind(&a, &b), Some("highway"));
+    /// ```
+    pub fn insert_edge_with_kind(
+        &mut self,
+        from: &DefaultNode,
+        to: &DefaultNode,
+        weight: Option<u16>,
+        kind: Option<String>,
+    ) -> Option<UndirectedGraphInsertionError> {
+        self.insert_edge_with_labels(from, to, weight, kind, None)
+    }
+
+    /// Inserts an edge like [`Graph::insert_edge`] and tags it with a kind
+    /// and a layer.
+    ///
+    /// Both apply to both directions. Unlike
+    /// [`UndirectedGraph::set_edge_layer`], which acts on the earliest edge
+    /// between the two nodes, this labels exactly the inserted edge, so
+    /// parallel edges of a multigraph can carry different kinds and layers.
+    ///
+    /// # Parameters
+    ///
+    /// - `from`, `to`, `weight`: Same as [`Graph::insert_edge`].
+    /// - `kind`: Optional edge kind, as in [`Self::insert_edge_with_kind`].
+    /// - `layer`: Optional layer, as in [`Self::set_edge_layer`].
+    ///
+    /// # Returns
+    ///
+    /// - `None` on success.
+    /// - `Some(UndirectedGraphInsertionError)` when the edge could not be
+    ///   inserted; no label is recorded in that case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = UndirectedGraph::new_multigraph(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(2)).is_none());
+    /// assert!(graph
+    ///     .insert_edge_with_labels(&a, &b, Some(5), None, Some("bus".to_string()))
+    ///     .is_none());
+    ///
+    /// let layers: Vec<_> = graph.labeled_neighbors(&b).map(|(_, _, _, layer)| layer).collect();
+    /// assert_eq!(layers, vec![None, Some("bus")]);
+    /// ```
+    pub fn insert_edge_with_labels(
+        &mut self,
+        from: &DefaultNode,
+        to: &DefaultNode,
+        weight: Option<u16>,
+        kind: Option<String>,
+        layer: Option<String>,
+    ) -> Option<UndirectedGraphInsertionError> {
+        if let Some(err) = self.insert_edge(from, to, weight) {
+            return Some(err);
+        }
+
+        // The edge was just inserted, so it is the last entry per direction.
+        let labels = EdgeLabels { kind, layer };
+        if let (Some(a_index), Some(b_index)) = (
+            self.node_index_for_id(from.get_id()),
+            self.node_index_for_id(to.get_id()),
+        ) {
+            for (from_index, to_index) in [(a_index, b_index), (b_index, a_index)] {
+                if let Some(entry) = self.adjacency[from_index]
+                    .iter_mut()
+                    .rev()
+                    .find(|(neighbor_index, ..)| *neighbor_index == to_index)
+                {
+                    entry.2 = labels.clone();
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the labels of the earliest inserted edge from `from` to `to`.
+    fn edge_labels(&self, from: &DefaultNode, to: &DefaultNode) -> Option<&EdgeLabels> {
+        let from_index = self.node_index_for_id(from.get_id())?;
+        let to_index = self.node_index_for_id(to.get_id())?;
+        self.adjacency[from_index]
+            .iter()
+            .find(|(neighbor_index, ..)| *neighbor_index == to_index)
+            .map(|(_, _, labels)| labels)
+    }
+
+    /// Inserts an undirected edge whose cost depends on the travel direction,
+    /// such as a road that is slower uphill than downhill.
+    ///
+    /// The edge exists once, like any undirected edge, but
+    /// [`Graph::neighbors`] of `a` yields `a_to_b` and of `b` yields
+    /// `b_to_a`. This is the graph-side counterpart of the `A<->B:7|9` file
+    /// syntax.
+    ///
+    /// # Parameters
+    ///
+    /// - `a`, `b`: Endpoints; both must already exist.
+    /// - `a_to_b`: Cost of travelling from `a` to `b`.
+    /// - `b_to_a`: Cost of travelling from `b` to `a`.
+    /// - `kind`: Optional edge kind, as in [`Self::insert_edge_with_kind`].
+    ///
+    /// # Returns
+    ///
+    /// - `None` on success.
+    /// - `Some(UndirectedGraphInsertionError)` under the same conditions as
+    ///   [`Graph::insert_edge`], or for a self-loop with two different
+    ///   weights, since a self-loop has only one direction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_two_way_edge(&a, &b, 7, 9, None).is_none());
+    ///
+    /// assert_eq!(graph.neighbors(&a).next().unwrap().1, 7);
+    /// assert_eq!(graph.neighbors(&b).next().unwrap().1, 9);
+    /// ```
+    pub fn insert_two_way_edge(
+        &mut self,
+        a: &DefaultNode,
+        b: &DefaultNode,
+        a_to_b: u16,
+        b_to_a: u16,
+        kind: Option<String>,
+    ) -> Option<UndirectedGraphInsertionError> {
+        self.insert_labeled_two_way_edge(a, b, a_to_b, b_to_a, kind, None)
+    }
+
+    /// Inserts an edge like [`Self::insert_two_way_edge`] and also tags it
+    /// with a layer, as [`Self::insert_edge_with_labels`] does.
+    pub(crate) fn insert_labeled_two_way_edge(
+        &mut self,
+        a: &DefaultNode,
+        b: &DefaultNode,
+        a_to_b: u16,
+        b_to_a: u16,
+        kind: Option<String>,
+        layer: Option<String>,
+    ) -> Option<UndirectedGraphInsertionError> {
+        if a.get_id() == b.get_id() && a_to_b != b_to_a {
+            return Some(UndirectedGraphInsertionError::new(format!(
+                "The self-loop at '{}' can't have two different weights ({} and {})!",
+                a.get_id(),
+                a_to_b,
+                b_to_a
+            )));
+        }
+        if let Some(err) = self.insert_edge_with_labels(a, b, Some(a_to_b), kind, layer) {
+            return Some(err);
+        }
+
+        // The edge was just inserted, so both indices and the entry exist.
+        // It is the last entry, which matters when parallel edges exist.
+        if let (Some(a_index), Some(b_index)) = (
+            self.node_index_for_id(a.get_id()),
+            self.node_index_for_id(b.get_id()),
+        ) && let Some(entry) = self.adjacency[b_index]
+            .iter_mut()
+            .rev()
+            .find(|(neighbor_index, ..)| *neighbor_index == a_index)
+        {
+            entry.1 = b_to_a;
+        }
+
+        None
+    }
+
+    /// Returns the weight of travelling from `from_index` to `to_index`.
+    fn weight_between(&self, from_index: usize, to_index: usize) -> Option<u16> {
+        self.adjacency[from_index]
+            .iter()
+            .find(|(neighbor_index, ..)| *neighbor_index == to_index)
+            .map(|&(_, weight, _)| weight)
+    }
+
+    /// Removes the edge between `a` and `b`, including its kind.
+    ///
+    /// Endpoint order does not matter (`A-B` equals `B-A`).
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the edge existed and was removed.
+    /// - `false` if either node or the edge does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(4)).is_none());
+    ///
+    /// assert!(graph.remove_edge(&b, &a));
+    /// assert!(!graph.does_edge_already_exist(&a, &b));
+    /// assert_eq!(graph.neighbors(&a).count(), 0);
+    /// ```
+    pub fn remove_edge(&mut self, a: &DefaultNode, b: &DefaultNode) -> bool {
+        let (Some(a_index), Some(b_index)) = (
+            self.node_index_for_id(a.get_id()),
+            self.node_index_for_id(b.get_id()),
+        ) else {
+            return false;
+        };
+
+        let Some(position) = self.adjacency[a_index]
+            .iter()
+            .position(|(neighbor_index, ..)| *neighbor_index == b_index)
+        else {
+            return false;
+        };
+        self.adjacency[a_index].remove(position);
+        // Self-loops are stored once; other edges also have a reverse entry.
+        if a_index != b_index
+            && let Some(position) = self.adjacency[b_index]
+                .iter()
+                .position(|(neighbor_index, ..)| *neighbor_index == a_index)
+        {
+            self.adjacency[b_index].remove(position);
+        }
+        true
+    }
+
+    /// Removes the node with ID `id` together with every edge touching the node, from either endpoint.
+    ///
+    /// Kinds and layers of the removed edges are dropped as well, so no
+    /// dangling edge remains. Remaining nodes keep their relative order.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(node)` with the removed node.
+    /// - `None` if no node has this ID.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone(), c.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(4)).is_none());
+    /// assert!(graph.insert_edge(&c, &b, Some(2)).is_none());
+    ///
+    /// assert_eq!(graph.remove_node("B"), Some(b));
+    /// assert_eq!(graph.get_all_nodes(), &vec![a.clone(), c.clone()]);
+    /// assert_eq!(graph.neighbors(&c).count(), 0);
+    /// assert_eq!(graph.remove_node("B"), None);
+    /// ```
+    pub fn remove_node(&mut self, id: &str) -> Option<DefaultNode> {
+        let removed_index = self.node_index_for_id(id)?;
+        let removed = self.nodes[removed_index].clone();
+
+        // Extracting every other node drops all edges touching the removed one.
+        let remaining: Vec<usize> = (0..self.nodes.len())
+            .filter(|&index| index != removed_index)
+            .collect();
+        let parts = extract_component(&self.nodes, &self.adjacency, &remaining);
+        self.nodes = parts.nodes;
+        self.node_index_by_id = parts.node_index_by_id;
+        self.adjacency = parts.adjacency;
+        Some(removed)
+    }
+
+    /// Assigns the edge between `a` and `b` to a layer, or clears its layer.
+    ///
+    /// Behaves like [`DirectedGraph::set_edge_layer`](crate::graphs::directed::DirectedGraph::set_edge_layer);
+    /// the layer applies to both directions and endpoint order does not
+    /// matter.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the edge exists and its layer was updated.
+    /// - `false` if either node or the edge does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(4)).is_none());
+    ///
+    /// assert!(graph.set_edge_layer(&b, &a, Some("walk".to_string())));
+    /// assert_eq!(graph.edge_layer(&a, &b), Some("walk"));
+    /// assert_eq!(graph.edge_layer(&b, &a), Some("walk"));
+    /// ```
+    pub fn set_edge_layer(
+        &mut self,
+        a: &DefaultNode,
+        b: &DefaultNode,
+        layer: Option<String>,
+    ) -> bool {
+        let (Some(a_index), Some(b_index)) = (
+            self.node_index_for_id(a.get_id()),
+            self.node_index_for_id(b.get_id()),
+        ) else {
+            return false;
+        };
+
+        let mut updated = false;
+        // Self-loops are stored once; other edges also have a reverse entry.
+        for (from_index, to_index) in [(a_index, b_index), (b_index, a_index)] {
+            if let Some(entry) = self.adjacency[from_index]
+                .iter_mut()
+                .find(|(neighbor_index, ..)| *neighbor_index == to_index)
+            {
+                entry.2.layer = layer.clone();
+                updated = true;
+            }
+        }
+        updated
+    }
+
+    /// Changes the weight of the existing edge between `a` and `b`.
+    ///
+    /// Both travel directions get the new weight, also when the edge was
+    /// inserted with two different ones
+    /// ([`UndirectedGraph::insert_two_way_edge`]). Endpoint order does not
+    /// matter; kind and layer are kept.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the edge exists and its weight was updated.
+    /// - `false` if either node or the edge does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(7)).is_none());
+    ///
+    /// assert!(graph.set_edge_weight(&b, &a, 2));
+    /// assert_eq!(graph.neighbors(&a).next().unwrap().1, 2);
+    /// assert_eq!(graph.neighbors(&b).next().unwrap().1, 2);
+    /// ```
+    pub fn set_edge_weight(&mut self, a: &DefaultNode, b: &DefaultNode, weight: u16) -> bool {
+        let (Some(a_index), Some(b_index)) = (
+            self.node_index_for_id(a.get_id()),
+            self.node_index_for_id(b.get_id()),
+        ) else {
+            return false;
+        };
+
+        let mut updated = false;
+        // Self-loops are stored once; other edges also have a reverse entry.
+        for (from_index, to_index) in [(a_index, b_index), (b_index, a_index)] {
+            if let Some(entry) = self.adjacency[from_index]
+                .iter_mut()
+                .find(|(neighbor_index, ..)| *neighbor_index == to_index)
+            {
+                entry.1 = weight;
+                updated = true;
+            }
+        }
+        updated
+    }
+
+    /// Removes every node without any incident edge and returns the removed nodes.
+    ///
+    /// Useful after [`UndirectedGraph::remove_edge`] calls leave orphaned nodes
+    /// behind. Remaining nodes keep their relative order, and edges, weights
+    /// and kinds are unchanged. A node with a self-loop is not isolated.
+    ///
+    /// # Returns
+    ///
+    /// The removed nodes in insertion order; empty if no node was isolated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let c = DefaultNode::new("C".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone(), c.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(4)).is_none());
+    /// assert!(graph.insert_edge(&b, &c, Some(2)).is_none());
+    /// assert!(graph.remove_edge(&b, &c));
+    ///
+    /// assert_eq!(graph.remove_isolated_nodes(), vec![c]);
+    /// assert_eq!(graph.get_all_nodes(), &vec![a, b]);
+    /// ```
+    pub fn remove_isolated_nodes(&mut self) -> Vec<DefaultNode> {
+        let (connected, isolated) = partition_isolated(&self.adjacency);
+        if isolated.is_empty() {
+            return Vec::new();
+        }
+
+        let removed = isolated
+            .iter()
+            .map(|&index| self.nodes[index].clone())
+            .collect();
+        let parts = extract_component(&self.nodes, &self.adjacency, &connected);
+        self.nodes = parts.nodes;
+        self.node_index_by_id = parts.node_index_by_id;
+        self.adjacency = parts.adjacency;
+        removed
+    }
+
+    /// Contracts pass-through nodes of degree two into single edges and
+    /// returns how many nodes were removed.
+    ///
+    /// A node qualifies when it has exactly two distinct neighbors; the
+    /// replacement edge keeps a separate sum for each travel direction.
+    /// The new edge weighs the sum of the two replaced edges and keeps their
+    /// kind if both share it. Contraction repeats until no node qualifies, so
+    /// a whole chain collapses into one edge between its ends, which shrinks
+    /// road-like graphs considerably before routing. Shortest distances
+    /// between the remaining nodes are unchanged.
+    ///
+    /// A node is kept if it is protected, has a self-loop, or its two
+    /// neighbors are already adjacent (the graph stores one edge per
+    /// direction). Remaining nodes keep their relative order.
+    ///
+    /// # Parameters
+    ///
+    /// - `protected`: IDs of nodes that must survive, such as route
+    ///   endpoints. Unknown IDs are ignored.
+    ///
+    /// # Returns
+    ///
+    /// The number of contracted nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashSet;
+    ///
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let nodes: Vec<DefaultNode> = ["A", "B", "C", "D"]
+    ///     .iter()
+    ///     .map(|id| DefaultNode::new(id.to_string()))
+    ///     .collect();
+    /// let mut graph = UndirectedGraph::new(nodes.clone());
+    /// assert!(graph.insert_edge(&nodes[0], &nodes[1], Some(2)).is_none());
+    /// assert!(graph.insert_edge(&nodes[1], &nodes[2], Some(3)).is_none());
+    /// assert!(graph.insert_edge(&nodes[2], &nodes[3], Some(4)).is_none());
+    ///
+    /// let protected: HashSet<String> = HashSet::from(["C".to_string()]);
+    /// assert_eq!(graph.simplify_chains(&protected), 1);
+    /// assert_eq!(
+    ///     graph.to_edge_list(),
+    ///     vec![
+    ///         ("A".to_string(), "C".to_string(), 5),
+    ///         ("C".to_string(), "D".to_string(), 4),
+    ///     ]
+    /// );
+    /// ```
+    pub fn simplify_chains(&mut self, protected: &HashSet<String>) -> usize {
+        let is_protected: Vec<bool> = self
+            .nodes
+            .iter()
+            .map(|node| protected.contains(node.get_id()))
+            .collect();
+        let contracted = contract_chains(&mut self.adjacency, &is_protected);
+        if contracted.is_empty() {
+            return 0;
+        }
+
+        let remaining: Vec<usize> = (0..self.nodes.len())
+            .filter(|index| contracted.binary_search(index).is_err())
+            .collect();
+        let parts = extract_component(&self.nodes, &self.adjacency, &remaining);
+        self.nodes = parts.nodes;
+        self.node_index_by_id = parts.node_index_by_id;
+        self.adjacency = parts.adjacency;
+        contracted.len()
+    }
+
+    /// Records that at least one edge weight was not given explicitly.
+    ///
+    /// Behaves like [`DirectedGraph::mark_weights_implicit`](crate::graphs::directed::DirectedGraph::mark_weights_implicit):
+    /// afterwards [`Graph::is_weighted`] returns `false`.
+    pub fn mark_weights_implicit(&mut self) {
+        self.has_implicit_weights = true;
+    }
+
+    /// Builds a graph from `(from_id, to_id, weight)` tuples.
+    ///
+    /// Nodes are created on first mention, in the order they appear. This is
+    /// the inverse of [`Graph::to_edge_list`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UndirectedGraphInsertionError`] when the list contains the same edge twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    ///
+    /// let edges: Vec<(String, String, u16)> = vec![("A".to_string(), "B".to_string(), 7)];
+    /// let graph = UndirectedGraph::from_edges(&edges).unwrap();
+    /// assert_eq!(graph.get_all_nodes().len(), 2);
+    /// assert_eq!(graph.to_edge_list(), edges);
+    /// ```
+    pub fn from_edges(
+        edges: &[(String, String, u16)],
+    ) -> Result<Self, UndirectedGraphInsertionError> {
+        let mut graph = Self::new(vec![]);
+
+        for (from_id, to_id, weight) in edges {
+            let from = DefaultNode::new(from_id.clone());
+            let to = DefaultNode::new(to_id.clone());
+            graph.insert_node(from.clone());
+            graph.insert_node(to.clone());
+            if let Some(err) = graph.insert_edge(&from, &to, Some(*weight)) {
+                return Err(err);
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Builds a graph from nodes and `(from_id, to_id, weight)` edges, checking
+    /// every edge first.
+    ///
+    /// This is the validated counterpart to [`UndirectedGraph::new`] followed by manual
+    /// [`Graph::insert_edge`] calls: instead of trusting the caller, it
+    /// reports the first inconsistent edge. Duplicate node IDs are ignored,
+    /// as in [`UndirectedGraph::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a message naming the offending edge when
+    /// - an endpoint ID is not among `nodes`, or
+    /// - the same edge appears twice. `(A, B)` and `(B, A)` count
+    ///   as the same edge.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let nodes = vec![DefaultNode::new("A".to_string()), DefaultNode::new("B".to_string())];
+    /// let edges = vec![("A".to_string(), "B".to_string(), 3)];
+    /// let graph = UndirectedGraph::try_new(nodes.clone(), &edges).unwrap();
+    /// assert_eq!(graph.to_edge_list(), edges);
+    ///
+    /// let dangling = vec![("A".to_string(), "Z".to_string(), 1)];
+    /// let err = UndirectedGraph::try_new(nodes, &dangling).unwrap_err();
+    /// assert!(err.contains("'Z'"));
+    /// ```
+    pub fn try_new(
+        nodes: Vec<DefaultNode>,
+        edges: &[(String, String, u16)],
+    ) -> Result<Self, String> {
+        let mut graph = Self::new(nodes);
+
+        for (from_id, to_id, weight) in edges {
+            let (from, to) = match (graph.get_node_by_id(from_id), graph.get_node_by_id(to_id)) {
+                (Some(from), Some(to)) => (from.clone(), to.clone()),
+                (from, _) => {
+                    let missing = if from.is_none() { from_id } else { to_id };
+                    return Err(format!(
+                        "The edge {} - {} references the unknown node '{}'!",
+                        from_id, to_id, missing
+                    ));
+                }
+            };
+            if graph.does_edge_already_exist(&from, &to) {
+                return Err(format!(
+                    "The edge {} - {} is listed more than once!",
+                    from_id, to_id
+                ));
+            }
+            if let Some(err) = graph.insert_edge(&from, &to, Some(*weight)) {
+                return Err(err.message);
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Returns a copy of the graph with every node ID transformed by `f`.
+    ///
+    /// Edges and weights are carried over unchanged, so every edge keeps
+    /// pointing at the renamed versions of its original endpoints.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: Maps an old node ID to the new one, e.g. to add a region prefix
+    ///   or strip a common suffix.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Self)` with the renamed graph.
+    /// - `Err(String)` when `f` maps two distinct IDs to the same ID, which
+    ///   would silently merge two nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::{Graph, GraphNode};
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("A".to_string());
+    /// let b = DefaultNode::new("B".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(3)).is_none());
+    ///
+    /// let prefixed = graph.map_nodes(|id| format!("north-{}", id)).unwrap();
+    /// let north_a = prefixed.get_node_by_id("north-A").unwrap();
+    /// assert_eq!(prefixed.neighbors(north_a).next().unwrap().0.get_id(), "north-B");
+    ///
+    /// assert!(graph.map_nodes(|_| "X".to_string()).is_err());
+    /// ```
+    pub fn map_nodes(&self, f: impl Fn(&str) -> String) -> Result<Self, String> {
+        let (nodes, node_index_by_id) = remap_node_ids(&self.nodes, f)?;
+
+        Ok(Self {
+            nodes,
+            node_index_by_id,
+            adjacency: self.adjacency.clone(),
+            has_implicit_weights: self.has_implicit_weights,
+            allows_parallel_edges: self.allows_parallel_edges,
+        })
+    }
+
+    /// Returns a copy of the graph whose node IDs are `"0"` to `"N-1"`, plus
+    /// the mapping from each original ID to its new index.
+    ///
+    /// Indices follow node insertion order, so relabeling the same graph
+    /// twice yields the same result. Edges, weights and kinds are carried
+    /// over unchanged. The reverse lookup (index to original ID) is simply
+    /// `self.get_all_nodes()[index]`.
+    ///
+    /// # Returns
+    ///
+    /// - The relabeled graph.
+    /// - A map from every original node ID to its dense integer index.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::{Graph, GraphNode};
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let a = DefaultNode::new("Berlin".to_string());
+    /// let b = DefaultNode::new("Hamburg".to_string());
+    /// let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone()]);
+    /// assert!(graph.insert_edge(&a, &b, Some(3)).is_none());
+    ///
+    /// let (relabeled, index_by_id) = graph.relabel_to_integers();
+    /// assert_eq!(index_by_id["Hamburg"], 1);
+    /// let zero = relabeled.get_node_by_id("0").unwrap();
+    /// assert_eq!(relabeled.neighbors(zero).next().unwrap(), (&DefaultNode::new("1".to_string()), 3));
+    /// ```
+    pub fn relabel_to_integers(&self) -> (Self, HashMap<String, usize>) {
+        let relabeled = self
+            .map_nodes(|id| self.node_index_by_id[id].to_string())
+            .expect("node indices are unique");
+
+        (relabeled, self.node_index_by_id.clone())
+    }
+
+    /// Returns the node sets of the connected components.
+    ///
+    /// Two nodes share a component when a path connects them, so a
+    /// shortest-path query between different components cannot succeed and
+    /// need not be run. Unlike [`UndirectedGraph::split_components`], only
+    /// the nodes are returned, not standalone graphs.
+    ///
+    /// # Returns
+    ///
+    /// One node list per component, ordered by the insertion order of each
+    /// component's first node; nodes keep their relative order. Isolated
+    /// nodes form single-node components. An empty graph yields an empty
+    /// list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::GraphNode;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    ///
+    /// let edges: Vec<(String, String, u16)> = vec![
+    ///     ("A".to_string(), "B".to_string(), 1),
+    ///     ("X".to_string(), "Y".to_string(), 2),
+    /// ];
+    /// let graph = UndirectedGraph::from_edges(&edges).unwrap();
+    ///
+    /// let components = graph.connected_components();
+    /// assert_eq!(components.len(), 2);
+    /// assert_eq!(components[1][0].get_id(), "X");
+    /// ```
+    pub fn connected_components(&self) -> Vec<Vec<DefaultNode>> {
+        weakly_connected_components(&self.adjacency)
+            .into_iter()
+            .map(|members| {
+                members
+                    .into_iter()
+                    .map(|index| self.nodes[index].clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Splits the graph into its connected components, each as a standalone graph.
+    ///
+    /// Every component keeps its nodes, the edges between them, their
+    /// weights and kinds, and whether weights are implicit. Because no edge
+    /// crosses components, algorithms can be run per component without
+    /// missing any route.
+    ///
+    /// # Returns
+    ///
+    /// One graph per component, ordered by the insertion order of each
+    /// component's first node; nodes keep their relative order. Isolated
+    /// nodes form single-node components. An empty graph yields an empty
+    /// list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let nodes: Vec<DefaultNode> = ["A", "B", "C", "Lonely"]
+    ///     .iter()
+    ///     .map(|id| DefaultNode::new(id.to_string()))
+    ///     .collect();
+    /// let mut graph = UndirectedGraph::new(nodes.clone());
+    /// graph.insert_edge(&nodes[0], &nodes[1], Some(4));
+    /// graph.insert_edge(&nodes[1], &nodes[2], Some(2));
+    ///
+    /// let components = graph.split_components();
+    /// assert_eq!(components.len(), 2);
+    /// assert_eq!(components[0].get_all_nodes().len(), 3);
+    /// assert_eq!(components[1].get_all_nodes()[0], nodes[3]);
+    /// ```
+    pub fn split_components(&self) -> Vec<Self> {
+        weakly_connected_components(&self.adjacency)
+            .iter()
+            .map(|members| {
+                let parts = extract_component(&self.nodes, &self.adjacency, members);
+                Self {
+                    nodes: parts.nodes,
+                    node_index_by_id: parts.node_index_by_id,
+                    adjacency: parts.adjacency,
+                    has_implicit_weights: self.has_implicit_weights,
+                    allows_parallel_edges: self.allows_parallel_edges,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the subgraph made of the edges accepted by `keep` and the nodes
+    /// they touch.
+    ///
+    /// Unlike a plain edge filter, nodes left without any kept edge are
+    /// dropped, so for example keeping only `highway` edges yields the highway
+    /// network and the towns on it. Weights, kinds, and the relative node
+    /// order are preserved. An undirected edge is
+    /// judged once, with its earlier-inserted endpoint as `from` (and the
+    /// weight of that direction), and kept or dropped as a whole.
+    ///
+    /// # Parameters
+    ///
+    /// - `keep`: Predicate over `(from, to, weight, kind)`; `kind` is the
+    ///   edge kind, if any.
+    ///
+    /// # Returns
+    ///
+    /// A new graph; `self` is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let nodes: Vec<DefaultNode> = ["A", "B", "C"]
+    ///     .iter()
+    ///     .map(|id| DefaultNode::new(id.to_string()))
+    ///     .collect();
+    /// let mut graph = UndirectedGraph::new(nodes.clone());
+    /// graph.insert_edge(&nodes[0], &nodes[1], Some(3));
+    /// graph.insert_edge(&nodes[1], &nodes[2], Some(9));
+    ///
+    /// let cheap = graph.induced_by_edges(|_, _, weight, _| weight < 5);
+    /// assert_eq!(cheap.get_all_nodes().len(), 2);
+    /// assert!(cheap.get_node_by_id("C").is_none());
+    /// ```
+    pub fn induced_by_edges(
+        &self,
+        keep: impl Fn(&DefaultNode, &DefaultNode, u16, Option<&str>) -> bool,
+    ) -> Self {
+        let parts = induce_by_edges(&self.nodes, &self.adjacency, false, keep);
+        Self {
+            nodes: parts.nodes,
+            node_index_by_id: parts.node_index_by_id,
+            adjacency: parts.adjacency,
+            has_implicit_weights: self.has_implicit_weights,
+            allows_parallel_edges: self.allows_parallel_edges,
+        }
+    }
+
+    /// Returns all bridges (cut edges) of the graph.
+    ///
+    /// A bridge is an edge whose removal disconnects its two endpoints, i.e.
+    /// a single point of failure for every route that crosses it. Edges on a
+    /// cycle are never bridges; self-loops are ignored.
+    ///
+    /// # Algorithm
+    ///
+    /// Tarjan's DFS low-link method: an edge `parent - child` of the DFS tree
+    /// is a bridge exactly when no node in the subtree of `child` has a back
+    /// edge to `parent` or above it (`low[child] > discovery[parent]`). The
+    /// DFS uses an explicit stack, so long chains cannot overflow the call
+    /// stack. Runs in `O(V + E)` and covers every connected component.
+    ///
+    /// # Returns
+    ///
+    /// The bridges as [`UndirectedEdge`] values. `a_node` is always the
+    /// endpoint that was inserted first, and the list is sorted by the
+    /// insertion order of `(a_node, b_node)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::{Graph, GraphNode};
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let nodes: Vec<DefaultNode> = ["A", "B", "C", "D"]
+    ///     .iter()
+    ///     .map(|id| DefaultNode::new(id.to_string()))
+    ///     .collect();
+    /// let mut graph = UndirectedGraph::new(nodes.clone());
+    /// // Triangle A-B-C plus the tail C-D.
+    /// graph.insert_edge(&nodes[0], &nodes[1], Some(1));
+    /// graph.insert_edge(&nodes[1], &nodes[2], Some(1));
+    /// graph.insert_edge(&nodes[2], &nodes[0], Some(1));
+    /// graph.insert_edge(&nodes[2], &nodes[3], Some(5));
+    ///
+    /// let bridges = graph.bridges();
+    /// assert_eq!(bridges.len(), 1);
+    /// assert_eq!(bridges[0].a_node.get_id(), "C");
+    /// assert_eq!(bridges[0].b_node.get_id(), "D");
+    /// assert_eq!(bridges[0].weight, 5);
+    /// ```
+    pub fn bridges(&self) -> Vec<UndirectedEdge> {
+        /// One DFS stack entry: the node, how it was entered, and how far its
+        /// adjacency list has been scanned.
+        struct Frame {
+            node: usize,
+            parent: Option<(usize, u16)>,
+            skipped_parent_edge: bool,
+            next_neighbor: usize,
+        }
+
+        let node_count = self.nodes.len();
+        let mut discovery: Vec<Option<usize>> = vec![None; node_count];
+        let mut low = vec![0; node_count];
+        let mut timer = 0;
+        let mut found: Vec<(usize, usize, u16)> = Vec::new();
+
+        for root in 0..node_count {
+            if discovery[root].is_some() {
+                continue;
+            }
+            discovery[root] = Some(timer);
+            low[root] = timer;
+            timer += 1;
+
+            let mut stack = vec![Frame {
+                node: root,
+                parent: None,
+                skipped_parent_edge: false,
+                next_neighbor: 0,
+            }];
+
+            while let Some(frame) = stack.last_mut() {
+                let node = frame.node;
+
+                if let Some(&(next, weight, _)) = self.adjacency[node].get(frame.next_neighbor) {
+                    frame.next_neighbor += 1;
+
+                    if next == node {
+                        continue;
+                    }
+                    // Skip the tree edge back to the parent exactly once.
+                    if !frame.skipped_parent_edge
+                        && frame.parent.is_some_and(|(parent, _)| parent == next)
+                    {
+                        frame.skipped_parent_edge = true;
+                        continue;
+                    }
+
+                    match discovery[next] {
+                        // Back edge: `node` can reach an already discovered node.
+                        Some(next_discovery) => low[node] = low[node].min(next_discovery),
+                        None => {
+                            discovery[next] = Some(timer);
+                            low[next] = timer;
+                            timer += 1;
+                            stack.push(Frame {
+                                node: next,
+                                parent: Some((node, weight)),
+                                skipped_parent_edge: false,
+                                next_neighbor: 0,
+                            });
+                        }
+                    }
+                    continue;
+                }
+
+                // All neighbours done: propagate `low` to the parent and test the tree edge.
+                let parent = frame.parent;
+                stack.pop();
+                if let Some((parent, weight)) = parent {
+                    low[parent] = low[parent].min(low[node]);
+                    if discovery[parent]
+                        .is_some_and(|parent_discovery| low[node] > parent_discovery)
+                    {
+                        found.push((parent.min(node), parent.max(node), weight));
+                    }
+                }
+            }
+        }
+
+        found.sort_unstable();
+        found
+            .into_iter()
+            .map(|(a_index, b_index, weight)| {
+                UndirectedEdge::new_two_way(
+                    self.nodes[a_index].clone(),
+                    self.nodes[b_index].clone(),
+                    self.weight_between(a_index, b_index).unwrap_or(weight),
+                    self.weight_between(b_index, a_index).unwrap_or(weight),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns a minimum spanning forest using Kruskal's algorithm.
+    ///
+    /// Edges are taken in ascending cost order and kept whenever they join
+    /// two different trees (tracked with a union-find). A disconnected graph
+    /// yields one tree per connected component; an isolated node adds no
+    /// edge. Self-loops are ignored.
+    ///
+    /// The cost of an edge is its cheaper direction (see
+    /// [`UndirectedGraph::insert_two_way_edge`]); between parallel edges of a
+    /// multigraph only the cheapest counts.
+    ///
+    /// # Returns
+    ///
+    /// The chosen edges in the order they were accepted, i.e. by ascending
+    /// cost. `a_node` is the endpoint that was inserted first, and both
+    /// weights are the cheapest seen for that direction. For a connected
+    /// graph with `n` nodes the list has `n - 1` entries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::{Graph, GraphNode};
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let nodes: Vec<DefaultNode> = ["A", "B", "C"]
+    ///     .iter()
+    ///     .map(|id| DefaultNode::new(id.to_string()))
+    ///     .collect();
+    /// let mut graph = UndirectedGraph::new(nodes.clone());
+    /// graph.insert_edge(&nodes[0], &nodes[1], Some(1));
+    /// graph.insert_edge(&nodes[1], &nodes[2], Some(2));
+    /// graph.insert_edge(&nodes[0], &nodes[2], Some(5));
+    ///
+    /// let tree = graph.mst_kruskal();
+    /// let total: u16 = tree.iter().map(|edge| edge.weight).sum();
+    /// assert_eq!(tree.len(), 2);
+    /// assert_eq!(total, 3);
+    /// ```
+    pub fn mst_kruskal(&self) -> Vec<UndirectedEdge> {
+        /// Returns the representative of `index`, halving the path on the way.
+        fn find(parent: &mut [usize], mut index: usize) -> usize {
+            while parent[index] != index {
+                parent[index] = parent[parent[index]];
+                index = parent[index];
+            }
+            index
+        }
+
+        let costs = self.spanning_costs();
+        let mut candidates: Vec<(u16, usize, usize)> = costs
+            .iter()
+            .map(|(&(a_index, b_index), &(forward, backward))| {
+                (forward.min(backward), a_index, b_index)
+            })
+            .collect();
+        // Ties are broken by node order, so the result is deterministic.
+        candidates.sort_unstable();
+
+        let mut parent: Vec<usize> = (0..self.nodes.len()).collect();
+        let mut tree = Vec::new();
+        for (_, a_index, b_index) in candidates {
+            let a_root = find(&mut parent, a_index);
+            let b_root = find(&mut parent, b_index);
+            if a_root != b_root {
+                parent[a_root] = b_root;
+                tree.push(self.spanning_edge(&costs, a_index, b_index));
+            }
+        }
+        tree
+    }
+
+    /// Returns a minimum spanning tree grown from `start` with Prim's
+    /// algorithm.
+    ///
+    /// Starting from `start`, the cheapest edge leaving the tree is added
+    /// until every node is reached. A binary heap of candidate edges keeps
+    /// this at `O(E log E)`, which suits dense graphs better than
+    /// [`UndirectedGraph::mst_kruskal`]. Edge costs follow the same rules, so
+    /// both methods find a tree of the same total cost.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: Node the tree is grown from.
+    ///
+    /// # Returns
+    ///
+    /// The tree edges in the order they were added. `a_node` is the endpoint
+    /// that was inserted first, not necessarily the one closer to `start`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message when
+    /// - `start` is not part of the graph, or
+    /// - the graph is not connected, so no spanning tree exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::{Graph, GraphNode};
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let nodes: Vec<DefaultNode> = ["A", "B", "C"]
+    ///     .iter()
+    ///     .map(|id| DefaultNode::new(id.to_string()))
+    ///     .collect();
+    /// let mut graph = UndirectedGraph::new(nodes.clone());
+    /// graph.insert_edge(&nodes[0], &nodes[1], Some(4));
+    /// graph.insert_edge(&nodes[1], &nodes[2], Some(1));
+    /// graph.insert_edge(&nodes[0], &nodes[2], Some(2));
+    ///
+    /// let tree = graph.mst_prim(&nodes[0]).unwrap();
+    /// assert_eq!(tree[0].to_string(), "A - C (2)");
+    /// assert_eq!(tree[1].to_string(), "B - C (1)");
+    ///
+    /// graph.insert_node(DefaultNode::new("Lonely".to_string()));
+    /// assert!(graph.mst_prim(&nodes[0]).is_err());
+    /// ```
+    pub fn mst_prim(&self, start: &DefaultNode) -> Result<Vec<UndirectedEdge>, String> {
+        let Some(start_index) = self.node_index_for_id(start.get_id()) else {
+            return Err(format!(
+                "The start node '{}' isn't part of the graph!",
+                start.get_id()
+            ));
+        };
+
+        let costs = self.spanning_costs();
+        let mut neighbors: Vec<Vec<(usize, u16)>> = vec![Vec::new(); self.nodes.len()];
+        for (&(a_index, b_index), &(forward, backward)) in &costs {
+            let cost = forward.min(backward);
+            neighbors[a_index].push((b_index, cost));
+            neighbors[b_index].push((a_index, cost));
+        }
+
+        let mut in_tree = vec![false; self.nodes.len()];
+        let mut tree = Vec::with_capacity(self.nodes.len().saturating_sub(1));
+        let mut queue: BinaryHeap<Reverse<SpanningQueueItem>> = BinaryHeap::new();
+        in_tree[start_index] = true;
+        for &(next, cost) in &neighbors[start_index] {
+            queue.push(Reverse(SpanningQueueItem::new(cost, start_index, next)));
+        }
+
+        while let Some(Reverse(SpanningQueueItem { to, from, .. })) = queue.pop() {
+            // Stale entry: `to` joined the tree through a cheaper edge.
+            if in_tree[to] {
+                continue;
+            }
+            in_tree[to] = true;
+            tree.push(self.spanning_edge(&costs, from.min(to), from.max(to)));
+            for &(next, cost) in &neighbors[to] {
+                if !in_tree[next] {
+                    queue.push(Reverse(SpanningQueueItem::new(cost, to, next)));
+                }
+            }
+        }
+
+        let unreached = in_tree.iter().filter(|reached| !**reached).count();
+        if unreached > 0 {
+            return Err(format!(
+                "The graph is not connected: {} node(s) can't be reached from '{}'!",
+                unreached,
+                start.get_id()
+            ));
+        }
+        Ok(tree)
+    }
+
+    /// Collects the spanning-tree candidates of the graph.
+    ///
+    /// Maps every node pair `(a, b)` with `a < b` that shares at least one
+    /// edge to the cheapest `a -> b` and `b -> a` weights. Self-loops are
+    /// left out.
+    fn spanning_costs(&self) -> HashMap<(usize, usize), (u16, u16)> {
+        let mut costs: HashMap<(usize, usize), (u16, u16)> = HashMap::new();
+        for (from_index, neighbors) in self.adjacency.iter().enumerate() {
+            for &(to_index, weight, _) in neighbors {
+                if from_index == to_index {
+                    continue;
+                }
+                let key = (from_index.min(to_index), from_index.max(to_index));
+                let entry = costs.entry(key).or_insert((weight, weight));
+                if from_index < to_index {
+                    entry.0 = entry.0.min(weight);
+                } else {
+                    entry.1 = entry.1.min(weight);
+                }
+            }
+        }
+        costs
+    }
+
+    /// Builds the [`UndirectedEdge`] for the candidate `a_index - b_index`
+    /// (with `a_index < b_index`) of [`UndirectedGraph::spanning_costs`].
+    fn spanning_edge(
+        &self,
+        costs: &HashMap<(usize, usize), (u16, u16)>,
+        a_index: usize,
+        b_index: usize,
+    ) -> UndirectedEdge {
+        let (forward, backward) = costs[&(a_index, b_index)];
+        UndirectedEdge::new_two_way(
+            self.nodes[a_index].clone(),
+            self.nodes[b_index].clone(),
+            forward,
+            backward,
+        )
+    }
+
+    /// Colors the graph greedily so that adjacent nodes get different colors.
+    ///
+    /// Uses the largest-first heuristic: nodes are visited by descending
+    /// degree (ties keep insertion order) and each one takes the smallest
+    /// color index not used by an already colored neighbour. The result is a
+    /// valid coloring but not necessarily one with the fewest colors.
+    ///
+    /// Self-loops are ignored, since no coloring can satisfy them.
+    ///
+    /// # Returns
+    ///
+    /// A map from node ID to color index. Color indices start at `0` and are
+    /// contiguous, so the number of colors used is the largest index plus one
+    /// (see [`UndirectedGraph::greedy_color_count`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let nodes: Vec<DefaultNode> = ["A", "B", "C"]
+    ///     .iter()
+    ///     .map(|id| DefaultNode::new(id.to_string()))
+    ///     .collect();
+    /// let mut graph = UndirectedGraph::new(nodes.clone());
+    /// graph.insert_edge(&nodes[0], &nodes[1], Some(1));
+    /// graph.insert_edge(&nodes[1], &nodes[2], Some(1));
+    ///
+    /// // B has the highest degree and is colored first.
+    /// let coloring = graph.greedy_coloring();
+    /// assert_eq!(coloring["B"], 0);
+    /// assert_eq!(coloring["A"], 1);
+    /// assert_eq!(coloring["C"], 1);
+    /// assert_eq!(graph.greedy_color_count(), 2);
+    /// ```
+    pub fn greedy_coloring(&self) -> HashMap<String, usize> {
+        self.greedy_color_indices()
+            .into_iter()
+            .enumerate()
+            .map(|(index, color)| (self.nodes[index].get_id().to_string(), color))
+            .collect()
+    }
+
+    /// Returns the number of colors used by [`UndirectedGraph::greedy_coloring`].
+    ///
+    /// This is an upper bound on the chromatic number of the graph. An empty
+    /// graph needs `0` colors.
+    pub fn greedy_color_count(&self) -> usize {
+        self.greedy_color_indices()
+            .into_iter()
+            .max()
+            .map_or(0, |max_color| max_color + 1)
+    }
+
+    /// Computes the largest-first greedy coloring indexed by node index.
+    fn greedy_color_indices(&self) -> Vec<usize> {
+        let degree = |index: usize| {
+            self.adjacency[index]
+                .iter()
+                .filter(|(neighbor, ..)| *neighbor != index)
+                .count()
+        };
+        let mut order: Vec<usize> = (0..self.nodes.len()).collect();
+        // Stable sort keeps insertion order among nodes with equal degree.
+        order.sort_by_key(|&index| std::cmp::Reverse(degree(index)));
+
+        let mut colors: Vec<Option<usize>> = vec![None; self.nodes.len()];
+        for index in order {
+            let mut used: Vec<bool> = vec![false; self.adjacency[index].len() + 1];
+            for &(neighbor, ..) in &self.adjacency[index] {
+                if let Some(color) = colors[neighbor].filter(|&color| color < used.len()) {
+                    used[color] = true;
+                }
+            }
+            colors[index] = used.iter().position(|taken| !taken);
+        }
+        colors.into_iter().map(|color| color.unwrap_or(0)).collect()
+    }
 }
 
 impl Display for UndirectedGraph {
@@ -256,7 +1660,7 @@ impl Display for UndirectedGraph {
         writeln!(f, "Edges:")?;
         for (index, neighbors) in self.adjacency.iter().enumerate() {
             let node_id = &self.nodes[index].get_id();
-            for (neighbor_index, weight) in neighbors {
+            for (neighbor_index, weight, _) in neighbors {
                 let neighbor_id = &self.nodes[*neighbor_index].get_id();
                 writeln!(f, "- {} --({})--> {}", node_id, weight, neighbor_id)?;
             }
@@ -271,6 +1675,150 @@ impl Default for UndirectedGraph {
     }
 }
 
+/// Collects `(a, b, weight)` edges into a graph.
+///
+/// Nodes are created on first mention, in the order they appear. Unlike
+/// [`UndirectedGraph::from_edges`], a repeated edge is not an error: the
+/// first occurrence and its weight win, and `B - A` repeats `A - B`.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::graphs::graph::Graph;
+/// use shortest_path_finder::graphs::undirected::UndirectedGraph;
+/// use shortest_path_finder::nodes::default_node::DefaultNode;
+///
+/// let a = DefaultNode::new("A".to_string());
+/// let b = DefaultNode::new("B".to_string());
+/// let graph: UndirectedGraph = vec![(a.clone(), b.clone(), 7), (b.clone(), a.clone(), 9)]
+///     .into_iter()
+///     .collect();
+/// assert_eq!(graph.to_edge_list(), vec![("A".to_string(), "B".to_string(), 7)]);
+/// ```
+impl FromIterator<(DefaultNode, DefaultNode, u16)> for UndirectedGraph {
+    fn from_iter<I: IntoIterator<Item = (DefaultNode, DefaultNode, u16)>>(edges: I) -> Self {
+        let mut graph = Self::default();
+        for (a, b, weight) in edges {
+            graph.insert_node(a.clone());
+            graph.insert_node(b.clone());
+            // A duplicate is the only possible insertion error here.
+            let _ = graph.insert_edge(&a, &b, Some(weight));
+        }
+        graph
+    }
+}
+
+/// Internal priority-queue element used by [`UndirectedGraph::mst_prim`].
+///
+/// Holds one candidate edge leaving the tree. Entries are ordered by cost
+/// (ties by node index) and wrapped in [`Reverse`] so `BinaryHeap` pops the
+/// cheapest edge first.
+#[derive(Eq, PartialEq, Ord, PartialOrd)]
+struct SpanningQueueItem {
+    /// Cost of the edge, compared first.
+    cost: u16,
+    /// Node outside the tree that the edge reaches.
+    to: usize,
+    /// Tree node the edge starts from.
+    from: usize,
+}
+
+impl SpanningQueueItem {
+    /// Creates a new queue item for the edge `from - to`.
+    fn new(cost: u16, from: usize, to: usize) -> Self {
+        Self { cost, to, from }
+    }
+}
+
+// ----- Implementation of the 'UndirectedEdge' struct -----
+
+/// Snapshot of one undirected edge `a_node - b_node`.
+///
+/// The graph itself stores edges as index-based adjacency entries; this type
+/// is what edge-level queries hand out. It is a plain value: changing it does
+/// not change the graph.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::graphs::undirected::UndirectedEdge;
+/// use shortest_path_finder::nodes::default_node::DefaultNode;
+///
+/// let edge = UndirectedEdge::new(
+///     DefaultNode::new("A".to_string()),
+///     DefaultNode::new("B".to_string()),
+///     4,
+/// );
+/// assert_eq!(edge.to_string(), "A - B (4)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UndirectedEdge {
+    /// First endpoint.
+    pub a_node: DefaultNode,
+    /// Second endpoint.
+    pub b_node: DefaultNode,
+    /// Weight of travelling from `a_node` to `b_node`.
+    pub weight: u16,
+    /// Weight of travelling from `b_node` to `a_node`; equal to `weight`
+    /// unless the edge was inserted with
+    /// [`UndirectedGraph::insert_two_way_edge`].
+    pub reverse_weight: u16,
+}
+
+impl UndirectedEdge {
+    /// Creates a new edge snapshot with the same weight in both directions.
+    pub fn new(a_node: DefaultNode, b_node: DefaultNode, weight: u16) -> Self {
+        Self::new_two_way(a_node, b_node, weight, weight)
+    }
+
+    /// Creates a new edge snapshot with a weight per direction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::undirected::UndirectedEdge;
+    /// use shortest_path_finder::nodes::default_node::DefaultNode;
+    ///
+    /// let edge = UndirectedEdge::new_two_way(
+    ///     DefaultNode::new("A".to_string()),
+    ///     DefaultNode::new("B".to_string()),
+    ///     7,
+    ///     9,
+    /// );
+    /// assert_eq!(edge.to_string(), "A - B (7|9)");
+    /// ```
+    pub fn new_two_way(
+        a_node: DefaultNode,
+        b_node: DefaultNode,
+        weight: u16,
+        reverse_weight: u16,
+    ) -> Self {
+        Self {
+            a_node,
+            b_node,
+            weight,
+            reverse_weight,
+        }
+    }
+}
+
+impl Display for UndirectedEdge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} - {} ({}",
+            self.a_node.get_id(),
+            self.b_node.get_id(),
+            self.weight
+        )?;
+        if self.reverse_weight != self.weight {
+            write!(f, "|{}", self.reverse_weight)?;
+        }
+        write!(f, ")")
+    }
+}
+
 // ----- Implementation of the 'UndirectedGraphInsertionError' struct -----
 
 /// Error returned when undirected graph insertion fails.
@@ -0,0 +1,282 @@
+//! Unweighted graph implementation.
+//!
+//! # Overview
+//!
+//! This module provides:
+//! - [`UnweightedGraph`], a directed or undirected graph whose edges carry no
+//!   weight ([`NoWeight`]),
+//! - [`UnweightedGraphInsertionError`] for insertion failures.
+//!
+//! Unweighted graphs are the native input of hop-based searches such as
+//! [`BfsAlgorithm`](crate::algorithms::bfs::BfsAlgorithm). Weight-based
+//! algorithms like Dijkstra do not accept them instead of silently treating
+//! every edge as weight `1`.
+//!
+//! # File input
+//!
+//! File input builds this graph for `D` and `UN` files in which no edge line
+//! carries a weight (`A->B`, `A-B`).
+//!
+//! # Usage
+//!
+//! ```rust
+//! use shortest_path_finder::graphs::graph::Graph;
+//! use shortest_path_finder::graphs::unweighted::UnweightedGraph;
+//! use shortest_path_finder::nodes::default_node::DefaultNode;
+//!
+//! let a = DefaultNode::new("A".to_string());
+//! let b = DefaultNode::new("B".to_string());
+//! let mut graph = UnweightedGraph::new_undirected(vec![a.clone(), b.clone()]);
+//! assert!(graph.insert_edge(&a, &b, None).is_none());
+//! assert!(graph.does_edge_already_exist(&b, &a));
+//! assert!(!graph.is_weighted());
+//! ```
+
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+use crate::{
+    graphs::graph::{Graph, GraphNode},
+    nodes::default_node::DefaultNode,
+    weight_types::impl_weights::NoWeight,
+};
+
+/// Directed or undirected graph without edge weights.
+///
+/// # Invariants
+///
+/// - Duplicate nodes are ignored on insertion and at construction time.
+/// - Duplicate edges are rejected; for undirected graphs regardless of
+///   endpoint order (`A-B` equals `B-A`).
+/// - Edges can only be inserted if both endpoint nodes already exist.
+/// - Undirected self-loops are stored once.
+/// - [`Graph::is_weighted`] always returns `false`.
+#[derive(Debug, Clone)]
+pub struct UnweightedGraph {
+    /// Nodes currently contained in the graph.
+    nodes: Vec<DefaultNode>,
+    /// Fast ID-to-index lookup for node access.
+    node_index_by_id: HashMap<String, usize>,
+    /// Adjacency list storing neighbor indices for each node index. Undirected
+    /// edges are stored in both directions.
+    adjacency: Vec<Vec<usize>>,
+    /// Whether edges are directed.
+    directed: bool,
+}
+
+impl Graph for UnweightedGraph {
+    type Node = DefaultNode;
+
+    type Weight = NoWeight;
+
+    type InsertionError = UnweightedGraphInsertionError;
+
+    fn neighbors<'a>(
+        &'a self,
+        u: &Self::Node,
+    ) -> Box<dyn Iterator<Item = (&'a Self::Node, Self::Weight)> + 'a> {
+        let Some(source_index) = self.node_index_for_id(u.get_id()) else {
+            return Box::new(std::iter::empty());
+        };
+
+        Box::new(
+            self.adjacency[source_index]
+                .iter()
+                .map(move |neighbor_index| (&self.nodes[*neighbor_index], NoWeight)),
+        )
+    }
+
+    fn out_degree(&self, u: &Self::Node) -> usize {
+        self.node_index_for_id(u.get_id())
+            .map_or(0, |index| self.adjacency[index].len())
+    }
+
+    fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    fn insert_node(&mut self, new_node: Self::Node) {
+        if self.does_node_already_exist(&new_node) {
+            return;
+        }
+
+        let new_index = self.nodes.len();
+        self.node_index_by_id
+            .insert(new_node.get_id().to_string(), new_index);
+        self.nodes.push(new_node);
+        self.adjacency.push(Vec::new());
+    }
+
+    /// Inserts an edge; `weight` is ignored because the graph stores none.
+    fn insert_edge(
+        &mut self,
+        from: &Self::Node,
+        to: &Self::Node,
+        _weight: Option<Self::Weight>,
+    ) -> Option<Self::InsertionError> {
+        let (Some(from_index), Some(to_index)) = (
+            self.node_index_for_id(from.get_id()),
+            self.node_index_for_id(to.get_id()),
+        ) else {
+            return Some(UnweightedGraphInsertionError::new(format!(
+                "The edge from {} to {} references a node that isn't part of the graph!",
+                from.get_id(),
+                to.get_id()
+            )));
+        };
+
+        if self.does_edge_already_exist(from, to) {
+            return Some(UnweightedGraphInsertionError::new(format!(
+                "The edge from '{}' to '{}' already exists in the graph!",
+                from.get_id(),
+                to.get_id()
+            )));
+        }
+
+        self.adjacency[from_index].push(to_index);
+        if !self.directed && from_index != to_index {
+            self.adjacency[to_index].push(from_index);
+        }
+
+        None
+    }
+
+    fn does_edge_already_exist(&self, from: &Self::Node, to: &Self::Node) -> bool {
+        let (Some(from_index), Some(to_index)) = (
+            self.node_index_for_id(from.get_id()),
+            self.node_index_for_id(to.get_id()),
+        ) else {
+            return false;
+        };
+
+        // Undirected edges are stored in both lists, so one lookup suffices.
+        self.adjacency[from_index].contains(&to_index)
+    }
+
+    fn does_node_already_exist(&self, node: &Self::Node) -> bool {
+        self.node_index_by_id.contains_key(node.get_id())
+    }
+
+    fn get_node_by_id(&self, id: &str) -> Option<&Self::Node> {
+        self.node_index_by_id
+            .get(id)
+            .and_then(|&index| self.nodes.get(index))
+    }
+
+    fn get_all_nodes(&self) -> &Vec<Self::Node> {
+        &self.nodes
+    }
+
+    fn is_weighted(&self) -> bool {
+        false
+    }
+
+    /// Returns `UW`. It is not a file header: unweighted files use `D`/`UN`.
+    fn abbreviation() -> String {
+        String::from("UW")
+    }
+}
+
+impl UnweightedGraph {
+    /// Creates a directed unweighted graph from a node vector.
+    ///
+    /// Duplicate node IDs are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::unweighted::UnweightedGraph;
+    ///
+    /// let graph = UnweightedGraph::new_directed(vec![]);
+    /// assert!(graph.is_directed());
+    /// ```
+    pub fn new_directed(nodes: Vec<DefaultNode>) -> Self {
+        Self::with_nodes(nodes, true)
+    }
+
+    /// Creates an undirected unweighted graph from a node vector.
+    ///
+    /// Duplicate node IDs are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::graph::Graph;
+    /// use shortest_path_finder::graphs::unweighted::UnweightedGraph;
+    ///
+    /// let graph = UnweightedGraph::new_undirected(vec![]);
+    /// assert!(!graph.is_directed());
+    /// ```
+    pub fn new_undirected(nodes: Vec<DefaultNode>) -> Self {
+        Self::with_nodes(nodes, false)
+    }
+
+    /// Builds an empty graph of the given direction and inserts `nodes`.
+    fn with_nodes(nodes: Vec<DefaultNode>, directed: bool) -> Self {
+        let mut graph = Self {
+            nodes: Vec::new(),
+            node_index_by_id: HashMap::new(),
+            adjacency: Vec::new(),
+            directed,
+        };
+
+        for node in nodes {
+            graph.insert_node(node);
+        }
+
+        graph
+    }
+
+    /// Looks up the index of a node by its identifier.
+    fn node_index_for_id(&self, id: &str) -> Option<usize> {
+        self.node_index_by_id.get(id).copied()
+    }
+}
+
+impl Display for UnweightedGraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Nodes: {:?}, Adjacency: {:?}",
+            self.nodes, self.adjacency
+        )
+    }
+}
+
+// ----- Implementation of the 'UnweightedGraphInsertionError' struct -----
+
+/// Error returned when inserting into an [`UnweightedGraph`] fails.
+///
+/// # Typical causes
+///
+/// - duplicate edge insertion,
+/// - inserting an edge whose endpoint node does not exist.
+#[derive(Debug)]
+pub struct UnweightedGraphInsertionError {
+    /// Human-readable description of the insertion failure.
+    pub message: String,
+}
+
+impl UnweightedGraphInsertionError {
+    /// Creates a new insertion error with a descriptive message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::graphs::unweighted::UnweightedGraphInsertionError;
+    ///
+    /// let err = UnweightedGraphInsertionError::new("duplicate edge".to_string());
+    /// assert_eq!(err.to_string(), "duplicate edge");
+    /// ```
+    pub fn new(message: String) -> Self {
+        Self { message }
+    }
+}
+
+impl Display for UnweightedGraphInsertionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for UnweightedGraphInsertionError {}
@@ -4,8 +4,14 @@
 //! and algorithms. The functions here are intentionally generic so they can be
 //! reused across graph variants.
 
-use crate::nodes::trait_decl::{
-    coordinate_datatype::CoordinateDatatype, coordinates_node::CoordinatesNode,
+use std::collections::HashMap;
+
+use crate::{
+    graphs::graph::{GraphNode, GraphWeight},
+    nodes::{
+        default_node::DefaultNode,
+        trait_decl::{coordinate_datatype::CoordinateDatatype, coordinates_node::CoordinatesNode},
+    },
 };
 
 /// Calculates edge weight using Euclidean distance between endpoints.
@@ -30,3 +36,417 @@ pub fn calculate_weight<CN: CoordinatesNode>(node_one: &CN, node_two: &CN) -> f3
     // Euclidean norm in 2D.
     (dx * dx + dy * dy).sqrt()
 }
+
+/// Renames every node with `f` while keeping the node order intact.
+///
+/// Shared by the `map_nodes` methods of the index-based graphs: because the
+/// order is preserved, adjacency lists stay valid without any rewriting.
+///
+/// # Returns
+///
+/// - `Ok((nodes, node_index_by_id))` with the renamed nodes and a rebuilt
+///   ID-to-index lookup.
+/// - `Err(String)` naming both original IDs when `f` maps two distinct IDs to
+///   the same new ID.
+pub(crate) fn remap_node_ids(
+    nodes: &[DefaultNode],
+    f: impl Fn(&str) -> String,
+) -> Result<(Vec<DefaultNode>, HashMap<String, usize>), String> {
+    let mut mapped_nodes = Vec::with_capacity(nodes.len());
+    let mut node_index_by_id: HashMap<String, usize> = HashMap::with_capacity(nodes.len());
+
+    for (index, node) in nodes.iter().enumerate() {
+        let new_id = f(node.get_id());
+        if let Some(&previous_index) = node_index_by_id.get(&new_id) {
+            return Err(format!(
+                "Mapping node IDs would merge '{}' and '{}' into '{}'!",
+                nodes[previous_index].get_id(),
+                node.get_id(),
+                new_id
+            ));
+        }
+        node_index_by_id.insert(new_id.clone(), index);
+        mapped_nodes.push(DefaultNode::new(new_id));
+    }
+
+    Ok((mapped_nodes, node_index_by_id))
+}
+
+/// Kind and layer of one stored edge.
+///
+/// Kept beside the target index and weight of each adjacency entry, so
+/// parallel edges between the same two nodes can carry different labels.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct EdgeLabels {
+    /// Edge kind such as `highway`; see [`Graph::edge_kind`](crate::graphs::graph::Graph::edge_kind).
+    pub(crate) kind: Option<String>,
+    /// Edge layer such as `train`; see [`Graph::edge_layer`](crate::graphs::graph::Graph::edge_layer).
+    pub(crate) layer: Option<String>,
+}
+
+/// One adjacency-list entry: target node index, weight and labels.
+pub(crate) type AdjacencyEntry<W> = (usize, W, EdgeLabels);
+
+/// Storage of one component extracted by [`extract_component`].
+///
+/// The fields mirror the private storage of the index-based graphs, so the
+/// caller can assemble its own graph type from them.
+pub(crate) struct ComponentParts<W> {
+    /// Component nodes in their original relative order.
+    pub(crate) nodes: Vec<DefaultNode>,
+    /// ID-to-index lookup for `nodes`.
+    pub(crate) node_index_by_id: HashMap<String, usize>,
+    /// Adjacency lists re-indexed to `nodes`, with their labels.
+    pub(crate) adjacency: Vec<Vec<AdjacencyEntry<W>>>,
+}
+
+/// Groups node indices into weakly connected components.
+///
+/// Every adjacency entry joins its two endpoints regardless of direction, so
+/// for undirected graphs this yields the ordinary connected components.
+///
+/// # Returns
+///
+/// One list of node indices per component. Indices are ascending within a
+/// component, and components are ordered by their smallest index (i.e. by
+/// the insertion order of their first node).
+pub(crate) fn weakly_connected_components<W>(
+    adjacency: &[Vec<AdjacencyEntry<W>>],
+) -> Vec<Vec<usize>> {
+    // Union-find with path halving; the representative of a set is not
+    // significant, only the grouping is.
+    fn find(parent: &mut [usize], mut index: usize) -> usize {
+        while parent[index] != index {
+            parent[index] = parent[parent[index]];
+            index = parent[index];
+        }
+        index
+    }
+
+    let mut parent: Vec<usize> = (0..adjacency.len()).collect();
+    for (from_index, neighbors) in adjacency.iter().enumerate() {
+        for &(to_index, ..) in neighbors {
+            let from_root = find(&mut parent, from_index);
+            let to_root = find(&mut parent, to_index);
+            if from_root != to_root {
+                parent[from_root.max(to_root)] = from_root.min(to_root);
+            }
+        }
+    }
+
+    let mut component_by_root: HashMap<usize, usize> = HashMap::new();
+    let mut components: Vec<Vec<usize>> = Vec::new();
+    for index in 0..adjacency.len() {
+        let root = find(&mut parent, index);
+        let component = *component_by_root.entry(root).or_insert_with(|| {
+            components.push(Vec::new());
+            components.len() - 1
+        });
+        components[component].push(index);
+    }
+
+    components
+}
+
+/// Groups node indices into strongly connected components (Tarjan).
+///
+/// Two nodes share a component when each can reach the other along edge
+/// direction. The depth-first search keeps its own frame stack instead of
+/// recursing, so long chains cannot overflow the call stack.
+///
+/// # Returns
+///
+/// One list of node indices per component, ascending within a component.
+/// Components come in the order Tarjan's algorithm completes them, which is a
+/// reverse topological order: every edge between two components points from a
+/// later component to an earlier one.
+pub(crate) fn strongly_connected_components<W>(
+    adjacency: &[Vec<AdjacencyEntry<W>>],
+) -> Vec<Vec<usize>> {
+    let mut discovery: Vec<Option<usize>> = vec![None; adjacency.len()];
+    let mut low_link = vec![0; adjacency.len()];
+    let mut on_stack = vec![false; adjacency.len()];
+    let mut stack: Vec<usize> = Vec::new();
+    let mut components: Vec<Vec<usize>> = Vec::new();
+    let mut next_discovery = 0;
+
+    for root in 0..adjacency.len() {
+        if discovery[root].is_some() {
+            continue;
+        }
+        // Each frame is a node and the position of its next neighbor to visit.
+        let mut frames = vec![(root, 0)];
+        discovery[root] = Some(next_discovery);
+        low_link[root] = next_discovery;
+        next_discovery += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some(frame) = frames.last_mut() {
+            let node = frame.0;
+            if let Some(&(neighbor, ..)) = adjacency[node].get(frame.1) {
+                frame.1 += 1;
+                match discovery[neighbor] {
+                    None => {
+                        discovery[neighbor] = Some(next_discovery);
+                        low_link[neighbor] = next_discovery;
+                        next_discovery += 1;
+                        stack.push(neighbor);
+                        on_stack[neighbor] = true;
+                        frames.push((neighbor, 0));
+                    }
+                    Some(neighbor_discovery) if on_stack[neighbor] => {
+                        low_link[node] = low_link[node].min(neighbor_discovery);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            // All neighbors done: hand the low link to the parent frame.
+            frames.pop();
+            if let Some(&(parent, _)) = frames.last() {
+                low_link[parent] = low_link[parent].min(low_link[node]);
+            }
+            if Some(low_link[node]) == discovery[node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
+
+/// Splits node indices into nodes incident to at least one edge and isolated
+/// nodes.
+///
+/// A node counts as connected if it has an outgoing entry or appears as the
+/// target of one, so directed graphs use the in- and out-degree. A self-loop
+/// keeps its node.
+///
+/// # Returns
+///
+/// `(connected, isolated)`, both in ascending index order.
+pub(crate) fn partition_isolated<W>(
+    adjacency: &[Vec<AdjacencyEntry<W>>],
+) -> (Vec<usize>, Vec<usize>) {
+    let mut connected = vec![false; adjacency.len()];
+    for (from_index, neighbors) in adjacency.iter().enumerate() {
+        for &(to_index, ..) in neighbors {
+            connected[from_index] = true;
+            connected[to_index] = true;
+        }
+    }
+
+    (0..adjacency.len()).partition(|&index| connected[index])
+}
+
+/// Copies the nodes in `members` and every edge between them into new storage.
+///
+/// Indices are renumbered to `0..members.len()` in the order of `members`.
+/// Edges leaving the member set are dropped, which never happens for a full
+/// component from [`weakly_connected_components`]. Kept edges keep their
+/// labels.
+pub(crate) fn extract_component<W: Copy>(
+    nodes: &[DefaultNode],
+    adjacency: &[Vec<AdjacencyEntry<W>>],
+    members: &[usize],
+) -> ComponentParts<W> {
+    let mut new_index: Vec<Option<usize>> = vec![None; nodes.len()];
+    for (position, &old_index) in members.iter().enumerate() {
+        new_index[old_index] = Some(position);
+    }
+
+    let component_nodes: Vec<DefaultNode> = members.iter().map(|&i| nodes[i].clone()).collect();
+    let node_index_by_id = component_nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| (node.get_id().to_string(), index))
+        .collect();
+    let component_adjacency = members
+        .iter()
+        .map(|&old_index| {
+            adjacency[old_index]
+                .iter()
+                .filter_map(|(to_index, weight, labels)| {
+                    new_index[*to_index].map(|to| (to, *weight, labels.clone()))
+                })
+                .collect()
+        })
+        .collect();
+
+    ComponentParts {
+        nodes: component_nodes,
+        node_index_by_id,
+        adjacency: component_adjacency,
+    }
+}
+
+/// Keeps the edges accepted by `keep` and the nodes incident to at least one
+/// of them.
+///
+/// `keep` receives `(from, to, weight, kind)` once per stored edge, so
+/// parallel edges are judged one by one. For undirected graphs
+/// (`directed == false`) the two stored directions of an edge are judged
+/// together, with the lower-index endpoint as `from`, so an edge is always
+/// kept or dropped as a whole. Kept edges keep their kind and layer.
+///
+/// # Returns
+///
+/// The storage of the induced graph; nodes keep their relative order.
+pub(crate) fn induce_by_edges<W: Copy>(
+    nodes: &[DefaultNode],
+    adjacency: &[Vec<AdjacencyEntry<W>>],
+    directed: bool,
+    keep: impl Fn(&DefaultNode, &DefaultNode, W, Option<&str>) -> bool,
+) -> ComponentParts<W> {
+    let mut kept_adjacency: Vec<Vec<AdjacencyEntry<W>>> = vec![Vec::new(); adjacency.len()];
+    let mut is_incident = vec![false; nodes.len()];
+
+    for (from_index, neighbors) in adjacency.iter().enumerate() {
+        // Counts earlier entries per target, to find the mirror of a parallel edge.
+        let mut seen_per_target: HashMap<usize, usize> = HashMap::new();
+        for (to_index, weight, labels) in neighbors {
+            let (to_index, weight) = (*to_index, *weight);
+            let occurrence = seen_per_target.entry(to_index).or_default();
+            let nth = *occurrence;
+            *occurrence += 1;
+
+            let (a, b) = if directed || from_index <= to_index {
+                (from_index, to_index)
+            } else {
+                (to_index, from_index)
+            };
+            // Judge both stored directions of an undirected edge by the
+            // canonical `a -> b` entry, whose weight may differ from this one's.
+            let (canonical_weight, kind) = if (a, b) == (from_index, to_index) {
+                (weight, labels.kind.as_deref())
+            } else {
+                adjacency[a]
+                    .iter()
+                    .filter(|(neighbor, ..)| *neighbor == b)
+                    .nth(nth)
+                    .map_or(
+                        (weight, labels.kind.as_deref()),
+                        |(_, canonical, labels)| (*canonical, labels.kind.as_deref()),
+                    )
+            };
+            if !keep(&nodes[a], &nodes[b], canonical_weight, kind) {
+                continue;
+            }
+
+            kept_adjacency[from_index].push((to_index, weight, labels.clone()));
+            is_incident[from_index] = true;
+            is_incident[to_index] = true;
+        }
+    }
+
+    let members: Vec<usize> = (0..nodes.len()).filter(|&i| is_incident[i]).collect();
+    extract_component(nodes, &kept_adjacency, &members)
+}
+
+/// Contracts pass-through nodes into single edges until none are left.
+///
+/// A node `v` is contracted when it is not protected, has no self-loop and
+/// touches exactly two other nodes `u` and `w` in one of two shapes:
+///
+/// - a one-way chain `u -> v -> w` (the only edges at `v`), replaced by
+///   `u -> w`;
+/// - a two-way chain with all four edges between `v` and `u`/`w`, replaced by
+///   `u -> w` and `w -> u`. Undirected graphs store every edge in both
+///   directions, so their degree-2 nodes always take this shape.
+///
+/// Each new edge weighs the sum of the two hops it replaces and keeps their
+/// kind and layer if both hops share them. A node is left alone when `u` and `w` are
+/// already adjacent, since the storage holds at most one edge per direction.
+///
+/// # Returns
+///
+/// The indices of the contracted nodes, ascending. Their adjacency lists are
+/// empty afterwards and no other list refers to them; the caller removes the
+/// nodes themselves, e.g. with [`extract_component`].
+pub(crate) fn contract_chains<W: GraphWeight>(
+    adjacency: &mut [Vec<AdjacencyEntry<W>>],
+    protected: &[bool],
+) -> Vec<usize> {
+    let mut incoming: Vec<Vec<usize>> = vec![Vec::new(); adjacency.len()];
+    for (from_index, neighbors) in adjacency.iter().enumerate() {
+        for &(to_index, ..) in neighbors {
+            incoming[to_index].push(from_index);
+        }
+    }
+    let entry_of = |adjacency: &[Vec<AdjacencyEntry<W>>], from: usize, to: usize| {
+        adjacency[from]
+            .iter()
+            .find(|(neighbor, ..)| *neighbor == to)
+            .map(|(_, weight, labels)| (*weight, labels.clone()))
+    };
+
+    let mut contracted = vec![false; adjacency.len()];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for v in 0..adjacency.len() {
+            if protected[v] || contracted[v] {
+                continue;
+            }
+            let mut outgoing: Vec<usize> = adjacency[v].iter().map(|&(to, ..)| to).collect();
+            let mut incoming_v = incoming[v].clone();
+            outgoing.sort_unstable();
+            incoming_v.sort_unstable();
+            if outgoing.contains(&v) {
+                continue;
+            }
+
+            // `(from, to)` pairs of the edges that replace `v`.
+            let replacements = match (incoming_v.as_slice(), outgoing.as_slice()) {
+                ([u], [w]) if u != w => vec![(*u, *w)],
+                ([u, w], [x, y]) if u != w && (u, w) == (x, y) => vec![(*u, *w), (*w, *u)],
+                _ => continue,
+            };
+            let (u, w) = replacements[0];
+            if entry_of(adjacency, u, w).is_some() || entry_of(adjacency, w, u).is_some() {
+                continue;
+            }
+
+            for &(from, to) in &replacements {
+                let (Some((first, first_labels)), Some((second, second_labels))) =
+                    (entry_of(adjacency, from, v), entry_of(adjacency, v, to))
+                else {
+                    continue;
+                };
+                // A label survives only if both replaced hops share it.
+                let labels = EdgeLabels {
+                    kind: first_labels
+                        .kind
+                        .filter(|kind| second_labels.kind.as_ref() == Some(kind)),
+                    layer: first_labels
+                        .layer
+                        .filter(|layer| second_labels.layer.as_ref() == Some(layer)),
+                };
+                adjacency[from].push((to, first + second, labels));
+                incoming[to].push(from);
+            }
+
+            for &neighbor in incoming_v.iter().chain(outgoing.iter()) {
+                adjacency[neighbor].retain(|&(to, ..)| to != v);
+                incoming[neighbor].retain(|&from| from != v);
+            }
+            adjacency[v].clear();
+            incoming[v].clear();
+            contracted[v] = true;
+            changed = true;
+        }
+    }
+
+    (0..adjacency.len()).filter(|&i| contracted[i]).collect()
+}
@@ -0,0 +1,144 @@
+//! Crate-internal instrumentation for long-running operations.
+//!
+//! # Overview
+//!
+//! Operations such as file loading and Dijkstra searches are wrapped in an
+//! [`OperationSpan`] created through the [`operation_span!`] macro.
+//!
+//! - With the `tracing` cargo feature enabled, the macro opens a
+//!   `tracing::info_span!` carrying the given fields. Durations are reported
+//!   by the installed subscriber (for example `FmtSpan::CLOSE` in
+//!   `tracing-subscriber`).
+//! - Without the feature, the span falls back to the `log` crate and emits a
+//!   single `debug!` line with the recorded fields and the elapsed time when
+//!   the operation finishes.
+//!
+//! Fields that are only known once the operation is done (such as the edge
+//! count of a freshly parsed graph) are declared after a `;` and filled in
+//! later with [`OperationSpan::record`].
+
+#[cfg(not(feature = "tracing"))]
+use std::time::Instant;
+
+use crate::graphs::graph::Graph;
+
+/// Guard that covers one instrumented operation until it is dropped.
+pub(crate) struct OperationSpan {
+    /// Entered `tracing` span; exiting happens on drop.
+    #[cfg(feature = "tracing")]
+    span: tracing::span::EnteredSpan,
+    /// Span name used in the fallback log line.
+    #[cfg(not(feature = "tracing"))]
+    name: &'static str,
+    /// Recorded `(field, value)` pairs for the fallback log line.
+    #[cfg(not(feature = "tracing"))]
+    fields: Vec<(&'static str, String)>,
+    /// Start time of the operation for the fallback log line.
+    #[cfg(not(feature = "tracing"))]
+    started: Instant,
+}
+
+impl OperationSpan {
+    /// Enters `span` and wraps it in a guard.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn from_span(span: tracing::Span) -> Self {
+        Self {
+            span: span.entered(),
+        }
+    }
+
+    /// Starts timing an operation that is reported through `log`.
+    #[cfg(not(feature = "tracing"))]
+    pub(crate) fn new(name: &'static str, fields: Vec<(&'static str, String)>) -> Self {
+        Self {
+            name,
+            fields,
+            started: Instant::now(),
+        }
+    }
+
+    /// Records a value for a field that was declared as pending in
+    /// [`operation_span!`].
+    pub(crate) fn record(&mut self, field: &'static str, value: usize) {
+        #[cfg(feature = "tracing")]
+        self.span.record(field, value);
+        #[cfg(not(feature = "tracing"))]
+        self.fields.push((field, value.to_string()));
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+impl Drop for OperationSpan {
+    fn drop(&mut self) {
+        if !log::log_enabled!(log::Level::Debug) {
+            return;
+        }
+        let fields = self
+            .fields
+            .iter()
+            .map(|(field, value)| format!("{}={}", field, value))
+            .collect::<Vec<_>>()
+            .join(" ");
+        log::debug!(
+            "{} finished in {:?} ({})",
+            self.name,
+            self.started.elapsed(),
+            fields
+        );
+    }
+}
+
+/// Opens an [`OperationSpan`].
+///
+/// Syntax: `operation_span!("name", field = value, ...; pending_field, ...)`.
+/// Field values must implement both `tracing::Value` and `ToString`. They are
+/// only evaluated when the span is enabled.
+#[cfg(feature = "tracing")]
+macro_rules! operation_span {
+    ($name:literal $(, $field:ident = $value:expr)* $(; $($pending:ident),+)? $(,)?) => {
+        $crate::instrumentation::OperationSpan::from_span(tracing::info_span!(
+            $name
+            $(, $field = $value)*
+            $($(, $pending = tracing::field::Empty)+)?
+        ))
+    };
+}
+
+/// Opens an [`OperationSpan`].
+///
+/// Syntax: `operation_span!("name", field = value, ...; pending_field, ...)`.
+/// Field values must implement both `tracing::Value` and `ToString`. They are
+/// only evaluated when `debug` logging is enabled.
+#[cfg(not(feature = "tracing"))]
+macro_rules! operation_span {
+    ($name:literal $(, $field:ident = $value:expr)* $(; $($pending:ident),+)? $(,)?) => {
+        $crate::instrumentation::OperationSpan::new(
+            $name,
+            // Skip evaluating potentially costly field values nobody will see.
+            if log::log_enabled!(log::Level::Debug) {
+                vec![$((stringify!($field), ($value).to_string())),*]
+            } else {
+                Vec::new()
+            },
+        )
+    };
+}
+
+pub(crate) use operation_span;
+
+/// Counts the adjacency entries of `graph`.
+///
+/// Undirected graphs store every edge in both directions, so the result is
+/// halved for them (self-loops are rounded down).
+pub(crate) fn edge_count<G: Graph>(graph: &G) -> usize {
+    let entries: usize = graph
+        .get_all_nodes()
+        .iter()
+        .map(|node| graph.out_degree(node))
+        .sum();
+    if graph.is_directed() {
+        entries
+    } else {
+        entries / 2
+    }
+}
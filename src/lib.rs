@@ -17,8 +17,9 @@
 //! - [`graphs`]: graph traits and graph data structures.
 //! - [`nodes`]: node models used by graph implementations.
 //! - [`data_input`]: graph input parsing (currently file-based).
-//! - [`cmd_line`]: CLI configuration parsing helpers.
+//! - [`cmd_line`]: CLI configuration parsing helpers and the CLI entry point.
 //! - [`error`]: parse-time and CLI configuration error definitions.
+//! - [`output`]: result formatting such as CSV/TSV distance tables.
 //! - [`weight_types`] and [`numeric_datatypes`]: numeric traits and impls.
 //!
 //! # Quick Start
@@ -47,6 +48,8 @@ pub mod cmd_line;
 pub mod data_input;
 pub mod error;
 pub mod graphs;
+mod instrumentation;
 pub mod nodes;
 pub mod numeric_datatypes;
+pub mod output;
 pub mod weight_types;
@@ -5,15 +5,40 @@
 //! The executable performs four high-level steps:
 //! 1. Initialize logging.
 //! 2. Parse command-line arguments into [`AppConfig`].
-//! 3. Load graph data from the selected origin (currently file input).
+//! 3. Load graph data from the selected origin (a file, standard input, or
+//!    lines typed into the terminal).
 //! 4. Execute the selected shortest-path algorithm and print the result.
 //!
+//! Steps 2 to 4 live in the library as
+//! [`run_from_args`](shortest_path_finder::cmd_line::runner::run_from_args),
+//! so binaries that register custom algorithms can reuse them.
+//!
+//! With `--distances`, step 4 instead prints the distance from the start node
+//! to every node, formatted according to `--output` (`text`, `json`, `csv`,
+//! `tsv`).
+//! With `--explain`, a Dijkstra search additionally narrates each settled node
+//! and relaxed edge on stderr. With `--pairs <file>`, step 4 answers every
+//! start/end pair of the file with one batched Dijkstra run and prints one line
+//! per pair, in file order; a failing pair is reported on its line without
+//! aborting the others. A repeated `--end` (`--end D --end E`) is answered the
+//! same way, with the single `--start` for every end node. With `--stats`, a
+//! Dijkstra or A* search additionally prints its elapsed time, expanded nodes,
+//! relaxed edges and distance on stderr.
+//!
 //! # Runtime Notes
 //!
-//! - `InputOrigin::File` is implemented and used in production flow.
-//! - `InputOrigin::CommandLine` is currently `unimplemented!()`.
+//! - `InputOrigin::File` reads the graph file (or standard input for `-`).
+//! - `InputOrigin::CommandLine` prompts for the graph line by line until an
+//!   empty line or Ctrl-D, then runs the same search.
 //! - Algorithm selection: Dijkstra for directed (`D`) and undirected (`UN`)
-//!   graphs; A* for two-dimensional (`TD`) graphs.
+//!   graphs, also when A* is selected, since their nodes carry no coordinates
+//!   for a heuristic; A* for two-dimensional (`TD`) graphs; BFS for unweighted
+//!   graphs (`D`/`UN` files without any edge weight) and, ignoring the
+//!   weights, for weighted `D`/`UN` graphs; DFS, which returns the first path
+//!   found, for weighted `D`/`UN` graphs; Bellman-Ford for `D` graphs with
+//!   signed weights and for `UN` graphs. Custom algorithms registered through
+//!   [`register_algorithm`](shortest_path_finder::algorithms::registry::register_algorithm)
+//!   run on `D` and `UN` graphs.
 //!
 //! # CLI Example
 //!
@@ -40,15 +65,7 @@
 use std::{env, process};
 
 use log::error;
-use shortest_path_finder::{
-    algorithms::{
-        a_star_algorithm::a_star::AStar,
-        algorithm::{Algorithm, Algorithms},
-        dijkstra::DijkstraAlgorithm,
-    },
-    cmd_line::app_config::{AppConfig, InputOrigin},
-    data_input::file_input::retrieve_graph_data_from_file,
-};
+use shortest_path_finder::cmd_line::runner::run_from_args;
 
 // TODO: Add a visualization function where the user can see how the algorithm is working step by
 // step (e.g. which nodes are being visited, which nodes are in the priority queue, ...). This can
@@ -72,120 +89,19 @@ use shortest_path_finder::{
 /// # Behavior
 ///
 /// - Initializes logger output through `env_logger`.
-/// - Parses CLI arguments into [`AppConfig`].
-/// - Loads graph data according to `InputOrigin`.
-/// - Executes selected algorithm for start/end node IDs.
-/// - Prints the resulting path output and exits with status code.
+/// - Runs [`run_from_args`] with the process arguments and logs any
+///   returned error.
 ///
 /// # Exit Codes
 ///
-/// - `0`: successful path computation.
+/// - `0`: successful path computation, or help printed for `--help`.
 /// - `1`: setup, parsing, graph-loading, or algorithm execution failure.
 fn main() {
     // enable logging to the terminal
     env_logger::init();
 
-    let args: Vec<String> = env::args().collect();
-    // -> '--graph <relative_path_to_file>' specifies which file to use to generate the graph
-    // -> '--start <node_name>' name of the node to start from
-    // -> '--end <node_name>' destination node
-    // -> '--algo <algorithm_name>' specify which path finder algorithm to use (default Dijkstra)
-    // -> '--origin [file / cmd-line]' set the origin of how the graph data will be inserted
-    // (default: file with the name 'graph.txt')
-
-    // validate the arguments and generate config data
-    let app_config = match AppConfig::setup_config(args) {
-        Ok(config) => config,
-        Err(err) => {
-            error!("{}", err);
-            process::exit(1);
-        }
-    };
-
-    // create the graph and execute the algorithm on it
-    match app_config.data_input {
-        InputOrigin::File => {
-            let graphs = match retrieve_graph_data_from_file(&app_config.file_path) {
-                Ok(graph) => graph,
-                Err(err) => {
-                    error!("{}", err);
-                    process::exit(1);
-                }
-            };
-            if let Some(graph) = graphs.directed_graph {
-                let algo = match app_config.algorithm {
-                    Algorithms::Dijkstra => DijkstraAlgorithm::new(graph),
-                    _ => {
-                        error!(
-                            "Algorithm {:?} is not implemented for directed graphs yet or a directed graph is not supported by the implementation of the algorithm!",
-                            app_config.algorithm
-                        );
-                        process::exit(1);
-                    }
-                };
-                let result =
-                    match algo.shortest_path(&app_config.start_node_id, &app_config.end_node_id) {
-                        Ok(res) => res,
-                        Err(err) => {
-                            error!("{}", err.message);
-                            process::exit(1);
-                        }
-                    };
-                // display the result
-                println!("{}", result);
-                process::exit(0);
-            } else if let Some(graph) = graphs.undirected_graph {
-                let algo = match app_config.algorithm {
-                    Algorithms::Dijkstra => DijkstraAlgorithm::new(graph),
-                    _ => {
-                        error!(
-                            "Algorithm {:?} is not implemented for undirected graphs yet or an undirected graph is not supported by the implementation of the algorithm!",
-                            app_config.algorithm
-                        );
-                        process::exit(1);
-                    }
-                };
-                let result =
-                    match algo.shortest_path(&app_config.start_node_id, &app_config.end_node_id) {
-                        Ok(res) => res,
-                        Err(err) => {
-                            error!("{}", err.message);
-                            process::exit(1);
-                        }
-                    };
-                // display the result
-                println!("{}", result);
-                process::exit(0);
-            } else if let Some(graph) = graphs.two_dimensional_graph {
-                let algo = match app_config.algorithm {
-                    Algorithms::AStar => AStar::new(graph),
-                    _ => {
-                        error!(
-                            "Algorithm {:?} is not implemented for two dimensional graphs yet or a two dimensional graph is not supported by the implementation of the algorithm!",
-                            app_config.algorithm
-                        );
-                        process::exit(1);
-                    }
-                };
-                let result =
-                    match algo.shortest_path(&app_config.start_node_id, &app_config.end_node_id) {
-                        Ok(res) => res,
-                        Err(err) => {
-                            error!("{}", err.message);
-                            process::exit(1);
-                        }
-                    };
-                // display the result
-                println!("{}", result);
-                process::exit(0);
-            } else {
-                error!(
-                    "No graph was create from the file {}!",
-                    app_config.file_path
-                );
-                process::exit(1);
-            };
-        }
-        InputOrigin::CommandLine => unimplemented!(),
+    if let Err(err) = run_from_args(env::args().collect()) {
+        error!("{}", err);
+        process::exit(1);
     }
 }
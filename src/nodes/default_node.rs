@@ -51,6 +51,7 @@ use crate::graphs::graph::GraphNode;
 /// assert_eq!(source.get_id(), "Source");
 /// ```
 #[derive(Clone, PartialEq, Eq, Hash, Debug, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DefaultNode {
     /// The unique identifier for the node.
     pub id: String,
@@ -0,0 +1,65 @@
+//! Unit and precision settings for printed distances.
+//!
+//! # Overview
+//!
+//! [`DistanceFormat`] controls how a total distance is rendered in path
+//! results. It is filled from the `--unit` and `--precision` flags by
+//! [`crate::cmd_line::app_config::AppConfig`] and only affects presentation;
+//! the algorithms always work on the raw weights.
+//!
+//! # Example
+//!
+//! ```rust
+//! use shortest_path_finder::output::distance_format::DistanceFormat;
+//!
+//! let format = DistanceFormat {
+//!     unit: Some("km".to_string()),
+//!     precision: Some(2),
+//! };
+//! assert_eq!(format.format(&4.5f32), "4.50 km");
+//! assert_eq!(DistanceFormat::default().format(&42u16), "42");
+//! ```
+
+use std::fmt::Display;
+
+/// Presentation settings for distances.
+///
+/// The default prints the plain value without a unit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DistanceFormat {
+    /// Label appended after the value, separated by a space (for example `km`).
+    pub unit: Option<String>,
+    /// Number of decimal places for floating-point distances.
+    ///
+    /// Integer distances are always printed without decimals. `None` keeps the
+    /// value's own formatting.
+    pub precision: Option<usize>,
+}
+
+impl DistanceFormat {
+    /// Renders `distance` with the configured precision and unit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::output::distance_format::DistanceFormat;
+    ///
+    /// let format = DistanceFormat {
+    ///     unit: Some("min".to_string()),
+    ///     precision: Some(1),
+    /// };
+    /// // Precision is ignored for integers.
+    /// assert_eq!(format.format(&42u16), "42 min");
+    /// assert_eq!(format.format(&2.25f32), "2.2 min");
+    /// ```
+    pub fn format<D: Display>(&self, distance: &D) -> String {
+        let value = match self.precision {
+            Some(precision) => format!("{:.*}", precision, distance),
+            None => distance.to_string(),
+        };
+        match &self.unit {
+            Some(unit) => format!("{} {}", value, unit),
+            None => value,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Single-source distance table writer.
+//!
+//! # Overview
+//!
+//! [`write_distance_table`] prints one row per node with the shortest distance
+//! from a start node, as produced by
+//! [`DijkstraAlgorithm::distances_from`](crate::algorithms::dijkstra::DijkstraAlgorithm::distances_from).
+//!
+//! # Format
+//!
+//! Delimited formats (`csv`, `tsv`) write a `node,distance` header row followed
+//! by one row per node. Unreachable nodes get an empty distance cell. Fields
+//! containing the delimiter, a quote, or a line break are quoted.
+//!
+//! ```text
+//! node,distance
+//! A,0
+//! B,7
+//! C,
+//! ```
+//!
+//! The text format prints `<node>: <distance>` lines and marks unreachable
+//! nodes as `unreachable`. The JSON format writes one object mapping node IDs
+//! to distances, with `null` for unreachable nodes: `{"A":0,"B":7,"C":null}`.
+
+use std::{fmt::Display, io};
+
+use crate::output::{json::json_string, output_format::OutputFormat};
+
+/// Writes a distance table in the selected format.
+///
+/// # Parameters
+///
+/// - `writer`: destination for the formatted table.
+/// - `rows`: `(node_id, distance)` pairs in output order; `None` marks an
+///   unreachable node.
+/// - `format`: output format to use.
+///
+/// # Errors
+///
+/// Returns any I/O error raised by `writer`.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::output::distance_table::write_distance_table;
+/// use shortest_path_finder::output::output_format::OutputFormat;
+///
+/// let rows = vec![("A".to_string(), Some(0u16)), ("B".to_string(), Some(7u16))];
+/// let mut buffer = Vec::new();
+/// write_distance_table(&mut buffer, &rows, &OutputFormat::Tsv).unwrap();
+/// assert_eq!(String::from_utf8(buffer).unwrap(), "node\tdistance\nA\t0\nB\t7\n");
+/// ```
+pub fn write_distance_table<W: Display>(
+    writer: &mut impl io::Write,
+    rows: &[(String, Option<W>)],
+    format: &OutputFormat,
+) -> io::Result<()> {
+    if *format == OutputFormat::Json {
+        let entries: Vec<String> = rows
+            .iter()
+            .map(|(node_id, distance)| {
+                let distance = distance
+                    .as_ref()
+                    .map(|distance| distance.to_string())
+                    .unwrap_or_else(|| "null".to_string());
+                format!("{}:{}", json_string(node_id), distance)
+            })
+            .collect();
+        return writeln!(writer, "{{{}}}", entries.join(","));
+    }
+
+    let Some(delimiter) = format.delimiter() else {
+        for (node_id, distance) in rows {
+            match distance {
+                Some(distance) => writeln!(writer, "{}: {}", node_id, distance)?,
+                None => writeln!(writer, "{}: unreachable", node_id)?,
+            }
+        }
+        return Ok(());
+    };
+
+    writeln!(writer, "node{}distance", delimiter)?;
+    for (node_id, distance) in rows {
+        let distance = distance
+            .as_ref()
+            .map(|distance| distance.to_string())
+            .unwrap_or_default();
+        writeln!(
+            writer,
+            "{}{}{}",
+            escape_field(node_id, delimiter),
+            delimiter,
+            distance
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Quotes a delimited field when it contains special characters.
+///
+/// Embedded quotes are doubled, following RFC 4180.
+pub(crate) fn escape_field(field: &str, delimiter: char) -> String {
+    if field.contains(delimiter) || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
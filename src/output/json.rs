@@ -0,0 +1,32 @@
+//! Minimal JSON encoding helpers.
+//!
+//! # Overview
+//!
+//! The JSON output of the CLI (`--output json`) only needs strings, numbers,
+//! arrays, and flat objects, so it is written by hand instead of depending on
+//! the optional `serde` feature. [`json_string`] quotes and escapes one string
+//! value following RFC 8259.
+
+/// Quotes `value` as a JSON string.
+///
+/// `"` and `\` are escaped, and control characters are written as `\n`,
+/// `\r`, `\t`, or `\u00XX`.
+pub(crate) fn json_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            control if control.is_control() && (control as u32) < 0x20 => {
+                quoted.push_str(&format!("\\u{:04x}", control as u32));
+            }
+            other => quoted.push(other),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
@@ -0,0 +1,28 @@
+//! Result formatting for the CLI and library consumers.
+//!
+//! # Overview
+//!
+//! This module groups the presentation layer that turns algorithm output into
+//! text:
+//! - [`output_format`]: user-selectable output formats.
+//! - [`distance_table`]: single-source distance tables (CSV/TSV/text).
+//! - [`distance_format`]: unit label and decimal precision for distances.
+//!
+//! JSON output is encoded by the crate-internal `json` helpers.
+//!
+//! # Usage
+//!
+//! ```rust
+//! use shortest_path_finder::output::distance_table::write_distance_table;
+//! use shortest_path_finder::output::output_format::OutputFormat;
+//!
+//! let rows = vec![("A".to_string(), Some(0u16)), ("B".to_string(), None)];
+//! let mut buffer = Vec::new();
+//! write_distance_table(&mut buffer, &rows, &OutputFormat::Csv).unwrap();
+//! assert_eq!(String::from_utf8(buffer).unwrap(), "node,distance\nA,0\nB,\n");
+//! ```
+
+pub mod distance_format;
+pub mod distance_table;
+pub(crate) mod json;
+pub mod output_format;
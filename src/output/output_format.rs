@@ -0,0 +1,85 @@
+//! Output format selection.
+//!
+//! # Overview
+//!
+//! [`OutputFormat`] describes how the CLI prints its results. It is parsed from
+//! the `--output` flag by [`crate::cmd_line::app_config::AppConfig`] and
+//! consumed by the writers in [`crate::output`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use shortest_path_finder::output::output_format::OutputFormat;
+//!
+//! assert_eq!(OutputFormat::get_from_string("csv"), Some(OutputFormat::Csv));
+//! assert_eq!(OutputFormat::get_from_string("tsv"), Some(OutputFormat::Tsv));
+//! assert_eq!(OutputFormat::get_from_string("json"), Some(OutputFormat::Json));
+//! assert_eq!(OutputFormat::Csv.delimiter(), Some(','));
+//! ```
+
+/// Output formats supported by the CLI.
+///
+/// # Variants
+///
+/// - [`OutputFormat::Text`]: human-readable text (default).
+/// - [`OutputFormat::Csv`]: comma-separated values with a header row.
+/// - [`OutputFormat::Tsv`]: tab-separated values with a header row.
+/// - [`OutputFormat::Json`]: one JSON document for programmatic consumers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text output.
+    #[default]
+    Text,
+    /// Comma-separated values.
+    Csv,
+    /// Tab-separated values.
+    Tsv,
+    /// JSON, e.g. `{"path":["A","B"],"distance":7}` for a path search.
+    Json,
+}
+
+impl OutputFormat {
+    /// Converts a user-provided string into an [`OutputFormat`].
+    ///
+    /// Matching is ASCII case-insensitive. Recognized values are `text`,
+    /// `csv`, `tsv`, and `json`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some` with the matching [`OutputFormat`].
+    /// - `None` for any other value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use shortest_path_finder::output::output_format::OutputFormat;
+    ///
+    /// assert_eq!(OutputFormat::get_from_string("CSV"), Some(OutputFormat::Csv));
+    /// assert_eq!(OutputFormat::get_from_string("text"), Some(OutputFormat::Text));
+    /// assert_eq!(OutputFormat::get_from_string("unknown"), None);
+    /// ```
+    pub fn get_from_string(src: &str) -> Option<Self> {
+        match src.to_ascii_lowercase().as_str() {
+            "text" => Some(Self::Text),
+            "csv" => Some(Self::Csv),
+            "tsv" => Some(Self::Tsv),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// Returns the column delimiter for delimited formats.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(',')` for [`OutputFormat::Csv`].
+    /// - `Some('\t')` for [`OutputFormat::Tsv`].
+    /// - `None` for [`OutputFormat::Text`] and [`OutputFormat::Json`].
+    pub fn delimiter(&self) -> Option<char> {
+        match self {
+            Self::Text | Self::Json => None,
+            Self::Csv => Some(','),
+            Self::Tsv => Some('\t'),
+        }
+    }
+}
@@ -7,6 +7,7 @@
 //!   graph format.
 //! - Implementations of [`GraphWeight`](crate::graphs::graph::GraphWeight)
 //!   for `u16`, `f32`, and `i32`.
+//! - [`NoWeight`], the weight type of graphs whose edges carry no weight.
 //!
 //! # Usage
 //!
@@ -21,6 +22,8 @@
 //! assert!(<u16 as GraphWeight>::max_value() > 1_000);
 //! ```
 
+use std::{fmt, ops::Add};
+
 use crate::graphs::graph::GraphWeight;
 
 /// Runtime weight wrapper used by parsing and intermediate conversion code.
@@ -98,3 +101,52 @@ impl GraphWeight for i32 {
         i32::MAX
     }
 }
+
+/// Weight type of graphs whose edges carry no weight at all.
+///
+/// Every value is equal, and adding two of them yields another [`NoWeight`],
+/// so sums carry no information. The type deliberately does not implement
+/// [`Ord`]: algorithms that need comparable weights, such as
+/// [`DijkstraAlgorithm`](crate::algorithms::dijkstra::DijkstraAlgorithm),
+/// therefore cannot be instantiated for it. Hop-based algorithms like
+/// [`BfsAlgorithm`](crate::algorithms::bfs::BfsAlgorithm) work on any graph.
+///
+/// # Example
+///
+/// ```rust
+/// use shortest_path_finder::graphs::graph::GraphWeight;
+/// use shortest_path_finder::weight_types::impl_weights::NoWeight;
+///
+/// assert_eq!(NoWeight + NoWeight, NoWeight::zero());
+/// assert_eq!(NoWeight.to_string(), "-");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Hash)]
+pub struct NoWeight;
+
+impl Add for NoWeight {
+    type Output = Self;
+
+    fn add(self, _: Self) -> Self {
+        NoWeight
+    }
+}
+
+impl fmt::Display for NoWeight {
+    /// Renders as `-`, since there is no weight to show.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "-")
+    }
+}
+
+/// Implements the `GraphWeight` trait for `NoWeight`.
+impl GraphWeight for NoWeight {
+    /// Returns the only `NoWeight` value.
+    fn zero() -> Self {
+        NoWeight
+    }
+
+    /// Returns the only `NoWeight` value.
+    fn max_value() -> Self {
+        NoWeight
+    }
+}
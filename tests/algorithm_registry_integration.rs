@@ -0,0 +1,184 @@
+//! Integration tests for registering custom algorithms.
+//!
+//! These tests register a trivial algorithm and check that name lookup, CLI
+//! parsing, graph binding, and the library CLI entry point reach it through
+//! the registry.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use shortest_path_finder::{
+    algorithms::{
+        algorithm::{Algorithm, Algorithms, SearchResult},
+        registry::{
+            CustomAlgorithmError, CustomSearchResult, DynGraph, create_algorithm, into_dyn_graph,
+            is_algorithm_registered, register_algorithm,
+        },
+    },
+    cmd_line::{app_config::AppConfig, runner::run_from_args},
+    error::pathfinder_error::PathfinderError,
+    graphs::{
+        graph::{Graph, GraphNode},
+        undirected::UndirectedGraph,
+    },
+    nodes::default_node::DefaultNode,
+};
+
+fn node(id: &str) -> DefaultNode {
+    DefaultNode::new(id.to_string())
+}
+
+/// Walks from the start along the cheapest edge to an unvisited node until
+/// the end is reached.
+struct GreedyWalk {
+    graph: Box<DynGraph>,
+}
+
+impl Algorithm for GreedyWalk {
+    type ExecutionError = CustomAlgorithmError;
+
+    type AlgorithmSearchResult = CustomSearchResult;
+
+    type NodeOfUsedGraph = DefaultNode;
+
+    fn shortest_path(
+        &self,
+        start_node_id: &str,
+        end_node_id: &str,
+    ) -> Result<CustomSearchResult, CustomAlgorithmError> {
+        let mut current = self
+            .graph
+            .get_node_by_id(start_node_id)
+            .ok_or_else(|| CustomAlgorithmError::new(format!("unknown {}", start_node_id)))?;
+        let mut path = vec![current.clone()];
+        let mut distance = 0;
+        while current.get_id() != end_node_id {
+            let (next, weight) = self
+                .graph
+                .neighbors(current)
+                .filter(|(next, _)| !path.contains(next))
+                .min_by_key(|(_, weight)| *weight)
+                .ok_or_else(|| CustomAlgorithmError::new("stuck".to_string()))?;
+            path.push(next.clone());
+            distance += weight;
+            current = next;
+        }
+        Ok(CustomSearchResult::new(path, distance))
+    }
+}
+
+fn register_greedy_walk(name: &str) {
+    register_algorithm(name, Box::new(|graph| Box::new(GreedyWalk { graph })));
+}
+
+#[test]
+fn registered_algorithm_is_selected_by_name_and_runs_on_the_graph() {
+    assert!(!is_algorithm_registered("greedy-walk"));
+    assert!(Algorithms::get_from_string("greedy-walk").is_none());
+    register_greedy_walk("greedy-walk");
+    assert!(is_algorithm_registered("greedy-walk"));
+
+    let config = AppConfig::setup_config(
+        [
+            "pathfinder",
+            "--algo",
+            "greedy-walk",
+            "--start",
+            "A",
+            "--end",
+            "C",
+        ]
+        .iter()
+        .map(|part| part.to_string())
+        .collect(),
+    )
+    .expect("expected valid config");
+    let Algorithms::Custom(name) = config.algorithm else {
+        panic!(
+            "expected the registered algorithm, got {:?}",
+            config.algorithm
+        );
+    };
+
+    let mut graph = UndirectedGraph::new(vec![node("A"), node("B"), node("C")]);
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(1)).is_none());
+    assert!(graph.insert_edge(&node("B"), &node("C"), Some(5)).is_none());
+    assert!(graph.insert_edge(&node("A"), &node("C"), Some(4)).is_none());
+
+    let algorithm = create_algorithm(&name, into_dyn_graph(graph)).expect("name is registered");
+    let result = algorithm.shortest_path("A", "C").unwrap();
+    // The greedy walk takes A-B first, so it misses the cheaper direct edge.
+    assert_eq!(result.to_string(), "A -> B -> C (total: 6)");
+    assert_eq!(result.get_total_distance(), 6);
+
+    let err: PathfinderError = algorithm.shortest_path("X", "C").unwrap_err().into();
+    assert!(matches!(err, PathfinderError::CustomAlgorithm(_)));
+}
+
+#[test]
+fn registered_algorithm_runs_through_the_cli_entry_point() {
+    static SEARCHES: AtomicUsize = AtomicUsize::new(0);
+
+    /// Counts its searches, then delegates to [`GreedyWalk`].
+    struct CountingWalk(GreedyWalk);
+
+    impl Algorithm for CountingWalk {
+        type ExecutionError = CustomAlgorithmError;
+
+        type AlgorithmSearchResult = CustomSearchResult;
+
+        type NodeOfUsedGraph = DefaultNode;
+
+        fn shortest_path(
+            &self,
+            start_node_id: &str,
+            end_node_id: &str,
+        ) -> Result<CustomSearchResult, CustomAlgorithmError> {
+            SEARCHES.fetch_add(1, Ordering::SeqCst);
+            self.0.shortest_path(start_node_id, end_node_id)
+        }
+    }
+
+    register_algorithm(
+        "counting-walk",
+        Box::new(|graph| Box::new(CountingWalk(GreedyWalk { graph }))),
+    );
+    let args = |end: &str| {
+        [
+            "pathfinder",
+            "--graph-file",
+            "test_files/directed_graph.txt",
+            "--algo",
+            "counting-walk",
+            "--start",
+            "A",
+            "--end",
+            end,
+        ]
+        .iter()
+        .map(|part| part.to_string())
+        .collect()
+    };
+
+    run_from_args(args("C")).expect("the registered algorithm should find a path");
+    assert_eq!(SEARCHES.load(Ordering::SeqCst), 1);
+
+    let err = run_from_args(args("missing")).expect_err("the end node does not exist");
+    assert!(matches!(err, PathfinderError::CustomAlgorithm(_)));
+    assert_eq!(SEARCHES.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn built_in_names_win_over_registered_algorithms() {
+    register_greedy_walk("BFS");
+    assert!(matches!(
+        Algorithms::get_from_string("BFS"),
+        Some(Algorithms::Bfs)
+    ));
+    assert!(
+        create_algorithm(
+            "not-registered",
+            into_dyn_graph(UndirectedGraph::new(vec![]))
+        )
+        .is_none()
+    );
+}
@@ -5,8 +5,13 @@
 
 use shortest_path_finder::{
     algorithms::algorithm::Algorithms,
-    cmd_line::app_config::{AppConfig, InputOrigin},
-    error::config_error::ConfigParseError,
+    cmd_line::{
+        app_config::{AppConfig, InputOrigin},
+        runner::run,
+    },
+    data_input::file_input::MissingWeightPolicy,
+    error::{config_error::ConfigParseError, pathfinder_error::PathfinderError},
+    output::{distance_format::DistanceFormat, output_format::OutputFormat},
 };
 
 fn build_args(parts: &[&str]) -> Vec<String> {
@@ -46,6 +51,40 @@ fn setup_config_parses_optional_graph_file_and_algorithm() {
     assert!(matches!(config.algorithm, Algorithms::AStar));
 }
 
+#[test]
+fn setup_config_accepts_snake_case_a_star_alias() {
+    // The snake-case spelling used by the benchmarks selects A* too.
+    let args = build_args(&[
+        "pathfinder",
+        "--algo",
+        "a_star",
+        "--start",
+        "A",
+        "--end",
+        "B",
+    ]);
+
+    let config = AppConfig::setup_config(args).expect("expected valid config");
+    assert!(matches!(config.algorithm, Algorithms::AStar));
+}
+
+#[test]
+fn setup_config_accepts_dash_as_stdin_graph_file() {
+    let args = build_args(&[
+        "pathfinder",
+        "--graph-file",
+        "-",
+        "--start",
+        "A",
+        "--end",
+        "B",
+    ]);
+
+    // '-' is kept as a value, not mistaken for a flag; main reads stdin for it.
+    let config = AppConfig::setup_config(args).expect("expected valid config");
+    assert_eq!(config.file_path, "-");
+}
+
 #[test]
 fn setup_config_requires_start_node() {
     let args = build_args(&["pathfinder", "--graph-file", "graph.txt", "--end", "B"]);
@@ -67,6 +106,27 @@ fn setup_config_requires_end_node() {
     assert_eq!(err, ConfigParseError::MissingRequiredFlag { flag: "--end" });
 }
 
+#[test]
+fn setup_config_reports_help_request_before_other_checks() {
+    // Help needs no start/end nodes and is not a "not enough arguments" error.
+    for args in [
+        vec!["pathfinder", "--help"],
+        vec!["pathfinder", "-h"],
+        vec!["pathfinder", "--start", "A", "--help"],
+        vec!["pathfinder", "--whoops", "--help"],
+    ] {
+        let err = AppConfig::setup_config(build_args(&args)).expect_err("help is not a config");
+        assert_eq!(err, ConfigParseError::HelpRequested, "{args:?}");
+    }
+
+    let usage = AppConfig::usage();
+    for flag in ["--graph-file", "--start", "--end", "--algo", "--origin"] {
+        assert!(usage.contains(flag), "usage should describe {flag}");
+    }
+    assert!(usage.contains("[default: graph.txt]"));
+    assert!(usage.contains("[default: Dijkstra]"));
+}
+
 #[test]
 fn setup_config_requires_minimum_argument_count() {
     let args = build_args(&["pathfinder", "--start", "A"]);
@@ -99,6 +159,36 @@ fn setup_config_parses_origin_from_origin_flag() {
     assert!(matches!(config.data_input, InputOrigin::CommandLine));
 }
 
+#[test]
+fn setup_config_origin_flag_takes_precedence_over_algo() {
+    let args = build_args(&[
+        "pathfinder",
+        "--origin",
+        "file",
+        "--start",
+        "A",
+        "--end",
+        "B",
+    ]);
+    let config = AppConfig::setup_config(args).expect("expected valid config");
+    assert!(matches!(config.data_input, InputOrigin::File));
+
+    // '--origin' decides even when '--algo' carries a legacy origin marker.
+    let args = build_args(&[
+        "pathfinder",
+        "--algo",
+        "cmd-line",
+        "--origin",
+        "file",
+        "--start",
+        "A",
+        "--end",
+        "B",
+    ]);
+    let config = AppConfig::setup_config(args).expect("expected valid config");
+    assert!(matches!(config.data_input, InputOrigin::File));
+}
+
 #[test]
 fn setup_config_keeps_legacy_origin_fallback_from_algo() {
     let args = build_args(&[
@@ -116,6 +206,27 @@ fn setup_config_keeps_legacy_origin_fallback_from_algo() {
     assert!(matches!(config.data_input, InputOrigin::CommandLine));
 }
 
+#[test]
+fn setup_config_rejects_unknown_algorithm() {
+    let args = build_args(&[
+        "pathfinder",
+        "--algo",
+        "Dijkstar",
+        "--start",
+        "A",
+        "--end",
+        "B",
+    ]);
+
+    let err = AppConfig::setup_config(args).expect_err("expected unknown algorithm error");
+
+    assert!(matches!(
+        err,
+        ConfigParseError::InvalidValueForFlag { flag, value, .. }
+            if flag == "--algo" && value == "Dijkstar"
+    ));
+}
+
 #[test]
 fn setup_config_rejects_missing_value_for_flag() {
     let args = build_args(&["pathfinder", "--start", "--end", "B"]);
@@ -131,6 +242,66 @@ fn setup_config_rejects_missing_value_for_flag() {
     );
 }
 
+#[test]
+fn setup_config_rejects_trailing_flag_without_value() {
+    // A value flag as the very last argument must be an error, not an
+    // out-of-bounds panic.
+    for flag in ["--graph-file", "--start", "--end", "--algo", "--origin"] {
+        let args = build_args(&["pathfinder", "--start", "A", "--end", "B", flag]);
+        let err = AppConfig::setup_config(args).expect_err("a trailing flag has no value");
+        assert_eq!(
+            err,
+            ConfigParseError::MissingValueForFlag {
+                flag: flag.to_string(),
+                index: 5,
+            }
+        );
+    }
+
+    let args = build_args(&["pathfinder", "--end", "B", "--start"]);
+    let err = AppConfig::setup_config(args).expect_err("a trailing '--start' has no value");
+    assert_eq!(
+        err,
+        ConfigParseError::MissingValueForFlag {
+            flag: "--start".to_string(),
+            index: 3,
+        }
+    );
+
+    let err = AppConfig::setup_config(build_args(&["pathfinder", "--start"]))
+        .expect_err("a lone '--start' is too short");
+    assert!(matches!(err, ConfigParseError::TooFewArguments { .. }));
+}
+
+#[test]
+fn setup_config_rejects_empty_or_flag_node_names() {
+    // A flag directly after '--start'/'--end' must not become the node name.
+    for (args, flag, index) in [
+        (vec!["pathfinder", "--start", "--end", "D"], "--start", 1),
+        (
+            vec!["pathfinder", "--start", "A", "--end", "--lenient"],
+            "--end",
+            3,
+        ),
+        (
+            vec!["pathfinder", "--start", "", "--end", "D"],
+            "--start",
+            1,
+        ),
+        (vec!["pathfinder", "--start", "A", "--end", ""], "--end", 3),
+    ] {
+        let err = AppConfig::setup_config(build_args(&args))
+            .expect_err("a node name must be a non-empty, non-flag value");
+        assert_eq!(
+            err,
+            ConfigParseError::MissingValueForFlag {
+                flag: flag.to_string(),
+                index,
+            }
+        );
+    }
+}
+
 #[test]
 fn setup_config_rejects_unknown_flag() {
     let args = build_args(&["pathfinder", "--whoops", "x", "--start", "A", "--end", "B"]);
@@ -176,3 +347,365 @@ fn setup_config_rejects_unexpected_non_flag_token() {
         }
     );
 }
+
+#[test]
+fn setup_config_parses_output_format_and_distances_switch() {
+    let args = build_args(&[
+        "pathfinder",
+        "--start",
+        "A",
+        "--distances",
+        "--output",
+        "csv",
+        "--end",
+        "B",
+    ]);
+
+    let config = AppConfig::setup_config(args).expect("expected valid config");
+
+    assert!(config.print_distances);
+    assert_eq!(config.output_format, OutputFormat::Csv);
+    assert_eq!(config.end_node_id, "B");
+}
+
+#[test]
+fn setup_config_defaults_to_text_output_without_distances() {
+    let args = build_args(&["pathfinder", "--start", "A", "--end", "B"]);
+
+    let config = AppConfig::setup_config(args).expect("expected valid config");
+
+    assert!(!config.print_distances);
+    assert_eq!(config.output_format, OutputFormat::Text);
+}
+
+#[test]
+fn setup_config_collects_repeated_end_nodes() {
+    let args = build_args(&["pathfinder", "--start", "A", "--end", "D", "--end", "E"]);
+
+    let config = AppConfig::setup_config(args).expect("expected valid config");
+
+    assert_eq!(config.end_node_ids, vec!["D".to_string(), "E".to_string()]);
+    assert_eq!(config.end_node_id, "D");
+
+    let config = AppConfig::setup_config(build_args(&["pathfinder", "--start", "A", "--end", "B"]))
+        .expect("expected valid config");
+    assert_eq!(config.end_node_ids, vec!["B".to_string()]);
+}
+
+#[test]
+fn setup_config_accepts_format_as_output_alias() {
+    let args = build_args(&[
+        "pathfinder",
+        "--start",
+        "A",
+        "--end",
+        "B",
+        "--format",
+        "json",
+    ]);
+
+    let config = AppConfig::setup_config(args).expect("expected valid config");
+
+    assert_eq!(config.output_format, OutputFormat::Json);
+}
+
+#[test]
+fn setup_config_rejects_unknown_output_format() {
+    let args = build_args(&[
+        "pathfinder",
+        "--start",
+        "A",
+        "--end",
+        "B",
+        "--output",
+        "xml",
+    ]);
+
+    let err = AppConfig::setup_config(args).expect_err("expected invalid output error");
+
+    assert!(matches!(
+        err,
+        ConfigParseError::InvalidValueForFlag { ref flag, ref value, .. }
+            if flag == "--output" && value == "xml"
+    ));
+}
+
+#[test]
+fn setup_config_accepts_distances_without_end_node() {
+    let args = build_args(&["pathfinder", "--start", "A", "--distances"]);
+
+    let config = AppConfig::setup_config(args).expect("expected valid config");
+
+    assert!(config.print_distances);
+    assert!(config.end_node_ids.is_empty());
+    assert_eq!(config.end_node_id, "");
+}
+
+#[test]
+fn distances_switch_rejects_other_algorithms() {
+    let args = build_args(&[
+        "pathfinder",
+        "--graph-file",
+        "test_files/directed_graph.txt",
+        "--start",
+        "A",
+        "--distances",
+        "--algo",
+        "BFS",
+    ]);
+    let config = AppConfig::setup_config(args).expect("expected valid config");
+
+    let err = run(&config).expect_err("'--distances' is Dijkstra only");
+
+    assert!(matches!(err, PathfinderError::Unsupported(_)));
+}
+
+#[test]
+fn setup_config_parses_optional_seed_flag() {
+    let args = build_args(&["pathfinder", "--start", "A", "--end", "B", "--seed", "7"]);
+
+    let config = AppConfig::setup_config(args).expect("expected valid config");
+    assert_eq!(config.seed, Some(7));
+
+    let config = AppConfig::setup_config(build_args(&["pathfinder", "--start", "A", "--end", "B"]))
+        .expect("expected valid config");
+    assert_eq!(config.seed, None);
+}
+
+#[test]
+fn setup_config_rejects_non_numeric_seed() {
+    let args = build_args(&["pathfinder", "--start", "A", "--end", "B", "--seed", "abc"]);
+
+    let err = AppConfig::setup_config(args).expect_err("expected invalid seed error");
+
+    assert!(matches!(
+        err,
+        ConfigParseError::InvalidValueForFlag { ref flag, ref value, .. }
+            if flag == "--seed" && value == "abc"
+    ));
+}
+
+#[test]
+fn setup_config_parses_unit_and_precision() {
+    let args = build_args(&[
+        "pathfinder",
+        "--start",
+        "A",
+        "--end",
+        "B",
+        "--unit",
+        "km",
+        "--precision",
+        "2",
+    ]);
+
+    let config = AppConfig::setup_config(args).expect("expected valid config");
+
+    assert_eq!(config.distance_format.unit.as_deref(), Some("km"));
+    assert_eq!(config.distance_format.precision, Some(2));
+    assert_eq!(config.distance_format.format(&1.5f32), "1.50 km");
+}
+
+#[test]
+fn setup_config_defaults_to_plain_distances_and_rejects_bad_precision() {
+    let config = AppConfig::setup_config(build_args(&["pathfinder", "--start", "A", "--end", "B"]))
+        .expect("expected valid config");
+    assert_eq!(config.distance_format, DistanceFormat::default());
+    assert_eq!(config.distance_format.format(&42u16), "42");
+
+    let args = build_args(&[
+        "pathfinder",
+        "--start",
+        "A",
+        "--end",
+        "B",
+        "--precision",
+        "-1",
+    ]);
+    let err = AppConfig::setup_config(args).expect_err("expected invalid precision error");
+    assert!(matches!(
+        err,
+        ConfigParseError::InvalidValueForFlag { ref flag, .. } if flag == "--precision"
+    ));
+}
+
+#[test]
+fn setup_config_parses_missing_weight_policy() {
+    let default_args = build_args(&["pathfinder", "--start", "A", "--end", "B"]);
+    let config = AppConfig::setup_config(default_args).expect("expected valid config");
+    assert_eq!(config.missing_weight_policy, MissingWeightPolicy::Reject);
+
+    let args = build_args(&[
+        "pathfinder",
+        "--start",
+        "A",
+        "--end",
+        "B",
+        "--missing-weight",
+        "default",
+    ]);
+    let config = AppConfig::setup_config(args).expect("expected valid config");
+    assert_eq!(
+        config.missing_weight_policy,
+        MissingWeightPolicy::DefaultToOne
+    );
+
+    let args = build_args(&[
+        "pathfinder",
+        "--start",
+        "A",
+        "--end",
+        "B",
+        "--missing-weight",
+        "zero",
+    ]);
+    let err = AppConfig::setup_config(args).expect_err("expected invalid policy error");
+    assert!(matches!(
+        err,
+        ConfigParseError::InvalidValueForFlag { ref flag, ref value, .. }
+            if flag == "--missing-weight" && value == "zero"
+    ));
+}
+
+#[test]
+fn setup_config_parses_progress_interval_and_rejects_zero() {
+    let args = build_args(&[
+        "pathfinder",
+        "--start",
+        "A",
+        "--end",
+        "B",
+        "--progress-interval",
+        "500",
+    ]);
+    let config = AppConfig::setup_config(args).expect("expected valid config");
+    assert_eq!(
+        config.progress_interval.map(|interval| interval.get()),
+        Some(500)
+    );
+
+    let args = build_args(&[
+        "pathfinder",
+        "--start",
+        "A",
+        "--end",
+        "B",
+        "--progress-interval",
+        "0",
+    ]);
+    let err = AppConfig::setup_config(args).expect_err("zero interval should be rejected");
+    assert!(matches!(
+        err,
+        ConfigParseError::InvalidValueForFlag { ref flag, .. } if flag == "--progress-interval"
+    ));
+}
+
+#[test]
+fn setup_config_parses_node_limit_and_rejects_invalid_values() {
+    let config = AppConfig::setup_config(build_args(&[
+        "pathfinder",
+        "--start",
+        "A",
+        "--end",
+        "B",
+        "--limit",
+        "1000",
+    ]))
+    .expect("expected valid config");
+    assert_eq!(config.node_limit.map(|limit| limit.get()), Some(1000));
+
+    let config = AppConfig::setup_config(build_args(&["pathfinder", "--start", "A", "--end", "B"]))
+        .expect("expected valid config");
+    assert_eq!(config.node_limit, None);
+
+    for invalid in ["0", "many"] {
+        let err = AppConfig::setup_config(build_args(&[
+            "pathfinder",
+            "--start",
+            "A",
+            "--end",
+            "B",
+            "--limit",
+            invalid,
+        ]))
+        .expect_err("invalid limit should be rejected");
+        assert!(matches!(
+            err,
+            ConfigParseError::InvalidValueForFlag { ref flag, .. } if flag == "--limit"
+        ));
+    }
+}
+
+#[test]
+fn setup_config_parses_lenient_switch() {
+    let config = AppConfig::setup_config(build_args(&[
+        "pathfinder",
+        "--lenient",
+        "--start",
+        "A",
+        "--end",
+        "B",
+    ]))
+    .expect("expected valid config");
+    assert!(config.lenient);
+
+    let config = AppConfig::setup_config(build_args(&["pathfinder", "--start", "A", "--end", "B"]))
+        .expect("expected valid config");
+    assert!(!config.lenient);
+}
+
+#[test]
+fn setup_config_parses_explain_switch() {
+    let config = AppConfig::setup_config(build_args(&[
+        "pathfinder",
+        "--start",
+        "A",
+        "--explain",
+        "--end",
+        "B",
+    ]))
+    .expect("expected valid config");
+    assert!(config.explain);
+    assert_eq!(config.end_node_id, "B");
+
+    let config = AppConfig::setup_config(build_args(&["pathfinder", "--start", "A", "--end", "B"]))
+        .expect("expected valid config");
+    assert!(!config.explain);
+}
+
+#[test]
+fn setup_config_parses_stats_switch() {
+    let config = AppConfig::setup_config(build_args(&[
+        "pathfinder",
+        "--stats",
+        "--start",
+        "A",
+        "--end",
+        "B",
+    ]))
+    .expect("expected valid config");
+    assert!(config.print_stats);
+    assert_eq!(config.start_node_id, "A");
+
+    let config = AppConfig::setup_config(build_args(&["pathfinder", "--start", "A", "--end", "B"]))
+        .expect("expected valid config");
+    assert!(!config.print_stats);
+}
+
+#[test]
+fn setup_config_parses_pairs_file_without_start_and_end() {
+    let config = AppConfig::setup_config(build_args(&[
+        "pathfinder",
+        "--graph-file",
+        "graph.txt",
+        "--pairs",
+        "pairs.txt",
+    ]))
+    .expect("start and end come from the pairs file");
+    assert_eq!(config.pairs_file.as_deref(), Some("pairs.txt"));
+    assert_eq!(config.start_node_id, "");
+
+    let config = AppConfig::setup_config(build_args(&["pathfinder", "--start", "A", "--end", "B"]))
+        .expect("expected valid config");
+    assert!(config.pairs_file.is_none());
+}
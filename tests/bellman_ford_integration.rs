@@ -0,0 +1,104 @@
+//! Integration tests for the Bellman-Ford algorithm.
+//!
+//! These tests cover shortest paths through negative edges, negative-cycle
+//! detection, and loading signed graphs from a file.
+
+use std::{
+    fs,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use shortest_path_finder::{
+    algorithms::{
+        algorithm::{Algorithm, SearchResult},
+        bellman_ford::BellmanFordAlgorithm,
+    },
+    data_input::file_input::{FileInputOptions, retrieve_graph_data_from_file_with_options},
+    graphs::{directed::SignedDirectedGraph, graph::GraphNode},
+    nodes::default_node::DefaultNode,
+};
+
+fn path_ids<R: SearchResult<Node = DefaultNode>>(result: &R) -> Vec<&str> {
+    result.get_path().iter().map(|n| n.get_id()).collect()
+}
+
+fn signed_graph(edges: &[(&str, &str, i32)]) -> SignedDirectedGraph {
+    let edges: Vec<(String, String, i32)> = edges
+        .iter()
+        .map(|(from, to, weight)| (from.to_string(), to.to_string(), *weight))
+        .collect();
+    SignedDirectedGraph::from_edges(&edges).expect("edges are valid")
+}
+
+#[test]
+fn bellman_ford_follows_negative_edges() {
+    let graph = signed_graph(&[
+        ("A", "B", 4),
+        ("A", "C", 2),
+        ("C", "D", 5),
+        ("D", "B", -6),
+        ("B", "E", 1),
+    ]);
+    let algo = BellmanFordAlgorithm::new(graph);
+
+    let result = algo.shortest_path("A", "E").expect("E is reachable");
+    assert_eq!(path_ids(&result), vec!["A", "C", "D", "B", "E"]);
+    assert_eq!(result.get_total_distance(), 2);
+
+    let result = algo.shortest_path("A", "A").expect("start equals end");
+    assert_eq!(result.to_string(), "A (total: 0)");
+
+    let err = algo
+        .shortest_path("E", "A")
+        .expect_err("A is not reachable");
+    assert!(err.to_string().contains("No path from E to A"), "{}", err);
+}
+
+#[test]
+fn bellman_ford_reports_reachable_negative_cycles_only() {
+    // B <-> C is a negative cycle; X -> Y never reaches it.
+    let graph = signed_graph(&[
+        ("A", "B", 1),
+        ("B", "C", -3),
+        ("C", "B", 1),
+        ("C", "D", 1),
+        ("X", "Y", 2),
+    ]);
+    let algo = BellmanFordAlgorithm::new(graph);
+
+    let err = algo
+        .shortest_path("A", "D")
+        .expect_err("the cycle makes distances unbounded");
+    assert!(err.to_string().contains("negative cycle"), "{}", err);
+
+    let result = algo
+        .shortest_path("X", "Y")
+        .expect("the cycle is not reachable from X");
+    assert_eq!(result.get_total_distance(), 2);
+}
+
+#[test]
+fn bellman_ford_searches_signed_graph_files() {
+    let unique_id = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock should be after UNIX epoch")
+        .as_nanos();
+    let path = std::env::temp_dir().join(format!("pathfinder-bellman-ford-{}.txt", unique_id));
+    fs::write(&path, "D\nA->B:5\nA->C:2\nC->B:-4\n").expect("temporary file should be writable");
+
+    let options = FileInputOptions {
+        allow_negative_weights: true,
+        ..FileInputOptions::default()
+    };
+    let parsed = retrieve_graph_data_from_file_with_options(&path.to_string_lossy(), &options);
+    let _ = fs::remove_file(&path);
+    let graph = parsed
+        .expect("signed file should load")
+        .signed_directed_graph
+        .expect("negative weights produce a signed graph");
+
+    let result = BellmanFordAlgorithm::new(graph)
+        .shortest_path("A", "B")
+        .expect("B is reachable");
+    assert_eq!(result.to_string(), "A -> C -> B (total: -2)");
+}
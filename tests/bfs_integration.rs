@@ -0,0 +1,95 @@
+//! Integration tests for breadth-first search.
+//!
+//! These tests cover fewest-hop routing on unweighted graphs, weight-agnostic
+//! search on weighted graphs, and the error cases.
+
+use shortest_path_finder::{
+    algorithms::{
+        algorithm::{Algorithm, SearchResult},
+        bfs::BfsAlgorithm,
+        dijkstra::DijkstraAlgorithm,
+    },
+    data_input::file_input::retrieve_graph_data_from_file,
+    graphs::{
+        directed::DirectedGraph,
+        graph::{Graph, GraphNode},
+        unweighted::UnweightedGraph,
+    },
+    nodes::default_node::DefaultNode,
+};
+
+fn node(id: &str) -> DefaultNode {
+    DefaultNode::new(id.to_string())
+}
+
+fn path_ids<R: SearchResult<Node = DefaultNode>>(result: &R) -> Vec<&str> {
+    result.get_path().iter().map(|n| n.get_id()).collect()
+}
+
+#[test]
+fn bfs_finds_fewest_hops_on_unweighted_graph() {
+    let nodes: Vec<DefaultNode> = ["A", "B", "C", "D", "E"]
+        .iter()
+        .map(|id| node(id))
+        .collect();
+    let mut graph = UnweightedGraph::new_undirected(nodes);
+    for (from, to) in [("A", "B"), ("B", "C"), ("C", "D"), ("A", "E"), ("E", "D")] {
+        assert!(graph.insert_edge(&node(from), &node(to), None).is_none());
+    }
+
+    let algo = BfsAlgorithm::new(graph);
+    let result = algo.shortest_path("A", "D").expect("D is reachable");
+    assert_eq!(path_ids(&result), vec!["A", "E", "D"]);
+    assert_eq!(result.get_total_distance(), 2);
+
+    // Undirected edges can be walked backwards.
+    let result = algo.shortest_path("D", "B").expect("B is reachable");
+    assert_eq!(result.hops(), 2);
+
+    let result = algo.shortest_path("C", "C").expect("start equals end");
+    assert_eq!(result.to_string(), "C (hops: 0)");
+}
+
+#[test]
+fn bfs_ignores_weights_and_respects_direction() {
+    let edges = vec![
+        ("A".to_string(), "B".to_string(), 1),
+        ("B".to_string(), "C".to_string(), 1),
+        ("A".to_string(), "C".to_string(), 100),
+    ];
+    let graph = DirectedGraph::from_edges(&edges).expect("edges are valid");
+    let algo = BfsAlgorithm::new(graph);
+
+    // The expensive direct edge is one hop, so BFS takes it.
+    let result = algo.shortest_path("A", "C").expect("C is reachable");
+    assert_eq!(path_ids(&result), vec!["A", "C"]);
+
+    let err = algo
+        .shortest_path("C", "A")
+        .expect_err("edges only point away from A");
+    assert!(err.to_string().contains("No path from C to A"), "{}", err);
+    let err = algo.shortest_path("A", "Z").expect_err("Z does not exist");
+    assert!(err.to_string().contains("end node Z"), "{}", err);
+}
+
+#[test]
+fn bfs_searches_weighted_undirected_file_by_hops() {
+    let graph = retrieve_graph_data_from_file("test_files/undirected_graph.txt")
+        .expect("test file should load")
+        .undirected_graph
+        .expect("file describes an undirected graph");
+
+    // Dijkstra prefers the cheap A-E-G-L detour; BFS takes the two-hop route.
+    let cheapest = DijkstraAlgorithm::new(graph.clone())
+        .shortest_path("A", "L")
+        .expect("L is reachable");
+    assert_eq!(cheapest.get_total_distance(), 11);
+    assert_eq!(cheapest.get_path().len(), 4);
+
+    let result = BfsAlgorithm::new(graph)
+        .shortest_path("A", "L")
+        .expect("L is reachable");
+    assert_eq!(result.hops(), 2);
+    assert_eq!(path_ids(&result).first(), Some(&"A"));
+    assert_eq!(path_ids(&result).last(), Some(&"L"));
+}
@@ -0,0 +1,71 @@
+//! Integration tests for parsing CSV edge lists into graphs.
+//!
+//! These tests cover header detection, quoted node names, both graph kinds,
+//! and the errors raised for malformed rows.
+
+use shortest_path_finder::{
+    data_input::{csv_input::graph_from_csv, file_input::FileInputError},
+    graphs::graph::{Graph, GraphNode},
+    nodes::default_node::DefaultNode,
+};
+
+fn node(id: &str) -> DefaultNode {
+    DefaultNode::new(id.to_string())
+}
+
+/// Parses `content` as a directed edge list and returns the error message.
+fn csv_error(content: &str) -> String {
+    let err = graph_from_csv(content, true).expect_err("the CSV text should be rejected");
+    assert!(matches!(err, FileInputError::Parse(_)), "{}", err);
+    err.to_string()
+}
+
+#[test]
+fn csv_with_header_builds_directed_graph() {
+    let csv = "source,target,weight\n\
+               A,B,4\n\
+               \"Portland, OR\",A,12\n\
+               B, \"C \"\"central\"\"\" ,3\n";
+
+    let parsed = graph_from_csv(csv, true).expect("CSV with a header should parse");
+    let graph = parsed.directed_graph.expect("directed was requested");
+
+    assert!(parsed.undirected_graph.is_none());
+    assert!(graph.get_node_by_id("source").is_none());
+    // Edges are listed in node insertion order: A, B, then Portland.
+    assert_eq!(
+        graph.to_edge_list(),
+        vec![
+            ("A".to_string(), "B".to_string(), 4),
+            ("B".to_string(), "C \"central\"".to_string(), 3),
+            ("Portland, OR".to_string(), "A".to_string(), 12),
+        ]
+    );
+}
+
+#[test]
+fn csv_without_header_builds_undirected_graph() {
+    let parsed = graph_from_csv("A,B,4\n\nB,C,3\nC,B,9\n", false).expect("CSV should parse");
+    let graph = parsed.undirected_graph.expect("undirected was requested");
+
+    // The first row is data because its third column is numeric.
+    assert_eq!(graph.get_all_nodes().len(), 3);
+    // `C,B` repeats the undirected `B,C` edge and is ignored.
+    assert_eq!(graph.all_edges().len(), 2);
+    assert_eq!(
+        graph
+            .neighbors(&node("C"))
+            .next()
+            .map(|(n, w)| (n.get_id(), w)),
+        Some(("B", 3))
+    );
+}
+
+#[test]
+fn csv_errors_name_the_offending_line() {
+    assert!(csv_error("from,to,weight\nA,B\n").contains("CSV line 2"));
+    assert!(csv_error("A,B,7\nB,C,heavy\n").contains("invalid weight 'heavy'"));
+    assert!(csv_error("A,B,70000\n").contains("invalid weight '70000'"));
+    assert!(csv_error("A,,7\n").contains("empty node name"));
+    assert!(csv_error("\"A,B,7\n").contains("unterminated"));
+}
@@ -0,0 +1,96 @@
+//! Integration tests for depth-first search.
+//!
+//! These tests cover the first-found path and its summed weight, cycle
+//! handling, and the error cases.
+
+use shortest_path_finder::{
+    algorithms::{
+        algorithm::{Algorithm, Algorithms, SearchResult},
+        dfs::DfsAlgorithm,
+    },
+    graphs::{
+        directed::DirectedGraph,
+        graph::{Graph, GraphNode},
+        undirected::UndirectedGraph,
+    },
+    nodes::default_node::DefaultNode,
+};
+
+fn node(id: &str) -> DefaultNode {
+    DefaultNode::new(id.to_string())
+}
+
+fn path_ids<R: SearchResult<Node = DefaultNode>>(result: &R) -> Vec<&str> {
+    result.get_path().iter().map(|n| n.get_id()).collect()
+}
+
+#[test]
+fn dfs_returns_first_found_path_with_summed_weights() {
+    // The first edge out of A leads down the long branch, so DFS follows it
+    // even though A -> D is shorter.
+    let edges = vec![
+        ("A".to_string(), "B".to_string(), 1),
+        ("B".to_string(), "C".to_string(), 2),
+        ("C".to_string(), "D".to_string(), 3),
+        ("A".to_string(), "D".to_string(), 1),
+    ];
+    let graph = DirectedGraph::from_edges(&edges).expect("edges are valid");
+    let algo = DfsAlgorithm::new(graph);
+
+    let result = algo.shortest_path("A", "D").expect("D is reachable");
+    assert_eq!(path_ids(&result), vec!["A", "B", "C", "D"]);
+    assert_eq!(result.get_total_distance(), 6);
+
+    let result = algo.shortest_path("B", "B").expect("start equals end");
+    assert_eq!(result.to_string(), "B (total: 0)");
+}
+
+#[test]
+fn dfs_backtracks_out_of_cycles_and_dead_ends() {
+    let mut graph = UndirectedGraph::default();
+    for id in ["A", "B", "C", "D", "E"] {
+        graph.insert_node(node(id));
+    }
+    // A-B-C is a cycle; E is only reachable through D.
+    for (from, to, weight) in [
+        ("A", "B", 1),
+        ("B", "C", 1),
+        ("C", "A", 1),
+        ("A", "D", 4),
+        ("D", "E", 5),
+    ] {
+        assert!(
+            graph
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+
+    let result = DfsAlgorithm::new(graph)
+        .shortest_path("B", "E")
+        .expect("E is reachable");
+    let ids = path_ids(&result);
+    assert!(ids.ends_with(&["D", "E"]), "{:?}", ids);
+    let mut unique = ids.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), ids.len(), "path must not revisit a node");
+}
+
+#[test]
+fn dfs_reports_unreachable_and_missing_nodes() {
+    let edges = vec![("A".to_string(), "B".to_string(), 1)];
+    let algo = DfsAlgorithm::new(DirectedGraph::from_edges(&edges).expect("edges are valid"));
+
+    let err = algo
+        .shortest_path("B", "A")
+        .expect_err("edges only point away from A");
+    assert!(err.to_string().contains("No path from B to A"), "{}", err);
+    let err = algo.shortest_path("Z", "A").expect_err("Z does not exist");
+    assert!(err.to_string().contains("start node Z"), "{}", err);
+
+    assert!(matches!(
+        Algorithms::get_from_string("DFS"),
+        Some(Algorithms::Dfs)
+    ));
+}
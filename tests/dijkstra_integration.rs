@@ -3,13 +3,16 @@
 //! These cases verify successful shortest-path execution and common error
 //! conditions expected in production input handling.
 
+use std::collections::{HashMap, HashSet};
+
 use shortest_path_finder::{
     algorithms::{
         algorithm::{Algorithm, SearchResult},
-        dijkstra::DijkstraAlgorithm,
+        dijkstra::{DijkstraAlgorithm, DijkstraSearchResult, PathOutcome},
+        turn_restriction::TurnRestriction,
     },
     graphs::{
-        directed::DirectedGraph,
+        directed::{DirectedGraph, SignedDirectedGraph},
         graph::{Graph, GraphNode},
         undirected::UndirectedGraph,
     },
@@ -46,6 +49,28 @@ fn dijkstra_finds_shortest_path_in_directed_graph() {
     assert_eq!(result.get_total_distance(), 3);
 }
 
+#[test]
+fn dijkstra_result_display_lists_hop_weights_without_leading_arrow() {
+    let mut graph = DirectedGraph::default();
+    for id in ["A", "B", "C"] {
+        graph.insert_node(node(id));
+    }
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(7)).is_none());
+    assert!(graph.insert_edge(&node("B"), &node("C"), Some(3)).is_none());
+    assert!(
+        graph
+            .insert_edge(&node("A"), &node("C"), Some(20))
+            .is_none()
+    );
+
+    let result = DijkstraAlgorithm::new(graph)
+        .shortest_path("A", "C")
+        .expect("path should exist");
+
+    assert_eq!(result.to_string(), "A -(7)-> B -(3)-> C (total: 10)");
+    assert_eq!(format!("{:#}", result), "A -> B -> C (total: 10)");
+}
+
 #[test]
 fn dijkstra_finds_shortest_path_in_undirected_graph() {
     let mut graph = UndirectedGraph::default();
@@ -109,3 +134,1682 @@ fn dijkstra_returns_error_when_no_path_exists() {
             || err.message.contains("A path could not be found")
     );
 }
+
+#[test]
+fn dijkstra_second_shortest_path_returns_alternative_route() {
+    let mut graph = DirectedGraph::default();
+
+    for id in ["A", "B", "C", "D"] {
+        graph.insert_node(node(id));
+    }
+
+    let node_a = node("A");
+    let node_b = node("B");
+    let node_c = node("C");
+    let node_d = node("D");
+    assert!(graph.insert_edge(&node_a, &node_b, Some(1)).is_none());
+    assert!(graph.insert_edge(&node_b, &node_d, Some(1)).is_none());
+    assert!(graph.insert_edge(&node_a, &node_c, Some(2)).is_none());
+    assert!(graph.insert_edge(&node_c, &node_d, Some(2)).is_none());
+    assert!(graph.insert_edge(&node_a, &node_d, Some(7)).is_none());
+
+    let dijkstra = DijkstraAlgorithm::new(graph);
+    let shortest = dijkstra.shortest_path("A", "D").expect("path should exist");
+    let second = dijkstra
+        .second_shortest_path("A", "D")
+        .expect("search should succeed")
+        .expect("an alternative route exists");
+
+    let path_ids: Vec<&str> = second.get_path().iter().map(|n| n.get_id()).collect();
+
+    assert_eq!(path_ids, vec!["A", "C", "D"]);
+    assert_eq!(second.get_total_distance(), 4);
+    assert_ne!(second.get_path(), shortest.get_path());
+}
+
+#[test]
+fn dijkstra_second_shortest_path_is_none_for_single_route() {
+    let mut graph = UndirectedGraph::default();
+
+    for id in ["A", "B", "C"] {
+        graph.insert_node(node(id));
+    }
+
+    let node_a = node("A");
+    let node_b = node("B");
+    let node_c = node("C");
+    assert!(graph.insert_edge(&node_a, &node_b, Some(2)).is_none());
+    assert!(graph.insert_edge(&node_b, &node_c, Some(3)).is_none());
+
+    let dijkstra = DijkstraAlgorithm::new(graph);
+    let second = dijkstra
+        .second_shortest_path("A", "C")
+        .expect("search should succeed");
+
+    assert!(second.is_none());
+}
+
+#[test]
+fn dijkstra_bidirectional_matches_unidirectional_search() {
+    // A sparse directed graph with pseudo-random weights; some pairs are
+    // unreachable because node 29 has no incoming edge.
+    let ids: Vec<String> = (0..30).map(|index| index.to_string()).collect();
+    let mut directed = DirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+    let mut undirected = UndirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+    for from in 0..ids.len() {
+        for to in [(from * 7 + 3) % 29, (from * 11 + 5) % 29, (from + 1) % 29] {
+            let weight = ((from * 13 + to * 7) % 17 + 1) as u16;
+            let (from_node, to_node) = (node(&ids[from]), node(&ids[to]));
+            if !directed.does_edge_already_exist(&from_node, &to_node) {
+                assert!(
+                    directed
+                        .insert_edge(&from_node, &to_node, Some(weight))
+                        .is_none()
+                );
+            }
+            if from != to && !undirected.does_edge_already_exist(&from_node, &to_node) {
+                let reverse_weight = weight % 5 + 1;
+                assert!(
+                    undirected
+                        .insert_two_way_edge(&from_node, &to_node, weight, reverse_weight, None)
+                        .is_none()
+                );
+            }
+        }
+    }
+
+    fn check<G>(dijkstra: &DijkstraAlgorithm<DefaultNode, u16, G>, ids: &[String])
+    where
+        G: Graph<Node = DefaultNode, Weight = u16> + std::fmt::Display,
+    {
+        for start in ids {
+            for end in ids {
+                let expected = dijkstra.shortest_path(start, end);
+                let actual = dijkstra.shortest_path_bidirectional(start, end);
+                match (expected, actual) {
+                    (Ok(expected), Ok(actual)) => {
+                        assert_eq!(
+                            actual.get_total_distance(),
+                            expected.get_total_distance(),
+                            "{start} -> {end}"
+                        );
+                        assert_eq!(actual.get_path().first().unwrap().get_id(), start);
+                        assert_eq!(actual.get_path().last().unwrap().get_id(), end);
+                        if start != end {
+                            let hops: u16 = actual
+                                .edges()
+                                .unwrap()
+                                .iter()
+                                .map(|(_, _, weight)| *weight)
+                                .sum();
+                            assert_eq!(hops, actual.get_total_distance(), "{start} -> {end}");
+                        }
+                    }
+                    (Err(_), Err(_)) => {}
+                    (expected, actual) => {
+                        panic!("{start} -> {end}: {expected:?} vs {actual:?}")
+                    }
+                }
+            }
+        }
+    }
+
+    check(&DijkstraAlgorithm::new(directed), &ids);
+    check(&DijkstraAlgorithm::new(undirected), &ids);
+}
+
+#[test]
+fn dijkstra_k_shortest_paths_lists_routes_in_distance_order() {
+    // Four simple A-to-D routes: A-B-D (2), A-C-D (4), A-B-C-D (5), A-D (7).
+    let mut graph = UndirectedGraph::new(["A", "B", "C", "D"].iter().map(|id| node(id)).collect());
+    for (a, b, weight) in [
+        ("A", "B", 1),
+        ("B", "D", 1),
+        ("A", "C", 2),
+        ("C", "D", 2),
+        ("B", "C", 2),
+        ("A", "D", 7),
+    ] {
+        assert!(
+            graph
+                .insert_edge(&node(a), &node(b), Some(weight))
+                .is_none()
+        );
+    }
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    let paths = dijkstra
+        .k_shortest_paths("A", "D", 3)
+        .expect("search should succeed");
+    let routes: Vec<(Vec<&str>, u16)> = paths
+        .iter()
+        .map(|result| {
+            (
+                result.get_path().iter().map(|n| n.get_id()).collect(),
+                result.get_total_distance(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        routes,
+        vec![
+            (vec!["A", "B", "D"], 2),
+            (vec!["A", "C", "D"], 4),
+            (vec!["A", "B", "C", "D"], 5),
+        ]
+    );
+
+    // Asking for more than exist returns every simple path, each loopless.
+    let all = dijkstra
+        .k_shortest_paths("A", "D", 10)
+        .expect("search should succeed");
+    assert_eq!(all.len(), 5);
+    for pair in all.windows(2) {
+        assert!(pair[0].get_total_distance() <= pair[1].get_total_distance());
+    }
+    for result in &all {
+        let unique: HashSet<&str> = result.get_path().iter().map(|n| n.get_id()).collect();
+        assert_eq!(unique.len(), result.get_path().len());
+    }
+
+    assert!(dijkstra.k_shortest_paths("A", "D", 0).unwrap().is_empty());
+    assert!(dijkstra.k_shortest_paths("A", "missing", 2).is_err());
+}
+
+#[test]
+fn dijkstra_turn_restriction_forces_detour_in_directed_graph() {
+    let mut graph = DirectedGraph::default();
+
+    for id in ["A", "B", "C", "D", "E"] {
+        graph.insert_node(node(id));
+    }
+
+    let node_a = node("A");
+    let node_b = node("B");
+    let node_c = node("C");
+    let node_d = node("D");
+    let node_e = node("E");
+    assert!(graph.insert_edge(&node_a, &node_b, Some(1)).is_none());
+    assert!(graph.insert_edge(&node_b, &node_c, Some(1)).is_none());
+    assert!(graph.insert_edge(&node_b, &node_d, Some(1)).is_none());
+    assert!(graph.insert_edge(&node_d, &node_c, Some(1)).is_none());
+    assert!(graph.insert_edge(&node_a, &node_e, Some(4)).is_none());
+    assert!(graph.insert_edge(&node_e, &node_c, Some(4)).is_none());
+
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    // Without restrictions the direct turn at B is taken.
+    let unrestricted = dijkstra
+        .shortest_path_with_turn_restrictions("A", "C", &[])
+        .expect("path should exist");
+    let unrestricted_ids: Vec<&str> = unrestricted.get_path().iter().map(|n| n.get_id()).collect();
+    assert_eq!(unrestricted_ids, vec!["A", "B", "C"]);
+
+    // Forbidding A -> B -> C forces the detour through D.
+    let restrictions = vec![TurnRestriction::new("A", "B", "C")];
+    let restricted = dijkstra
+        .shortest_path_with_turn_restrictions("A", "C", &restrictions)
+        .expect("detour should exist");
+    let restricted_ids: Vec<&str> = restricted.get_path().iter().map(|n| n.get_id()).collect();
+
+    assert_eq!(restricted_ids, vec!["A", "B", "D", "C"]);
+    assert_eq!(restricted.get_total_distance(), 3);
+}
+
+#[test]
+fn dijkstra_turn_restriction_reports_error_when_every_route_is_forbidden() {
+    let mut graph = DirectedGraph::default();
+
+    for id in ["A", "B", "C"] {
+        graph.insert_node(node(id));
+    }
+
+    let node_a = node("A");
+    let node_b = node("B");
+    let node_c = node("C");
+    assert!(graph.insert_edge(&node_a, &node_b, Some(1)).is_none());
+    assert!(graph.insert_edge(&node_b, &node_c, Some(1)).is_none());
+
+    let dijkstra = DijkstraAlgorithm::new(graph);
+    let err = dijkstra
+        .shortest_path_with_turn_restrictions("A", "C", &[TurnRestriction::new("A", "B", "C")])
+        .expect_err("the only route uses a forbidden turn");
+
+    assert!(err.message.contains("turn restrictions"));
+}
+
+/// Sums every adjacency entry once; undirected graphs count each edge twice.
+fn total_adjacency_weight<G: Graph<Weight = u16>>(graph: &G) -> u16 {
+    graph
+        .get_all_nodes()
+        .iter()
+        .flat_map(|node| graph.neighbors(node).map(|(_, weight)| weight))
+        .sum()
+}
+
+#[test]
+fn dijkstra_result_converts_into_path_graph_with_matching_weight() {
+    let mut graph = DirectedGraph::default();
+
+    for id in ["A", "B", "C", "D"] {
+        graph.insert_node(node(id));
+    }
+
+    let node_a = node("A");
+    let node_b = node("B");
+    let node_c = node("C");
+    let node_d = node("D");
+    assert!(graph.insert_edge(&node_a, &node_b, Some(2)).is_none());
+    assert!(graph.insert_edge(&node_b, &node_c, Some(3)).is_none());
+    assert!(graph.insert_edge(&node_c, &node_d, Some(4)).is_none());
+    assert!(graph.insert_edge(&node_a, &node_d, Some(20)).is_none());
+
+    let result = DijkstraAlgorithm::new(graph)
+        .shortest_path("A", "D")
+        .expect("path should exist");
+    assert_eq!(result.hop_weights, vec![2, 3, 4]);
+
+    let directed = result
+        .to_directed_graph()
+        .expect("path graph should be built");
+    assert_eq!(directed.get_all_nodes().len(), 4);
+    assert_eq!(total_adjacency_weight(&directed), result.distance);
+
+    let undirected = result
+        .to_undirected_graph()
+        .expect("path graph should be built");
+    assert_eq!(total_adjacency_weight(&undirected), result.distance * 2);
+}
+
+#[test]
+fn dijkstra_result_edges_reversed_mirrors_forward_edges() {
+    let mut graph = DirectedGraph::default();
+
+    for id in ["A", "B", "C", "D"] {
+        graph.insert_node(node(id));
+    }
+
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(2)).is_none());
+    assert!(graph.insert_edge(&node("B"), &node("C"), Some(3)).is_none());
+    assert!(graph.insert_edge(&node("C"), &node("D"), Some(4)).is_none());
+
+    let result = DijkstraAlgorithm::new(graph)
+        .shortest_path("A", "D")
+        .expect("path should exist");
+
+    let forward = result.edges().expect("hop weights are attached");
+    assert_eq!(
+        forward,
+        vec![
+            (node("A"), node("B"), 2),
+            (node("B"), node("C"), 3),
+            (node("C"), node("D"), 4),
+        ]
+    );
+
+    // Same edges, same direction, just walked from the destination backward.
+    let mut reversed = result.edges_reversed().expect("hop weights are attached");
+    assert_eq!(reversed.first(), Some(&(node("C"), node("D"), 4)));
+    reversed.reverse();
+    assert_eq!(reversed, forward);
+
+    // Results built without hop weights can't produce edges.
+    let bare = DijkstraSearchResult::new(vec![node("A"), node("B")], 2u16).expect("path is valid");
+    assert!(bare.edges_reversed().is_err());
+}
+
+#[test]
+fn dijkstra_rejects_graph_with_negative_edge_weight() {
+    let mut graph = SignedDirectedGraph::new_signed(vec![]);
+    for id in ["A", "B", "C", "D"] {
+        graph.insert_node(node(id));
+    }
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(2)).is_none());
+    assert!(graph.insert_edge(&node("B"), &node("C"), Some(3)).is_none());
+    // The negative edge is not on any A -> C route; the graph is still rejected.
+    assert!(
+        graph
+            .insert_edge(&node("D"), &node("A"), Some(-4))
+            .is_none()
+    );
+
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    let err = dijkstra
+        .shortest_path("A", "C")
+        .expect_err("negative edge weights must be rejected");
+    assert!(err.message.contains("negative edge weights"));
+    assert!(err.message.contains("-4"));
+    assert!(dijkstra.distances_from("A").is_err());
+}
+
+#[test]
+fn dijkstra_accepts_signed_graph_without_negative_edges() {
+    let mut graph = SignedDirectedGraph::new_signed(vec![node("A"), node("B")]);
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(6)).is_none());
+
+    let result = DijkstraAlgorithm::new(graph)
+        .shortest_path("A", "B")
+        .expect("non-negative signed graph should be searchable");
+    assert_eq!(result.get_total_distance(), 6);
+}
+
+#[test]
+fn dijkstra_allowed_kinds_force_detour_around_disallowed_edges() {
+    let mut graph = UndirectedGraph::new(vec![node("A"), node("B"), node("C"), node("D")]);
+    let ferry = Some("ferry".to_string());
+    let highway = Some("highway".to_string());
+    assert!(
+        graph
+            .insert_edge_with_kind(&node("A"), &node("D"), Some(2), ferry)
+            .is_none()
+    );
+    assert!(
+        graph
+            .insert_edge_with_kind(&node("A"), &node("B"), Some(3), highway.clone())
+            .is_none()
+    );
+    assert!(
+        graph
+            .insert_edge_with_kind(&node("B"), &node("C"), Some(3), highway)
+            .is_none()
+    );
+    // Edges without a kind are allowed by default.
+    assert!(graph.insert_edge(&node("C"), &node("D"), Some(1)).is_none());
+
+    let unrestricted = DijkstraAlgorithm::new(graph.clone())
+        .shortest_path("A", "D")
+        .expect("ferry route should be used without restrictions");
+    assert_eq!(unrestricted.get_total_distance(), 2);
+
+    let no_ferries = HashSet::from(["highway".to_string()]);
+    let dijkstra = DijkstraAlgorithm::new(graph).with_allowed_kinds(Some(no_ferries));
+    let detour = dijkstra
+        .shortest_path("A", "D")
+        .expect("highway detour should be found");
+    let ids: Vec<&str> = detour.get_path().iter().map(|n| n.get_id()).collect();
+    assert_eq!(ids, vec!["A", "B", "C", "D"]);
+    assert_eq!(detour.get_total_distance(), 7);
+
+    // Forbidding unkinded edges as well removes the last link to D.
+    let strict = dijkstra.with_edges_without_kind_allowed(false);
+    assert!(strict.shortest_path("A", "D").is_err());
+}
+
+#[test]
+fn dijkstra_disabled_layers_force_walking_route() {
+    // Home -> Station -> Work by train is fast; walking via Park is slow.
+    let mut graph = DirectedGraph::default();
+    for id in ["Home", "Station", "Park", "Work"] {
+        graph.insert_node(node(id));
+    }
+    for (from, to, weight, layer) in [
+        ("Home", "Station", 2, "walk"),
+        ("Station", "Work", 3, "train"),
+        ("Home", "Park", 6, "walk"),
+        ("Park", "Work", 6, "walk"),
+    ] {
+        assert!(
+            graph
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+        assert!(graph.set_edge_layer(&node(from), &node(to), Some(layer.to_string())));
+    }
+
+    let by_train = DijkstraAlgorithm::new(graph.clone())
+        .shortest_path("Home", "Work")
+        .expect("train route should be used with every layer enabled");
+    assert_eq!(by_train.get_total_distance(), 5);
+
+    let dijkstra =
+        DijkstraAlgorithm::new(graph).with_disabled_layers(HashSet::from(["train".to_string()]));
+    let walking = dijkstra
+        .shortest_path("Home", "Work")
+        .expect("walking route should be found");
+    let ids: Vec<&str> = walking.get_path().iter().map(|n| n.get_id()).collect();
+    assert_eq!(ids, vec!["Home", "Park", "Work"]);
+    assert_eq!(walking.get_total_distance(), 12);
+    assert_eq!(
+        dijkstra.distances_from("Home").unwrap().get("Work"),
+        Some(&12)
+    );
+}
+
+#[test]
+fn dijkstra_filters_parallel_edges_by_their_own_kind_and_layer() {
+    // Two parallel A -> B edges: a cheap ferry and a pricier highway.
+    let mut graph = DirectedGraph::new_multigraph(vec![node("A"), node("B")]);
+    assert!(
+        graph
+            .insert_edge_with_labels(
+                &node("A"),
+                &node("B"),
+                Some(2),
+                Some("ferry".to_string()),
+                Some("boat".to_string())
+            )
+            .is_none()
+    );
+    assert!(
+        graph
+            .insert_edge_with_kind(&node("A"), &node("B"), Some(5), Some("highway".to_string()))
+            .is_none()
+    );
+    assert_eq!(graph.edge_kind(&node("A"), &node("B")), Some("ferry"));
+
+    let highways_only = DijkstraAlgorithm::new(graph.clone())
+        .with_allowed_kinds(Some(HashSet::from(["highway".to_string()])));
+    let result = highways_only
+        .shortest_path("A", "B")
+        .expect("the highway edge should be usable");
+    assert_eq!(result.get_total_distance(), 5);
+    assert_eq!(result.hop_weights, vec![5]);
+
+    let no_boats =
+        DijkstraAlgorithm::new(graph).with_disabled_layers(HashSet::from(["boat".to_string()]));
+    assert_eq!(
+        no_boats
+            .shortest_path("A", "B")
+            .unwrap()
+            .get_total_distance(),
+        5
+    );
+}
+
+#[test]
+fn dijkstra_hop_weights_skip_cheap_edges_on_disabled_layers() {
+    // The cheap ferry edges Dock -> Island are disabled, so the route must
+    // take the pricier bridge via Bridge, and every reported hop weight must
+    // come from an edge that was actually usable.
+    let mut graph = DirectedGraph::new_multigraph(vec![
+        node("Dock"),
+        node("Island"),
+        node("Bridge"),
+        node("Town"),
+    ]);
+    for (from, to, weight, layer) in [
+        ("Dock", "Island", 1, Some("ferry")),
+        ("Dock", "Island", 2, Some("ferry")),
+        ("Dock", "Bridge", 4, None),
+        ("Bridge", "Island", 4, None),
+        ("Island", "Town", 1, None),
+    ] {
+        assert!(
+            graph
+                .insert_edge_with_labels(
+                    &node(from),
+                    &node(to),
+                    Some(weight),
+                    None,
+                    layer.map(str::to_string)
+                )
+                .is_none()
+        );
+    }
+
+    let dijkstra =
+        DijkstraAlgorithm::new(graph).with_disabled_layers(HashSet::from(["ferry".to_string()]));
+    let result = dijkstra
+        .shortest_path("Dock", "Town")
+        .expect("the bridge route should be found");
+    let ids: Vec<&str> = result.get_path().iter().map(|n| n.get_id()).collect();
+    assert_eq!(ids, vec!["Dock", "Bridge", "Island", "Town"]);
+    assert_eq!(result.get_total_distance(), 9);
+    assert_eq!(result.hop_weights, vec![4, 4, 1]);
+
+    // Alternatives are priced with the same filter: there is no second
+    // route, because the only other one uses the disabled ferry.
+    let paths = dijkstra.k_shortest_paths("Dock", "Town", 3).unwrap();
+    let distances: Vec<u16> = paths.iter().map(|path| path.get_total_distance()).collect();
+    assert_eq!(distances, vec![9]);
+    assert!(
+        dijkstra
+            .second_shortest_path("Dock", "Town")
+            .unwrap()
+            .is_none()
+    );
+    for path in &paths {
+        assert_eq!(
+            path.hop_weights.iter().sum::<u16>(),
+            path.get_total_distance()
+        );
+    }
+}
+
+#[test]
+fn dijkstra_shortest_path_with_stats_counts_expansions_and_relaxations() {
+    let mut graph = DirectedGraph::default();
+    for id in ["A", "B", "C"] {
+        graph.insert_node(node(id));
+    }
+    for (from, to, weight) in [("A", "B", 1), ("B", "C", 1), ("A", "C", 5)] {
+        assert!(
+            graph
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+
+    let dijkstra = DijkstraAlgorithm::new(graph);
+    let (result, stats) = dijkstra
+        .shortest_path_with_stats("A", "C")
+        .expect("path should be found");
+    let plain = dijkstra
+        .shortest_path("A", "C")
+        .expect("path should be found");
+
+    assert_eq!(result.get_total_distance(), plain.get_total_distance());
+    assert_eq!(result.get_total_distance(), 2);
+    // Every reachable node is expanded at least once.
+    assert!(stats.nodes_expanded >= 3);
+    // A->B, A->C and the improvement B->C.
+    assert_eq!(stats.edges_relaxed, 3);
+
+    assert!(dijkstra.shortest_path_with_stats("C", "A").is_err());
+}
+
+#[test]
+fn dijkstra_stepper_settles_one_node_per_step() {
+    let mut graph = DirectedGraph::default();
+    for id in ["A", "B", "C", "D"] {
+        graph.insert_node(node(id));
+    }
+    for (from, to, weight) in [("A", "B", 1), ("A", "C", 5), ("B", "C", 2)] {
+        assert!(
+            graph
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+    let dijkstra = DijkstraAlgorithm::new(graph);
+    let mut stepper = dijkstra.begin("A").expect("A is in the graph");
+
+    let (settled, entry) = stepper.execute_step().expect("A is queued");
+    assert_eq!(settled.get_id(), "A");
+    assert_eq!(entry.get_distance(), 0);
+    assert!(entry.get_previous_node().is_none());
+    // Both neighbors of A are now tentatively known.
+    assert_eq!(stepper.get_distances()["C"].get_distance(), 5);
+
+    let (settled, _) = stepper.execute_step().expect("B is queued");
+    assert_eq!(settled.get_id(), "B");
+    // Settling B improved C through B.
+    assert_eq!(stepper.get_distances()["C"].get_distance(), 3);
+
+    let (settled, entry) = stepper.execute_step().expect("C is queued");
+    assert_eq!(settled.get_id(), "C");
+    assert_eq!(entry.get_previous_node().map(|n| n.get_id()), Some("B"));
+
+    // D is unreachable, so the search ends after three steps.
+    assert!(stepper.execute_step().is_none());
+    assert!(stepper.is_finished());
+    assert!(dijkstra.begin("Z").is_err());
+}
+
+#[test]
+fn dijkstra_follows_updated_edge_weights() {
+    let mut graph = DirectedGraph::default();
+    for id in ["A", "B", "C"] {
+        graph.insert_node(node(id));
+    }
+    for (from, to, weight) in [("A", "C", 7), ("A", "B", 2), ("B", "C", 2)] {
+        assert!(
+            graph
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+    let detour = DijkstraAlgorithm::new(graph.clone())
+        .shortest_path("A", "C")
+        .expect("C is reachable");
+    assert_eq!(detour.get_path().len(), 3);
+    assert_eq!(detour.get_total_distance(), 4);
+
+    // The direct edge gets cheaper than the detour via B.
+    assert!(graph.set_edge_weight(&node("A"), &node("C"), 2));
+    assert!(!graph.set_edge_weight(&node("C"), &node("A"), 2));
+    let direct = DijkstraAlgorithm::new(graph)
+        .shortest_path("A", "C")
+        .expect("C is reachable");
+    let ids: Vec<&str> = direct.get_path().iter().map(|n| n.get_id()).collect();
+    assert_eq!(ids, vec!["A", "C"]);
+    assert_eq!(direct.get_total_distance(), 2);
+}
+
+#[test]
+fn dijkstra_picks_cheapest_parallel_edge_in_multigraph() {
+    let mut directed = DirectedGraph::new_multigraph(vec![node("A"), node("B"), node("C")]);
+    let mut undirected = UndirectedGraph::new_multigraph(vec![node("A"), node("B"), node("C")]);
+    for (from, to, weight) in [("A", "B", 7), ("A", "B", 3), ("B", "C", 1)] {
+        assert!(
+            directed
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+        assert!(
+            undirected
+                .insert_edge(&node(to), &node(from), Some(weight))
+                .is_none()
+        );
+    }
+    assert_eq!(directed.neighbors(&node("A")).count(), 2);
+
+    let result = DijkstraAlgorithm::new(directed.clone())
+        .shortest_path("A", "C")
+        .expect("C is reachable");
+    assert_eq!(result.get_total_distance(), 4);
+    assert_eq!(result.to_string(), "A -(3)-> B -(1)-> C (total: 4)");
+    let result = DijkstraAlgorithm::new(undirected)
+        .shortest_path("C", "A")
+        .expect("A is reachable");
+    assert_eq!(result.get_total_distance(), 4);
+
+    // Removing one parallel edge leaves the other in place.
+    assert!(directed.remove_edge(&node("A"), &node("B")));
+    assert!(directed.does_edge_already_exist(&node("A"), &node("B")));
+    let result = DijkstraAlgorithm::new(directed)
+        .shortest_path("A", "C")
+        .expect("C is reachable");
+    assert_eq!(result.get_total_distance(), 4);
+
+    // Plain graphs still reject the second edge.
+    let mut plain = DirectedGraph::new(vec![node("A"), node("B")]);
+    assert!(plain.insert_edge(&node("A"), &node("B"), Some(7)).is_none());
+    assert!(plain.insert_edge(&node("A"), &node("B"), Some(3)).is_some());
+}
+
+#[test]
+fn dijkstra_farthest_node_on_path_graph() {
+    // Path graph A - B - C - D - E with increasing weights.
+    let ids = ["A", "B", "C", "D", "E"];
+    let mut graph = UndirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+    for (pair, weight) in ids.windows(2).zip([1, 2, 3, 4]) {
+        assert!(
+            graph
+                .insert_edge(&node(pair[0]), &node(pair[1]), Some(weight))
+                .is_none()
+        );
+    }
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    let (farthest, distance) = dijkstra
+        .farthest_node("A")
+        .expect("search should succeed")
+        .expect("path graph has reachable nodes");
+    assert_eq!((farthest.get_id(), distance), ("E", 10));
+
+    let (farthest, distance) = dijkstra
+        .farthest_node("D")
+        .expect("search should succeed")
+        .expect("path graph has reachable nodes");
+    assert_eq!((farthest.get_id(), distance), ("A", 6));
+}
+
+#[test]
+fn dijkstra_farthest_node_breaks_ties_by_id_and_handles_isolated_start() {
+    let mut graph = DirectedGraph::new(vec![node("S"), node("Z"), node("M"), node("X")]);
+    assert!(graph.insert_edge(&node("S"), &node("Z"), Some(5)).is_none());
+    assert!(graph.insert_edge(&node("S"), &node("M"), Some(5)).is_none());
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    let (farthest, distance) = dijkstra
+        .farthest_node("S")
+        .expect("search should succeed")
+        .expect("S reaches two nodes");
+    assert_eq!((farthest.get_id(), distance), ("M", 5));
+
+    assert!(
+        dijkstra
+            .farthest_node("X")
+            .expect("search should succeed")
+            .is_none()
+    );
+    assert!(dijkstra.farthest_node("missing").is_err());
+}
+
+#[test]
+fn dijkstra_batch_matches_individual_calls_in_input_order() {
+    let mut graph = UndirectedGraph::new(["A", "B", "C", "D", "E", "X"].map(node).to_vec());
+    for (a, b, weight) in [
+        ("A", "B", 2),
+        ("B", "C", 2),
+        ("A", "C", 5),
+        ("C", "D", 1),
+        ("D", "E", 7),
+        ("B", "E", 12),
+    ] {
+        assert!(
+            graph
+                .insert_edge(&node(a), &node(b), Some(weight))
+                .is_none()
+        );
+    }
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    let pairs = [
+        ("A", "E"),
+        ("C", "A"),
+        ("A", "D"),
+        ("A", "X"),
+        ("missing", "A"),
+        ("C", "E"),
+        ("A", "missing"),
+    ];
+    let batch = dijkstra.shortest_paths_batch(&pairs);
+    assert_eq!(batch.len(), pairs.len());
+
+    for ((start, end), batch_result) in pairs.iter().zip(&batch) {
+        match (dijkstra.shortest_path(start, end), batch_result) {
+            (Ok(single), Ok(batched)) => {
+                assert_eq!(single.get_path(), batched.get_path());
+                assert_eq!(single.get_total_distance(), batched.get_total_distance());
+            }
+            (Err(single), Err(batched)) => assert_eq!(single.message, batched.message),
+            (single, batched) => panic!(
+                "batch result for {} -> {} differs: {:?} vs {:?}",
+                start, end, single, batched
+            ),
+        }
+    }
+    assert_eq!(
+        batch[0].as_ref().expect("A reaches E").get_total_distance(),
+        12
+    );
+}
+
+#[test]
+fn dijkstra_shortest_paths_from_answers_two_targets() {
+    let mut graph = DirectedGraph::new(["A", "B", "C", "D", "E"].map(node).to_vec());
+    for (a, b, weight) in [("A", "B", 1), ("B", "D", 2), ("A", "C", 4), ("C", "E", 1)] {
+        assert!(
+            graph
+                .insert_edge(&node(a), &node(b), Some(weight))
+                .is_none()
+        );
+    }
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    let results = dijkstra.shortest_paths_from("A", &["D", "E"]);
+    let paths: Vec<(Vec<&str>, u16)> = results
+        .iter()
+        .map(|result| {
+            let result = result.as_ref().expect("both targets are reachable");
+            let ids = result.get_path().iter().map(|n| n.get_id()).collect();
+            (ids, result.get_total_distance())
+        })
+        .collect();
+    assert_eq!(
+        paths,
+        vec![(vec!["A", "B", "D"], 3), (vec!["A", "C", "E"], 5)]
+    );
+
+    // An unknown target fails on its own entry only.
+    let results = dijkstra.shortest_paths_from("A", &["missing", "D"]);
+    assert!(results[0].is_err());
+    assert!(results[1].is_ok());
+}
+
+#[test]
+fn dijkstra_same_start_and_end_returns_single_node_zero_distance_result() {
+    let mut graph = DirectedGraph::new(vec![node("A"), node("B")]);
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(4)).is_none());
+    assert!(graph.insert_edge(&node("B"), &node("A"), Some(1)).is_none());
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    let result = dijkstra.shortest_path("A", "A").expect("start equals end");
+    assert_eq!(result.get_path(), &vec![node("A")]);
+    assert_eq!(result.get_total_distance(), 0);
+    assert!(result.edges().expect("no hops").is_empty());
+    assert_eq!(result.to_string(), "A (total: 0)");
+
+    // The other entry points agree.
+    let observed = dijkstra
+        .shortest_path_with_observer("A", "A", |_| {})
+        .expect("start equals end");
+    assert_eq!(observed.get_path(), &vec![node("A")]);
+    let batched = dijkstra.shortest_paths_batch(&[("B", "B")]);
+    assert_eq!(batched[0].as_ref().unwrap().get_path(), &vec![node("B")]);
+    assert!(matches!(
+        dijkstra.try_shortest_path("A", "A"),
+        Ok(PathOutcome::Found(ref found)) if found.get_path().len() == 1
+    ));
+
+    // A missing node is still an error, even when both IDs are equal.
+    assert!(dijkstra.shortest_path("Z", "Z").is_err());
+}
+
+#[test]
+fn try_shortest_path_reports_found_paths() {
+    let mut graph = DirectedGraph::new(vec![node("A"), node("B"), node("C")]);
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(2)).is_none());
+    assert!(graph.insert_edge(&node("B"), &node("C"), Some(3)).is_none());
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    match dijkstra.try_shortest_path("A", "C") {
+        Ok(PathOutcome::Found(result)) => {
+            let expected = dijkstra.shortest_path("A", "C").expect("C is reachable");
+            assert_eq!(result.get_path(), expected.get_path());
+            assert_eq!(result.get_total_distance(), 5);
+        }
+        other => panic!("expected a found path, got {:?}", other),
+    }
+}
+
+#[test]
+fn try_shortest_path_reports_unreachable_targets_without_error() {
+    let mut graph = DirectedGraph::new(vec![node("A"), node("B"), node("Island")]);
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(2)).is_none());
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    assert!(matches!(
+        dijkstra.try_shortest_path("A", "Island"),
+        Ok(PathOutcome::Unreachable)
+    ));
+    // Edges are directed, so B cannot go back to A.
+    assert!(matches!(
+        dijkstra.try_shortest_path("B", "A"),
+        Ok(PathOutcome::Unreachable)
+    ));
+    // Missing nodes remain real errors.
+    assert!(dijkstra.try_shortest_path("A", "missing").is_err());
+    assert!(dijkstra.try_shortest_path("missing", "A").is_err());
+}
+
+#[test]
+fn distances_from_matches_individual_shortest_path_distances() {
+    let mut graph = DirectedGraph::new(["A", "B", "C", "D", "E", "X"].map(node).to_vec());
+    for (a, b, weight) in [
+        ("A", "B", 4),
+        ("A", "C", 1),
+        ("C", "B", 2),
+        ("B", "D", 5),
+        ("C", "D", 8),
+        ("D", "E", 3),
+        ("X", "A", 1),
+    ] {
+        assert!(
+            graph
+                .insert_edge(&node(a), &node(b), Some(weight))
+                .is_none()
+        );
+    }
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    let distances = dijkstra.distances_from("A").expect("A is in the graph");
+    for id in ["B", "C", "D", "E"] {
+        let single = dijkstra.shortest_path("A", id).expect("reachable from A");
+        assert_eq!(
+            distances.get(id),
+            Some(&single.get_total_distance()),
+            "{id}"
+        );
+    }
+    assert_eq!(distances.get("A"), Some(&0));
+    // Unreachable nodes are left out rather than mapped to u16::MAX.
+    assert!(dijkstra.shortest_path("A", "X").is_err());
+    assert!(!distances.contains_key("X"));
+    assert_eq!(distances.len(), 5);
+}
+
+#[test]
+fn all_pairs_dijkstra_matches_distances_from_every_node() {
+    let mut graph = DirectedGraph::default();
+    for id in ["A", "B", "C", "D"] {
+        graph.insert_node(node(id));
+    }
+    for (a, b, weight) in [("A", "B", 1), ("B", "C", 2), ("A", "C", 5), ("C", "A", 1)] {
+        assert!(
+            graph
+                .insert_edge(&node(a), &node(b), Some(weight))
+                .is_none()
+        );
+    }
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    let all_pairs = dijkstra.all_pairs_dijkstra().expect("no negative edges");
+
+    assert_eq!(all_pairs.len(), 4);
+    for id in ["A", "B", "C", "D"] {
+        assert_eq!(all_pairs[id], dijkstra.distances_from(id).unwrap());
+    }
+    assert_eq!(all_pairs["A"].get("C"), Some(&3));
+    assert_eq!(all_pairs["B"].get("A"), Some(&3));
+    assert_eq!(all_pairs["D"].len(), 1);
+}
+
+#[test]
+fn all_pairs_dijkstra_rejects_negative_edges() {
+    let mut graph = SignedDirectedGraph::new_signed(vec![node("A"), node("B")]);
+    assert!(
+        graph
+            .insert_edge(&node("A"), &node("B"), Some(-1))
+            .is_none()
+    );
+
+    assert!(DijkstraAlgorithm::new(graph).all_pairs_dijkstra().is_err());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn all_pairs_dijkstra_parallel_matches_sequential_run() {
+    let mut graph = UndirectedGraph::default();
+    let ids: Vec<String> = (0..40).map(|i| format!("N{}", i)).collect();
+    for id in &ids {
+        graph.insert_node(node(id));
+    }
+    for i in 0..ids.len() {
+        for step in [1, 7] {
+            let j = (i + step) % ids.len();
+            graph.insert_edge(
+                &node(&ids[i]),
+                &node(&ids[j]),
+                Some(((i * 31 + j * 17) % 23 + 1) as u16),
+            );
+        }
+    }
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    let sequential = dijkstra.all_pairs_dijkstra().unwrap();
+    let parallel = dijkstra.all_pairs_dijkstra_parallel().unwrap();
+
+    assert_eq!(sequential.len(), ids.len());
+    assert_eq!(parallel, sequential);
+}
+
+#[test]
+fn distances_to_matches_forward_searches_on_directed_graph() {
+    let mut graph = DirectedGraph::default();
+    for id in ["A", "B", "C", "D", "E"] {
+        graph.insert_node(node(id));
+    }
+
+    // A -> B -> D is cheaper than A -> C -> D, C reaches D cheapest via B, and
+    // E is only reachable from D, so it has no route to D.
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(1)).is_none());
+    assert!(graph.insert_edge(&node("B"), &node("D"), Some(2)).is_none());
+    assert!(graph.insert_edge(&node("A"), &node("C"), Some(1)).is_none());
+    assert!(graph.insert_edge(&node("C"), &node("D"), Some(5)).is_none());
+    assert!(graph.insert_edge(&node("D"), &node("E"), Some(4)).is_none());
+    assert!(graph.insert_edge(&node("C"), &node("B"), Some(1)).is_none());
+
+    let dijkstra = DijkstraAlgorithm::new(graph);
+    let to_d = dijkstra.distances_to("D").expect("target exists");
+
+    // Every reverse distance must equal the forward distance from that node.
+    for id in ["A", "B", "C", "D", "E"] {
+        let forward = dijkstra
+            .distances_from(id)
+            .expect("start exists")
+            .get("D")
+            .copied();
+        assert_eq!(to_d.get(id).copied(), forward, "distance from {}", id);
+    }
+    assert_eq!(to_d.get("A"), Some(&3));
+    assert_eq!(to_d.get("C"), Some(&3));
+    assert_eq!(to_d.get("E"), None);
+
+    assert!(dijkstra.distances_to("missing").is_err());
+}
+
+#[test]
+fn distances_to_equals_distances_from_on_undirected_graph() {
+    let nodes = vec![node("A"), node("B"), node("C")];
+    let mut graph = UndirectedGraph::new(nodes);
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(2)).is_none());
+    assert!(graph.insert_edge(&node("B"), &node("C"), Some(3)).is_none());
+
+    let dijkstra = DijkstraAlgorithm::new(graph);
+    assert_eq!(
+        dijkstra.distances_to("C").expect("target exists"),
+        dijkstra.distances_from("C").expect("start exists")
+    );
+}
+
+/// Minimal undirected multigraph: every inserted edge is kept, including
+/// parallel edges between the same two nodes.
+struct UndirectedMultigraph {
+    nodes: Vec<DefaultNode>,
+    edges: Vec<(DefaultNode, DefaultNode, u16)>,
+}
+
+impl std::fmt::Display for UndirectedMultigraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "multigraph with {} edges", self.edges.len())
+    }
+}
+
+impl Graph for UndirectedMultigraph {
+    type Node = DefaultNode;
+    type Weight = u16;
+    type InsertionError = std::fmt::Error;
+
+    fn neighbors<'a>(
+        &'a self,
+        u: &DefaultNode,
+    ) -> Box<dyn Iterator<Item = (&'a DefaultNode, u16)> + 'a> {
+        let id = u.get_id().to_string();
+        Box::new(self.edges.iter().filter_map(move |(a, b, weight)| {
+            if a.get_id() == id {
+                Some((b, *weight))
+            } else if b.get_id() == id {
+                Some((a, *weight))
+            } else {
+                None
+            }
+        }))
+    }
+
+    fn is_directed(&self) -> bool {
+        false
+    }
+
+    fn insert_node(&mut self, new_node: DefaultNode) {
+        self.nodes.push(new_node);
+    }
+
+    fn insert_edge(
+        &mut self,
+        from: &DefaultNode,
+        to: &DefaultNode,
+        weight: Option<u16>,
+    ) -> Option<std::fmt::Error> {
+        self.edges
+            .push((from.clone(), to.clone(), weight.unwrap_or(1)));
+        None
+    }
+
+    fn does_edge_already_exist(&self, from: &DefaultNode, to: &DefaultNode) -> bool {
+        self.neighbors(from).any(|(neighbor, _)| neighbor == to)
+    }
+
+    fn does_node_already_exist(&self, node: &DefaultNode) -> bool {
+        self.nodes.contains(node)
+    }
+
+    fn get_node_by_id(&self, id: &str) -> Option<&DefaultNode> {
+        self.nodes.iter().find(|node| node.get_id() == id)
+    }
+
+    fn get_all_nodes(&self) -> &Vec<DefaultNode> {
+        &self.nodes
+    }
+
+    fn is_weighted(&self) -> bool {
+        true
+    }
+
+    fn abbreviation() -> String {
+        "MUN".to_string()
+    }
+}
+
+#[test]
+fn dijkstra_uses_cheaper_of_two_parallel_edges() {
+    let mut graph = UndirectedMultigraph {
+        nodes: vec![node("A"), node("B"), node("C")],
+        edges: Vec::new(),
+    };
+    // Two parallel A-B edges; the cheaper one is inserted second.
+    graph.insert_edge(&node("A"), &node("B"), Some(9));
+    graph.insert_edge(&node("B"), &node("A"), Some(2));
+    graph.insert_edge(&node("B"), &node("C"), Some(1));
+
+    // Inspection keeps both parallel edges.
+    assert_eq!(graph.neighbors(&node("A")).count(), 2);
+    assert_eq!(graph.edges_between("A", "B").len(), 2);
+
+    let result = DijkstraAlgorithm::new(graph)
+        .shortest_path("A", "C")
+        .expect("path should exist");
+    assert_eq!(result.get_total_distance(), 3);
+    assert_eq!(result.hop_weights, vec![2, 1]);
+}
+
+#[test]
+fn node_bonus_steers_route_through_favored_hub() {
+    let mut graph = UndirectedGraph::new(vec![node("A"), node("B"), node("Hub"), node("D")]);
+    // Direct route A-B-D costs 6, the hub route A-Hub-D costs 8.
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(3)).is_none());
+    assert!(graph.insert_edge(&node("B"), &node("D"), Some(3)).is_none());
+    assert!(
+        graph
+            .insert_edge(&node("A"), &node("Hub"), Some(4))
+            .is_none()
+    );
+    assert!(
+        graph
+            .insert_edge(&node("Hub"), &node("D"), Some(4))
+            .is_none()
+    );
+
+    let dijkstra = DijkstraAlgorithm::new(graph);
+    let path_ids = |result: &DijkstraSearchResult<DefaultNode, u16>| -> Vec<String> {
+        result
+            .get_path()
+            .iter()
+            .map(|n| n.get_id().to_string())
+            .collect()
+    };
+
+    let unbiased = dijkstra
+        .shortest_path_with_node_bonus("A", "D", &HashMap::new())
+        .expect("path should exist");
+    assert_eq!(path_ids(&unbiased), vec!["A", "B", "D"]);
+
+    // A bonus of 2 makes both hub edges cost 2, so the hub route wins, but the
+    // reported distance is still the true sum of 8. The bonus for the unknown
+    // node is ignored.
+    let node_bonus = HashMap::from([("Hub".to_string(), 2), ("Ghost".to_string(), 50)]);
+    let biased = dijkstra
+        .shortest_path_with_node_bonus("A", "D", &node_bonus)
+        .expect("path should exist");
+    assert_eq!(path_ids(&biased), vec!["A", "Hub", "D"]);
+    assert_eq!(biased.get_total_distance(), 8);
+    assert_eq!(biased.hop_weights, vec![4, 4]);
+
+    // A bonus larger than the weight clamps the edge at zero instead of wrapping.
+    let node_bonus = HashMap::from([("Hub".to_string(), u16::MAX)]);
+    let clamped = dijkstra
+        .shortest_path_with_node_bonus("A", "D", &node_bonus)
+        .expect("path should exist");
+    assert_eq!(clamped.get_total_distance(), 8);
+}
+
+#[test]
+fn node_bonus_saturates_long_detours_instead_of_overflowing() {
+    // A-B-D sums to 80000, past `u16::MAX`; A-C-D sums to 60000.
+    let mut graph = DirectedGraph::new(vec![node("A"), node("B"), node("C"), node("D")]);
+    for (from, to, weight) in [
+        ("A", "B", 40000),
+        ("B", "D", 40000),
+        ("A", "C", 30000),
+        ("C", "D", 30000),
+    ] {
+        assert!(
+            graph
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+
+    let dijkstra = DijkstraAlgorithm::new(graph.clone());
+    let result = dijkstra
+        .shortest_path_with_node_bonus("A", "D", &HashMap::new())
+        .expect("the route via C fits into u16");
+    let ids: Vec<&str> = result.get_path().iter().map(|n| n.get_id()).collect();
+    assert_eq!(ids, vec!["A", "C", "D"]);
+    assert_eq!(result.get_total_distance(), 60000);
+
+    // Without the route via C, the only path is too long to report.
+    assert!(graph.remove_edge(&node("A"), &node("C")));
+    let err = DijkstraAlgorithm::new(graph)
+        .shortest_path_with_node_bonus("A", "D", &HashMap::new())
+        .unwrap_err();
+    assert!(err.to_string().contains("exceeds 65535"));
+}
+
+#[test]
+fn radius_and_center_of_path_graphs() {
+    let path_graph = |ids: &[&str]| {
+        let mut graph = UndirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+        for pair in ids.windows(2) {
+            assert!(
+                graph
+                    .insert_edge(&node(pair[0]), &node(pair[1]), Some(2))
+                    .is_none()
+            );
+        }
+        graph
+    };
+    let center_ids = |dijkstra: &DijkstraAlgorithm<_, _, UndirectedGraph>| -> Vec<String> {
+        dijkstra
+            .center()
+            .unwrap()
+            .iter()
+            .map(|n: &DefaultNode| n.get_id().to_string())
+            .collect()
+    };
+
+    // Odd path A - B - C - D - E: C is two hops from both ends.
+    let odd = DijkstraAlgorithm::new(path_graph(&["A", "B", "C", "D", "E"]));
+    assert_eq!(odd.radius().unwrap(), Some(4));
+    assert_eq!(center_ids(&odd), vec!["C"]);
+
+    // Even path: both middle nodes share the radius.
+    let even = DijkstraAlgorithm::new(path_graph(&["A", "B", "C", "D"]));
+    assert_eq!(even.radius().unwrap(), Some(4));
+    assert_eq!(center_ids(&even), vec!["B", "C"]);
+
+    // A node that reaches nothing makes the graph disconnected.
+    let mut disconnected = path_graph(&["A", "B", "C"]);
+    disconnected.insert_node(node("Island"));
+    let disconnected = DijkstraAlgorithm::new(disconnected);
+    assert_eq!(disconnected.radius().unwrap(), None);
+    assert!(disconnected.center().unwrap().is_empty());
+}
+
+#[test]
+fn count_shortest_paths_matches_binomial_coefficient_on_grid() {
+    // A 5x4 grid of unit edges: every shortest corner-to-corner route takes
+    // 4 steps right and 3 steps down, so there are C(7, 3) = 35 of them.
+    let (columns, rows) = (5, 4);
+    let id = |x: usize, y: usize| format!("N{}x{}", x, y);
+    let mut graph = UndirectedGraph::default();
+    for y in 0..rows {
+        for x in 0..columns {
+            graph.insert_node(node(&id(x, y)));
+        }
+    }
+    for y in 0..rows {
+        for x in 0..columns {
+            if x + 1 < columns {
+                assert!(
+                    graph
+                        .insert_edge(&node(&id(x, y)), &node(&id(x + 1, y)), Some(1))
+                        .is_none()
+                );
+            }
+            if y + 1 < rows {
+                assert!(
+                    graph
+                        .insert_edge(&node(&id(x, y)), &node(&id(x, y + 1)), Some(1))
+                        .is_none()
+                );
+            }
+        }
+    }
+    graph.insert_node(node("Island"));
+
+    let dijkstra = DijkstraAlgorithm::new(graph);
+    assert_eq!(dijkstra.count_shortest_paths("N0x0", "N4x3").unwrap(), 35);
+    assert_eq!(dijkstra.count_shortest_paths("N0x0", "N2x0").unwrap(), 1);
+    assert_eq!(dijkstra.count_shortest_paths("N0x0", "Island").unwrap(), 0);
+    assert!(dijkstra.count_shortest_paths("N0x0", "Ghost").is_err());
+}
+
+#[test]
+fn count_shortest_paths_saturates_on_overflow() {
+    // 70 diamonds in a row double the path count 70 times: 2^70 > u64::MAX.
+    let mut graph = DirectedGraph::default();
+    graph.insert_node(node("J0"));
+    for i in 0..70 {
+        let (from, to) = (node(&format!("J{}", i)), node(&format!("J{}", i + 1)));
+        let (upper, lower) = (node(&format!("U{}", i)), node(&format!("L{}", i)));
+        for n in [&to, &upper, &lower] {
+            graph.insert_node(n.clone());
+        }
+        for (a, b) in [
+            (&from, &upper),
+            (&from, &lower),
+            (&upper, &to),
+            (&lower, &to),
+        ] {
+            assert!(graph.insert_edge(a, b, Some(1)).is_none());
+        }
+    }
+
+    let dijkstra = DijkstraAlgorithm::new(graph);
+    assert_eq!(dijkstra.count_shortest_paths("J0", "J3").unwrap(), 8);
+    assert_eq!(
+        dijkstra.count_shortest_paths("J0", "J70").unwrap(),
+        u64::MAX
+    );
+    // Edges only point forward along the chain.
+    assert_eq!(dijkstra.count_shortest_paths("J70", "J0").unwrap(), 0);
+}
+
+#[test]
+fn count_shortest_paths_counts_ties_over_zero_weight_edges() {
+    // Each stage offers S -> B and S -> A -> B, where A -> B is free, so both
+    // routes tie. A and B share a distance, so B may be settled before the
+    // free edge into it is looked at.
+    let mut graph = DirectedGraph::default();
+    graph.insert_node(node("S0"));
+    for i in 0..3 {
+        let (from, to) = (node(&format!("S{}", i)), node(&format!("S{}", i + 1)));
+        let (a, b) = (node(&format!("A{}", i)), node(&format!("B{}", i)));
+        for n in [&to, &a, &b] {
+            graph.insert_node(n.clone());
+        }
+        for (x, y, weight) in [(&from, &b, 1), (&from, &a, 1), (&a, &b, 0), (&b, &to, 1)] {
+            assert!(graph.insert_edge(x, y, Some(weight)).is_none());
+        }
+    }
+
+    let dijkstra = DijkstraAlgorithm::new(graph.clone());
+    assert_eq!(dijkstra.count_shortest_paths("S0", "B0").unwrap(), 2);
+    assert_eq!(dijkstra.count_shortest_paths("S0", "S3").unwrap(), 8);
+
+    // A free edge back from B0 to A0 closes a zero-weight cycle.
+    assert!(
+        graph
+            .insert_edge(&node("B0"), &node("A0"), Some(0))
+            .is_none()
+    );
+    let err = DijkstraAlgorithm::new(graph)
+        .count_shortest_paths("S0", "S3")
+        .unwrap_err();
+    assert!(err.to_string().contains("cycle of zero-weight edges"));
+}
+
+#[test]
+fn all_shortest_paths_returns_every_tied_route() {
+    // Diamond A-B-D / A-C-D with equal weights, plus a longer A-E-D detour.
+    let mut graph = UndirectedGraph::default();
+    for id in ["A", "B", "C", "D", "E", "Island"] {
+        graph.insert_node(node(id));
+    }
+    for (from, to, weight) in [
+        ("A", "B", 2),
+        ("A", "C", 1),
+        ("B", "D", 1),
+        ("C", "D", 2),
+        ("A", "E", 1),
+        ("E", "D", 5),
+    ] {
+        assert!(
+            graph
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    let results = dijkstra
+        .all_shortest_paths("A", "D")
+        .expect("D is reachable");
+    let paths: Vec<Vec<&str>> = results
+        .iter()
+        .map(|result| result.get_path().iter().map(|n| n.get_id()).collect())
+        .collect();
+    assert_eq!(paths, vec![vec!["A", "B", "D"], vec!["A", "C", "D"]]);
+    assert!(
+        results
+            .iter()
+            .all(|result| result.get_total_distance() == 3)
+    );
+    assert_eq!(
+        results.len() as u64,
+        dijkstra.count_shortest_paths("A", "D").unwrap()
+    );
+
+    // Without a tie there is exactly the one path `shortest_path` returns.
+    let single = dijkstra.all_shortest_paths("A", "E").unwrap();
+    assert_eq!(single.len(), 1);
+    assert_eq!(single[0].get_total_distance(), 1);
+
+    // A node reaches itself by the one-node path, like `shortest_path`.
+    let itself = dijkstra.all_shortest_paths("A", "A").unwrap();
+    assert_eq!(itself.len(), 1);
+    let ids: Vec<&str> = itself[0].get_path().iter().map(|n| n.get_id()).collect();
+    assert_eq!(ids, vec!["A"]);
+    assert_eq!(itself[0].get_total_distance(), 0);
+
+    assert!(dijkstra.all_shortest_paths("A", "Island").is_err());
+    assert!(dijkstra.all_shortest_paths("A", "Ghost").is_err());
+}
+
+#[test]
+fn all_shortest_paths_includes_routes_over_zero_weight_edges() {
+    // S -> B -> T ties with S -> A -> B -> T because A -> B is free.
+    let mut graph = DirectedGraph::default();
+    for id in ["S", "A", "B", "T"] {
+        graph.insert_node(node(id));
+    }
+    for (from, to, weight) in [("S", "B", 1), ("S", "A", 1), ("A", "B", 0), ("B", "T", 1)] {
+        assert!(
+            graph
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+    let dijkstra = DijkstraAlgorithm::new(graph.clone());
+
+    let results = dijkstra
+        .all_shortest_paths("S", "T")
+        .expect("T is reachable");
+    let paths: Vec<Vec<&str>> = results
+        .iter()
+        .map(|result| result.get_path().iter().map(|n| n.get_id()).collect())
+        .collect();
+    assert_eq!(paths, vec![vec!["S", "A", "B", "T"], vec!["S", "B", "T"]]);
+    assert!(
+        results
+            .iter()
+            .all(|result| result.get_total_distance() == 2)
+    );
+    assert_eq!(results[0].hop_weights, vec![1, 0, 1]);
+
+    // A free edge back from B to A would allow endless detours.
+    assert!(graph.insert_edge(&node("B"), &node("A"), Some(0)).is_none());
+    assert!(
+        DijkstraAlgorithm::new(graph)
+            .all_shortest_paths("S", "T")
+            .is_err()
+    );
+}
+
+#[test]
+fn minimax_path_minimizes_heaviest_edge() {
+    // S -> T directly costs 9. S -> A -> T sums to 8 but crosses a 7.
+    // S -> B -> C -> T sums to 15 yet never exceeds 5, so it wins.
+    let mut graph = DirectedGraph::default();
+    for id in ["S", "A", "B", "C", "T", "Island"] {
+        graph.insert_node(node(id));
+    }
+    for (from, to, weight) in [
+        ("S", "T", 9),
+        ("S", "A", 1),
+        ("A", "T", 7),
+        ("S", "B", 5),
+        ("B", "C", 5),
+        ("C", "T", 5),
+    ] {
+        assert!(
+            graph
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+
+    let dijkstra = DijkstraAlgorithm::new(graph);
+    let result = dijkstra.minimax_path("S", "T").unwrap();
+    let ids: Vec<&str> = result.path.iter().map(|n| n.get_id()).collect();
+    assert_eq!(ids, vec!["S", "B", "C", "T"]);
+    assert_eq!(result.distance, 5);
+    assert_eq!(result.hop_weights, vec![5, 5, 5]);
+
+    // The ordinary shortest path takes the other route.
+    assert_eq!(dijkstra.shortest_path("S", "T").unwrap().distance, 8);
+
+    let itself = dijkstra.minimax_path("S", "S").unwrap();
+    let ids: Vec<&str> = itself.path.iter().map(|n| n.get_id()).collect();
+    assert_eq!(ids, vec!["S"]);
+    assert_eq!(itself.distance, 0);
+    assert!(itself.hop_weights.is_empty());
+    assert!(dijkstra.minimax_path("S", "Island").is_err());
+    assert!(dijkstra.minimax_path("T", "S").is_err());
+    assert!(dijkstra.minimax_path("S", "Ghost").is_err());
+}
+
+#[test]
+fn observer_narrates_search_in_deterministic_order() {
+    let mut graph = DirectedGraph::default();
+    for id in ["A", "B", "C", "D"] {
+        graph.insert_node(node(id));
+    }
+    for (from, to, weight) in [("A", "B", 7), ("A", "C", 15), ("B", "C", 3), ("A", "D", 7)] {
+        assert!(
+            graph
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    let narrate = || {
+        let mut narration = Vec::new();
+        let result = dijkstra
+            .shortest_path_with_observer("A", "C", |event| narration.push(event.to_string()))
+            .expect("C is reachable");
+        (result, narration)
+    };
+    let (result, narration) = narrate();
+
+    assert_eq!(result.get_total_distance(), 10);
+    assert_eq!(
+        narration,
+        vec![
+            "Settled A (0).",
+            "Relaxed B to 7 via A.",
+            "Relaxed C to 15 via A.",
+            "Relaxed D to 7 via A.",
+            // B and D tie at 7; the smaller ID is settled first.
+            "Settled B (7).",
+            "Relaxed C to 10 via B (improved).",
+            "Settled D (7).",
+            "Settled C (10).",
+        ]
+    );
+    assert_eq!(narrate().1, narration);
+
+    let err = dijkstra
+        .shortest_path_with_observer("C", "A", |_| {})
+        .expect_err("edges only point away from A");
+    assert!(err.to_string().contains("could not be found"), "{}", err);
+}
+
+#[test]
+fn dijkstra_uses_direction_specific_weights_of_two_way_edges() {
+    // A hill road: cheap downhill from Top to Valley, expensive back up.
+    let (top, valley, tunnel) = (node("Top"), node("Valley"), node("Tunnel"));
+    let mut graph = UndirectedGraph::new(vec![top.clone(), valley.clone(), tunnel.clone()]);
+    assert!(
+        graph
+            .insert_two_way_edge(&top, &valley, 2, 10, None)
+            .is_none()
+    );
+    assert!(graph.insert_edge(&top, &tunnel, Some(3)).is_none());
+    assert!(graph.insert_edge(&tunnel, &valley, Some(3)).is_none());
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    let down = dijkstra.shortest_path("Top", "Valley").unwrap();
+    assert_eq!(down.get_total_distance(), 2);
+    assert_eq!(down.hop_weights, vec![2]);
+
+    // Uphill the direct road costs 10, so the tunnel (3 + 3) wins.
+    let up = dijkstra.shortest_path("Valley", "Top").unwrap();
+    assert_eq!(up.get_total_distance(), 6);
+    let ids: Vec<&str> = up.get_path().iter().map(|n| n.get_id()).collect();
+    assert_eq!(ids, vec!["Valley", "Tunnel", "Top"]);
+}
+
+#[test]
+fn relabeled_graph_paths_correspond_through_the_mapping() {
+    let mut graph = UndirectedGraph::new(vec![
+        node("Kiel"),
+        node("Hamburg"),
+        node("Bremen"),
+        node("Hannover"),
+    ]);
+    for (a, b, weight) in [
+        ("Kiel", "Hamburg", 9),
+        ("Hamburg", "Bremen", 12),
+        ("Hamburg", "Hannover", 15),
+        ("Bremen", "Hannover", 13),
+    ] {
+        assert!(
+            graph
+                .insert_edge(&node(a), &node(b), Some(weight))
+                .is_none()
+        );
+    }
+
+    let (relabeled, index_by_id) = graph.relabel_to_integers();
+    let ids: Vec<&str> = relabeled
+        .get_all_nodes()
+        .iter()
+        .map(|node| node.get_id())
+        .collect();
+    assert_eq!(ids, vec!["0", "1", "2", "3"]);
+    assert_eq!(index_by_id.len(), 4);
+    assert_eq!(relabeled.to_edge_list().len(), graph.to_edge_list().len());
+
+    let original = DijkstraAlgorithm::new(graph.clone())
+        .shortest_path("Kiel", "Hannover")
+        .unwrap();
+    let relabeled_result = DijkstraAlgorithm::new(relabeled)
+        .shortest_path(
+            &index_by_id["Kiel"].to_string(),
+            &index_by_id["Hannover"].to_string(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        relabeled_result.get_total_distance(),
+        original.get_total_distance()
+    );
+    let mapped_back: Vec<&str> = relabeled_result
+        .get_path()
+        .iter()
+        .map(|node| {
+            let index: usize = node.get_id().parse().unwrap();
+            graph.get_all_nodes()[index].get_id()
+        })
+        .collect();
+    let original_ids: Vec<&str> = original
+        .get_path()
+        .iter()
+        .map(|node| node.get_id())
+        .collect();
+    assert_eq!(mapped_back, original_ids);
+}
+
+#[test]
+fn spt_edges_has_one_edge_per_reachable_node_except_the_root() {
+    let mut graph = DirectedGraph::new(
+        ["A", "B", "C", "D", "E", "Island"]
+            .iter()
+            .map(|id| node(id))
+            .collect(),
+    );
+    for (from, to, weight) in [
+        ("A", "B", 4),
+        ("A", "C", 1),
+        ("C", "B", 2),
+        ("B", "D", 5),
+        ("C", "D", 8),
+        ("D", "E", 1),
+        ("Island", "A", 1),
+    ] {
+        assert!(
+            graph
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+    let dijkstra = DijkstraAlgorithm::new(graph);
+
+    let tree: Vec<(String, String, u16)> = dijkstra
+        .spt_edges("A")
+        .unwrap()
+        .into_iter()
+        .map(|(from, to, weight)| (from.get_id().to_string(), to.get_id().to_string(), weight))
+        .collect();
+    assert_eq!(
+        tree,
+        vec![
+            ("C".to_string(), "B".to_string(), 2),
+            ("A".to_string(), "C".to_string(), 1),
+            ("B".to_string(), "D".to_string(), 5),
+            ("D".to_string(), "E".to_string(), 1),
+        ]
+    );
+    let reachable = dijkstra.distances_from("A").unwrap().len();
+    assert_eq!(tree.len(), reachable - 1);
+
+    // A root without outgoing edges has an empty tree.
+    assert!(dijkstra.spt_edges("E").unwrap().is_empty());
+    assert!(dijkstra.spt_edges("Missing").is_err());
+}
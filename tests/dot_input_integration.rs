@@ -0,0 +1,90 @@
+//! Integration tests for parsing Graphviz DOT text into graphs.
+//!
+//! These tests round-trip graphs through `Graph::to_dot` and cover the
+//! defaults and errors of the supported DOT subset.
+
+use shortest_path_finder::{
+    data_input::{dot_input::graph_from_dot, file_input::FileInputError},
+    graphs::{directed::DirectedGraph, graph::Graph, undirected::UndirectedGraph},
+    nodes::default_node::DefaultNode,
+};
+
+fn node(id: &str) -> DefaultNode {
+    DefaultNode::new(id.to_string())
+}
+
+/// Parses `content` and returns the parse error message.
+fn dot_error(content: &str) -> String {
+    let err = graph_from_dot(content).expect_err("the DOT text should be rejected");
+    assert!(matches!(err, FileInputError::Parse(_)), "{}", err);
+    err.to_string()
+}
+
+#[test]
+fn directed_graph_round_trips_through_dot() {
+    let edges: Vec<(String, String, u16)> = vec![
+        ("A".to_string(), "B".to_string(), 7),
+        ("B".to_string(), "C".to_string(), 2),
+        ("C".to_string(), "A".to_string(), 0),
+        ("New \"York\"".to_string(), "A".to_string(), 65535),
+    ];
+    let mut original = DirectedGraph::from_edges(&edges).unwrap();
+    // An isolated node is exported as a lone node statement.
+    original.insert_node(node("Lonely"));
+
+    let parsed = graph_from_dot(&original.to_dot()).expect("exported DOT should parse");
+    let reimported = parsed.directed_graph.expect("a digraph is directed");
+
+    assert_eq!(reimported.to_edge_list(), original.to_edge_list());
+    assert!(reimported.get_node_by_id("Lonely").is_some());
+    assert_eq!(
+        reimported.get_all_nodes().len(),
+        original.get_all_nodes().len()
+    );
+}
+
+#[test]
+fn undirected_graph_round_trips_through_dot() {
+    let mut original = UndirectedGraph::default();
+    for id in ["A", "B", "C"] {
+        original.insert_node(node(id));
+    }
+    original.insert_edge(&node("A"), &node("B"), Some(4));
+    original.insert_edge(&node("B"), &node("C"), Some(9));
+
+    let parsed = graph_from_dot(&original.to_dot()).expect("exported DOT should parse");
+    let reimported = parsed.undirected_graph.expect("a graph is undirected");
+
+    assert!(parsed.directed_graph.is_none());
+    assert_eq!(reimported.all_edges().len(), 2);
+    assert_eq!(reimported.to_edge_list(), original.to_edge_list());
+}
+
+#[test]
+fn missing_labels_default_to_weight_one_and_other_attributes_are_ignored() {
+    let parsed = graph_from_dot(
+        "strict digraph roads {\n  node [shape=circle];\n  A -> B\n  B -> C [color=red, label=\"5\"]\n}\n",
+    )
+    .expect("DOT with attributes should parse");
+    let graph = parsed.directed_graph.unwrap();
+
+    assert_eq!(
+        graph.to_edge_list(),
+        vec![
+            ("A".to_string(), "B".to_string(), 1),
+            ("B".to_string(), "C".to_string(), 5),
+        ]
+    );
+    // `node [...]` sets defaults and must not create a node called "node".
+    assert!(graph.get_node_by_id("node").is_none());
+}
+
+#[test]
+fn dot_errors_name_the_offending_line() {
+    assert!(dot_error("digraph {\n  A -> B;\n  B -- C;\n}").contains("DOT line 3"));
+    assert!(dot_error("graph {\n  A -> B;\n}").contains("'->' is not allowed in a graph"));
+    assert!(dot_error("digraph {\n  A -> B [label=\"heavy\"];\n}").contains("'heavy'"));
+    assert!(dot_error("digraph {\n  A -> B -> C;\n}").contains("edge chains"));
+    assert!(dot_error("A -> B").contains("must start with"));
+    assert!(dot_error("digraph {\n  A -> B;\n").contains("closing '}'"));
+}
@@ -0,0 +1,81 @@
+//! Integration tests for the unified [`PathfinderError`] type.
+//!
+//! These tests check that errors from different modules can be propagated
+//! through one `Result<_, PathfinderError>` with `?` while keeping the
+//! specific error details reachable.
+
+use std::error::Error;
+
+use shortest_path_finder::{
+    algorithms::{
+        algorithm::{Algorithm, SearchResult},
+        dijkstra::DijkstraAlgorithm,
+    },
+    cmd_line::app_config::AppConfig,
+    data_input::file_input::{FileInputError, retrieve_graph_data_from_file},
+    error::{config_error::ConfigParseError, pathfinder_error::PathfinderError},
+};
+
+/// Mirrors the CLI flow: parse arguments, load the graph, and search it.
+fn shortest_distance(args: &[&str]) -> Result<u16, PathfinderError> {
+    let args = args.iter().map(|arg| arg.to_string()).collect();
+    let config = AppConfig::setup_config(args)?;
+    let graphs = retrieve_graph_data_from_file(&config.file_path)?;
+    let graph = graphs
+        .directed_graph
+        .ok_or_else(|| PathfinderError::Unsupported("expected a directed graph".to_string()))?;
+    let result =
+        DijkstraAlgorithm::new(graph).shortest_path(&config.start_node_id, &config.end_node_id)?;
+    Ok(result.get_total_distance())
+}
+
+#[test]
+fn question_mark_propagates_errors_from_every_stage() {
+    // Missing '--end' fails during configuration parsing.
+    let config_err = shortest_distance(&["pathfinder", "--start", "A"]).unwrap_err();
+    assert!(matches!(config_err, PathfinderError::Config(_)));
+
+    // A missing file fails while loading the graph.
+    let file_err = shortest_distance(&[
+        "pathfinder",
+        "--graph-file",
+        "test_files/does_not_exist.txt",
+        "--start",
+        "A",
+        "--end",
+        "B",
+    ])
+    .unwrap_err();
+    assert!(matches!(
+        file_err,
+        PathfinderError::FileInput(FileInputError::Io { .. })
+    ));
+
+    // An unknown node fails inside the algorithm.
+    let dijkstra_err = shortest_distance(&[
+        "pathfinder",
+        "--graph-file",
+        "test_files/directed_graph.txt",
+        "--start",
+        "A",
+        "--end",
+        "UNKNOWN",
+    ])
+    .unwrap_err();
+    assert!(matches!(dijkstra_err, PathfinderError::Dijkstra(_)));
+}
+
+#[test]
+fn wrapped_error_keeps_message_and_source() {
+    let specific = ConfigParseError::MissingRequiredFlag { flag: "--start" };
+    let unified = PathfinderError::from(specific.clone());
+
+    // Display and source both expose the original error unchanged.
+    assert_eq!(unified.to_string(), specific.to_string());
+    let source = unified.source().expect("wrapped errors expose a source");
+    assert_eq!(source.to_string(), specific.to_string());
+    assert!(matches!(
+        unified,
+        PathfinderError::Config(ConfigParseError::MissingRequiredFlag { flag: "--start" })
+    ));
+}
@@ -4,10 +4,15 @@
 //! successful loading for all supported graph headers and representative
 //! malformed input cases.
 
-use std::io::Write;
+use std::{io::Write, num::NonZeroUsize};
 
 use shortest_path_finder::{
-    data_input::file_input::retrieve_graph_data_from_file, graphs::graph::Graph,
+    data_input::file_input::{
+        FileInputError, FileInputOptions, MAX_COLLECTED_LINE_ERRORS, MissingWeightPolicy,
+        STDIN_PATH, retrieve_graph_data_from_file, retrieve_graph_data_from_file_with_options,
+        retrieve_graph_data_from_reader,
+    },
+    graphs::graph::{Graph, GraphNode},
 };
 use tempfile::NamedTempFile;
 
@@ -49,6 +54,35 @@ fn parser_reads_directed_graph_from_file() {
     assert_eq!(count_directed_edges(&graph), 3);
 }
 
+#[test]
+fn reader_input_matches_file_input() {
+    let contents = "D\nA->B:4\nB->C:2\nA->C:10\n";
+    let file = write_temp_graph(contents);
+    let path = file.path().to_string_lossy().into_owned();
+
+    // Piped text goes through the same parser as a file.
+    let from_file = retrieve_graph_data_from_file(&path).expect("file parsing should succeed");
+    let from_reader =
+        retrieve_graph_data_from_reader(contents.as_bytes(), &FileInputOptions::default())
+            .expect("reader parsing should succeed");
+    assert_eq!(
+        from_reader
+            .directed_graph
+            .expect("directed graph must exist")
+            .to_edge_list(),
+        from_file
+            .directed_graph
+            .expect("directed graph must exist")
+            .to_edge_list()
+    );
+
+    // Empty input is reported like an empty file.
+    let err = retrieve_graph_data_from_reader("".as_bytes(), &FileInputOptions::default())
+        .expect_err("empty input should fail");
+    assert!(matches!(err, FileInputError::Parse(_)));
+    assert_eq!(STDIN_PATH, "-");
+}
+
 #[test]
 fn parser_reads_undirected_graph_from_file() {
     let file = write_temp_graph("UN\nA-B:7\nB-C:3\nA-C:9\n");
@@ -97,7 +131,22 @@ fn parser_rejects_unknown_graph_header() {
         Err(err) => err,
     };
 
-    assert!(err.to_string().contains("Invalid graph header"));
+    assert!(err.to_string().contains("Invalid graph header at line 1"));
+}
+
+#[test]
+fn parser_reports_line_number_of_bad_line_in_longer_file() {
+    let file = write_temp_graph("UN\nA-B:4\nA=B\nB-C:2\nC-D:1\n");
+    let path = file.path().to_string_lossy().into_owned();
+
+    let err = match retrieve_graph_data_from_file(&path) {
+        Ok(_) => panic!("the bad third line should fail"),
+        Err(err) => err,
+    };
+
+    let message = err.to_string();
+    assert!(message.contains("line 3"));
+    assert!(message.contains("'A=B'"));
 }
 
 #[test]
@@ -141,6 +190,75 @@ fn parser_ignores_whitespace_only_lines() {
     assert_eq!(count_directed_edges(&graph), 2);
 }
 
+#[test]
+fn parser_skips_comments_and_indentation() {
+    let file = write_temp_graph(
+        "D # road network\n# cities\n  A->B:7 # main road\n\tB->C:2#train\n   # closed: C->A:1\n",
+    );
+    let path = file.path().to_string_lossy().into_owned();
+
+    let result = retrieve_graph_data_from_file(&path).expect("comments should be skipped");
+
+    let graph = result.directed_graph.expect("directed graph must exist");
+    assert_eq!(
+        graph.to_edge_list(),
+        vec![
+            ("A".to_string(), "B".to_string(), 7),
+            ("B".to_string(), "C".to_string(), 2),
+        ]
+    );
+    // A `#` glued to the edge is still a layer suffix.
+    let b = graph.get_node_by_id("B").expect("B must exist");
+    let c = graph.get_node_by_id("C").expect("C must exist");
+    assert_eq!(graph.edge_layer(b, c), Some("train"));
+}
+
+#[test]
+fn parser_rejects_comment_only_file() {
+    let file = write_temp_graph("# nothing here yet\n\n  # still nothing\n");
+    let path = file.path().to_string_lossy().into_owned();
+
+    let err = match retrieve_graph_data_from_file(&path) {
+        Ok(_) => panic!("a comment-only file has no edges"),
+        Err(err) => err,
+    };
+    assert!(err.to_string().contains("no edges"));
+}
+
+#[test]
+fn parser_reads_header_after_leading_comments() {
+    let file = write_temp_graph("# city roads\n\nD\nA->B:7\n");
+    let path = file.path().to_string_lossy().into_owned();
+
+    let result = retrieve_graph_data_from_file(&path).expect("comments may precede the header");
+    let graph = result.directed_graph.expect("directed graph must exist");
+    assert_eq!(
+        graph.to_edge_list(),
+        vec![("A".to_string(), "B".to_string(), 7)]
+    );
+
+    // Errors still name the physical line of the file.
+    let file = write_temp_graph("# city roads\nD\nA->B:7\nA-C:2\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let err = match retrieve_graph_data_from_file(&path) {
+        Ok(_) => panic!("mixed syntax should fail"),
+        Err(err) => err,
+    };
+    assert!(err.to_string().contains("line 4"), "{}", err);
+}
+
+#[test]
+fn parser_rejects_header_followed_only_by_comments() {
+    let file = write_temp_graph("D\n# only a comment\n");
+    let path = file.path().to_string_lossy().into_owned();
+
+    let err = match retrieve_graph_data_from_file(&path) {
+        Ok(_) => panic!("a header without edge lines has no edges"),
+        Err(err) => err,
+    };
+    assert!(err.to_string().contains("no edges"), "{}", err);
+}
+
 #[test]
 fn parser_rejects_prefixed_graph_header() {
     let file = write_temp_graph("D_extra\nA->B:4\n");
@@ -156,3 +274,505 @@ fn parser_rejects_prefixed_graph_header() {
             .contains("Expected exactly one of: D, UN, TD")
     );
 }
+
+#[test]
+fn parser_rejects_negative_weight_by_default() {
+    let file = write_temp_graph("D\nA->B:-3\n");
+    let path = file.path().to_string_lossy().into_owned();
+
+    let err = match retrieve_graph_data_from_file(&path) {
+        Ok(_) => panic!("negative weight should fail without opting in"),
+        Err(err) => err,
+    };
+
+    assert!(err.to_string().contains("line 2"));
+}
+
+#[test]
+fn parser_reads_negative_weights_into_signed_graph_when_allowed() {
+    let file = write_temp_graph("D\nA->B:-3\nB->C:5\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let options = FileInputOptions {
+        allow_negative_weights: true,
+        ..FileInputOptions::default()
+    };
+
+    let result = retrieve_graph_data_from_file_with_options(&path, &options)
+        .expect("signed parsing should succeed");
+
+    assert!(result.directed_graph.is_none());
+    let graph = result
+        .signed_directed_graph
+        .expect("signed directed graph must exist");
+    assert_eq!(count_directed_edges(&graph), 2);
+    let a = graph.get_node_by_id("A").expect("A must exist");
+    let weights: Vec<i32> = graph.neighbors(a).map(|(_, weight)| weight).collect();
+    assert_eq!(weights, vec![-3]);
+}
+
+#[test]
+fn parser_reads_edge_kinds() {
+    let file = write_temp_graph("D\nA->B:4@highway\nB->C:2\n");
+    let path = file.path().to_string_lossy().into_owned();
+
+    let graph = retrieve_graph_data_from_file(&path)
+        .expect("edge kinds should parse")
+        .directed_graph
+        .expect("directed graph must exist");
+
+    let a = graph.get_node_by_id("A").expect("A must exist");
+    let b = graph.get_node_by_id("B").expect("B must exist");
+    let c = graph.get_node_by_id("C").expect("C must exist");
+    assert_eq!(graph.edge_kind(a, b), Some("highway"));
+    assert_eq!(graph.edge_kind(b, c), None);
+    assert_eq!(graph.neighbors(a).next().map(|(_, weight)| weight), Some(4));
+}
+
+#[test]
+fn parser_reads_edge_layers() {
+    let file = write_temp_graph("UN\nA-B:4#walk\nB-C:2@rail#train\nC<->D:3|5#bus\nA-D:9\n");
+    let path = file.path().to_string_lossy().into_owned();
+
+    let graph = retrieve_graph_data_from_file(&path)
+        .expect("edge layers should parse")
+        .undirected_graph
+        .expect("undirected graph must exist");
+
+    let node = |id: &str| graph.get_node_by_id(id).expect("node must exist");
+    assert_eq!(graph.edge_layer(node("B"), node("A")), Some("walk"));
+    assert_eq!(graph.edge_layer(node("B"), node("C")), Some("train"));
+    assert_eq!(graph.edge_kind(node("B"), node("C")), Some("rail"));
+    assert_eq!(graph.edge_layer(node("D"), node("C")), Some("bus"));
+    assert_eq!(graph.edge_layer(node("A"), node("D")), None);
+
+    let invalid = write_temp_graph("D\nA->B:4#\n");
+    let path = invalid.path().to_string_lossy().into_owned();
+    let message = match retrieve_graph_data_from_file(&path) {
+        Ok(_) => panic!("an empty layer should be rejected"),
+        Err(err) => err.to_string(),
+    };
+    assert!(message.contains("Invalid layer at line 2"));
+}
+
+#[test]
+fn parser_rejects_missing_weight_by_default() {
+    let file = write_temp_graph("D\nA->B:7\nA->C\n");
+    let path = file.path().to_string_lossy().into_owned();
+
+    let err = match retrieve_graph_data_from_file(&path) {
+        Ok(_) => panic!("an edge without a weight should fail under the reject policy"),
+        Err(err) => err,
+    };
+
+    let message = err.to_string();
+    assert!(message.contains("Missing weight at line 3"));
+    assert!(message.contains("A->C"));
+}
+
+#[test]
+fn parser_defaults_missing_weights_to_one_when_requested() {
+    let file = write_temp_graph("UN\nA-B:7\nA-C\nC-D@ferry\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let options = FileInputOptions {
+        missing_weight: MissingWeightPolicy::DefaultToOne,
+        ..FileInputOptions::default()
+    };
+
+    let result = retrieve_graph_data_from_file_with_options(&path, &options)
+        .expect("missing weights should default to one");
+    let graph = result.undirected_graph.expect("undirected graph expected");
+
+    let edges = graph.to_edge_list();
+    assert!(edges.contains(&("A".to_string(), "B".to_string(), 7)));
+    assert!(edges.contains(&("A".to_string(), "C".to_string(), 1)));
+    assert!(edges.contains(&("C".to_string(), "D".to_string(), 1)));
+    let c = graph.get_node_by_id("C").expect("C should exist");
+    let d = graph.get_node_by_id("D").expect("D should exist");
+    assert_eq!(graph.edge_kind(c, d), Some("ferry"));
+    // Not every weight was explicit, so the graph no longer claims to be weighted.
+    assert!(!graph.is_weighted());
+}
+
+#[test]
+fn parser_keeps_graph_weighted_when_no_weight_was_defaulted() {
+    let file = write_temp_graph("D\nA->B:7\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let options = FileInputOptions {
+        missing_weight: MissingWeightPolicy::DefaultToOne,
+        ..FileInputOptions::default()
+    };
+
+    let result = retrieve_graph_data_from_file_with_options(&path, &options)
+        .expect("explicit weights should parse");
+
+    assert!(
+        result
+            .directed_graph
+            .expect("directed graph expected")
+            .is_weighted()
+    );
+}
+
+#[test]
+fn parser_with_progress_interval_builds_the_same_graph() {
+    let file = write_temp_graph("D\nA->B:1\n\nB->C:2\nC->D:3\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let options = FileInputOptions {
+        progress_interval: NonZeroUsize::new(2),
+        ..FileInputOptions::default()
+    };
+
+    let with_progress = retrieve_graph_data_from_file_with_options(&path, &options)
+        .expect("progress reporting should not affect parsing")
+        .directed_graph
+        .expect("directed graph expected");
+    let without_progress = retrieve_graph_data_from_file(&path)
+        .expect("graph should parse")
+        .directed_graph
+        .expect("directed graph expected");
+
+    assert_eq!(
+        with_progress.to_edge_list(),
+        without_progress.to_edge_list()
+    );
+}
+
+#[test]
+fn parser_stops_after_node_limit_and_skips_later_edges() {
+    // The limit of 3 is reached after `B->C`; `C->A` connects existing nodes
+    // but comes later in the file, so it is skipped as well.
+    let file = write_temp_graph("UN\nA-B:1\nB-C:2\nC-A:3\nC-D:4\nD-E:5\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let options = FileInputOptions {
+        node_limit: NonZeroUsize::new(3),
+        ..FileInputOptions::default()
+    };
+
+    let graph = retrieve_graph_data_from_file_with_options(&path, &options)
+        .expect("limited graph should parse")
+        .undirected_graph
+        .expect("undirected graph expected");
+
+    assert_eq!(graph.get_all_nodes().len(), 3);
+    assert_eq!(count_undirected_edges(&graph), 2);
+    assert!(graph.get_node_by_id("D").is_none());
+    for node in graph.get_all_nodes() {
+        for (neighbor, _) in graph.neighbors(node) {
+            assert!(graph.get_node_by_id(neighbor.get_id()).is_some());
+        }
+    }
+}
+
+#[test]
+fn parser_finishes_the_line_that_crosses_the_node_limit() {
+    let file = write_temp_graph("D\nA->B:1\nC->D:2\nE->F:3\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let options = FileInputOptions {
+        node_limit: NonZeroUsize::new(3),
+        ..FileInputOptions::default()
+    };
+
+    let graph = retrieve_graph_data_from_file_with_options(&path, &options)
+        .expect("limited graph should parse")
+        .directed_graph
+        .expect("directed graph expected");
+
+    assert_eq!(graph.get_all_nodes().len(), 4);
+    assert_eq!(count_directed_edges(&graph), 2);
+}
+
+#[test]
+fn lenient_parser_skips_invalid_lines_and_reports_them() {
+    let file = write_temp_graph("D\nA->B:1\nA-B:2\nB->C:3\nC->:4\nC->D:x\nD->A:5\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let options = FileInputOptions {
+        lenient: true,
+        ..FileInputOptions::default()
+    };
+
+    let parsed = retrieve_graph_data_from_file_with_options(&path, &options)
+        .expect("lenient parsing should not fail on bad lines");
+
+    assert_eq!(parsed.skipped_line_count, 3);
+    assert_eq!(parsed.skipped_lines.len(), 3);
+    for (err, line_number) in parsed.skipped_lines.iter().zip([3, 5, 6]) {
+        assert!(
+            err.to_string().contains(&format!("line {}", line_number)),
+            "{}",
+            err
+        );
+    }
+    let graph = parsed.directed_graph.expect("directed graph expected");
+    assert_eq!(count_directed_edges(&graph), 3);
+    assert!(graph.get_node_by_id("D").is_some());
+}
+
+#[test]
+fn lenient_parser_caps_collected_errors() {
+    let mut contents = String::from("UN\nA-B:1\n");
+    for _ in 0..MAX_COLLECTED_LINE_ERRORS + 5 {
+        contents.push_str("not an edge\n");
+    }
+    let file = write_temp_graph(&contents);
+    let path = file.path().to_string_lossy().into_owned();
+    let options = FileInputOptions {
+        lenient: true,
+        ..FileInputOptions::default()
+    };
+
+    let parsed = retrieve_graph_data_from_file_with_options(&path, &options)
+        .expect("lenient parsing should not fail on bad lines");
+
+    assert_eq!(parsed.skipped_line_count, MAX_COLLECTED_LINE_ERRORS + 5);
+    assert_eq!(parsed.skipped_lines.len(), MAX_COLLECTED_LINE_ERRORS);
+    assert!(parsed.undirected_graph.is_some());
+}
+
+#[test]
+fn strict_parser_reports_no_skipped_lines() {
+    let file = write_temp_graph("D\nA->B:1\n");
+    let path = file.path().to_string_lossy().into_owned();
+
+    let parsed = retrieve_graph_data_from_file(&path).expect("graph should parse");
+
+    assert!(parsed.skipped_lines.is_empty());
+    assert_eq!(parsed.skipped_line_count, 0);
+}
+
+#[test]
+fn parser_builds_unweighted_graph_when_no_line_has_a_weight() {
+    let file = write_temp_graph("UN\nA-B\n\nB-C\nC-B\n");
+    let path = file.path().to_string_lossy().into_owned();
+
+    let result = retrieve_graph_data_from_file(&path).expect("weightless file should parse");
+    assert!(result.undirected_graph.is_none());
+    let graph = result.unweighted_graph.expect("unweighted graph expected");
+
+    assert!(!graph.is_weighted());
+    assert!(!graph.is_directed());
+    assert_eq!(graph.get_all_nodes().len(), 3);
+    // The repeated C-B line is the same undirected edge and is skipped.
+    assert_eq!(count_undirected_edges(&graph), 2);
+
+    // Asking for default weights keeps the weighted graph of earlier versions.
+    let options = FileInputOptions {
+        missing_weight: MissingWeightPolicy::DefaultToOne,
+        ..FileInputOptions::default()
+    };
+    let result = retrieve_graph_data_from_file_with_options(&path, &options)
+        .expect("missing weights should default to one");
+    assert!(result.unweighted_graph.is_none());
+    assert!(result.undirected_graph.is_some());
+}
+
+#[test]
+fn parser_reads_two_way_undirected_weights() {
+    let file = write_temp_graph("UN\nA<->B:7|9\nB<->C:4@hill\nC-D:2\n");
+    let path = file.path().to_string_lossy().into_owned();
+
+    let graph = retrieve_graph_data_from_file(&path)
+        .expect("two-way lines should parse")
+        .undirected_graph
+        .expect("undirected graph expected");
+    let weight_of = |from: &str, to: &str| {
+        let from = graph.get_node_by_id(from).expect("node should exist");
+        graph
+            .neighbors(from)
+            .find(|(neighbor, _)| neighbor.get_id() == to)
+            .map(|(_, weight)| weight)
+    };
+
+    assert_eq!(weight_of("A", "B"), Some(7));
+    assert_eq!(weight_of("B", "A"), Some(9));
+    // A single weight is used for both directions.
+    assert_eq!(weight_of("B", "C"), Some(4));
+    assert_eq!(weight_of("C", "B"), Some(4));
+    let b = graph.get_node_by_id("B").unwrap();
+    let c = graph.get_node_by_id("C").unwrap();
+    assert_eq!(graph.edge_kind(c, b), Some("hill"));
+
+    let file = write_temp_graph("UN\nA<->B:7|70000\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let message = retrieve_graph_data_from_file(&path)
+        .expect_err("reverse weight exceeds u16")
+        .to_string();
+    assert!(message.contains("line 2"), "{}", message);
+}
+
+#[test]
+fn parser_reads_quoted_node_ids_with_spaces() {
+    let file = write_temp_graph(
+        "D\n\"New York\"->\"Los Angeles\":400\n\"Los Angeles\"->Denver:150@flight\nDenver->\"New York\":250 # back east\n",
+    );
+    let path = file.path().to_string_lossy().into_owned();
+
+    let graph = retrieve_graph_data_from_file(&path)
+        .expect("quoted IDs should parse")
+        .directed_graph
+        .expect("directed graph expected");
+
+    // Quotes are not part of the ID, and plain IDs keep working alongside.
+    let new_york = graph.get_node_by_id("New York").expect("node should exist");
+    let los_angeles = graph.get_node_by_id("Los Angeles").unwrap();
+    assert_eq!(graph.get_all_nodes().len(), 3);
+    assert_eq!(
+        graph
+            .neighbors(new_york)
+            .map(|(node, weight)| (node.get_id(), weight))
+            .collect::<Vec<_>>(),
+        vec![("Los Angeles", 400)]
+    );
+    assert_eq!(
+        graph.edge_kind(los_angeles, graph.get_node_by_id("Denver").unwrap()),
+        Some("flight")
+    );
+}
+
+#[test]
+fn parser_reports_layer_and_kind_separators_inside_quoted_ids_as_syntax_errors() {
+    // `#` and `@` inside quotes are not suffix separators, so these lines are
+    // not misread as a bad layer or a missing weight.
+    for line in ["\"A#1\"->B:3#boat", "\"A@x\"->B:3", "A->\"B@x\":3@ferry"] {
+        let err = retrieve_graph_data_from_reader(
+            format!("D\n{}\n", line).as_bytes(),
+            &FileInputOptions::default(),
+        )
+        .expect_err("'#' and '@' are not allowed in node IDs");
+
+        let message = err.to_string();
+        assert!(
+            message.contains("Invalid syntax at line 2"),
+            "{}: {}",
+            line,
+            message
+        );
+    }
+}
+
+#[test]
+fn parser_splits_quoted_node_ids_with_hyphens_at_the_top_level_separator() {
+    let file = write_temp_graph("UN\n\"St-Louis\"-\"Kansas-City\":250\n\"St-Louis\"<->A:3|5\n");
+    let path = file.path().to_string_lossy().into_owned();
+
+    let graph = retrieve_graph_data_from_file(&path)
+        .expect("hyphenated quoted IDs should parse")
+        .undirected_graph
+        .expect("undirected graph expected");
+
+    let mut ids: Vec<&str> = graph.get_all_nodes().iter().map(|n| n.get_id()).collect();
+    ids.sort();
+    assert_eq!(ids, vec!["A", "Kansas-City", "St-Louis"]);
+    let st_louis = graph.get_node_by_id("St-Louis").unwrap();
+    assert_eq!(
+        graph
+            .neighbors(st_louis)
+            .find(|(node, _)| node.get_id() == "Kansas-City")
+            .map(|(_, weight)| weight),
+        Some(250)
+    );
+
+    // Unquoted, the hyphenated name is still rejected as ambiguous.
+    let file = write_temp_graph("UN\nSt-Louis-Denver:4\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let message = retrieve_graph_data_from_file(&path)
+        .expect_err("unquoted hyphenated ID is ambiguous")
+        .to_string();
+    assert!(message.contains("line 2"), "{}", message);
+
+    // Weightless quoted lines still build an unweighted graph.
+    let file = write_temp_graph("D\n\"San Jose\"->\"Santa-Cruz\"\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let graph = retrieve_graph_data_from_file(&path)
+        .expect("weightless quoted IDs should parse")
+        .unweighted_graph
+        .expect("unweighted graph expected");
+    assert!(graph.get_node_by_id("Santa-Cruz").is_some());
+}
+
+#[test]
+fn parser_rejects_files_mixing_directed_and_undirected_separators() {
+    let file = write_temp_graph("D\nA->B:7\nB-C:3\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let message = retrieve_graph_data_from_file(&path)
+        .expect_err("an undirected line in a D file must be rejected")
+        .to_string();
+    assert!(
+        message.contains("Mixed edge syntax at line 3 ('B-C:3')"),
+        "{}",
+        message
+    );
+    assert!(message.contains("undirected '-'"), "{}", message);
+
+    let file = write_temp_graph("UN\nA-B:7\nB->C:3\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let message = retrieve_graph_data_from_file(&path)
+        .expect_err("a directed line in a UN file must be rejected")
+        .to_string();
+    assert!(
+        message.contains("Mixed edge syntax at line 3 ('B->C:3')"),
+        "{}",
+        message
+    );
+
+    // Weightless lines are checked too, before the missing-weight policy.
+    let file = write_temp_graph("D\nA->B:7\nB-C\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let message = retrieve_graph_data_from_file(&path)
+        .expect_err("an undirected weightless line in a D file must be rejected")
+        .to_string();
+    assert!(
+        message.contains("Mixed edge syntax at line 3"),
+        "{}",
+        message
+    );
+
+    // Negative weights and hyphens inside quoted IDs are not separators.
+    let options = FileInputOptions {
+        allow_negative_weights: true,
+        ..FileInputOptions::default()
+    };
+    let file = write_temp_graph("D\n\"St-Louis\"->B:-3\n");
+    let path = file.path().to_string_lossy().into_owned();
+    assert!(retrieve_graph_data_from_file_with_options(&path, &options).is_ok());
+}
+
+#[test]
+fn parser_reports_ignored_duplicate_edges_as_warnings() {
+    let file = write_temp_graph("D\nA->B:4\nB->C:2\n\nA->B:9\nB->C:2\n");
+    let path = file.path().to_string_lossy().into_owned();
+
+    let parsed = retrieve_graph_data_from_file(&path).expect("duplicates are not errors");
+
+    assert_eq!(
+        parsed.warnings,
+        vec![
+            "Duplicate edge at line 5 ('A->B:9') was ignored".to_string(),
+            "Duplicate edge at line 6 ('B->C:2') was ignored".to_string(),
+        ]
+    );
+    // The first occurrence wins, and duplicates are not counted as skipped errors.
+    let graph = parsed.directed_graph.expect("directed graph expected");
+    let a = graph.get_node_by_id("A").unwrap();
+    assert_eq!(graph.neighbors(a).next().map(|(_, weight)| weight), Some(4));
+    assert_eq!(parsed.skipped_line_count, 0);
+
+    // Unweighted files report repeated (here: reversed undirected) edges too.
+    let file = write_temp_graph("UN\nA-B\nB-A\n");
+    let path = file.path().to_string_lossy().into_owned();
+    let parsed = retrieve_graph_data_from_file(&path).expect("weightless file should parse");
+    assert_eq!(parsed.warnings.len(), 1);
+    assert!(
+        parsed.warnings[0].contains("line 3"),
+        "{:?}",
+        parsed.warnings
+    );
+
+    let file = write_temp_graph("D\nA->B:4\n");
+    let path = file.path().to_string_lossy().into_owned();
+    assert!(
+        retrieve_graph_data_from_file(&path)
+            .expect("graph should parse")
+            .warnings
+            .is_empty()
+    );
+}
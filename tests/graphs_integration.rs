@@ -3,12 +3,15 @@
 //! These tests cover insertion semantics, duplicate protection, and neighbor
 //! traversal behavior for directed and undirected graphs.
 
+use std::collections::HashSet;
+
 use shortest_path_finder::{
     graphs::{
         directed::DirectedGraph,
         graph::{Graph, GraphNode},
         two_dimensional_coordinate_graph::TwoDimensionalCoordinateGraph,
-        undirected::UndirectedGraph,
+        undirected::{UndirectedEdge, UndirectedGraph},
+        unweighted::UnweightedGraph,
     },
     nodes::{default_node::DefaultNode, two_dimensional_node::TwoDimensionalNode},
 };
@@ -149,3 +152,1372 @@ fn two_dimensional_graph_rejects_duplicate_coordinates_even_with_different_ids()
 
     assert_eq!(graph.get_all_nodes().len(), 1);
 }
+
+#[test]
+fn directed_graph_map_nodes_renames_nodes_and_keeps_edges() {
+    let mut graph = DirectedGraph::new(vec![node("A.old"), node("B.old"), node("C.old")]);
+    assert!(
+        graph
+            .insert_edge(&node("A.old"), &node("B.old"), Some(4))
+            .is_none()
+    );
+    assert!(
+        graph
+            .insert_edge(&node("B.old"), &node("C.old"), Some(2))
+            .is_none()
+    );
+
+    let mapped = graph
+        .map_nodes(|id| id.trim_end_matches(".old").to_string())
+        .expect("distinct IDs should stay distinct");
+
+    let ids: Vec<&str> = mapped
+        .get_all_nodes()
+        .iter()
+        .map(|node| node.get_id())
+        .collect();
+    assert_eq!(ids, vec!["A", "B", "C"]);
+    let neighbors: Vec<(String, u16)> = mapped
+        .neighbors(&node("B"))
+        .map(|(neighbor, weight)| (neighbor.get_id().to_string(), weight))
+        .collect();
+    assert_eq!(neighbors, vec![("C".to_string(), 2)]);
+    assert!(mapped.get_node_by_id("A.old").is_none());
+}
+
+#[test]
+fn map_nodes_rejects_mappings_that_merge_nodes() {
+    let directed = DirectedGraph::new(vec![node("A1"), node("A2"), node("B")]);
+    let err = directed
+        .map_nodes(|id| id[..1].to_string())
+        .expect_err("A1 and A2 would collapse into A");
+    assert!(err.contains("'A1'"));
+    assert!(err.contains("'A2'"));
+
+    let mut undirected = UndirectedGraph::new(vec![node("x"), node("X")]);
+    assert!(
+        undirected
+            .insert_edge(&node("x"), &node("X"), Some(1))
+            .is_none()
+    );
+    assert!(undirected.map_nodes(|id| id.to_uppercase()).is_err());
+    assert!(undirected.map_nodes(|id| format!("n-{}", id)).is_ok());
+}
+
+#[test]
+fn undirected_graph_bridges_excludes_cycle_edges() {
+    // Cycle A-B-C-D-A, bridge D-E, then the chain E-F-G (all bridges), plus an
+    // isolated component H-I (a bridge of its own component).
+    let ids = ["A", "B", "C", "D", "E", "F", "G", "H", "I"];
+    let mut graph = UndirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+    for (a, b, weight) in [
+        ("A", "B", 1),
+        ("B", "C", 1),
+        ("C", "D", 1),
+        ("D", "A", 1),
+        ("D", "E", 9),
+        ("E", "F", 2),
+        ("F", "G", 3),
+        ("H", "I", 4),
+    ] {
+        assert!(
+            graph
+                .insert_edge(&node(a), &node(b), Some(weight))
+                .is_none()
+        );
+    }
+
+    let bridges: Vec<(String, String, u16)> = graph
+        .bridges()
+        .into_iter()
+        .map(|edge| {
+            (
+                edge.a_node.get_id().to_string(),
+                edge.b_node.get_id().to_string(),
+                edge.weight,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        bridges,
+        vec![
+            ("D".to_string(), "E".to_string(), 9),
+            ("E".to_string(), "F".to_string(), 2),
+            ("F".to_string(), "G".to_string(), 3),
+            ("H".to_string(), "I".to_string(), 4),
+        ]
+    );
+}
+
+#[test]
+fn mst_prim_and_kruskal_agree_on_total_weight() {
+    // A dense graph with a pseudo-random weight on every pair, plus a
+    // self-loop that must never be part of the tree.
+    let ids: Vec<String> = (0..12).map(|index| format!("N{}", index)).collect();
+    let mut graph = UndirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+    for a in 0..ids.len() {
+        for b in (a + 1)..ids.len() {
+            let weight = ((a * 31 + b * 17) % 23 + 1) as u16;
+            assert!(
+                graph
+                    .insert_edge(&node(&ids[a]), &node(&ids[b]), Some(weight))
+                    .is_none()
+            );
+        }
+    }
+    assert!(
+        graph
+            .insert_edge(&node("N3"), &node("N3"), Some(0))
+            .is_none()
+    );
+
+    let total = |tree: &[UndirectedEdge]| -> u32 {
+        tree.iter()
+            .map(|edge| u32::from(edge.weight.min(edge.reverse_weight)))
+            .sum()
+    };
+    let kruskal = graph.mst_kruskal();
+    assert_eq!(kruskal.len(), ids.len() - 1);
+    for start in &ids {
+        let prim = graph.mst_prim(&node(start)).expect("graph is connected");
+        assert_eq!(prim.len(), ids.len() - 1);
+        assert_eq!(total(&prim), total(&kruskal), "start {start}");
+    }
+    assert!(
+        kruskal
+            .iter()
+            .all(|edge| edge.a_node.get_id() != edge.b_node.get_id())
+    );
+
+    assert!(graph.mst_prim(&node("missing")).is_err());
+    graph.insert_node(node("Lonely"));
+    assert!(
+        graph
+            .mst_prim(&node("N0"))
+            .unwrap_err()
+            .contains("not connected")
+    );
+    // Kruskal still returns the forest.
+    assert_eq!(total(&graph.mst_kruskal()), total(&kruskal));
+}
+
+#[test]
+fn undirected_graph_without_bridges_returns_empty_list() {
+    let mut graph = UndirectedGraph::new(vec![node("A"), node("B"), node("C")]);
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(1)).is_none());
+    assert!(graph.insert_edge(&node("B"), &node("C"), Some(1)).is_none());
+    assert!(graph.insert_edge(&node("C"), &node("A"), Some(1)).is_none());
+    assert!(graph.insert_edge(&node("A"), &node("A"), Some(1)).is_none());
+
+    assert!(graph.bridges().is_empty());
+}
+
+#[test]
+fn undirected_graph_greedy_coloring_never_shares_color_across_an_edge() {
+    // Wheel: hub H connected to the 5-cycle A-B-C-D-E-A, plus a self-loop on A.
+    let ids = ["A", "B", "C", "D", "E", "H"];
+    let mut graph = UndirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+    let mut edges = vec![
+        ("A", "B"),
+        ("B", "C"),
+        ("C", "D"),
+        ("D", "E"),
+        ("E", "A"),
+        ("A", "A"),
+    ];
+    edges.extend(["A", "B", "C", "D", "E"].iter().map(|rim| ("H", *rim)));
+    for (a, b) in &edges {
+        assert!(graph.insert_edge(&node(a), &node(b), Some(1)).is_none());
+    }
+
+    let coloring = graph.greedy_coloring();
+
+    assert_eq!(coloring.len(), ids.len());
+    for (a, b) in edges.iter().filter(|(a, b)| a != b) {
+        assert_ne!(
+            coloring[*a], coloring[*b],
+            "edge {}-{} shares a color",
+            a, b
+        );
+    }
+    // The hub has the highest degree and is colored first; an odd wheel needs 4 colors.
+    assert_eq!(coloring["H"], 0);
+    assert_eq!(graph.greedy_color_count(), 4);
+}
+
+#[test]
+fn undirected_graph_greedy_coloring_of_empty_graph_uses_no_colors() {
+    let graph = UndirectedGraph::default();
+
+    assert!(graph.greedy_coloring().is_empty());
+    assert_eq!(graph.greedy_color_count(), 0);
+}
+
+#[test]
+fn edge_list_round_trips_through_from_edges() {
+    let mut directed = DirectedGraph::new(vec![node("A"), node("B"), node("C")]);
+    assert!(
+        directed
+            .insert_edge(&node("B"), &node("A"), Some(3))
+            .is_none()
+    );
+    assert!(
+        directed
+            .insert_edge(&node("A"), &node("C"), Some(8))
+            .is_none()
+    );
+    assert!(
+        directed
+            .insert_edge(&node("A"), &node("B"), Some(5))
+            .is_none()
+    );
+
+    let directed_list = directed.to_edge_list();
+    assert_eq!(
+        directed_list,
+        vec![
+            ("A".to_string(), "C".to_string(), 8),
+            ("A".to_string(), "B".to_string(), 5),
+            ("B".to_string(), "A".to_string(), 3),
+        ]
+    );
+    let rebuilt = DirectedGraph::from_edges(&directed_list).expect("edge list should rebuild");
+    assert_eq!(rebuilt.to_edge_list(), directed_list);
+
+    let mut undirected = UndirectedGraph::new(vec![node("A"), node("B"), node("C")]);
+    assert!(
+        undirected
+            .insert_edge(&node("C"), &node("A"), Some(2))
+            .is_none()
+    );
+    assert!(
+        undirected
+            .insert_edge(&node("B"), &node("C"), Some(6))
+            .is_none()
+    );
+
+    // Each undirected edge appears once, earlier-inserted endpoint first.
+    let undirected_list = undirected.to_edge_list();
+    assert_eq!(
+        undirected_list,
+        vec![
+            ("A".to_string(), "C".to_string(), 2),
+            ("B".to_string(), "C".to_string(), 6),
+        ]
+    );
+    // The rebuilt graph inserts C before B, so compare endpoint-order independently.
+    let rebuilt = UndirectedGraph::from_edges(&undirected_list).expect("edge list should rebuild");
+    let normalize = |list: Vec<(String, String, u16)>| {
+        let mut list: Vec<_> = list
+            .into_iter()
+            .map(|(a, b, weight)| {
+                if a <= b {
+                    (a, b, weight)
+                } else {
+                    (b, a, weight)
+                }
+            })
+            .collect();
+        list.sort();
+        list
+    };
+    assert_eq!(
+        normalize(rebuilt.to_edge_list()),
+        normalize(undirected_list)
+    );
+}
+
+#[test]
+fn to_dot_renders_two_edge_graphs() {
+    let edges = vec![
+        ("A".to_string(), "B".to_string(), 7),
+        ("B".to_string(), "C".to_string(), 2),
+    ];
+    let directed = DirectedGraph::from_edges(&edges).expect("edges should build a graph");
+    assert_eq!(
+        directed.to_dot(),
+        "digraph {\n    \"A\" -> \"B\" [label=\"7\"];\n    \"B\" -> \"C\" [label=\"2\"];\n}\n"
+    );
+
+    let mut undirected = UndirectedGraph::from_edges(&edges).expect("edges should build a graph");
+    undirected.insert_node(node("Say \"hi\""));
+    // Isolated nodes are listed first, with quotes in their IDs escaped.
+    assert_eq!(
+        undirected.to_dot(),
+        "graph {\n    \"Say \\\"hi\\\"\";\n    \"A\" -- \"B\" [label=\"7\"];\n    \"B\" -- \"C\" [label=\"2\"];\n}\n"
+    );
+}
+
+#[test]
+fn adjacency_matrix_reports_weights_by_insertion_order() {
+    let edges = vec![
+        ("A".to_string(), "B".to_string(), 7),
+        ("B".to_string(), "C".to_string(), 2),
+        ("C".to_string(), "A".to_string(), 4),
+    ];
+
+    let directed = DirectedGraph::from_edges(&edges).expect("edges should build a graph");
+    let (labels, matrix) = directed.adjacency_matrix();
+    assert_eq!(labels, vec!["A", "B", "C"]);
+    assert_eq!(matrix[0][1], Some(7));
+    assert_eq!(matrix[1][0], None);
+    assert_eq!(matrix[2][0], Some(4));
+    assert_eq!(matrix[0][0], None);
+
+    let undirected = UndirectedGraph::from_edges(&edges).expect("edges should build a graph");
+    let (labels, matrix) = undirected.adjacency_matrix();
+    assert_eq!(labels, vec!["A", "B", "C"]);
+    assert_eq!(matrix.len(), 3);
+    for (i, row) in matrix.iter().enumerate() {
+        assert_eq!(row.len(), 3);
+        for (j, cell) in row.iter().enumerate() {
+            assert_eq!(*cell, matrix[j][i], "matrix[{i}][{j}] should be symmetric");
+        }
+    }
+    assert_eq!(matrix[1][2], Some(2));
+    assert_eq!(matrix[0][2], Some(4));
+}
+
+#[test]
+fn from_adjacency_matrix_builds_one_edge_per_weighted_cell() {
+    let labels: Vec<String> = ["A", "B", "C"].map(String::from).to_vec();
+    let matrix = vec![
+        vec![None, Some(7), Some(1)],
+        vec![None, None, Some(2)],
+        vec![Some(4), None, None],
+    ];
+
+    let graph = DirectedGraph::from_adjacency_matrix(labels.clone(), matrix.clone())
+        .expect("a square matrix should build a graph");
+    assert_eq!(
+        graph.get_all_nodes(),
+        &vec![node("A"), node("B"), node("C")]
+    );
+    assert_eq!(graph.all_edges().len(), 4);
+    let weights: Vec<u16> = graph
+        .edges_between("A", "B")
+        .into_iter()
+        .map(|(_, _, weight)| weight)
+        .collect();
+    assert_eq!(weights, vec![7], "A -> B keeps its weight");
+    assert!(!graph.does_edge_already_exist(&node("B"), &node("A")));
+    // Exporting again yields the same matrix.
+    assert_eq!(graph.adjacency_matrix(), (labels.clone(), matrix));
+
+    let ragged = vec![vec![None, None, None], vec![None], vec![None, None, None]];
+    assert!(DirectedGraph::<u16>::from_adjacency_matrix(labels.clone(), ragged).is_err());
+    let too_few_rows = vec![vec![None, None, None]];
+    assert!(DirectedGraph::<u16>::from_adjacency_matrix(labels, too_few_rows).is_err());
+    let duplicate_labels = vec!["A".to_string(), "A".to_string()];
+    assert!(
+        DirectedGraph::<u16>::from_adjacency_matrix(duplicate_labels, vec![vec![None; 2]; 2])
+            .is_err()
+    );
+}
+
+#[test]
+fn transpose_reverses_every_edge_and_keeps_nodes() {
+    let mut graph = DirectedGraph::new(vec![node("A"), node("B"), node("C"), node("Lonely")]);
+    assert!(
+        graph
+            .insert_edge_with_kind(&node("A"), &node("B"), Some(4), Some("ferry".to_string()))
+            .is_none()
+    );
+    assert!(graph.insert_edge(&node("B"), &node("C"), Some(2)).is_none());
+    assert!(graph.insert_edge(&node("C"), &node("B"), Some(5)).is_none());
+    let original_edges = graph.to_edge_list();
+
+    let transposed = graph.transpose();
+
+    assert_eq!(transposed.get_all_nodes(), graph.get_all_nodes());
+    assert!(transposed.does_edge_already_exist(&node("B"), &node("A")));
+    assert!(!transposed.does_edge_already_exist(&node("A"), &node("B")));
+    assert_eq!(transposed.edge_kind(&node("B"), &node("A")), Some("ferry"));
+    let mut reversed: Vec<_> = original_edges
+        .iter()
+        .map(|(from, to, weight)| (to.clone(), from.clone(), *weight))
+        .collect();
+    let mut transposed_edges = transposed.to_edge_list();
+    reversed.sort();
+    transposed_edges.sort();
+    assert_eq!(transposed_edges, reversed);
+
+    // The original graph is untouched.
+    assert_eq!(graph.to_edge_list(), original_edges);
+}
+
+#[test]
+fn to_undirected_merges_reciprocal_edges_with_minimum_weight() {
+    let mut directed = DirectedGraph::new(vec![node("A"), node("B"), node("C"), node("Lonely")]);
+    for (from, to, weight) in [("A", "B", 5), ("B", "A", 3), ("B", "C", 2), ("C", "B", 9)] {
+        assert!(
+            directed
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+
+    let undirected = directed.to_undirected();
+
+    // Every node survives, including the isolated one.
+    assert_eq!(undirected.get_all_nodes(), directed.get_all_nodes());
+    // One edge per node pair, each with the cheaper of the two directions.
+    assert_eq!(
+        undirected.to_edge_list(),
+        vec![
+            ("A".to_string(), "B".to_string(), 3),
+            ("B".to_string(), "C".to_string(), 2),
+        ]
+    );
+    assert_eq!(undirected.neighbors(&node("B")).count(), 2);
+    assert_eq!(directed.to_edge_list().len(), 4);
+}
+
+#[test]
+fn connected_components_separates_disjoint_triangles() {
+    let ids = ["A", "B", "C", "X", "Y", "Z", "Lonely"];
+    let mut graph = UndirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+    for (a, b) in [
+        ("A", "B"),
+        ("B", "C"),
+        ("C", "A"),
+        ("X", "Y"),
+        ("Y", "Z"),
+        ("Z", "X"),
+    ] {
+        assert!(graph.insert_edge(&node(a), &node(b), Some(1)).is_none());
+    }
+
+    let components = graph.connected_components();
+
+    assert_eq!(
+        components,
+        vec![
+            vec![node("A"), node("B"), node("C")],
+            vec![node("X"), node("Y"), node("Z")],
+            vec![node("Lonely")],
+        ]
+    );
+}
+
+#[test]
+fn strongly_connected_components_groups_cycles_and_splits_dags() {
+    let ids = |components: Vec<Vec<DefaultNode>>| -> Vec<Vec<String>> {
+        components
+            .into_iter()
+            .map(|component| component.into_iter().map(|node| node.id).collect())
+            .collect()
+    };
+
+    let cycle_edges: Vec<(String, String, u16)> = [("A", "B"), ("B", "C"), ("C", "A")]
+        .iter()
+        .map(|(from, to)| (from.to_string(), to.to_string(), 1))
+        .collect();
+    let cycle = DirectedGraph::from_edges(&cycle_edges).expect("edges should build a graph");
+    assert_eq!(
+        ids(cycle.strongly_connected_components()),
+        vec![vec!["A", "B", "C"]]
+    );
+
+    // A DAG: every node is its own component, sinks first.
+    let dag_edges: Vec<(String, String, u16)> = [("A", "B"), ("A", "C"), ("B", "C")]
+        .iter()
+        .map(|(from, to)| (from.to_string(), to.to_string(), 1))
+        .collect();
+    let dag = DirectedGraph::from_edges(&dag_edges).expect("edges should build a graph");
+    assert_eq!(
+        ids(dag.strongly_connected_components()),
+        vec![vec!["C"], vec!["B"], vec!["A"]]
+    );
+
+    // A long chain closed into one cycle must not overflow the stack.
+    let count = 100_000;
+    let long_cycle: Vec<(String, String, u16)> = (0..count)
+        .map(|index| (index.to_string(), ((index + 1) % count).to_string(), 1))
+        .collect();
+    let long_cycle = DirectedGraph::from_edges(&long_cycle).expect("edges should build a graph");
+    let components = long_cycle.strongly_connected_components();
+    assert_eq!(components.len(), 1);
+    assert_eq!(components[0].len(), count);
+}
+
+#[test]
+fn topological_sort_orders_dags_and_rejects_cycles() {
+    let to_edges = |pairs: &[(&str, &str)]| -> Vec<(String, String, u16)> {
+        pairs
+            .iter()
+            .map(|(from, to)| (from.to_string(), to.to_string(), 1))
+            .collect()
+    };
+
+    let dag_edges = to_edges(&[
+        ("shirt", "tie"),
+        ("tie", "jacket"),
+        ("trousers", "shoes"),
+        ("trousers", "belt"),
+        ("belt", "jacket"),
+        ("shirt", "belt"),
+    ]);
+    let dag = DirectedGraph::from_edges(&dag_edges).expect("edges should build a graph");
+    let order = dag
+        .topological_sort()
+        .expect("a DAG has a topological order");
+    assert_eq!(order.len(), dag.get_all_nodes().len());
+    let position = |id: &str| order.iter().position(|n| n.get_id() == id).unwrap();
+    for (from, to, _) in &dag_edges {
+        assert!(
+            position(from) < position(to),
+            "{from} must come before {to}"
+        );
+    }
+
+    let cyclic =
+        DirectedGraph::from_edges(&to_edges(&[("A", "B"), ("B", "C"), ("C", "A"), ("X", "A")]))
+            .expect("edges should build a graph");
+    let err = cyclic.topological_sort().unwrap_err();
+    assert!(err.contains("cycle"));
+    assert!(err.contains("A, B, C"));
+    assert!(!err.contains('X'));
+}
+
+#[test]
+fn find_cycle_reports_cycles_and_self_loops() {
+    let graph_of = |pairs: &[(&str, &str)]| {
+        let edges: Vec<(String, String, u16)> = pairs
+            .iter()
+            .map(|(from, to)| (from.to_string(), to.to_string(), 1))
+            .collect();
+        DirectedGraph::from_edges(&edges).expect("edges should build a graph")
+    };
+    let cycle_ids = |graph: &DirectedGraph| -> Option<Vec<String>> {
+        graph
+            .find_cycle()
+            .map(|cycle| cycle.into_iter().map(|node| node.id).collect())
+    };
+
+    // Diamond: C is reached twice, but there is no cycle.
+    let dag = graph_of(&[("A", "B"), ("A", "C"), ("B", "C"), ("C", "D")]);
+    assert!(!dag.has_cycle());
+    assert_eq!(cycle_ids(&dag), None);
+
+    let two_cycle = graph_of(&[("X", "A"), ("A", "B"), ("B", "A")]);
+    assert!(two_cycle.has_cycle());
+    assert_eq!(
+        cycle_ids(&two_cycle),
+        Some(vec!["A".to_string(), "B".to_string()])
+    );
+    assert!(two_cycle.topological_sort().is_err());
+
+    let self_loop = graph_of(&[("A", "B"), ("B", "B")]);
+    assert!(self_loop.has_cycle());
+    assert_eq!(cycle_ids(&self_loop), Some(vec!["B".to_string()]));
+}
+
+#[test]
+fn from_edges_rejects_duplicate_edges() {
+    let edges = vec![
+        ("A".to_string(), "B".to_string(), 1),
+        ("B".to_string(), "A".to_string(), 2),
+    ];
+
+    assert!(DirectedGraph::from_edges(&edges).is_ok());
+    assert!(UndirectedGraph::from_edges(&edges).is_err());
+}
+
+#[test]
+fn directed_graph_path_becomes_invalid_after_removing_a_hop() {
+    let mut graph = DirectedGraph::new(vec![node("A"), node("B"), node("C")]);
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(1)).is_none());
+    assert!(graph.insert_edge(&node("B"), &node("C"), Some(1)).is_none());
+    let path = vec![node("A"), node("B"), node("C")];
+
+    assert!(graph.is_path_valid(&path));
+    // Direction matters: the reversed route was never walkable.
+    let reversed: Vec<DefaultNode> = path.iter().rev().cloned().collect();
+    assert!(!graph.is_path_valid(&reversed));
+
+    assert!(graph.remove_edge(&node("B"), &node("C")));
+    assert!(!graph.is_path_valid(&path));
+    assert!(graph.is_path_valid(&path[..2]));
+}
+
+#[test]
+fn undirected_graph_path_validity_ignores_direction_until_edge_removed() {
+    let mut graph = UndirectedGraph::new(vec![node("A"), node("B"), node("C")]);
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(1)).is_none());
+    assert!(graph.insert_edge(&node("B"), &node("C"), Some(1)).is_none());
+    let path = vec![node("C"), node("B"), node("A")];
+
+    assert!(graph.is_path_valid(&path));
+    assert!(!graph.is_path_valid(&[]));
+    assert!(!graph.is_path_valid(&[node("Z")]));
+
+    // Removing with swapped endpoints still drops the edge in both directions.
+    assert!(graph.remove_edge(&node("B"), &node("A")));
+    assert!(!graph.is_path_valid(&path));
+    assert!(!graph.remove_edge(&node("A"), &node("B")));
+}
+
+#[test]
+fn removed_edges_can_no_longer_be_looked_up() {
+    let mut directed = DirectedGraph::new(vec![node("A"), node("B")]);
+    let mut undirected = UndirectedGraph::new(vec![node("A"), node("B")]);
+    assert!(
+        directed
+            .insert_edge_with_kind(&node("A"), &node("B"), Some(4), Some("ferry".to_string()))
+            .is_none()
+    );
+    assert!(
+        directed
+            .insert_edge(&node("B"), &node("A"), Some(6))
+            .is_none()
+    );
+    assert!(
+        undirected
+            .insert_edge(&node("A"), &node("B"), Some(4))
+            .is_none()
+    );
+
+    // Edges are identified by their endpoints, so the lookup uses them too.
+    assert_eq!(directed.edges_between("A", "B").len(), 1);
+    assert!(directed.remove_edge(&node("A"), &node("B")));
+    assert!(directed.edges_between("A", "B").is_empty());
+    assert_eq!(directed.edge_kind(&node("A"), &node("B")), None);
+    // Only the removed direction is gone.
+    assert_eq!(directed.edges_between("B", "A").len(), 1);
+
+    assert!(undirected.remove_edge(&node("A"), &node("B")));
+    assert!(undirected.edges_between("A", "B").is_empty());
+    assert!(undirected.edges_between("B", "A").is_empty());
+    assert!(undirected.all_edges().is_empty());
+}
+
+#[test]
+fn degree_queries_count_incoming_and_outgoing_edges() {
+    // B has two incoming edges (A, C), one outgoing edge (D), and a self-loop.
+    let edges: Vec<(String, String, u16)> = [("A", "B"), ("C", "B"), ("B", "D"), ("B", "B")]
+        .iter()
+        .map(|(from, to)| (from.to_string(), to.to_string(), 1))
+        .collect();
+    let directed = DirectedGraph::from_edges(&edges).expect("edges should build a graph");
+    assert_eq!(directed.in_degree("B"), Some(3));
+    assert_eq!(directed.out_degree(&node("B")), 2);
+    assert_eq!(directed.degree("B"), Some(5));
+    assert_eq!(directed.in_degree("A"), Some(0));
+    assert_eq!(directed.degree("A"), Some(1));
+    assert_eq!(directed.in_degree("missing"), None);
+    assert_eq!(directed.degree("missing"), None);
+
+    let undirected: UndirectedGraph = [("A", "B"), ("C", "B"), ("B", "D"), ("B", "B")]
+        .iter()
+        .map(|(a, b)| (node(a), node(b), 1))
+        .collect();
+    assert_eq!(undirected.degree("B"), Some(4));
+    assert_eq!(undirected.degree("D"), Some(1));
+    assert_eq!(undirected.degree("missing"), None);
+
+    // The trait default agrees with the directed override.
+    let mut unweighted = UnweightedGraph::new_directed(vec![node("A"), node("B"), node("C")]);
+    assert!(
+        unweighted
+            .insert_edge(&node("A"), &node("B"), None)
+            .is_none()
+    );
+    assert!(
+        unweighted
+            .insert_edge(&node("C"), &node("B"), None)
+            .is_none()
+    );
+    assert_eq!(unweighted.degree("B"), Some(2));
+}
+
+#[test]
+fn out_degree_matches_neighbor_count_for_all_graph_types() {
+    let mut directed = DirectedGraph::new(vec![node("A"), node("B"), node("C")]);
+    assert!(
+        directed
+            .insert_edge(&node("A"), &node("B"), Some(1))
+            .is_none()
+    );
+    assert!(
+        directed
+            .insert_edge(&node("A"), &node("C"), Some(1))
+            .is_none()
+    );
+    assert!(
+        directed
+            .insert_edge(&node("B"), &node("A"), Some(1))
+            .is_none()
+    );
+
+    let mut undirected = UndirectedGraph::new(vec![node("A"), node("B"), node("C")]);
+    assert!(
+        undirected
+            .insert_edge(&node("A"), &node("B"), Some(1))
+            .is_none()
+    );
+    assert!(
+        undirected
+            .insert_edge(&node("C"), &node("A"), Some(1))
+            .is_none()
+    );
+    assert!(
+        undirected
+            .insert_edge(&node("C"), &node("C"), Some(1))
+            .is_none()
+    );
+
+    for id in ["A", "B", "C", "missing"] {
+        assert_eq!(
+            directed.out_degree(&node(id)),
+            directed.neighbors(&node(id)).count()
+        );
+        assert_eq!(
+            undirected.out_degree(&node(id)),
+            undirected.neighbors(&node(id)).count()
+        );
+    }
+    assert_eq!(directed.out_degree(&node("A")), 2);
+    assert_eq!(directed.out_degree(&node("C")), 0);
+    assert_eq!(undirected.out_degree(&node("A")), 2);
+    // The self-loop counts once.
+    assert_eq!(undirected.out_degree(&node("C")), 2);
+}
+
+#[test]
+fn edges_between_respects_direction_and_never_returns_parallel_edges() {
+    let mut directed = DirectedGraph::new(vec![node("A"), node("B")]);
+    assert!(
+        directed
+            .insert_edge(&node("A"), &node("B"), Some(3))
+            .is_none()
+    );
+    // A second, parallel A->B edge is rejected, so only one connection exists.
+    assert!(
+        directed
+            .insert_edge(&node("A"), &node("B"), Some(8))
+            .is_some()
+    );
+
+    let edges = directed.edges_between("A", "B");
+    assert_eq!(edges.len(), 1);
+    assert_eq!(
+        (edges[0].0.get_id(), edges[0].1.get_id(), edges[0].2),
+        ("A", "B", 3)
+    );
+    assert!(directed.edges_between("B", "A").is_empty());
+
+    let mut undirected = UndirectedGraph::new(vec![node("A"), node("B")]);
+    assert!(
+        undirected
+            .insert_edge(&node("A"), &node("B"), Some(5))
+            .is_none()
+    );
+    assert!(
+        undirected
+            .insert_edge(&node("B"), &node("A"), Some(2))
+            .is_some()
+    );
+
+    let edges = undirected.edges_between("B", "A");
+    assert_eq!(edges.len(), 1);
+    assert_eq!(
+        (edges[0].0.get_id(), edges[0].1.get_id(), edges[0].2),
+        ("B", "A", 5)
+    );
+    assert!(undirected.edges_between("A", "C").is_empty());
+}
+
+#[test]
+fn undirected_split_components_separates_two_clusters() {
+    let mut graph = UndirectedGraph::default();
+    // Cluster one: triangle A-B-C. Cluster two: X-Y-Z path, with a kind on X-Y.
+    for id in ["A", "X", "B", "Y", "C", "Z"] {
+        graph.insert_node(node(id));
+    }
+    for (a, b, weight) in [("A", "B", 1), ("B", "C", 2), ("C", "A", 3), ("Y", "Z", 8)] {
+        assert!(
+            graph
+                .insert_edge(&node(a), &node(b), Some(weight))
+                .is_none()
+        );
+    }
+    assert!(
+        graph
+            .insert_edge_with_kind(&node("X"), &node("Y"), Some(7), Some("ferry".to_string()))
+            .is_none()
+    );
+
+    let components = graph.split_components();
+
+    assert_eq!(components.len(), 2);
+    let ids = |component: &UndirectedGraph| -> Vec<String> {
+        component
+            .get_all_nodes()
+            .iter()
+            .map(|n| n.get_id().to_string())
+            .collect()
+    };
+    assert_eq!(ids(&components[0]), vec!["A", "B", "C"]);
+    assert_eq!(ids(&components[1]), vec!["X", "Y", "Z"]);
+
+    let triangle = &components[0];
+    assert_eq!(
+        triangle.to_edge_list(),
+        vec![
+            ("A".to_string(), "B".to_string(), 1),
+            ("A".to_string(), "C".to_string(), 3),
+            ("B".to_string(), "C".to_string(), 2),
+        ]
+    );
+    let path = &components[1];
+    assert_eq!(path.edge_kind(&node("Y"), &node("X")), Some("ferry"));
+    assert_eq!(
+        path.edges_between("Y", "Z"),
+        vec![(&node("Y"), &node("Z"), 8)]
+    );
+    assert!(path.get_node_by_id("A").is_none());
+}
+
+#[test]
+fn directed_split_components_uses_weak_connectivity() {
+    let edges: Vec<(String, String, u16)> =
+        [("A", "B", 4), ("C", "B", 5), ("P", "Q", 1), ("Q", "P", 2)]
+            .iter()
+            .map(|(from, to, weight)| (from.to_string(), to.to_string(), *weight))
+            .collect();
+    let mut graph = DirectedGraph::from_edges(&edges).unwrap();
+    graph.insert_node(node("Lonely"));
+
+    let components = graph.split_components();
+
+    assert_eq!(components.len(), 3);
+    // `A -> B <- C` has no directed path from A to C, but is one weak component.
+    assert_eq!(components[0].to_edge_list(), edges[..2].to_vec());
+    assert_eq!(components[1].to_edge_list(), edges[2..].to_vec());
+    assert_eq!(components[2].get_all_nodes(), &vec![node("Lonely")]);
+    assert!(components.iter().all(|component| component.is_directed()));
+    assert!(DirectedGraph::default().split_components().is_empty());
+}
+
+fn edge(from: &str, to: &str, weight: u16) -> (String, String, u16) {
+    (from.to_string(), to.to_string(), weight)
+}
+
+#[test]
+fn directed_try_new_rejects_dangling_and_duplicate_edges() {
+    let nodes = vec![node("A"), node("B")];
+
+    let graph = DirectedGraph::try_new(nodes.clone(), &[edge("A", "B", 1), edge("B", "A", 2)])
+        .expect("consistent edges should be accepted");
+    assert_eq!(graph.to_edge_list().len(), 2);
+
+    let err = DirectedGraph::try_new(nodes.clone(), &[edge("Ghost", "B", 1)])
+        .expect_err("unknown source should be rejected");
+    assert!(err.contains("'Ghost'"), "{}", err);
+
+    let err = DirectedGraph::try_new(nodes.clone(), &[edge("A", "Ghost", 1)])
+        .expect_err("unknown target should be rejected");
+    assert!(err.contains("'Ghost'"), "{}", err);
+
+    let err = DirectedGraph::try_new(nodes, &[edge("A", "B", 1), edge("A", "B", 5)])
+        .expect_err("duplicate edge should be rejected");
+    assert!(err.contains("more than once"), "{}", err);
+}
+
+#[test]
+fn undirected_try_new_treats_reversed_edge_as_duplicate() {
+    let nodes = vec![node("A"), node("B"), node("C")];
+
+    let graph = UndirectedGraph::try_new(nodes.clone(), &[edge("A", "B", 1), edge("B", "C", 2)])
+        .expect("consistent edges should be accepted");
+    assert!(graph.does_edge_already_exist(&node("C"), &node("B")));
+
+    let err = UndirectedGraph::try_new(nodes.clone(), &[edge("A", "B", 1), edge("B", "A", 1)])
+        .expect_err("reversed duplicate should be rejected");
+    assert!(err.contains("more than once"), "{}", err);
+
+    let err = UndirectedGraph::try_new(nodes, &[edge("A", "D", 1)])
+        .expect_err("unknown node should be rejected");
+    assert!(err.contains("'D'"), "{}", err);
+}
+
+#[test]
+fn weight_histogram_buckets_known_distribution() {
+    let nodes = vec![node("A"), node("B"), node("C"), node("D")];
+    // Weights 0, 4, 5, 9, 10 and 23 with bucket size 5 -> [0,5): 2, [5,10): 2,
+    // [10,15): 1, [20,25): 1; the empty [15,20) bucket must not appear.
+    let graph = DirectedGraph::try_new(
+        nodes.clone(),
+        &[
+            edge("A", "B", 0),
+            edge("B", "C", 4),
+            edge("C", "D", 5),
+            edge("D", "A", 9),
+            edge("A", "C", 10),
+            edge("B", "D", 23),
+        ],
+    )
+    .expect("edges should be valid");
+
+    let histogram = graph.weight_histogram(5).expect("bucket size is positive");
+    assert_eq!(
+        histogram.into_iter().collect::<Vec<_>>(),
+        vec![(0, 2), (5, 2), (10, 1), (20, 1)]
+    );
+
+    let err = graph
+        .weight_histogram(0)
+        .expect_err("zero bucket size should be rejected");
+    assert!(err.contains("greater than zero"), "{}", err);
+
+    // Undirected edges are stored twice internally but must be counted once.
+    let undirected = UndirectedGraph::try_new(nodes, &[edge("A", "B", 3), edge("C", "D", 3)])
+        .expect("edges should be valid");
+    let histogram = undirected
+        .weight_histogram(1)
+        .expect("bucket size is positive");
+    assert_eq!(histogram.into_iter().collect::<Vec<_>>(), vec![(3, 2)]);
+}
+
+#[test]
+fn iter_nodes_follows_insertion_order_like_get_all_nodes() {
+    let mut directed = DirectedGraph::default();
+    let mut undirected = UndirectedGraph::default();
+    for id in ["C", "A", "B"] {
+        directed.insert_node(node(id));
+        undirected.insert_node(node(id));
+    }
+
+    let directed_ids: Vec<&str> = directed.iter_nodes().map(|n| n.get_id()).collect();
+    assert_eq!(directed_ids, vec!["C", "A", "B"]);
+    assert!(directed.iter_nodes().eq(directed.get_all_nodes().iter()));
+    assert!(
+        undirected
+            .iter_nodes()
+            .eq(undirected.get_all_nodes().iter())
+    );
+}
+
+#[test]
+fn directed_induced_by_edges_drops_nodes_left_isolated() {
+    let nodes = vec![node("A"), node("B"), node("C"), node("D"), node("Lonely")];
+    let mut graph = DirectedGraph::new(nodes);
+    graph.insert_edge_with_kind(&node("A"), &node("B"), Some(5), Some("highway".to_string()));
+    graph.insert_edge_with_kind(&node("C"), &node("B"), Some(2), Some("highway".to_string()));
+    graph.insert_edge_with_kind(&node("C"), &node("D"), Some(1), Some("ferry".to_string()));
+    graph.insert_edge(&node("D"), &node("A"), Some(7));
+
+    let highways = graph.induced_by_edges(|_, _, _, kind| kind == Some("highway"));
+
+    // D only had ferry/untyped edges and Lonely had none, so both disappear.
+    let ids: Vec<&str> = highways.iter_nodes().map(|n| n.get_id()).collect();
+    assert_eq!(ids, vec!["A", "B", "C"]);
+    assert_eq!(
+        highways.to_edge_list(),
+        vec![edge("A", "B", 5), edge("C", "B", 2)]
+    );
+    assert_eq!(highways.edge_kind(&node("C"), &node("B")), Some("highway"));
+
+    // The source graph is untouched.
+    assert_eq!(graph.get_all_nodes().len(), 5);
+}
+
+#[test]
+fn undirected_induced_by_edges_judges_each_edge_once() {
+    let nodes = vec![node("A"), node("B"), node("C"), node("D")];
+    let graph = UndirectedGraph::try_new(
+        nodes,
+        &[edge("A", "B", 1), edge("C", "B", 8), edge("C", "D", 2)],
+    )
+    .expect("edges should be valid");
+
+    // The predicate sees the earlier-inserted endpoint as `from`: B for B-C.
+    let kept = graph.induced_by_edges(|from, _, weight, _| weight < 5 || from.get_id() == "B");
+    assert_eq!(kept.to_edge_list().len(), 3);
+
+    let cheap = graph.induced_by_edges(|_, _, weight, _| weight < 5);
+    assert_eq!(
+        cheap.to_edge_list(),
+        vec![edge("A", "B", 1), edge("C", "D", 2)]
+    );
+    // Both directions of a dropped edge are gone.
+    assert!(!cheap.does_edge_already_exist(&node("B"), &node("C")));
+    assert!(!cheap.does_edge_already_exist(&node("C"), &node("B")));
+
+    let none = graph.induced_by_edges(|_, _, _, _| false);
+    assert!(none.get_all_nodes().is_empty());
+}
+
+#[test]
+fn unweighted_graph_respects_direction_and_rejects_duplicates() {
+    let (a, b, c) = (node("A"), node("B"), node("C"));
+
+    let mut directed = UnweightedGraph::new_directed(vec![a.clone(), b.clone(), c.clone()]);
+    assert!(directed.insert_edge(&a, &b, None).is_none());
+    assert!(directed.insert_edge(&a, &b, None).is_some());
+    assert!(directed.does_edge_already_exist(&a, &b));
+    assert!(!directed.does_edge_already_exist(&b, &a));
+    assert!(directed.insert_edge(&a, &node("X"), None).is_some());
+
+    let mut undirected = UnweightedGraph::new_undirected(vec![a.clone(), b.clone(), c.clone()]);
+    assert!(undirected.insert_edge(&a, &b, None).is_none());
+    // B-A is the same undirected edge as A-B.
+    assert!(undirected.insert_edge(&b, &a, None).is_some());
+    assert!(undirected.insert_edge(&c, &c, None).is_none());
+    assert_eq!(undirected.out_degree(&b), 1);
+    assert_eq!(undirected.out_degree(&c), 1);
+    assert!(!undirected.is_weighted());
+}
+
+#[test]
+fn two_way_edges_stay_single_edges() {
+    let (a, b, c) = (node("A"), node("B"), node("C"));
+    let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone(), c.clone()]);
+    assert!(graph.insert_two_way_edge(&a, &b, 7, 9, None).is_none());
+    assert!(graph.insert_edge(&b, &a, Some(1)).is_some());
+    assert!(graph.insert_two_way_edge(&c, &c, 1, 2, None).is_some());
+
+    let bridges = graph.bridges();
+    assert_eq!(bridges.len(), 1);
+    assert_eq!((bridges[0].weight, bridges[0].reverse_weight), (7, 9));
+    assert_eq!(bridges[0].to_string(), "A - B (7|9)");
+
+    // The edge is judged once by its A -> B weight, so both directions go together.
+    let kept = graph.induced_by_edges(|_, _, weight, _| weight == 7);
+    let kept_b = kept.get_node_by_id("B").unwrap();
+    assert_eq!(kept.neighbors(kept_b).next().map(|(_, w)| w), Some(9));
+    assert!(
+        graph
+            .induced_by_edges(|_, _, weight, _| weight == 9)
+            .get_all_nodes()
+            .is_empty()
+    );
+}
+
+#[test]
+fn edge_exports_keep_only_the_forward_weight_of_two_way_edges() {
+    let (a, b) = (node("A"), node("B"));
+    let mut graph = UndirectedGraph::new(vec![a.clone(), b.clone()]);
+    assert!(graph.insert_two_way_edge(&a, &b, 7, 9, None).is_none());
+
+    assert_eq!(
+        graph.to_edge_list(),
+        vec![("A".to_string(), "B".to_string(), 7)]
+    );
+    assert_eq!(
+        graph.to_dot(),
+        "graph {\n    \"A\" -- \"B\" [label=\"7\"];\n}\n"
+    );
+    // The reverse weight is still there for callers that need it.
+    assert_eq!(graph.neighbors(&b).next().map(|(_, w)| w), Some(9));
+}
+
+#[test]
+fn min_incident_edge_picks_cheapest_edge() {
+    let nodes: Vec<DefaultNode> = ["A", "B", "C", "D", "E"]
+        .iter()
+        .map(|id| node(id))
+        .collect();
+    let edges = [("A", "B", 8), ("A", "C", 3), ("D", "A", 1), ("A", "E", 3)];
+
+    let mut directed = DirectedGraph::new(nodes.clone());
+    let mut undirected = UndirectedGraph::new(nodes);
+    for (from, to, weight) in edges {
+        assert!(
+            directed
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+        assert!(
+            undirected
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+    let cheapest = |edge: Option<(&DefaultNode, &DefaultNode, u16)>| {
+        edge.map(|(from, to, weight)| (from.get_id().to_string(), to.get_id().to_string(), weight))
+    };
+
+    // Directed: D -> A is incoming, so the cheapest outgoing edge wins; the
+    // C/E tie keeps the earlier-inserted edge.
+    assert_eq!(
+        cheapest(directed.min_incident_edge(&node("A"))),
+        Some(("A".to_string(), "C".to_string(), 3))
+    );
+    assert_eq!(cheapest(directed.min_incident_edge(&node("B"))), None);
+
+    // Undirected: every incident edge counts.
+    assert_eq!(
+        cheapest(undirected.min_incident_edge(&node("A"))),
+        Some(("A".to_string(), "D".to_string(), 1))
+    );
+    assert_eq!(
+        cheapest(undirected.min_incident_edge(&node("B"))),
+        Some(("B".to_string(), "A".to_string(), 8))
+    );
+    undirected.insert_node(node("Lonely"));
+    assert_eq!(
+        cheapest(undirected.min_incident_edge(&node("Lonely"))),
+        None
+    );
+    assert_eq!(cheapest(undirected.min_incident_edge(&node("Ghost"))), None);
+}
+
+#[test]
+fn adjacent_respects_direction_only_for_directed_graphs() {
+    let nodes = vec![node("A"), node("B"), node("C")];
+    let mut directed = DirectedGraph::new(nodes.clone());
+    let mut undirected = UndirectedGraph::new(nodes);
+    assert!(
+        directed
+            .insert_edge(&node("A"), &node("B"), Some(2))
+            .is_none()
+    );
+    assert!(
+        undirected
+            .insert_edge(&node("A"), &node("B"), Some(2))
+            .is_none()
+    );
+
+    assert!(directed.adjacent("A", "B"));
+    assert!(!directed.adjacent("B", "A"));
+    assert!(undirected.adjacent("A", "B"));
+    assert!(undirected.adjacent("B", "A"));
+
+    // Unconnected and unknown nodes are never adjacent.
+    assert!(!directed.adjacent("A", "C"));
+    assert!(!directed.adjacent("Ghost", "A"));
+    assert!(!undirected.adjacent("C", "A"));
+    assert!(!undirected.adjacent("A", "Ghost"));
+}
+
+#[test]
+fn remove_node_leaves_no_dangling_edges() {
+    let ids = ["A", "B", "C", "D"];
+    let mut directed = DirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+    let mut undirected = UndirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+    for (from, to, weight) in [("A", "B", 3), ("B", "C", 1), ("C", "B", 4), ("C", "D", 2)] {
+        assert!(
+            directed
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+    for (from, to, weight) in [("A", "B", 3), ("C", "B", 1), ("C", "D", 2)] {
+        assert!(
+            undirected
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+    assert!(directed.set_edge_layer(&node("C"), &node("D"), Some("bus".to_string())));
+
+    // B has incoming and outgoing edges in the directed graph.
+    assert_eq!(directed.remove_node("B"), Some(node("B")));
+    assert!(!directed.does_node_already_exist(&node("B")));
+    assert_eq!(
+        directed.to_edge_list(),
+        vec![("C".to_string(), "D".to_string(), 2)]
+    );
+    // Re-indexing keeps the layer on the surviving edge.
+    assert_eq!(directed.edge_layer(&node("C"), &node("D")), Some("bus"));
+
+    assert_eq!(undirected.remove_node("B"), Some(node("B")));
+    assert_eq!(undirected.neighbors(&node("A")).count(), 0);
+    assert_eq!(undirected.neighbors(&node("C")).count(), 1);
+    assert!(!undirected.adjacent("C", "B"));
+
+    assert_eq!(directed.remove_node("B"), None);
+    assert_eq!(undirected.remove_node("Ghost"), None);
+    assert_eq!(directed.get_all_nodes().len(), 3);
+}
+
+#[test]
+fn node_lookup_by_id_survives_removal_and_reinsertion() {
+    // The constructor ignores the duplicate "A" when it builds the ID index.
+    let ids = ["A", "B", "A", "C", "D"];
+    let mut directed = DirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+    let mut undirected = UndirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+    assert_eq!(directed.get_all_nodes().len(), 4);
+    assert_eq!(undirected.get_all_nodes().len(), 4);
+
+    // Removing B shifts C and D down; the index has to follow them.
+    assert_eq!(directed.remove_node("B"), Some(node("B")));
+    assert_eq!(undirected.remove_node("B"), Some(node("B")));
+    for id in ["A", "C", "D"] {
+        assert_eq!(directed.get_node_by_id(id), Some(&node(id)));
+        assert_eq!(undirected.get_node_by_id(id), Some(&node(id)));
+    }
+    assert_eq!(directed.get_node_by_id("B"), None);
+    assert_eq!(undirected.get_node_by_id("B"), None);
+
+    // A re-inserted node is found again and reaches the shifted nodes.
+    directed.insert_node(node("B"));
+    undirected.insert_node(node("B"));
+    assert_eq!(directed.get_node_by_id("B"), Some(&node("B")));
+    assert!(
+        directed
+            .insert_edge(&node("B"), &node("D"), Some(2))
+            .is_none()
+    );
+    assert!(
+        undirected
+            .insert_edge(&node("D"), &node("B"), Some(2))
+            .is_none()
+    );
+    assert!(directed.adjacent("B", "D"));
+    assert!(undirected.adjacent("B", "D"));
+    assert!(!directed.adjacent("B", "C"));
+}
+
+#[test]
+fn remove_isolated_nodes_keeps_every_node_on_an_edge() {
+    let ids = ["A", "B", "C", "D", "Loop", "Lonely"];
+    let mut directed = DirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+    let mut undirected = UndirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+    for (from, to, weight) in [("A", "B", 3), ("C", "D", 1), ("Loop", "Loop", 2)] {
+        assert!(
+            directed
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+        assert!(
+            undirected
+                .insert_edge(&node(from), &node(to), Some(weight))
+                .is_none()
+        );
+    }
+    assert!(directed.remove_edge(&node("C"), &node("D")));
+    assert!(undirected.remove_edge(&node("D"), &node("C")));
+
+    // B only has an incoming edge and Loop only a self-loop; both stay.
+    let remaining = |nodes: &Vec<DefaultNode>| -> Vec<String> {
+        nodes.iter().map(|node| node.get_id().to_string()).collect()
+    };
+    assert_eq!(
+        directed.remove_isolated_nodes(),
+        vec![node("C"), node("D"), node("Lonely")]
+    );
+    assert_eq!(remaining(directed.get_all_nodes()), vec!["A", "B", "Loop"]);
+    assert_eq!(
+        undirected.remove_isolated_nodes(),
+        vec![node("C"), node("D"), node("Lonely")]
+    );
+    assert_eq!(
+        remaining(undirected.get_all_nodes()),
+        vec!["A", "B", "Loop"]
+    );
+
+    // Edges survive the re-indexing, and a second call finds nothing.
+    assert_eq!(
+        directed.to_edge_list(),
+        vec![
+            ("A".to_string(), "B".to_string(), 3),
+            ("Loop".to_string(), "Loop".to_string(), 2),
+        ]
+    );
+    assert!(undirected.adjacent("B", "A"));
+    assert!(directed.get_node_by_id("Lonely").is_none());
+    assert!(directed.remove_isolated_nodes().is_empty());
+}
+
+#[test]
+fn simplify_chains_sums_weights_along_contracted_chains() {
+    // Undirected chain A - B - C - D - E with direction-dependent costs and a
+    // triangle C - X - Y - C hanging off C.
+    let ids = ["A", "B", "C", "D", "E", "X", "Y"];
+    let mut undirected = UndirectedGraph::new(ids.iter().map(|id| node(id)).collect());
+    for (a, b, forward, backward) in [
+        ("A", "B", 1, 10),
+        ("B", "C", 2, 20),
+        ("C", "D", 3, 30),
+        ("D", "E", 4, 40),
+        ("C", "X", 1, 1),
+        ("X", "Y", 1, 1),
+        ("Y", "C", 1, 1),
+    ] {
+        assert!(
+            undirected
+                .insert_two_way_edge(&node(a), &node(b), forward, backward, None)
+                .is_none()
+        );
+    }
+
+    // B and D are contracted. X and Y stay: contracting X would need a second
+    // edge between C and Y.
+    let protected: HashSet<String> = HashSet::from(["A".to_string(), "E".to_string()]);
+    assert_eq!(undirected.simplify_chains(&protected), 2);
+    let weight = |graph: &UndirectedGraph, from: &str, to: &str| {
+        graph
+            .neighbors(&node(from))
+            .find(|(neighbour, _)| neighbour.get_id() == to)
+            .map(|(_, weight)| weight)
+    };
+    assert_eq!(weight(&undirected, "A", "C"), Some(3));
+    assert_eq!(weight(&undirected, "C", "A"), Some(30));
+    assert_eq!(weight(&undirected, "C", "E"), Some(7));
+    assert_eq!(weight(&undirected, "E", "C"), Some(70));
+    assert!(undirected.get_node_by_id("B").is_none());
+    assert_eq!(undirected.get_all_nodes().len(), 5);
+    assert_eq!(undirected.simplify_chains(&protected), 0);
+
+    // Even with nothing protected, the ends of a one-way chain survive since
+    // each touches a single node. The shared kind carries over.
+    let mut directed = DirectedGraph::new(["P", "Q", "R", "S"].iter().map(|id| node(id)).collect());
+    for (from, to, weight) in [("P", "Q", 5), ("Q", "R", 6), ("R", "S", 7)] {
+        assert!(
+            directed
+                .insert_edge_with_kind(
+                    &node(from),
+                    &node(to),
+                    Some(weight),
+                    Some("road".to_string())
+                )
+                .is_none()
+        );
+    }
+    assert_eq!(directed.simplify_chains(&HashSet::new()), 2);
+    assert_eq!(
+        directed.to_edge_list(),
+        vec![("P".to_string(), "S".to_string(), 18)]
+    );
+    assert_eq!(directed.edge_kind(&node("P"), &node("S")), Some("road"));
+}
+
+#[test]
+fn graphs_collect_from_edge_tuples() {
+    let edges = vec![
+        (node("A"), node("B"), 4),
+        (node("B"), node("C"), 2),
+        (node("A"), node("B"), 9),
+        (node("C"), node("B"), 5),
+    ];
+
+    let directed: DirectedGraph = edges.clone().into_iter().collect();
+    let ids = |nodes: &Vec<DefaultNode>| -> Vec<String> {
+        nodes.iter().map(|node| node.get_id().to_string()).collect()
+    };
+    assert_eq!(ids(directed.get_all_nodes()), vec!["A", "B", "C"]);
+    // The repeated A -> B keeps its first weight; C -> B is a distinct edge.
+    assert_eq!(
+        directed.to_edge_list(),
+        vec![
+            ("A".to_string(), "B".to_string(), 4),
+            ("B".to_string(), "C".to_string(), 2),
+            ("C".to_string(), "B".to_string(), 5),
+        ]
+    );
+
+    // Undirected, C - B repeats B - C as well.
+    let undirected: UndirectedGraph = edges.into_iter().collect();
+    assert_eq!(ids(undirected.get_all_nodes()), vec!["A", "B", "C"]);
+    assert_eq!(
+        undirected.to_edge_list(),
+        vec![
+            ("A".to_string(), "B".to_string(), 4),
+            ("B".to_string(), "C".to_string(), 2),
+        ]
+    );
+}
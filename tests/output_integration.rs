@@ -0,0 +1,153 @@
+//! Integration tests for result formatting.
+//!
+//! These tests check the exact text produced for distance tables because
+//! downstream tools (spreadsheets, scripts) depend on a stable shape.
+
+use std::io::{self, Write};
+
+use shortest_path_finder::{
+    algorithms::{
+        algorithm::{Algorithm, SearchResult},
+        dijkstra::DijkstraAlgorithm,
+    },
+    graphs::{
+        directed::DirectedGraph,
+        graph::{Graph, GraphNode},
+        undirected::UndirectedGraph,
+    },
+    nodes::default_node::DefaultNode,
+    output::{distance_table::write_distance_table, output_format::OutputFormat},
+};
+
+fn node(id: &str) -> DefaultNode {
+    DefaultNode::new(id.to_string())
+}
+
+fn render(rows: &[(String, Option<u16>)], format: OutputFormat) -> String {
+    let mut buffer = Vec::new();
+    write_distance_table(&mut buffer, rows, &format).expect("writing to a buffer should succeed");
+    String::from_utf8(buffer).expect("table output should be valid UTF-8")
+}
+
+#[test]
+fn distance_table_csv_leaves_unreachable_cells_empty() {
+    let mut graph = DirectedGraph::default();
+    for id in ["A", "B", "C"] {
+        graph.insert_node(node(id));
+    }
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(7)).is_none());
+
+    let node_ids: Vec<String> = graph
+        .get_all_nodes()
+        .iter()
+        .map(|n| n.get_id().to_string())
+        .collect();
+    let distances = DijkstraAlgorithm::new(graph)
+        .distances_from("A")
+        .expect("distances should be computed");
+    let rows: Vec<(String, Option<u16>)> = node_ids
+        .into_iter()
+        .map(|id| {
+            let distance = distances.get(&id).copied();
+            (id, distance)
+        })
+        .collect();
+
+    assert_eq!(
+        render(&rows, OutputFormat::Csv),
+        "node,distance\nA,0\nB,7\nC,\n"
+    );
+}
+
+#[test]
+fn distance_table_quotes_fields_containing_the_delimiter() {
+    let rows = vec![("A,1".to_string(), Some(3u16))];
+
+    assert_eq!(
+        render(&rows, OutputFormat::Csv),
+        "node,distance\n\"A,1\",3\n"
+    );
+    assert_eq!(render(&rows, OutputFormat::Tsv), "node\tdistance\nA,1\t3\n");
+}
+
+#[test]
+fn distance_table_text_marks_unreachable_nodes() {
+    let rows = vec![("A".to_string(), Some(0u16)), ("B".to_string(), None)];
+
+    assert_eq!(render(&rows, OutputFormat::Text), "A: 0\nB: unreachable\n");
+}
+
+/// Writer that records what was flushed, to observe streaming.
+#[derive(Default)]
+struct FlushRecorder {
+    pending: Vec<u8>,
+    flushed: Vec<String>,
+}
+
+impl Write for FlushRecorder {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.pending.is_empty() {
+            let chunk = String::from_utf8(std::mem::take(&mut self.pending))
+                .expect("output should be valid UTF-8");
+            self.flushed.push(chunk);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn search_result_to_json_lists_path_ids_and_distance() {
+    let mut graph = DirectedGraph::new(vec![node("A"), node("B"), node("C"), node("D")]);
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(4)).is_none());
+    assert!(graph.insert_edge(&node("B"), &node("D"), Some(5)).is_none());
+    assert!(graph.insert_edge(&node("A"), &node("C"), Some(2)).is_none());
+    assert!(graph.insert_edge(&node("C"), &node("D"), Some(9)).is_none());
+
+    let result = DijkstraAlgorithm::new(graph)
+        .shortest_path("A", "D")
+        .expect("D is reachable");
+    assert_eq!(result.to_json(), r#"{"path":["A","B","D"],"distance":9}"#);
+}
+
+#[test]
+fn distance_table_json_maps_nodes_to_distances() {
+    let rows = vec![
+        ("A".to_string(), Some(0)),
+        ("B\"x".to_string(), Some(7)),
+        ("C".to_string(), None),
+    ];
+    assert_eq!(
+        render(&rows, OutputFormat::Json),
+        "{\"A\":0,\"B\\\"x\":7,\"C\":null}\n"
+    );
+}
+
+#[test]
+fn all_pairs_to_writer_streams_csv_per_source() {
+    let nodes = vec![node("A"), node("B"), node("C,D")];
+    let mut graph = UndirectedGraph::new(nodes.clone());
+    assert!(graph.insert_edge(&nodes[0], &nodes[1], Some(4)).is_none());
+    graph.insert_node(node("Island"));
+
+    let mut recorder = FlushRecorder::default();
+    DijkstraAlgorithm::new(graph)
+        .all_pairs_to_writer(&mut recorder)
+        .expect("writing to memory should succeed");
+
+    // One flushed chunk per source; the header leaves with the first one.
+    assert_eq!(
+        recorder.flushed,
+        vec![
+            "from,to,distance\nA,A,0\nA,B,4\n",
+            "B,A,4\nB,B,0\n",
+            "\"C,D\",\"C,D\",0\n",
+            "Island,Island,0\n",
+        ]
+    );
+    assert!(recorder.pending.is_empty());
+}
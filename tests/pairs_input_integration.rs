@@ -0,0 +1,71 @@
+//! Integration tests for reading batch query pairs.
+//!
+//! These tests cover the `<start> <end>` line format used by `--pairs` and the
+//! errors raised for malformed lines, plus how the CLI reports pairs it
+//! cannot answer.
+
+use std::io::Write;
+
+use shortest_path_finder::{
+    cmd_line::runner::run_from_args,
+    data_input::{file_input::FileInputError, pairs_input::retrieve_pairs_from_file},
+    error::pathfinder_error::PathfinderError,
+};
+use tempfile::NamedTempFile;
+
+fn write_temp_file(contents: &str) -> NamedTempFile {
+    let mut file = NamedTempFile::new().expect("temp file creation should succeed");
+    file.write_all(contents.as_bytes())
+        .expect("temp file write should succeed");
+    file.flush().expect("temp file flush should succeed");
+    file
+}
+
+#[test]
+fn pairs_are_returned_in_file_order() {
+    let file = write_temp_file("A D\nB C\n   \n\tA   C \nA D\n");
+
+    let pairs = retrieve_pairs_from_file(file.path().to_str().unwrap())
+        .expect("every line is a valid pair");
+
+    // Repeated pairs are kept so each request gets its own output line.
+    let expected: Vec<(String, String)> = [("A", "D"), ("B", "C"), ("A", "C"), ("A", "D")]
+        .iter()
+        .map(|(start, end)| (start.to_string(), end.to_string()))
+        .collect();
+    assert_eq!(pairs, expected);
+}
+
+#[test]
+fn pairs_reject_lines_without_exactly_two_nodes() {
+    for contents in ["A D\nB\n", "A D\nB C D\n"] {
+        let file = write_temp_file(contents);
+        let err = retrieve_pairs_from_file(file.path().to_str().unwrap())
+            .expect_err("line 2 is malformed");
+        assert!(matches!(err, FileInputError::Parse(_)), "{}", err);
+        assert!(err.to_string().contains("line 2"), "{}", err);
+    }
+}
+
+#[test]
+fn pairs_on_a_graph_without_weights_name_the_missing_weights() {
+    let graph = write_temp_file("D\nA->B\nB->C\n");
+    let pairs = write_temp_file("A C\n");
+    let args = [
+        "pathfinder",
+        "--graph-file",
+        graph.path().to_str().unwrap(),
+        "--pairs",
+        pairs.path().to_str().unwrap(),
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect();
+
+    let err = run_from_args(args).expect_err("batched Dijkstra needs weights");
+
+    // BFS cannot answer pairs either, so the error must not suggest it.
+    assert!(matches!(err, PathfinderError::Unsupported(_)), "{}", err);
+    assert!(err.to_string().contains("'--pairs'"), "{}", err);
+    assert!(!err.to_string().contains("--algo BFS"), "{}", err);
+}
@@ -0,0 +1,107 @@
+//! Integration tests for serializing graphs with serde.
+//!
+//! The tests round-trip graphs through JSON and check that nodes, weights,
+//! and edge metadata survive. The whole file only compiles with the `serde`
+//! feature.
+
+#![cfg(feature = "serde")]
+
+use shortest_path_finder::{
+    graphs::{
+        directed::{DirectedGraph, SignedDirectedGraph},
+        graph::Graph,
+        undirected::UndirectedGraph,
+    },
+    nodes::default_node::DefaultNode,
+};
+
+fn node(id: &str) -> DefaultNode {
+    DefaultNode::new(id.to_string())
+}
+
+#[test]
+fn directed_graph_round_trips_through_json() {
+    let mut graph = DirectedGraph::new(vec![node("A"), node("B"), node("C"), node("Lonely")]);
+    assert!(
+        graph
+            .insert_edge_with_kind(&node("A"), &node("B"), Some(7), Some("highway".to_string()))
+            .is_none()
+    );
+    assert!(graph.insert_edge(&node("B"), &node("C"), Some(2)).is_none());
+    assert!(graph.insert_edge(&node("C"), &node("A"), Some(4)).is_none());
+    assert!(graph.set_edge_layer(&node("B"), &node("C"), Some("train".to_string())));
+
+    let json = graph.to_json().expect("graph should serialize");
+    let restored = DirectedGraph::from_json(&json).expect("json should deserialize");
+
+    // Nodes keep their order, including the one without edges.
+    assert_eq!(restored.get_all_nodes(), graph.get_all_nodes());
+    assert_eq!(restored.to_edge_list(), graph.to_edge_list());
+    assert_eq!(restored.edge_kind(&node("A"), &node("B")), Some("highway"));
+    assert_eq!(restored.edge_layer(&node("B"), &node("C")), Some("train"));
+    assert!(restored.is_weighted());
+}
+
+#[test]
+fn undirected_graph_round_trip_keeps_two_way_weights() {
+    let mut graph = UndirectedGraph::new(vec![node("A"), node("B"), node("C")]);
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(3)).is_none());
+    assert!(
+        graph
+            .insert_two_way_edge(&node("B"), &node("C"), 5, 8, None)
+            .is_none()
+    );
+    graph.mark_weights_implicit();
+
+    let json = graph.to_json().expect("graph should serialize");
+    let restored = UndirectedGraph::from_json(&json).expect("json should deserialize");
+
+    assert_eq!(restored.get_all_nodes(), graph.get_all_nodes());
+    assert_eq!(restored.to_edge_list(), graph.to_edge_list());
+    // The direction-specific weight survives the round trip.
+    assert_eq!(
+        restored.neighbors(&node("C")).next().map(|(_, w)| w),
+        Some(8)
+    );
+    assert!(!restored.is_weighted());
+}
+
+#[test]
+fn signed_graph_keeps_negative_weights() {
+    let edges = vec![("A".to_string(), "B".to_string(), -3)];
+    let graph = SignedDirectedGraph::from_edges(&edges).expect("edges should build a graph");
+
+    let restored =
+        SignedDirectedGraph::from_json(&graph.to_json().expect("graph should serialize"))
+            .expect("json should deserialize");
+    assert_eq!(restored.to_edge_list(), edges);
+}
+
+#[test]
+fn from_json_rejects_inconsistent_documents() {
+    // Dangling endpoint.
+    let dangling = r#"{"nodes":[{"id":"A"}],"edges":[{"from":"A","to":"B","weight":1}]}"#;
+    assert!(DirectedGraph::<u16>::from_json(dangling).is_err());
+    assert!(UndirectedGraph::from_json(dangling).is_err());
+
+    // The same undirected edge listed in both directions.
+    let duplicate = r#"{"nodes":[{"id":"A"},{"id":"B"}],"edges":[
+        {"from":"A","to":"B","weight":1},
+        {"from":"B","to":"A","weight":1}
+    ]}"#;
+    assert!(UndirectedGraph::from_json(duplicate).is_err());
+
+    assert!(DirectedGraph::<u16>::from_json("not json").is_err());
+}
+
+#[test]
+fn multigraph_round_trip_keeps_parallel_edges() {
+    let mut graph = DirectedGraph::new_multigraph(vec![node("A"), node("B")]);
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(7)).is_none());
+    assert!(graph.insert_edge(&node("A"), &node("B"), Some(3)).is_none());
+
+    let restored = DirectedGraph::from_json(&graph.to_json().expect("graph should serialize"))
+        .expect("json should deserialize");
+    assert!(restored.is_multigraph());
+    assert_eq!(restored.to_edge_list(), graph.to_edge_list());
+}
@@ -0,0 +1,149 @@
+//! Integration tests for SQLite graph loading.
+//!
+//! Each test writes a small database into a temporary file, so the schema
+//! documented in `data_input::sqlite_input` is exercised end to end. The
+//! whole file only compiles with the `sqlite` feature.
+
+#![cfg(feature = "sqlite")]
+
+use rusqlite::Connection;
+use shortest_path_finder::{
+    data_input::{
+        file_input::FileInputGraphResult,
+        sqlite_input::{SqliteInputError, retrieve_graph_data_from_sqlite},
+    },
+    graphs::graph::Graph,
+};
+use tempfile::NamedTempFile;
+
+/// Creates a database with the documented schema and runs `inserts` on it.
+fn write_temp_database(inserts: &str) -> NamedTempFile {
+    let file = NamedTempFile::new().expect("temp file creation should succeed");
+    let connection = Connection::open(file.path()).expect("database should open");
+    connection
+        .execute_batch(&format!(
+            r#"
+            CREATE TABLE nodes (id TEXT NOT NULL PRIMARY KEY);
+            CREATE TABLE edges (
+                "from" TEXT NOT NULL,
+                "to" TEXT NOT NULL,
+                weight INTEGER NOT NULL,
+                directed INTEGER NOT NULL
+            );
+            {}
+            "#,
+            inserts
+        ))
+        .expect("schema and rows should be written");
+    file
+}
+
+/// Loads the database behind `file`.
+fn load(file: &NamedTempFile) -> Result<FileInputGraphResult, SqliteInputError> {
+    retrieve_graph_data_from_sqlite(&file.path().to_string_lossy())
+}
+
+#[test]
+fn sqlite_loader_builds_directed_graph() {
+    let file = write_temp_database(
+        "INSERT INTO nodes VALUES ('A'), ('B'), ('C'), ('Lonely');
+         INSERT INTO edges VALUES ('A', 'B', 4, 1), ('B', 'C', 2, 1), ('A', 'B', 9, 1);",
+    );
+
+    let graph = load(&file)
+        .expect("database should load")
+        .directed_graph
+        .expect("directed graph expected");
+
+    assert_eq!(graph.get_all_nodes().len(), 4);
+    // The duplicate `A -> B` row is ignored, like duplicate lines in files.
+    assert_eq!(
+        graph.to_edge_list(),
+        vec![
+            ("A".to_string(), "B".to_string(), 4),
+            ("B".to_string(), "C".to_string(), 2),
+        ]
+    );
+}
+
+#[test]
+fn sqlite_loader_builds_undirected_graph() {
+    let file = write_temp_database(
+        "INSERT INTO nodes VALUES ('A'), ('B');
+         INSERT INTO edges VALUES ('A', 'B', 7, 0);",
+    );
+
+    let parsed = load(&file).expect("database should load");
+    assert!(parsed.directed_graph.is_none());
+    let graph = parsed.undirected_graph.expect("undirected graph expected");
+    let a = graph.get_node_by_id("A").unwrap();
+    let b = graph.get_node_by_id("B").unwrap();
+    assert!(graph.does_edge_already_exist(b, a));
+}
+
+#[test]
+fn sqlite_loader_rejects_mixed_edge_directions() {
+    let file = write_temp_database(
+        "INSERT INTO nodes VALUES ('A'), ('B'), ('C');
+         INSERT INTO edges VALUES ('A', 'B', 1, 1), ('B', 'C', 1, 0);",
+    );
+
+    let err = load(&file).expect_err("mixed directions should be rejected");
+    assert!(matches!(err, SqliteInputError::Parse(_)));
+    assert!(err.to_string().contains("mixes directed and undirected"));
+}
+
+#[test]
+fn sqlite_loader_rejects_edges_to_unknown_nodes() {
+    let file = write_temp_database(
+        "INSERT INTO nodes VALUES ('A');
+         INSERT INTO edges VALUES ('A', 'Ghost', 1, 1);",
+    );
+
+    let err = load(&file).expect_err("unknown node should be rejected");
+    assert!(matches!(err, SqliteInputError::Parse(_)));
+}
+
+#[test]
+fn sqlite_loader_rejects_weights_outside_u16() {
+    let file = write_temp_database(
+        "INSERT INTO nodes VALUES ('A'), ('B');
+         INSERT INTO edges VALUES ('A', 'B', -1, 1);",
+    );
+
+    assert!(matches!(
+        load(&file),
+        Err(SqliteInputError::Database { .. })
+    ));
+}
+
+#[test]
+fn sqlite_loader_handles_empty_tables() {
+    let empty = write_temp_database("");
+    let graph = load(&empty)
+        .expect("empty database should load")
+        .directed_graph
+        .expect("empty database defaults to a directed graph");
+    assert!(graph.get_all_nodes().is_empty());
+
+    let nodes_only = write_temp_database("INSERT INTO nodes VALUES ('A'), ('B');");
+    let graph = load(&nodes_only)
+        .expect("nodes without edges should load")
+        .directed_graph
+        .expect("edgeless database defaults to a directed graph");
+    assert_eq!(graph.get_all_nodes().len(), 2);
+    assert!(graph.to_edge_list().is_empty());
+}
+
+#[test]
+fn sqlite_loader_reports_missing_tables() {
+    let file = NamedTempFile::new().expect("temp file creation should succeed");
+    Connection::open(file.path())
+        .and_then(|connection| connection.execute_batch("CREATE TABLE unrelated (x INTEGER);"))
+        .expect("database should be writable");
+
+    assert!(matches!(
+        load(&file),
+        Err(SqliteInputError::Database { .. })
+    ));
+}
@@ -0,0 +1,59 @@
+//! Integration tests for interactive terminal graph input.
+//!
+//! Typed lines are simulated with in-memory readers, covering the two ways
+//! to finish input (empty line and end of input) and the empty-input error.
+
+use shortest_path_finder::{
+    algorithms::{
+        algorithm::{Algorithm, SearchResult},
+        dijkstra::DijkstraAlgorithm,
+    },
+    data_input::{
+        file_input::{FileInputError, FileInputOptions},
+        terminal_input::retrieve_graph_data_from_lines,
+    },
+    graphs::graph::Graph,
+};
+
+#[test]
+fn terminal_input_stops_at_empty_line() {
+    let typed = "D\nA->B:7\nB->C:2\nA->C:12\n\nC->A:1\n";
+    let parsed = retrieve_graph_data_from_lines(typed.as_bytes(), &FileInputOptions::default())
+        .expect("typed graph should parse");
+    let graph = parsed
+        .directed_graph
+        .expect("header D builds a directed graph");
+
+    // The edge after the empty line was not read.
+    assert_eq!(graph.to_edge_list().len(), 3);
+    let result = DijkstraAlgorithm::new(graph)
+        .shortest_path("A", "C")
+        .expect("path should exist");
+    assert_eq!(result.get_total_distance(), 9);
+}
+
+#[test]
+fn terminal_input_accepts_end_of_input_without_trailing_newline() {
+    // Ctrl-D after the last edge, with Windows line endings on the others.
+    let typed = "UN\r\nA-B:4\r\nB-C:1";
+    let parsed = retrieve_graph_data_from_lines(typed.as_bytes(), &FileInputOptions::default())
+        .expect("typed graph should parse");
+    let graph = parsed
+        .undirected_graph
+        .expect("header UN builds an undirected graph");
+    assert_eq!(graph.to_edge_list().len(), 2);
+}
+
+#[test]
+fn terminal_input_reports_empty_and_invalid_input() {
+    for typed in ["", "\n", "   \nD\nA->B:1\n"] {
+        let err = retrieve_graph_data_from_lines(typed.as_bytes(), &FileInputOptions::default())
+            .unwrap_err();
+        assert!(matches!(err, FileInputError::Parse(_)), "{typed:?}");
+        assert!(err.to_string().contains("No graph data was entered"));
+    }
+
+    let err = retrieve_graph_data_from_lines("D\nA=>B\n".as_bytes(), &FileInputOptions::default())
+        .unwrap_err();
+    assert!(matches!(err, FileInputError::Parse(_)));
+}
@@ -0,0 +1,116 @@
+//! Integration tests for loading a topology file joined with a weight table.
+//!
+//! These tests cover the join for both graph types and the errors raised when
+//! the two files do not agree.
+
+use std::io::Write;
+
+use shortest_path_finder::{
+    data_input::{
+        file_input::FileInputError, weight_table_input::retrieve_graph_with_weight_table,
+    },
+    graphs::graph::Graph,
+};
+use tempfile::NamedTempFile;
+
+fn write_temp_file(contents: &str) -> NamedTempFile {
+    let mut file = NamedTempFile::new().expect("temp file creation should succeed");
+    file.write_all(contents.as_bytes())
+        .expect("temp file write should succeed");
+    file.flush().expect("temp file flush should succeed");
+    file
+}
+
+/// Loads the two given file contents and returns the parse error message.
+fn join_error(topology: &str, weights: &str) -> String {
+    let topology = write_temp_file(topology);
+    let weights = write_temp_file(weights);
+    let err = retrieve_graph_with_weight_table(
+        topology.path().to_str().unwrap(),
+        weights.path().to_str().unwrap(),
+    )
+    .expect_err("the files should not join");
+    assert!(matches!(err, FileInputError::Parse(_)), "{}", err);
+    err.to_string()
+}
+
+#[test]
+fn weight_table_join_builds_directed_graph() {
+    let topology = write_temp_file("D\nA->B\nB->C@ferry\n\nA->C\n");
+    let weights = write_temp_file("A,B,4\nB,C,2\n\nA,C,9\nC,A,1\n");
+
+    let parsed = retrieve_graph_with_weight_table(
+        topology.path().to_str().unwrap(),
+        weights.path().to_str().unwrap(),
+    )
+    .expect("every edge has a weight");
+    let graph = parsed.directed_graph.expect("topology is directed");
+
+    assert_eq!(
+        graph.to_edge_list(),
+        vec![
+            ("A".to_string(), "B".to_string(), 4),
+            ("A".to_string(), "C".to_string(), 9),
+            ("B".to_string(), "C".to_string(), 2),
+        ]
+    );
+    // The kind suffix survives the join; the unused C->A entry is ignored.
+    let b = graph.get_node_by_id("B").unwrap();
+    let c = graph.get_node_by_id("C").unwrap();
+    assert_eq!(graph.edge_kind(b, c), Some("ferry"));
+    assert!(graph.is_weighted());
+}
+
+#[test]
+fn weight_table_join_matches_undirected_edges_in_either_order() {
+    let topology = write_temp_file("UN\nA-B\nC-B\n");
+    // The C-B edge is listed as B,C in the table.
+    let weights = write_temp_file("A,B,3\nB,C,5\n");
+
+    let parsed = retrieve_graph_with_weight_table(
+        topology.path().to_str().unwrap(),
+        weights.path().to_str().unwrap(),
+    )
+    .expect("every edge has a weight");
+    let graph = parsed.undirected_graph.expect("topology is undirected");
+
+    assert_eq!(graph.edges_between("C", "B")[0].2, 5);
+    assert_eq!(graph.edges_between("A", "B")[0].2, 3);
+}
+
+#[test]
+fn weight_table_join_rejects_edge_without_weight_entry() {
+    let message = join_error("D\nA->B\nB->C\n", "A,B,4\n");
+    assert!(message.contains("line 3"), "{}", message);
+    assert!(
+        message.contains("no entry in the weight table"),
+        "{}",
+        message
+    );
+
+    // Directed edges do not fall back to the reversed table entry.
+    let message = join_error("D\nA->B\n", "B,A,4\n");
+    assert!(
+        message.contains("no entry in the weight table"),
+        "{}",
+        message
+    );
+}
+
+#[test]
+fn weight_table_join_rejects_malformed_inputs() {
+    let message = join_error("D\nA->B\n", "A,B\n");
+    assert!(message.contains("from,to,weight"), "{}", message);
+
+    let message = join_error("D\nA->B\n", "A,B,70000\n");
+    assert!(message.contains("invalid weight"), "{}", message);
+
+    let message = join_error("D\nA->B\n", "A,B,1\nA,B,2\n");
+    assert!(message.contains("repeats the edge"), "{}", message);
+
+    let message = join_error("D\nA->B:5\n", "A,B,1\n");
+    assert!(message.contains("already has a weight"), "{}", message);
+
+    let message = join_error("TD\nA:0,0=>B:1,1\n", "A,B,1\n");
+    assert!(message.contains("Invalid topology header"), "{}", message);
+}